@@ -0,0 +1,203 @@
+//! Throughput benchmarks comparing scalar and wide code paths.
+//!
+//! Run with `cargo bench --bench throughput`. See the "Benchmarks" section of the README
+//! for how to compare a change against a saved baseline.
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ultraviolet::*;
+
+/// Number of scalar elements processed per iteration in every benchmark, so that results for
+/// scalar and wide variants are directly comparable.
+const N: usize = 1024;
+
+/// A tiny deterministic generator, so that benchmarks don't depend on `rand`.
+struct Lcg(u32);
+
+impl Lcg {
+    fn next(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+        (self.0 >> 8) as f32 / (1 << 24) as f32 * 2.0 - 1.0
+    }
+
+    fn vec3(&mut self) -> Vec3 {
+        Vec3::new(self.next(), self.next(), self.next())
+    }
+
+    fn rotor3(&mut self) -> Rotor3 {
+        Rotor3::from_rotation_between(Vec3::unit_z(), self.vec3().normalized())
+    }
+
+    fn mat4(&mut self) -> Mat4 {
+        Mat4::from_translation(self.vec3()) * self.rotor3().into_matrix().into_homogeneous()
+    }
+}
+
+fn vec3s(rng: &mut Lcg) -> Vec<Vec3> {
+    (0..N).map(|_| rng.vec3()).collect()
+}
+
+fn vec3x8s(vs: &[Vec3]) -> Vec<Vec3x8> {
+    vs.chunks_exact(8)
+        .map(|c| Vec3x8::from([c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]]))
+        .collect()
+}
+
+/// Gather eight matrices into the lanes of a wide matrix.
+fn mat4x8(ms: &[Mat4]) -> Mat4x8 {
+    let col = |i: usize| {
+        let mut c = [Vec4::zero(); 8];
+        for (c, m) in c.iter_mut().zip(ms) {
+            *c = m.cols[i];
+        }
+        Vec4x8::from(c)
+    };
+    Mat4x8::new(col(0), col(1), col(2), col(3))
+}
+
+fn rotor_application(c: &mut Criterion) {
+    let mut rng = Lcg(1);
+    let rotor = rng.rotor3();
+    let vs = vec3s(&mut rng);
+    let vs8 = vec3x8s(&vs);
+
+    let mut group = c.benchmark_group("rotor3_rotate_vec");
+    group.throughput(Throughput::Elements(N as u64));
+    group.bench_function("scalar", |b| {
+        let mut vs = vs.clone();
+        b.iter(|| black_box(rotor).rotate_vecs(black_box(&mut vs)))
+    });
+    group.bench_function("x8", |b| {
+        let rotor = Rotor3x8::new(
+            f32x8::splat(rotor.s),
+            Bivec3x8::new(
+                f32x8::splat(rotor.bv.xy),
+                f32x8::splat(rotor.bv.xz),
+                f32x8::splat(rotor.bv.yz),
+            ),
+        );
+        let mut vs8 = vs8.clone();
+        b.iter(|| {
+            for v in vs8.iter_mut() {
+                black_box(rotor).rotate_vec(v);
+            }
+        })
+    });
+    group.finish();
+}
+
+fn mat4_mul(c: &mut Criterion) {
+    let mut rng = Lcg(2);
+    let ms: Vec<Mat4> = (0..N).map(|_| rng.mat4()).collect();
+    let ms8: Vec<Mat4x8> = ms.chunks_exact(8).map(mat4x8).collect();
+    let rhs = rng.mat4();
+
+    let mut group = c.benchmark_group("mat4_mul");
+    group.throughput(Throughput::Elements(N as u64));
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            for m in ms.iter() {
+                black_box(*m * black_box(rhs));
+            }
+        })
+    });
+    group.bench_function("x8", |b| {
+        let rhs = mat4x8(&[rhs; 8]);
+        b.iter(|| {
+            for m in ms8.iter() {
+                black_box(*m * black_box(rhs));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn vec3_normalize(c: &mut Criterion) {
+    let mut rng = Lcg(3);
+    let vs = vec3s(&mut rng);
+    let vs8 = vec3x8s(&vs);
+
+    let mut group = c.benchmark_group("vec3_normalize");
+    group.throughput(Throughput::Elements(N as u64));
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            for v in vs.iter() {
+                black_box(v.normalized());
+            }
+        })
+    });
+    group.bench_function("x8", |b| {
+        b.iter(|| {
+            for v in vs8.iter() {
+                black_box(v.normalized());
+            }
+        })
+    });
+    group.finish();
+}
+
+fn projection(c: &mut Criterion) {
+    let mut rng = Lcg(4);
+    let vs = vec3s(&mut rng);
+    let vs8 = vec3x8s(&vs);
+    let view = Mat4::look_at(Vec3::new(0.0, 0.0, 5.0), Vec3::zero(), Vec3::unit_y());
+    let proj = projection::perspective_wgpu_dx(1.0, 16.0 / 9.0, 0.1, 100.0);
+    let clip = proj * view;
+
+    let mut group = c.benchmark_group("project_points");
+    group.throughput(Throughput::Elements(N as u64));
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            for v in vs.iter() {
+                black_box(black_box(clip).transform_point3(*v));
+            }
+        })
+    });
+    group.bench_function("x8", |b| {
+        let clip = mat4x8(&[clip; 8]);
+        b.iter(|| {
+            for v in vs8.iter() {
+                black_box(black_box(clip).transform_point3(*v));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn transform_batches(c: &mut Criterion) {
+    let mut rng = Lcg(5);
+    let isos: Vec<Isometry3> = (0..N)
+        .map(|_| Isometry3::new(rng.vec3(), rng.rotor3()))
+        .collect();
+    let vs = vec3s(&mut rng);
+
+    let mut group = c.benchmark_group("isometry3");
+    group.throughput(Throughput::Elements(N as u64));
+    group.bench_function(BenchmarkId::new("transform_vec", "scalar"), |b| {
+        b.iter(|| {
+            for (iso, v) in isos.iter().zip(vs.iter()) {
+                black_box(iso.transform_vec(*v));
+            }
+        })
+    });
+    group.bench_function(BenchmarkId::new("inverse", "scalar"), |b| {
+        b.iter(|| {
+            for iso in isos.iter() {
+                black_box(iso.inversed());
+            }
+        })
+    });
+    group.bench_function(BenchmarkId::new("inverse", "invert_all"), |b| {
+        let mut isos = isos.clone();
+        b.iter(|| Isometry3::invert_all(black_box(&mut isos)))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    rotor_application,
+    mat4_mul,
+    vec3_normalize,
+    projection,
+    transform_batches
+);
+criterion_main!(benches);
@@ -0,0 +1,382 @@
+//! Axis-aligned bounding boxes in 2d and 3d.
+//!
+//! In 2d, an axis-aligned bounding box is the same thing as a rectangle, so [`Aabb2`] is also
+//! available under the name [`Rect`], which may read more naturally in UI or 2d game code.
+use crate::*;
+
+macro_rules! aabb2s {
+    ($($n:ident => ($mt:ident, $vt:ident, $t:ident)),+) => {
+        $(/// An axis-aligned bounding box in 2d space, i.e. a rectangle.
+        ///
+        /// Defined by its `min` and `max` corners. A box with any component of `min` greater than
+        /// the corresponding component of `max` is considered empty.
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[repr(C)]
+        pub struct $n {
+            pub min: $vt,
+            pub max: $vt,
+        }
+
+        impl $n {
+            #[inline]
+            pub const fn new(min: $vt, max: $vt) -> Self {
+                Self { min, max }
+            }
+
+            /// Create a box centered at `center` which has a total width and height of `size`.
+            #[inline]
+            pub fn from_center_size(center: $vt, size: $vt) -> Self {
+                let half = size * 0.5;
+                Self::new(center - half, center + half)
+            }
+
+            /// Create a box of the given `size` which is placed such that the point at `anchor`
+            /// (see [`Self::anchor_point`]) ends up at `position`.
+            ///
+            /// For example, an `anchor` of `(0.5, 1.0)` will place the middle of the top edge
+            /// (in a y-up space) at `position`.
+            #[inline]
+            pub fn from_anchor(anchor: $vt, position: $vt, size: $vt) -> Self {
+                let min = position - anchor * size;
+                Self::new(min, min + size)
+            }
+
+            /// Create the smallest box containing all of the given points.
+            ///
+            /// If `points` is empty, the returned box is empty.
+            #[inline]
+            pub fn from_points(points: &[$vt]) -> Self {
+                points.iter().fold(Self::empty(), |aabb, &p| aabb.including(p))
+            }
+
+            /// A box which contains nothing, and which acts as the identity for [`Self::union`].
+            #[inline]
+            pub fn empty() -> Self {
+                Self::new($vt::broadcast($t::INFINITY), $vt::broadcast($t::NEG_INFINITY))
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.min.x > self.max.x || self.min.y > self.max.y
+            }
+
+            #[inline]
+            pub fn size(&self) -> $vt {
+                self.max - self.min
+            }
+
+            #[inline]
+            pub fn width(&self) -> $t {
+                self.max.x - self.min.x
+            }
+
+            #[inline]
+            pub fn height(&self) -> $t {
+                self.max.y - self.min.y
+            }
+
+            #[inline]
+            pub fn area(&self) -> $t {
+                self.width() * self.height()
+            }
+
+            #[inline]
+            pub fn center(&self) -> $vt {
+                (self.min + self.max) * 0.5
+            }
+
+            /// The point inside this box at the normalized coordinates `anchor`, where
+            /// `(0.0, 0.0)` is `min` and `(1.0, 1.0)` is `max`.
+            #[inline]
+            pub fn anchor_point(&self, anchor: $vt) -> $vt {
+                self.min + anchor * self.size()
+            }
+
+            /// The normalized coordinates of `point` relative to this box, i.e. the inverse
+            /// of [`Self::anchor_point`].
+            #[inline]
+            pub fn normalized_coords(&self, point: $vt) -> $vt {
+                (point - self.min) / self.size()
+            }
+
+            /// Whether `point` lies inside this box or on its boundary.
+            #[inline]
+            pub fn contains(&self, point: $vt) -> bool {
+                point.x >= self.min.x && point.x <= self.max.x
+                    && point.y >= self.min.y && point.y <= self.max.y
+            }
+
+            /// Whether `other` lies entirely inside this box.
+            #[inline]
+            pub fn contains_aabb(&self, other: Self) -> bool {
+                self.contains(other.min) && self.contains(other.max)
+            }
+
+            /// Whether this box and `other` overlap, including touching at their boundaries.
+            #[inline]
+            pub fn intersects(&self, other: Self) -> bool {
+                self.min.x <= other.max.x && other.min.x <= self.max.x
+                    && self.min.y <= other.max.y && other.min.y <= self.max.y
+            }
+
+            /// The smallest box containing both this box and `other`.
+            #[inline]
+            pub fn union(&self, other: Self) -> Self {
+                Self::new(
+                    self.min.min_by_component(other.min),
+                    self.max.max_by_component(other.max),
+                )
+            }
+
+            /// The region where this box and `other` overlap, or `None` if they do not.
+            #[inline]
+            pub fn intersection(&self, other: Self) -> Option<Self> {
+                let res = Self::new(
+                    self.min.max_by_component(other.min),
+                    self.max.min_by_component(other.max),
+                );
+                if res.is_empty() {
+                    None
+                } else {
+                    Some(res)
+                }
+            }
+
+            /// Grow this box so that it contains `point`.
+            #[inline]
+            pub fn include(&mut self, point: $vt) {
+                self.min = self.min.min_by_component(point);
+                self.max = self.max.max_by_component(point);
+            }
+
+            /// Return a copy of this box grown so that it contains `point`.
+            #[inline]
+            pub fn including(mut self, point: $vt) -> Self {
+                self.include(point);
+                self
+            }
+
+            /// Grow this box by `amount` on every side. A negative `amount` shrinks it.
+            #[inline]
+            pub fn expand(&mut self, amount: $t) {
+                self.min -= $vt::broadcast(amount);
+                self.max += $vt::broadcast(amount);
+            }
+
+            /// Return a copy of this box grown by `amount` on every side. A negative `amount`
+            /// shrinks it.
+            #[inline]
+            pub fn expanded(mut self, amount: $t) -> Self {
+                self.expand(amount);
+                self
+            }
+
+            /// Move this box by `offset`.
+            #[inline]
+            pub fn translate(&mut self, offset: $vt) {
+                self.min += offset;
+                self.max += offset;
+            }
+
+            /// Return a copy of this box moved by `offset`.
+            #[inline]
+            pub fn translated(mut self, offset: $vt) -> Self {
+                self.translate(offset);
+                self
+            }
+
+            /// A homogeneous 2d transformation matrix which maps the unit square from
+            /// `(0.0, 0.0)` to `(1.0, 1.0)` onto this box.
+            #[inline]
+            pub fn into_homogeneous_matrix(self) -> $mt {
+                $mt::from_translation(self.min) * $mt::from_nonuniform_scale_homogeneous(self.size())
+            }
+
+            /// A homogeneous 2d transformation matrix which maps this box onto `other`.
+            #[inline]
+            pub fn mapping_to(&self, other: Self) -> $mt {
+                let scale = other.size() / self.size();
+                $mt::from_translation(other.min - self.min * scale)
+                    * $mt::from_nonuniform_scale_homogeneous(scale)
+            }
+        }
+        )+
+    };
+}
+
+macro_rules! aabb3s {
+    ($($n:ident => ($vt:ident, $t:ident)),+) => {
+        $(/// An axis-aligned bounding box in 3d space.
+        ///
+        /// Defined by its `min` and `max` corners. A box with any component of `min` greater than
+        /// the corresponding component of `max` is considered empty.
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[repr(C)]
+        pub struct $n {
+            pub min: $vt,
+            pub max: $vt,
+        }
+
+        impl $n {
+            #[inline]
+            pub const fn new(min: $vt, max: $vt) -> Self {
+                Self { min, max }
+            }
+
+            /// Create a box centered at `center` which has a total extent of `size` along each axis.
+            #[inline]
+            pub fn from_center_size(center: $vt, size: $vt) -> Self {
+                let half = size * 0.5;
+                Self::new(center - half, center + half)
+            }
+
+            /// Create the smallest box containing all of the given points.
+            ///
+            /// If `points` is empty, the returned box is empty.
+            #[inline]
+            pub fn from_points(points: &[$vt]) -> Self {
+                points.iter().fold(Self::empty(), |aabb, &p| aabb.including(p))
+            }
+
+            /// A box which contains nothing, and which acts as the identity for [`Self::union`].
+            #[inline]
+            pub fn empty() -> Self {
+                Self::new($vt::broadcast($t::INFINITY), $vt::broadcast($t::NEG_INFINITY))
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+            }
+
+            #[inline]
+            pub fn size(&self) -> $vt {
+                self.max - self.min
+            }
+
+            #[inline]
+            pub fn center(&self) -> $vt {
+                (self.min + self.max) * 0.5
+            }
+
+            /// The eight corners of this box.
+            #[inline]
+            pub fn corners(&self) -> [$vt; 8] {
+                let (a, b) = (self.min, self.max);
+                [
+                    $vt::new(a.x, a.y, a.z),
+                    $vt::new(b.x, a.y, a.z),
+                    $vt::new(a.x, b.y, a.z),
+                    $vt::new(b.x, b.y, a.z),
+                    $vt::new(a.x, a.y, b.z),
+                    $vt::new(b.x, a.y, b.z),
+                    $vt::new(a.x, b.y, b.z),
+                    $vt::new(b.x, b.y, b.z),
+                ]
+            }
+
+            /// Whether `point` lies inside this box or on its boundary.
+            #[inline]
+            pub fn contains(&self, point: $vt) -> bool {
+                point.x >= self.min.x && point.x <= self.max.x
+                    && point.y >= self.min.y && point.y <= self.max.y
+                    && point.z >= self.min.z && point.z <= self.max.z
+            }
+
+            /// Whether this box and `other` overlap, including touching at their boundaries.
+            #[inline]
+            pub fn intersects(&self, other: Self) -> bool {
+                self.min.x <= other.max.x && other.min.x <= self.max.x
+                    && self.min.y <= other.max.y && other.min.y <= self.max.y
+                    && self.min.z <= other.max.z && other.min.z <= self.max.z
+            }
+
+            /// The smallest box containing both this box and `other`.
+            #[inline]
+            pub fn union(&self, other: Self) -> Self {
+                Self::new(
+                    self.min.min_by_component(other.min),
+                    self.max.max_by_component(other.max),
+                )
+            }
+
+            /// Grow this box so that it contains `point`.
+            #[inline]
+            pub fn include(&mut self, point: $vt) {
+                self.min = self.min.min_by_component(point);
+                self.max = self.max.max_by_component(point);
+            }
+
+            /// Return a copy of this box grown so that it contains `point`.
+            #[inline]
+            pub fn including(mut self, point: $vt) -> Self {
+                self.include(point);
+                self
+            }
+        }
+        )+
+    };
+}
+
+aabb2s!(Aabb2 => (Mat3, Vec2, f32));
+
+#[cfg(feature = "f64")]
+aabb2s!(DAabb2 => (DMat3, DVec2, f64));
+
+aabb3s!(Aabb3 => (Vec3, f32));
+
+#[cfg(feature = "f64")]
+aabb3s!(DAabb3 => (DVec3, f64));
+
+/// A rectangle, which is another name for an [`Aabb2`].
+pub type Rect = Aabb2;
+
+/// A rectangle, which is another name for a [`DAabb2`].
+#[cfg(feature = "f64")]
+pub type DRect = DAabb2;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rect_union_intersection() {
+        let a = Rect::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0));
+        let b = Rect::from_center_size(Vec2::new(2.0, 2.0), Vec2::new(2.0, 2.0));
+        let c = Rect::new(Vec2::new(5.0, 5.0), Vec2::new(6.0, 6.0));
+
+        assert_eq!(
+            a.union(b),
+            Rect::new(Vec2::new(0.0, 0.0), Vec2::new(3.0, 3.0))
+        );
+        assert_eq!(
+            a.intersection(b),
+            Some(Rect::new(Vec2::new(1.0, 1.0), Vec2::new(2.0, 2.0)))
+        );
+        assert_eq!(a.intersection(c), None);
+        assert!(a.intersects(b));
+        assert!(!a.intersects(c));
+        assert!(a.contains(Vec2::new(1.0, 2.0)));
+        assert!(!a.contains(Vec2::new(1.0, 2.5)));
+        assert_eq!(Rect::empty().union(a), a);
+    }
+
+    #[test]
+    fn rect_anchor_and_matrix() {
+        let r = Rect::from_anchor(
+            Vec2::new(0.5, 1.0),
+            Vec2::new(10.0, 10.0),
+            Vec2::new(4.0, 2.0),
+        );
+        assert_eq!(r, Rect::new(Vec2::new(8.0, 8.0), Vec2::new(12.0, 10.0)));
+        assert_eq!(r.anchor_point(Vec2::new(0.5, 1.0)), Vec2::new(10.0, 10.0));
+
+        let m = r.into_homogeneous_matrix();
+        assert_eq!(m.transform_point2(Vec2::new(1.0, 1.0)), r.max);
+
+        let other = Rect::new(Vec2::new(-1.0, -1.0), Vec2::new(1.0, 1.0));
+        let m = r.mapping_to(other);
+        assert_eq!(m.transform_point2(r.min), other.min);
+        assert_eq!(m.transform_point2(r.max), other.max);
+    }
+}
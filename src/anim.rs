@@ -0,0 +1,385 @@
+//! Sampling of keyframed animation tracks, such as the animation channels of a glTF file.
+//!
+//! A track is a list of keyframe times in increasing order along with the values at those times.
+//! Sampling it finds the keyframes around the sample time with a binary search and interpolates
+//! between them using the track's [`Interpolation`]. Sample times before the first keyframe or
+//! after the last one are clamped to it.
+//!
+//! Each track type also has a `sample_x8` function which samples eight tracks, for example the
+//! same channel of eight bones, at once. The keyframe search is done per track, while all the
+//! interpolation is done with the wide types.
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! use ultraviolet::anim::{Interpolation, Vec3Track};
+//!
+//! let times = [0.0, 1.0, 3.0];
+//! let values = [Vec3::zero(), Vec3::unit_x(), Vec3::unit_y()];
+//! let track = Vec3Track::new(&times, &values, Interpolation::Linear);
+//! assert_eq!(track.sample(0.5), Vec3::new(0.5, 0.0, 0.0));
+//! assert_eq!(track.sample(2.0), Vec3::new(0.5, 0.5, 0.0));
+//! assert_eq!(track.sample(10.0), Vec3::unit_y());
+//! ```
+use crate::*;
+
+/// How values are interpolated between keyframes, matching the `interpolation` property of
+/// glTF animation samplers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// Hold the value of each keyframe until the next one.
+    Step,
+    /// Linear interpolation for vectors, and spherical linear interpolation for rotors.
+    Linear,
+    /// Cubic Hermite spline interpolation. Every keyframe has three values in the track: its
+    /// in-tangent, its value and its out-tangent, in that order. Tangents are per second, as in
+    /// glTF. Interpolated rotors are normalized.
+    CubicSpline,
+}
+
+/// Find the keyframe segment of `times` which contains `t`.
+///
+/// Returns the index of the keyframe at the start of the segment and how far `t` is between it
+/// and the next keyframe, from 0.0 to 1.0. `t` is clamped to the range of `times`, and a
+/// single keyframe gives `(0, 0.0)`. `times` must be sorted. Returns `None` if `times` is empty.
+#[inline]
+pub fn find_keyframe(times: &[f32], t: f32) -> Option<(usize, f32)> {
+    if times.len() < 2 {
+        return times.first().map(|_| (0, 0.0));
+    }
+    let i = times
+        .partition_point(|&key| key <= t)
+        .saturating_sub(1)
+        .min(times.len() - 2);
+    let duration = times[i + 1] - times[i];
+    let s = if duration > 0.0 {
+        ((t - times[i]) / duration).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    Some((i, s))
+}
+
+/// The weights of the start value, start tangent, end value and end tangent of a cubic Hermite
+/// spline at `s`.
+macro_rules! hermite_weights {
+    ($s:expr, $t:ident) => {{
+        let s = $s;
+        let s2 = s * s;
+        let s3 = s2 * s;
+        let two = $t::splat(2.0);
+        let three = $t::splat(3.0);
+        (
+            two * s3 - three * s2 + $t::splat(1.0),
+            s3 - two * s2 + s,
+            three * s2 - two * s3,
+            s3 - s2,
+        )
+    }};
+}
+
+/// The keyframe values around a sample time, in Hermite form: start value, start tangent scaled
+/// by the segment duration, end value and scaled end tangent.
+struct Segment<T> {
+    start: T,
+    start_tangent: T,
+    end: T,
+    end_tangent: T,
+    s: f32,
+}
+
+/// A keyframed track of `Vec3` values, such as a translation or scale channel.
+///
+/// A track without keyframes samples to zero.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec3Track<'a> {
+    pub times: &'a [f32],
+    pub values: &'a [Vec3],
+    pub interpolation: Interpolation,
+}
+
+impl<'a> Vec3Track<'a> {
+    /// Create a track. For [`Interpolation::CubicSpline`], `values` must have three entries
+    /// per keyframe, and otherwise one.
+    #[inline]
+    pub fn new(times: &'a [f32], values: &'a [Vec3], interpolation: Interpolation) -> Self {
+        debug_assert_eq!(values.len(), values_len(times, interpolation));
+        Self {
+            times,
+            values,
+            interpolation,
+        }
+    }
+
+    /// Sample the track at time `t`.
+    #[inline]
+    pub fn sample(&self, t: f32) -> Vec3 {
+        let seg = self.segment(t);
+        let (h0, h1, h2, h3) = hermite_weights!(seg.s, f32);
+        seg.start * h0 + seg.start_tangent * h1 + seg.end * h2 + seg.end_tangent * h3
+    }
+
+    /// Sample eight tracks, each at the time in the corresponding lane of `t`.
+    ///
+    /// The tracks may have different keyframes and interpolation modes.
+    pub fn sample_x8(tracks: &[Self; 8], t: f32x8) -> Vec3x8 {
+        let t = t.to_array();
+        let segs: [Segment<Vec3>; 8] = core::array::from_fn(|i| tracks[i].segment(t[i]));
+        let (h0, h1, h2, h3) = hermite_weights!(f32x8::from(segs.each_ref().map(|s| s.s)), f32x8);
+        Vec3x8::from(segs.each_ref().map(|s| s.start)) * h0
+            + Vec3x8::from(segs.each_ref().map(|s| s.start_tangent)) * h1
+            + Vec3x8::from(segs.each_ref().map(|s| s.end)) * h2
+            + Vec3x8::from(segs.each_ref().map(|s| s.end_tangent)) * h3
+    }
+
+    /// Step and linear interpolation are expressed as Hermite splines too, so that scalar and
+    /// wide sampling share one formula.
+    fn segment(&self, t: f32) -> Segment<Vec3> {
+        let (i, s) = match find_keyframe(self.times, t) {
+            Some(keyframe) => keyframe,
+            None => {
+                return Segment {
+                    start: Vec3::zero(),
+                    start_tangent: Vec3::zero(),
+                    end: Vec3::zero(),
+                    end_tangent: Vec3::zero(),
+                    s: 0.0,
+                }
+            }
+        };
+        let next = (i + 1).min(self.times.len() - 1);
+        match self.interpolation {
+            Interpolation::Step => {
+                // `s` only reaches 1.0 at or after the last keyframe, whose value then applies.
+                let key = if s >= 1.0 { next } else { i };
+                Segment {
+                    start: self.values[key],
+                    start_tangent: Vec3::zero(),
+                    end: self.values[key],
+                    end_tangent: Vec3::zero(),
+                    s,
+                }
+            }
+            Interpolation::Linear => {
+                let delta = self.values[next] - self.values[i];
+                Segment {
+                    start: self.values[i],
+                    start_tangent: delta,
+                    end: self.values[next],
+                    end_tangent: delta,
+                    s,
+                }
+            }
+            Interpolation::CubicSpline => {
+                let duration = self.times[next] - self.times[i];
+                Segment {
+                    start: self.values[3 * i + 1],
+                    start_tangent: self.values[3 * i + 2] * duration,
+                    end: self.values[3 * next + 1],
+                    end_tangent: self.values[3 * next] * duration,
+                    s,
+                }
+            }
+        }
+    }
+}
+
+/// A keyframed track of `Rotor3` values, such as a rotation channel.
+///
+/// All the rotors must be normalized. A track without keyframes samples to the identity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rotor3Track<'a> {
+    pub times: &'a [f32],
+    pub values: &'a [Rotor3],
+    pub interpolation: Interpolation,
+}
+
+impl<'a> Rotor3Track<'a> {
+    /// Create a track. For [`Interpolation::CubicSpline`], `values` must have three entries
+    /// per keyframe, and otherwise one.
+    #[inline]
+    pub fn new(times: &'a [f32], values: &'a [Rotor3], interpolation: Interpolation) -> Self {
+        debug_assert_eq!(values.len(), values_len(times, interpolation));
+        Self {
+            times,
+            values,
+            interpolation,
+        }
+    }
+
+    /// Sample the track at time `t`.
+    #[inline]
+    pub fn sample(&self, t: f32) -> Rotor3 {
+        let seg = self.segment(t);
+        if self.interpolation == Interpolation::CubicSpline {
+            let (h0, h1, h2, h3) = hermite_weights!(seg.s, f32);
+            (seg.start * h0 + seg.start_tangent * h1 + seg.end * h2 + seg.end_tangent * h3)
+                .normalized()
+        } else {
+            seg.start.slerp(seg.end, seg.s)
+        }
+    }
+
+    /// Sample eight tracks, each at the time in the corresponding lane of `t`.
+    ///
+    /// The tracks may have different keyframes and interpolation modes.
+    pub fn sample_x8(tracks: &[Self; 8], t: f32x8) -> Rotor3x8 {
+        let t = t.to_array();
+        let segs: [Segment<Rotor3>; 8] = core::array::from_fn(|i| tracks[i].segment(t[i]));
+        let s = f32x8::from(segs.each_ref().map(|s| s.s));
+        let start = Rotor3x8::from(segs.each_ref().map(|s| s.start));
+        let end = Rotor3x8::from(segs.each_ref().map(|s| s.end));
+
+        let (h0, h1, h2, h3) = hermite_weights!(s, f32x8);
+        let cubic = (start * h0
+            + Rotor3x8::from(segs.each_ref().map(|s| s.start_tangent)) * h1
+            + end * h2
+            + Rotor3x8::from(segs.each_ref().map(|s| s.end_tangent)) * h3)
+            .normalized();
+        let slerped = start.slerp(end, s);
+
+        let mask = f32x8::from(tracks.each_ref().map(|track| {
+            if track.interpolation == Interpolation::CubicSpline {
+                f32::from_bits(u32::MAX)
+            } else {
+                0.0
+            }
+        }));
+        Rotor3x8::new(
+            mask.blend(cubic.s, slerped.s),
+            Bivec3x8::new(
+                mask.blend(cubic.bv.xy, slerped.bv.xy),
+                mask.blend(cubic.bv.xz, slerped.bv.xz),
+                mask.blend(cubic.bv.yz, slerped.bv.yz),
+            ),
+        )
+    }
+
+    /// Step interpolation is expressed as a slerp between a keyframe and itself. The tangents
+    /// are only used for cubic interpolation.
+    fn segment(&self, t: f32) -> Segment<Rotor3> {
+        let zero = Rotor3::new(0.0, Bivec3::zero());
+        let (i, s) = match find_keyframe(self.times, t) {
+            Some(keyframe) => keyframe,
+            None => {
+                return Segment {
+                    start: Rotor3::identity(),
+                    start_tangent: zero,
+                    end: Rotor3::identity(),
+                    end_tangent: zero,
+                    s: 0.0,
+                }
+            }
+        };
+        let next = (i + 1).min(self.times.len() - 1);
+        match self.interpolation {
+            Interpolation::Step => {
+                // `s` only reaches 1.0 at or after the last keyframe, whose value then applies.
+                let key = if s >= 1.0 { next } else { i };
+                Segment {
+                    start: self.values[key],
+                    start_tangent: zero,
+                    end: self.values[key],
+                    end_tangent: zero,
+                    s,
+                }
+            }
+            Interpolation::Linear => Segment {
+                start: self.values[i],
+                start_tangent: zero,
+                end: self.values[next],
+                end_tangent: zero,
+                s,
+            },
+            Interpolation::CubicSpline => {
+                let duration = self.times[next] - self.times[i];
+                Segment {
+                    start: self.values[3 * i + 1],
+                    start_tangent: self.values[3 * i + 2] * duration,
+                    end: self.values[3 * next + 1],
+                    end_tangent: self.values[3 * next] * duration,
+                    s,
+                }
+            }
+        }
+    }
+}
+
+#[inline]
+fn values_len(times: &[f32], interpolation: Interpolation) -> usize {
+    match interpolation {
+        Interpolation::CubicSpline => 3 * times.len(),
+        _ => times.len(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    #[test]
+    fn keyframe_search() {
+        let times = [0.0, 1.0, 1.0, 4.0];
+        assert_eq!(find_keyframe(&times, -1.0), Some((0, 0.0)));
+        assert_eq!(find_keyframe(&times, 0.25), Some((0, 0.25)));
+        assert_eq!(find_keyframe(&times, 1.0), Some((2, 0.0)));
+        assert_eq!(find_keyframe(&times, 2.5), Some((2, 0.5)));
+        assert_eq!(find_keyframe(&times, 9.0), Some((2, 1.0)));
+        assert_eq!(find_keyframe(&[3.0], 9.0), Some((0, 0.0)));
+        assert_eq!(find_keyframe(&[], 9.0), None);
+    }
+
+    #[test]
+    fn empty_tracks() {
+        let vecs = Vec3Track::new(&[], &[], Interpolation::Linear);
+        assert_eq!(vecs.sample(1.0), Vec3::zero());
+        let rotors = Rotor3Track::new(&[], &[], Interpolation::CubicSpline);
+        assert_eq!(rotors.sample(1.0), Rotor3::identity());
+        let wide = Rotor3Track::sample_x8(&[rotors; 8], f32x8::ONE);
+        assert_eq!(wide, Rotor3x8::splat(Rotor3::identity()));
+    }
+
+    #[test]
+    fn sample_tracks() {
+        let times = [0.0, 2.0];
+        let values = [Vec3::zero(), Vec3::new(2.0, 4.0, 0.0)];
+        let step = Vec3Track::new(&times, &values, Interpolation::Step);
+        assert_eq!(step.sample(1.9), Vec3::zero());
+        assert_eq!(step.sample(2.0), values[1]);
+
+        // A cubic spline whose tangents match the slope of the segment is a straight line.
+        let slope = Vec3::new(1.0, 2.0, 0.0);
+        let cubic_values = [slope, values[0], slope, slope, values[1], slope];
+        let cubic = Vec3Track::new(&times, &cubic_values, Interpolation::CubicSpline);
+        assert!(cubic.sample(0.5).eq_eps(Vec3::new(0.5, 1.0, 0.0)));
+
+        let rotors = [Rotor3::identity(), Rotor3::from_rotation_xz(1.0)];
+        let linear = Rotor3Track::new(&times, &rotors, Interpolation::Linear);
+        assert!(linear.sample(1.0).eq_eps(Rotor3::from_rotation_xz(0.5)));
+
+        let linear_vec = Vec3Track::new(&times, &values, Interpolation::Linear);
+        let vec_tracks = [
+            step, cubic, linear_vec, step, cubic, linear_vec, step, cubic,
+        ];
+        let ts = [0.5, 0.5, 1.5, 3.0, 1.0, -1.0, 0.0, 2.0];
+        let wide: [Vec3; 8] = Vec3Track::sample_x8(&vec_tracks, f32x8::from(ts)).into();
+        for i in 0..8 {
+            assert!(wide[i].eq_eps(vec_tracks[i].sample(ts[i])));
+        }
+
+        let zero = Rotor3::new(0.0, Bivec3::zero());
+        let cubic_rotors = [zero, rotors[0], zero, zero, rotors[1], zero];
+        let cubic = Rotor3Track::new(&times, &cubic_rotors, Interpolation::CubicSpline);
+        let step = Rotor3Track::new(&times, &rotors, Interpolation::Step);
+        let rotor_tracks = [linear, cubic, step, linear, cubic, step, linear, cubic];
+        let wide = Rotor3Track::sample_x8(&rotor_tracks, f32x8::from(ts));
+        for i in 0..8 {
+            let l = |x: f32x8| x.as_array_ref()[i];
+            let lane = Rotor3::new(
+                l(wide.s),
+                Bivec3::new(l(wide.bv.xy), l(wide.bv.xz), l(wide.bv.yz)),
+            );
+            assert!(lane.eq_eps(rotor_tracks[i].sample(ts[i])));
+        }
+    }
+}
@@ -36,7 +36,7 @@ use crate::*;
 
 use crate::util::*;
 
-use std::ops::*;
+use core::ops::*;
 
 macro_rules! bivec2s {
     ($(($bn:ident) => $t:ident),+) => {
@@ -62,13 +62,13 @@ macro_rules! bivec2s {
             }
 
             #[inline]
-            pub fn zero() -> Self {
-                Self::new($t::splat(0.0))
+            pub const fn zero() -> Self {
+                Self::new(<$t as Consts>::ZERO)
             }
 
             #[inline]
-            pub fn unit_xy() -> Self {
-                Self::new($t::splat(1.0))
+            pub const fn unit_xy() -> Self {
+                Self::new(<$t as Consts>::ONE)
             }
 
             #[inline]
@@ -100,9 +100,42 @@ macro_rules! bivec2s {
                 self.xy * rhs.xy
             }
 
+            #[inline]
+            pub fn mul_add(&self, mul: Self, add: Self) -> Self {
+                Self::new(self.xy.fused_mul_add(mul.xy, add.xy))
+            }
+
+            #[inline]
+            pub fn abs(&self) -> Self {
+                Self::new(self.xy.abs())
+            }
+
+            #[inline]
+            pub fn clamp(&mut self, min: Self, max: Self) {
+                self.xy = self.xy.max(min.xy).min(max.xy);
+            }
+
+            #[inline]
+            pub fn clamped(mut self, min: Self, max: Self) -> Self {
+                self.clamp(min, max);
+                self
+            }
+
+            #[inline]
+            pub fn max_by_component(mut self, other: Self) -> Self {
+                self.xy = self.xy.max(other.xy);
+                self
+            }
+
+            #[inline]
+            pub fn min_by_component(mut self, other: Self) -> Self {
+                self.xy = self.xy.min(other.xy);
+                self
+            }
+
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$t>()).unwrap()
             }
 
             #[inline]
@@ -110,7 +143,7 @@ macro_rules! bivec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $bn as *const $t, 1)
+                    core::slice::from_raw_parts(self as *const $bn as *const $t, 1)
                 }
             }
 
@@ -120,7 +153,7 @@ macro_rules! bivec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $bn as *const u8, std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts(self as *const $bn as *const u8, core::mem::size_of::<$t>())
                 }
             }
 
@@ -129,7 +162,7 @@ macro_rules! bivec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $bn as *mut $t, 1)
+                    core::slice::from_raw_parts_mut(self as *mut $bn as *mut $t, 1)
                 }
             }
 
@@ -138,7 +171,7 @@ macro_rules! bivec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $bn as *mut u8, std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts_mut(self as *mut $bn as *mut u8, core::mem::size_of::<$t>())
                 }
             }
 
@@ -286,6 +319,26 @@ macro_rules! bivec2s {
                 self
             }
         }
+
+        impl Index<usize> for $bn {
+            type Output = $t;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    0 => &self.xy,
+                    _ => panic!("Invalid for bivector of type: {}", core::any::type_name::<$bn>()),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $bn {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                match index {
+                    0 => &mut self.xy,
+                    _ => panic!("Invalid for bivector of type: {}", core::any::type_name::<$bn>()),
+                }
+            }
+        }
         )+
     }
 }
@@ -326,8 +379,8 @@ macro_rules! bivec3s {
             }
 
             #[inline]
-            pub fn zero() -> Self {
-                Self::new($t::splat(0.0), $t::splat(0.0), $t::splat(0.0))
+            pub const fn zero() -> Self {
+                Self::new(<$t as Consts>::ZERO, <$t as Consts>::ZERO, <$t as Consts>::ZERO)
             }
 
             /// Create the bivector which represents the same plane of rotation as a given
@@ -338,18 +391,18 @@ macro_rules! bivec3s {
             }
 
             #[inline]
-            pub fn unit_xy() -> Self {
-                Self::new($t::splat(1.0), $t::splat(0.0), $t::splat(0.0))
+            pub const fn unit_xy() -> Self {
+                Self::new(<$t as Consts>::ONE, <$t as Consts>::ZERO, <$t as Consts>::ZERO)
             }
 
             #[inline]
-            pub fn unit_xz() -> Self {
-                Self::new($t::splat(0.0), $t::splat(1.0), $t::splat(0.0))
+            pub const fn unit_xz() -> Self {
+                Self::new(<$t as Consts>::ZERO, <$t as Consts>::ONE, <$t as Consts>::ZERO)
             }
 
             #[inline]
-            pub fn unit_yz() -> Self {
-                Self::new($t::splat(0.0), $t::splat(0.0), $t::splat(1.0))
+            pub const fn unit_yz() -> Self {
+                Self::new(<$t as Consts>::ZERO, <$t as Consts>::ZERO, <$t as Consts>::ONE)
             }
 
             #[inline]
@@ -383,9 +436,62 @@ macro_rules! bivec3s {
                 (self.xy * rhs.xy) + (self.xz * rhs.xz) + (self.yz * rhs.yz)
             }
 
+            #[inline]
+            pub fn mul_add(&self, mul: Self, add: Self) -> Self {
+                Self::new(
+                    self.xy.fused_mul_add(mul.xy, add.xy),
+                    self.xz.fused_mul_add(mul.xz, add.xz),
+                    self.yz.fused_mul_add(mul.yz, add.yz),
+                )
+            }
+
+            #[inline]
+            pub fn abs(&self) -> Self {
+                Self::new(self.xy.abs(), self.xz.abs(), self.yz.abs())
+            }
+
+            #[inline]
+            pub fn clamp(&mut self, min: Self, max: Self) {
+                self.xy = self.xy.max(min.xy).min(max.xy);
+                self.xz = self.xz.max(min.xz).min(max.xz);
+                self.yz = self.yz.max(min.yz).min(max.yz);
+            }
+
+            #[inline]
+            pub fn clamped(mut self, min: Self, max: Self) -> Self {
+                self.clamp(min, max);
+                self
+            }
+
+            #[inline]
+            pub fn max_by_component(mut self, other: Self) -> Self {
+                self.xy = self.xy.max(other.xy);
+                self.xz = self.xz.max(other.xz);
+                self.yz = self.yz.max(other.yz);
+                self
+            }
+
+            #[inline]
+            pub fn min_by_component(mut self, other: Self) -> Self {
+                self.xy = self.xy.min(other.xy);
+                self.xz = self.xz.min(other.xz);
+                self.yz = self.yz.min(other.yz);
+                self
+            }
+
+            #[inline]
+            pub fn component_max(&self) -> $t {
+                self.xy.max(self.xz).max(self.yz)
+            }
+
+            #[inline]
+            pub fn component_min(&self) -> $t {
+                self.xy.min(self.xz).min(self.yz)
+            }
+
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$t>()).unwrap()
             }
 
             #[inline]
@@ -393,7 +499,7 @@ macro_rules! bivec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $bn as *const $t, 3)
+                    core::slice::from_raw_parts(self as *const $bn as *const $t, 3)
                 }
             }
 
@@ -403,7 +509,7 @@ macro_rules! bivec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $bn as *const u8, 3 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts(self as *const $bn as *const u8, 3 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -412,7 +518,7 @@ macro_rules! bivec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $bn as *mut $t, 3)
+                    core::slice::from_raw_parts_mut(self as *mut $bn as *mut $t, 3)
                 }
             }
 
@@ -421,7 +527,7 @@ macro_rules! bivec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $bn as *mut u8, 3 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts_mut(self as *mut $bn as *mut u8, 3 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -544,7 +650,7 @@ macro_rules! bivec3s {
             type Output = $bn;
             #[inline]
             fn div(mut self, rhs: $t) -> $bn {
-                self.xy /= rhs;
+                self /= rhs;
                 self
             }
         }
@@ -577,6 +683,30 @@ macro_rules! bivec3s {
                 self
             }
         }
+
+        impl Index<usize> for $bn {
+            type Output = $t;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    0 => &self.xy,
+                    1 => &self.xz,
+                    2 => &self.yz,
+                    _ => panic!("Invalid for bivector of type: {}", core::any::type_name::<$bn>()),
+                }
+            }
+        }
+
+        impl IndexMut<usize> for $bn {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                match index {
+                    0 => &mut self.xy,
+                    1 => &mut self.xz,
+                    2 => &mut self.yz,
+                    _ => panic!("Invalid for bivector of type: {}", core::any::type_name::<$bn>()),
+                }
+            }
+        }
         )+
     }
 }
@@ -587,6 +717,9 @@ bivec2s!(
     (Bivec2x8) => f32x8
 );
 
+#[cfg(feature = "portable-simd")]
+bivec2s!((Bivec2x16) => f32x16);
+
 #[cfg(feature = "f64")]
 bivec2s!(
     (DBivec2) => f64,
@@ -600,9 +733,57 @@ bivec3s!(
     Bivec3x8 => (Vec3x8, f32x8)
 );
 
+#[cfg(feature = "portable-simd")]
+bivec3s!(Bivec3x16 => (Vec3x16, f32x16));
+
 #[cfg(feature = "f64")]
 bivec3s!(
     DBivec3 => (DVec3, f64),
     DBivec3x2 => (DVec3x2, f64x2),
     DBivec3x4 => (DVec3x4, f64x4)
 );
+
+crate::util::impl_halves!(Bivec3x8 => Bivec3x4: (xy, xz, yz));
+
+#[cfg(feature = "portable-simd")]
+crate::util::impl_halves!(Bivec3x16 => Bivec3x8: (xy, xz, yz));
+
+#[cfg(feature = "f64")]
+crate::util::impl_halves!(DBivec3x4 => DBivec3x2: (xy, xz, yz));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bivec3_div_scalar() {
+        let b = Bivec3::new(2.0, -4.0, 6.0);
+        assert_eq!(b / 2.0, Bivec3::new(1.0, -2.0, 3.0));
+        let wide = Bivec3x4::new(f32x4::splat(2.0), f32x4::splat(-4.0), f32x4::splat(6.0));
+        assert_eq!(
+            wide / f32x4::splat(2.0),
+            Bivec3x4::new(f32x4::splat(1.0), f32x4::splat(-2.0), f32x4::splat(3.0))
+        );
+    }
+
+    #[test]
+    fn componentwise_ops() {
+        let mut omega = Bivec3::new(3.0, -4.0, 0.5);
+        let limit = Bivec3::new(1.0, 1.0, 1.0);
+        assert_eq!(omega.clamped(-limit, limit), Bivec3::new(1.0, -1.0, 0.5));
+        assert_eq!(omega.abs().component_max(), 4.0);
+        assert_eq!(omega.component_min(), -4.0);
+        assert_eq!(
+            omega.mul_add(Bivec3::new(2.0, 2.0, 2.0), limit),
+            Bivec3::new(7.0, -7.0, 2.0)
+        );
+        assert_eq!(omega.min_by_component(limit), Bivec3::new(1.0, -4.0, 0.5));
+        omega[2] = 2.0;
+        assert_eq!(omega[1], -4.0);
+        assert_eq!(omega.yz, 2.0);
+
+        let wide = Bivec2x4::new(f32x4::from([-2.0, 0.0, 2.0, 5.0]));
+        let clamped = wide.clamped(Bivec2x4::new(f32x4::splat(-1.0)), Bivec2x4::unit_xy());
+        assert_eq!(clamped[0], f32x4::from([-1.0, 0.0, 1.0, 1.0]));
+    }
+}
@@ -0,0 +1,474 @@
+//! Utility functions for working with cameras, such as generating picking rays and framing
+//! bounding boxes.
+//!
+//! As with the [`projection`](crate::projection) module, the function to use is determined by
+//! the graphics api your projection matrix was built for, since that decides the clip space
+//! depth range and the direction of the y axis in normalized device coordinates.
+//!
+//! In all functions here, `viewport` is given as `(x, y, width, height)` in pixels and
+//! `screen` is a pixel position, both with the origin at the *top left* of the window and
+//! +Y pointing down, which is how windowing libraries report cursor positions.
+//!
+//! Functions which build a camera take its `orientation` as the rotation from camera space to
+//! world space, and assume a right-handed, y-up camera space in which the camera looks down -Z,
+//! matching the projections in [`projection::rh_yup`](crate::projection::rh_yup). The view
+//! transform they return maps world space to camera space.
+use crate::*;
+
+/// Unproject a point in normalized device coordinates (xy) through `inv_proj` at each of the
+/// given clip space depths, and build a view space ray from the nearer point towards the farther.
+///
+/// Unprojecting at the far end of the depth range may result in a point at infinity for
+/// infinite or reversed-z projections, so the direction is taken from the midpoint instead.
+#[inline]
+fn view_space_ray(inv_proj: Mat4, ndc: Vec2, z_min: f32) -> Ray3 {
+    let unproject =
+        |z: f32| Vec3::from_homogeneous_point(inv_proj * Vec4::new(ndc.x, ndc.y, z, 1.0));
+
+    let mid = unproject((z_min + 1.0) * 0.5);
+    let mut near = unproject(z_min);
+    // With a reversed-z projection the near plane is at the top of the depth range.
+    if !near.z.is_finite() || near.z.abs() > mid.z.abs() {
+        near = unproject(1.0);
+    }
+
+    Ray3::new(near, (mid - near).normalized())
+}
+
+#[inline]
+fn screen_point_to_view_ray(
+    proj: Mat4,
+    viewport: Vec4,
+    screen: Vec2,
+    y_down: bool,
+    z_min: f32,
+) -> Ray3 {
+    let x = (screen.x - viewport.x) / viewport.z * 2.0 - 1.0;
+    let y = (screen.y - viewport.y) / viewport.w * 2.0 - 1.0;
+    let ndc = Vec2::new(x, if y_down { y } else { -y });
+
+    view_space_ray(proj.inversed(), ndc, z_min)
+}
+
+#[inline]
+fn screen_point_to_ray(
+    view: Mat4,
+    proj: Mat4,
+    viewport: Vec4,
+    screen: Vec2,
+    y_down: bool,
+    z_min: f32,
+) -> Ray3 {
+    let ray = screen_point_to_view_ray(proj, viewport, screen, y_down, z_min);
+    let inv_view = view.inversed();
+    Ray3::new(
+        inv_view.transform_point3(ray.origin),
+        inv_view.transform_vec3(ray.direction).normalized(),
+    )
+}
+
+#[inline]
+fn screen_point_to_ray_isometry(
+    view: Isometry3,
+    proj: Mat4,
+    viewport: Vec4,
+    screen: Vec2,
+    y_down: bool,
+    z_min: f32,
+) -> Ray3 {
+    let ray = screen_point_to_view_ray(proj, viewport, screen, y_down, z_min);
+    let inv_view = view.inversed();
+    Ray3::new(inv_view * ray.origin, inv_view.rotation * ray.direction)
+}
+
+/// Create a world space ray passing through the pixel at `screen`, for use with a projection
+/// matrix built for OpenGL, i.e. one with clip space depth from -1.0 to 1.0 and +Y up in NDC.
+///
+/// The returned ray starts on the near plane and has a normalized direction. `view` is the
+/// world to view space transform; if you have it as an [`Isometry3`], use
+/// [`screen_point_to_ray_isometry_gl`].
+#[inline]
+pub fn screen_point_to_ray_gl(view: Mat4, proj: Mat4, viewport: Vec4, screen: Vec2) -> Ray3 {
+    screen_point_to_ray(view, proj, viewport, screen, false, -1.0)
+}
+
+/// [`screen_point_to_ray_gl`] with the world to view space transform `view` as an
+/// [`Isometry3`], such as the view transforms returned by the functions in this module.
+#[inline]
+pub fn screen_point_to_ray_isometry_gl(
+    view: Isometry3,
+    proj: Mat4,
+    viewport: Vec4,
+    screen: Vec2,
+) -> Ray3 {
+    screen_point_to_ray_isometry(view, proj, viewport, screen, false, -1.0)
+}
+
+/// Create a world space ray passing through the pixel at `screen`, for use with a projection
+/// matrix built for Vulkan, i.e. one with clip space depth from 0.0 to 1.0 and +Y down in NDC.
+///
+/// The returned ray starts on the near plane and has a normalized direction. `view` is the
+/// world to view space transform; if you have it as an [`Isometry3`], use
+/// [`screen_point_to_ray_isometry_vk`].
+#[inline]
+pub fn screen_point_to_ray_vk(view: Mat4, proj: Mat4, viewport: Vec4, screen: Vec2) -> Ray3 {
+    screen_point_to_ray(view, proj, viewport, screen, true, 0.0)
+}
+
+/// [`screen_point_to_ray_vk`] with the world to view space transform `view` as an
+/// [`Isometry3`], such as the view transforms returned by the functions in this module.
+#[inline]
+pub fn screen_point_to_ray_isometry_vk(
+    view: Isometry3,
+    proj: Mat4,
+    viewport: Vec4,
+    screen: Vec2,
+) -> Ray3 {
+    screen_point_to_ray_isometry(view, proj, viewport, screen, true, 0.0)
+}
+
+/// Create a world space ray passing through the pixel at `screen`, for use with a projection
+/// matrix built for WebGPU or DirectX, i.e. one with clip space depth from 0.0 to 1.0 and +Y up
+/// in NDC.
+///
+/// The returned ray starts on the near plane and has a normalized direction. `view` is the
+/// world to view space transform; if you have it as an [`Isometry3`], use
+/// [`screen_point_to_ray_isometry_wgpu_dx`].
+#[inline]
+pub fn screen_point_to_ray_wgpu_dx(view: Mat4, proj: Mat4, viewport: Vec4, screen: Vec2) -> Ray3 {
+    screen_point_to_ray(view, proj, viewport, screen, false, 0.0)
+}
+
+/// [`screen_point_to_ray_wgpu_dx`] with the world to view space transform `view` as an
+/// [`Isometry3`], such as the view transforms returned by the functions in this module.
+#[inline]
+pub fn screen_point_to_ray_isometry_wgpu_dx(
+    view: Isometry3,
+    proj: Mat4,
+    viewport: Vec4,
+    screen: Vec2,
+) -> Ray3 {
+    screen_point_to_ray_isometry(view, proj, viewport, screen, false, 0.0)
+}
+
+/// A viewport, the rectangle of the window that normalized device coordinates are mapped to.
+///
+/// `origin` is the top left corner and `size` the extent of the rectangle, in pixels with +Y
+/// pointing down as everywhere else in this module, and `depth_range` is the `(min, max)` range
+/// that clip space depth is mapped to, as in `VkViewport` or `glDepthRange`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Viewport {
+    pub origin: Vec2,
+    pub size: Vec2,
+    pub depth_range: Vec2,
+}
+
+macro_rules! viewport_mappings {
+    ($($ndc_to_screen:ident, $screen_to_ndc:ident, $ndc_to_window:ident, $window_to_ndc:ident => ($api:expr, $y_down:expr, $z_min:expr)),+) => {
+        impl Viewport {
+            $(#[doc = concat!("Map a point in normalized device coordinates from a projection built for ", $api, " to a pixel position.")]
+            #[inline]
+            pub fn $ndc_to_screen(&self, ndc: Vec2) -> Vec2 {
+                self.ndc_to_screen(ndc, $y_down)
+            }
+
+            #[doc = concat!("Map a pixel position to normalized device coordinates for a projection built for ", $api, ".")]
+            #[inline]
+            pub fn $screen_to_ndc(&self, screen: Vec2) -> Vec2 {
+                self.screen_to_ndc(screen, $y_down)
+            }
+
+            #[doc = concat!("Map a point in normalized device coordinates from a projection built for ", $api, " to a pixel position and a depth within `depth_range`.")]
+            #[inline]
+            pub fn $ndc_to_window(&self, ndc: Vec3) -> Vec3 {
+                let t = (ndc.z - $z_min) / (1.0 - $z_min);
+                let depth = self.depth_range.x + t * (self.depth_range.y - self.depth_range.x);
+                self.ndc_to_screen(ndc.xy(), $y_down).xyz() + Vec3::new(0.0, 0.0, depth)
+            }
+
+            #[doc = concat!("Map a pixel position and a depth within `depth_range` to normalized device coordinates for a projection built for ", $api, ".")]
+            #[inline]
+            pub fn $window_to_ndc(&self, window: Vec3) -> Vec3 {
+                let t = (window.z - self.depth_range.x) / (self.depth_range.y - self.depth_range.x);
+                let z = $z_min + t * (1.0 - $z_min);
+                self.screen_to_ndc(window.xy(), $y_down).xyz() + Vec3::new(0.0, 0.0, z)
+            })+
+        }
+    };
+}
+
+impl Viewport {
+    #[inline]
+    pub const fn new(origin: Vec2, size: Vec2, depth_range: Vec2) -> Self {
+        Self {
+            origin,
+            size,
+            depth_range,
+        }
+    }
+
+    /// A viewport covering a whole window or render target of the given size, with the
+    /// default depth range of `(0.0, 1.0)`.
+    #[inline]
+    pub const fn from_size(size: Vec2) -> Self {
+        Self::new(Vec2::zero(), size, Vec2::new(0.0, 1.0))
+    }
+
+    #[inline]
+    fn ndc_to_screen(&self, ndc: Vec2, y_down: bool) -> Vec2 {
+        let y = if y_down { ndc.y } else { -ndc.y };
+        self.origin + (Vec2::new(ndc.x, y) + Vec2::one()) * 0.5 * self.size
+    }
+
+    #[inline]
+    fn screen_to_ndc(&self, screen: Vec2, y_down: bool) -> Vec2 {
+        let ndc = (screen - self.origin) / self.size * 2.0 - Vec2::one();
+        Vec2::new(ndc.x, if y_down { ndc.y } else { -ndc.y })
+    }
+}
+
+viewport_mappings!(
+    ndc_to_screen_gl, screen_to_ndc_gl, ndc_to_window_gl, window_to_ndc_gl => ("OpenGL", false, -1.0),
+    ndc_to_screen_vk, screen_to_ndc_vk, ndc_to_window_vk, window_to_ndc_vk => ("Vulkan", true, 0.0),
+    ndc_to_screen_wgpu_dx, screen_to_ndc_wgpu_dx, ndc_to_window_wgpu_dx, window_to_ndc_wgpu_dx => ("WebGPU or DirectX", false, 0.0)
+);
+
+/// The viewport as `(x, y, width, height)`, as taken by the other functions in this module.
+impl From<Viewport> for Vec4 {
+    #[inline]
+    fn from(viewport: Viewport) -> Self {
+        Vec4::new(
+            viewport.origin.x,
+            viewport.origin.y,
+            viewport.size.x,
+            viewport.size.y,
+        )
+    }
+}
+
+/// Compute the bounds of an orthographic projection, as `(left, right, bottom, top, near, far)`,
+/// along with the view transform which frames `aabb` when looking along `orientation`.
+#[inline]
+fn fit_orthographic(
+    aabb: Aabb3,
+    orientation: Rotor3,
+    aspect_ratio: f32,
+    margin: f32,
+) -> ([f32; 6], Isometry3) {
+    let center = aabb.center();
+    let radius = aabb.size().mag() * 0.5;
+    let eye = center + orientation * Vec3::new(0.0, 0.0, radius + margin);
+    let view = Isometry3::new(eye, orientation).inversed();
+
+    let mut bounds = Aabb3::empty();
+    for corner in aabb.corners().iter() {
+        bounds.include(view.transform_vec(*corner));
+    }
+
+    let mut min = bounds.min - Vec3::broadcast(margin);
+    let mut max = bounds.max + Vec3::broadcast(margin);
+    let (w, h) = (max.x - min.x, max.y - min.y);
+    if w < h * aspect_ratio {
+        let grow = (h * aspect_ratio - w) * 0.5;
+        min.x -= grow;
+        max.x += grow;
+    } else {
+        let grow = (w / aspect_ratio - h) * 0.5;
+        min.y -= grow;
+        max.y += grow;
+    }
+
+    ([min.x, max.x, min.y, max.y, -max.z, -min.z], view)
+}
+
+/// Create an orthographic projection matrix for OpenGL and a view transform which together fit
+/// `aabb` tightly into view when looking along `orientation`, leaving `margin` world units of
+/// space on every side.
+///
+/// `aspect_ratio` should be the quotient `width / height` of the viewport; the projection is
+/// widened along one axis as needed so that the box is not stretched.
+#[inline]
+pub fn fit_orthographic_to_aabb_gl(
+    aabb: Aabb3,
+    orientation: Rotor3,
+    aspect_ratio: f32,
+    margin: f32,
+) -> (Mat4, Isometry3) {
+    let ([l, r, b, t, n, f], view) = fit_orthographic(aabb, orientation, aspect_ratio, margin);
+    (projection::rh_yup::orthographic_gl(l, r, b, t, n, f), view)
+}
+
+/// Create an orthographic projection matrix for Vulkan and a view transform which together fit
+/// `aabb` tightly into view when looking along `orientation`, leaving `margin` world units of
+/// space on every side.
+///
+/// `aspect_ratio` should be the quotient `width / height` of the viewport; the projection is
+/// widened along one axis as needed so that the box is not stretched.
+#[inline]
+pub fn fit_orthographic_to_aabb_vk(
+    aabb: Aabb3,
+    orientation: Rotor3,
+    aspect_ratio: f32,
+    margin: f32,
+) -> (Mat4, Isometry3) {
+    let ([l, r, b, t, n, f], view) = fit_orthographic(aabb, orientation, aspect_ratio, margin);
+    (projection::rh_yup::orthographic_vk(l, r, b, t, n, f), view)
+}
+
+/// Create an orthographic projection matrix for WebGPU or DirectX and a view transform which
+/// together fit `aabb` tightly into view when looking along `orientation`, leaving `margin`
+/// world units of space on every side.
+///
+/// `aspect_ratio` should be the quotient `width / height` of the viewport; the projection is
+/// widened along one axis as needed so that the box is not stretched.
+#[inline]
+pub fn fit_orthographic_to_aabb_wgpu_dx(
+    aabb: Aabb3,
+    orientation: Rotor3,
+    aspect_ratio: f32,
+    margin: f32,
+) -> (Mat4, Isometry3) {
+    let ([l, r, b, t, n, f], view) = fit_orthographic(aabb, orientation, aspect_ratio, margin);
+    (
+        projection::rh_yup::orthographic_wgpu_dx(l, r, b, t, n, f),
+        view,
+    )
+}
+
+/// Create a view transform for a perspective camera looking along `orientation` which is just
+/// far enough away from `aabb` to have all of it in view.
+///
+/// The box is framed by its bounding sphere, so the result does not depend on `orientation`
+/// and will not change as an orbiting camera rotates. The distance from the camera to the
+/// center of the box is the length of the returned transform's translation.
+///
+/// * `vertical_fov` should be provided in radians.
+/// * `aspect_ratio` should be the quotient `width / height`.
+#[inline]
+pub fn frame_aabb(
+    aabb: Aabb3,
+    orientation: Rotor3,
+    vertical_fov: f32,
+    aspect_ratio: f32,
+) -> Isometry3 {
+    let half_v = vertical_fov * 0.5;
+    let half_h = Transcendental::atan(Transcendental::tan(half_v) * aspect_ratio);
+    let radius = aabb.size().mag() * 0.5;
+    let distance = radius / Transcendental::sin(half_v.min(half_h));
+
+    let eye = aabb.center() + orientation * Vec3::new(0.0, 0.0, distance);
+    Isometry3::new(eye, orientation).inversed()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn center_ray_follows_camera() {
+        let eye = Vec3::new(1.0, 2.0, 5.0);
+        let at = Vec3::new(1.0, 2.0, 0.0);
+        let view = Mat4::look_at(eye, at, Vec3::unit_y());
+        let viewport = Vec4::new(0.0, 0.0, 800.0, 600.0);
+        let center = Vec2::new(400.0, 300.0);
+        let fov = core::f32::consts::FRAC_PI_2;
+
+        let projs = [
+            (projection::perspective_gl(fov, 4.0 / 3.0, 0.1, 100.0), -1.0),
+            (
+                projection::perspective_wgpu_dx(fov, 4.0 / 3.0, 0.1, 100.0),
+                0.0,
+            ),
+            (
+                projection::perspective_reversed_z_wgpu_dx_gl(fov, 4.0 / 3.0, 0.1, 100.0),
+                0.0,
+            ),
+            (
+                projection::perspective_infinite_z_wgpu_dx(fov, 4.0 / 3.0, 0.1),
+                0.0,
+            ),
+            (
+                projection::perspective_reversed_infinite_z_wgpu_dx_gl(fov, 4.0 / 3.0, 0.1),
+                0.0,
+            ),
+        ];
+        for (proj, z_min) in projs.iter() {
+            let ray = screen_point_to_ray(view, *proj, viewport, center, false, *z_min);
+            assert!(ray.direction.eq_eps(-Vec3::unit_z()));
+            assert!(ray.origin.eq_eps(Vec3::new(1.0, 2.0, 4.9)));
+        }
+
+        // Top edge of the window with a 90 degree vertical fov is 45 degrees upwards.
+        let proj = projection::perspective_vk(fov, 4.0 / 3.0, 0.1, 100.0);
+        let ray = screen_point_to_ray_vk(view, proj, viewport, Vec2::new(400.0, 0.0));
+        assert!(ray.direction.eq_eps(Vec3::new(0.0, 1.0, -1.0).normalized()));
+
+        let iso = Isometry3::look_at(eye, at, Vec3::unit_y());
+        let ray = screen_point_to_ray_isometry_vk(iso, proj, viewport, Vec2::new(400.0, 0.0));
+        assert!(ray.direction.eq_eps(Vec3::new(0.0, 1.0, -1.0).normalized()));
+        let proj = projection::perspective_gl(fov, 4.0 / 3.0, 0.1, 100.0);
+        let screen = Vec2::new(130.0, 470.0);
+        let from_mat = screen_point_to_ray_gl(view, proj, viewport, screen);
+        let from_iso = screen_point_to_ray_isometry_gl(iso, proj, viewport, screen);
+        assert!(from_iso.origin.eq_eps(from_mat.origin));
+        assert!(from_iso.direction.eq_eps(from_mat.direction));
+    }
+
+    #[test]
+    fn fit_and_frame_aabb() {
+        let aabb = Aabb3::new(Vec3::new(-1.0, 0.0, 2.0), Vec3::new(3.0, 1.0, 4.0));
+        let orientation = Rotor3::from_rotation_xz(0.3) * Rotor3::from_rotation_yz(-0.4);
+
+        let (proj, view) = fit_orthographic_to_aabb_wgpu_dx(aabb, orientation, 1.5, 0.0);
+        let clip = proj * view.into_homogeneous_matrix();
+        let mut ndc = Aabb3::empty();
+        for corner in aabb.corners().iter() {
+            ndc.include(clip.transform_point3(*corner));
+        }
+        // The box fills the viewport along exactly one of x or y, and the depth range.
+        assert!(ndc.min.z.eq_eps(0.0) && ndc.max.z.eq_eps(1.0));
+        assert!(ndc.min.x > -1.0 - 1e-5 && ndc.max.x < 1.0 + 1e-5);
+        assert!(ndc.min.y > -1.0 - 1e-5 && ndc.max.y < 1.0 + 1e-5);
+        assert!(
+            (ndc.min.x.eq_eps(-1.0) && ndc.max.x.eq_eps(1.0))
+                || (ndc.min.y.eq_eps(-1.0) && ndc.max.y.eq_eps(1.0))
+        );
+
+        let fov = 1.0;
+        let view = frame_aabb(aabb, orientation, fov, 0.5);
+        let proj = projection::perspective_wgpu_dx(fov, 0.5, 0.1, 100.0);
+        let clip = proj * view.into_homogeneous_matrix();
+        for corner in aabb.corners().iter() {
+            let p = clip.transform_point3(*corner);
+            assert!(p.x.abs() <= 1.0 && p.y.abs() <= 1.0);
+        }
+        assert!(view.transform_vec(aabb.center()).x.eq_eps(0.0));
+    }
+
+    #[test]
+    fn viewport_roundtrip() {
+        let viewport = Viewport::new(
+            Vec2::new(100.0, 50.0),
+            Vec2::new(800.0, 600.0),
+            Vec2::new(0.25, 0.75),
+        );
+        assert_eq!(
+            viewport.ndc_to_screen_gl(Vec2::new(-1.0, 1.0)),
+            viewport.origin
+        );
+        assert_eq!(
+            viewport.ndc_to_screen_vk(Vec2::new(1.0, 1.0)),
+            viewport.origin + viewport.size
+        );
+
+        let ndc = Vec3::new(0.25, -0.5, 0.5);
+        let window = viewport.ndc_to_window_gl(ndc);
+        assert!(window.z.eq_eps(0.625));
+        assert!(viewport.window_to_ndc_gl(window).eq_eps(ndc));
+        let window = viewport.ndc_to_window_wgpu_dx(ndc);
+        assert!(window.eq_eps(Vec3::new(600.0, 500.0, 0.5)));
+        assert!(viewport.window_to_ndc_wgpu_dx(window).eq_eps(ndc));
+    }
+}
@@ -0,0 +1,181 @@
+//! Helpers for using 3d and 4d vectors as RGB and RGBA colors.
+//!
+//! These are inherent methods on the vector types: [`Vec3::srgb_to_linear`] and
+//! [`Vec3::linear_to_srgb`] convert with the piecewise sRGB transfer function,
+//! [`Vec3::luminance`] computes the relative luminance of a linear color, and
+//! [`Vec3::rotated_hue`] rotates a color around the gray axis with a [`Rotor3`]. The `Vec4`
+//! variants leave alpha untouched, since it is always stored linearly.
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! let srgb = Vec3::new(1.0, 0.5, 0.0);
+//! let linear = srgb.srgb_to_linear();
+//! assert!((linear.y - 0.21404).abs() < 1e-5);
+//! assert!((linear.linear_to_srgb() - srgb).mag() < 1e-5);
+//! ```
+use crate::*;
+
+use wide::CmpLe;
+
+/// Crate-private per-component transfer functions, implemented for the scalar and lane types.
+trait Transfer {
+    fn srgb_to_linear(self) -> Self;
+    fn linear_to_srgb(self) -> Self;
+}
+
+macro_rules! impl_transfer_scalar {
+    ($($t:ident),+) => {
+        $(impl Transfer for $t {
+            #[inline]
+            fn srgb_to_linear(self) -> $t {
+                if self <= 0.04045 {
+                    self / 12.92
+                } else {
+                    Transcendental::powf((self + 0.055) / 1.055, 2.4)
+                }
+            }
+
+            #[inline]
+            fn linear_to_srgb(self) -> $t {
+                if self <= 0.0031308 {
+                    self * 12.92
+                } else {
+                    1.055 * Transcendental::powf(self, 1.0 / 2.4) - 0.055
+                }
+            }
+        })+
+    };
+}
+
+macro_rules! impl_transfer_wide {
+    ($($t:ident),+) => {
+        $(impl Transfer for $t {
+            #[inline]
+            fn srgb_to_linear(self) -> $t {
+                let low = self / $t::splat(12.92);
+                let high = Transcendental::powf((self + $t::splat(0.055)) / $t::splat(1.055), $t::splat(2.4));
+                self.cmp_le($t::splat(0.04045)).blend(low, high)
+            }
+
+            #[inline]
+            fn linear_to_srgb(self) -> $t {
+                let low = self * $t::splat(12.92);
+                let high = $t::splat(1.055) * Transcendental::powf(self, $t::splat(1.0 / 2.4)) - $t::splat(0.055);
+                self.cmp_le($t::splat(0.0031308)).blend(low, high)
+            }
+        })+
+    };
+}
+
+impl_transfer_scalar!(f32, f64);
+impl_transfer_wide!(f32x4, f32x8, f64x2, f64x4);
+
+macro_rules! colors {
+    ($($vt:ident, $v4t:ident => ($rt:ident, $bt:ident, $t:ident)),+) => {
+        $(impl $vt {
+            /// Convert an sRGB encoded color to linear RGB, using the piecewise sRGB transfer
+            /// function rather than a gamma of 2.2.
+            #[inline]
+            pub fn srgb_to_linear(&self) -> Self {
+                Self::new(self.x.srgb_to_linear(), self.y.srgb_to_linear(), self.z.srgb_to_linear())
+            }
+
+            /// Convert a linear RGB color to sRGB encoding, the inverse of
+            /// [`Self::srgb_to_linear`].
+            #[inline]
+            pub fn linear_to_srgb(&self) -> Self {
+                Self::new(self.x.linear_to_srgb(), self.y.linear_to_srgb(), self.z.linear_to_srgb())
+            }
+
+            /// The relative luminance of a linear RGB color with the sRGB (Rec. 709) primaries.
+            #[inline]
+            pub fn luminance(&self) -> $t {
+                self.dot(Self::new($t::splat(0.2126), $t::splat(0.7152), $t::splat(0.0722)))
+            }
+
+            /// The rotation which rotates the hue of a color by `angle` radians, keeping its
+            /// distance from the gray axis.
+            ///
+            /// This is a rotation around the `(1, 1, 1)` axis in RGB space, so it is a crude
+            /// approximation of a perceptual hue shift. A positive angle turns red towards green,
+            /// and an angle of a third of a turn maps red to green exactly.
+            #[inline]
+            pub fn hue_rotor(angle: $t) -> $rt {
+                let k = $t::splat(1.0) / $t::splat(3.0).sqrt();
+                $rt::from_angle_plane(angle, $bt::new(k, -k, k))
+            }
+
+            /// Rotate the hue of this color by `angle` radians, see [`Self::hue_rotor`].
+            #[inline]
+            pub fn rotated_hue(&self, angle: $t) -> Self {
+                Self::hue_rotor(angle) * *self
+            }
+        }
+
+        impl $v4t {
+            /// Convert an sRGB encoded color to linear RGB, leaving alpha as it is.
+            #[inline]
+            pub fn srgb_to_linear(&self) -> Self {
+                Self::new(self.x.srgb_to_linear(), self.y.srgb_to_linear(), self.z.srgb_to_linear(), self.w)
+            }
+
+            /// Convert a linear RGB color to sRGB encoding, leaving alpha as it is.
+            #[inline]
+            pub fn linear_to_srgb(&self) -> Self {
+                Self::new(self.x.linear_to_srgb(), self.y.linear_to_srgb(), self.z.linear_to_srgb(), self.w)
+            }
+
+            /// The relative luminance of the color part of a linear RGBA color, ignoring alpha.
+            #[inline]
+            pub fn luminance(&self) -> $t {
+                self.xyz().luminance()
+            }
+        })+
+    };
+}
+
+colors!(
+    Vec3, Vec4 => (Rotor3, Bivec3, f32),
+    Vec3x4, Vec4x4 => (Rotor3x4, Bivec3x4, f32x4),
+    Vec3x8, Vec4x8 => (Rotor3x8, Bivec3x8, f32x8)
+);
+
+#[cfg(feature = "f64")]
+colors!(
+    DVec3, DVec4 => (DRotor3, DBivec3, f64),
+    DVec3x2, DVec4x2 => (DRotor3x2, DBivec3x2, f64x2),
+    DVec3x4, DVec4x4 => (DRotor3x4, DBivec3x4, f64x4)
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    #[test]
+    fn srgb_roundtrip() {
+        let srgb = Vec4::new(0.02, 0.5, 1.0, 0.5);
+        let linear = srgb.srgb_to_linear();
+        assert!(linear.eq_eps(Vec4::new(
+            0.02 / 12.92,
+            (0.555f32 / 1.055).powf(2.4),
+            1.0,
+            0.5
+        )));
+        assert!(linear.linear_to_srgb().eq_eps(srgb));
+
+        let wide = Vec3x4::splat(srgb.xyz()).srgb_to_linear();
+        let lanes: [Vec3; 4] = wide.into();
+        assert!(lanes[3].eq_eps(linear.xyz()));
+    }
+
+    #[test]
+    fn hue_rotation() {
+        assert!(Vec3::one().luminance().eq_eps(1.0));
+
+        let third = core::f32::consts::TAU / 3.0;
+        assert!(Vec3::unit_x().rotated_hue(third).eq_eps(Vec3::unit_y()));
+        let gray = Vec3::broadcast(0.3);
+        assert!(gray.rotated_hue(1.0).eq_eps(gray));
+    }
+}
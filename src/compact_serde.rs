@@ -0,0 +1,148 @@
+//! An opt-in compact serde representation for vectors.
+//!
+//! By default, vectors are serialized as structs with named fields, i.e. `{"x":1.0,"y":2.0,"z":3.0}`
+//! in JSON. Using this module with serde's `with` attribute instead stores them as plain
+//! arrays like `[1.0,2.0,3.0]`, which is considerably smaller for large scene files.
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Vertex {
+//!     #[serde(with = "ultraviolet::compact_serde")]
+//!     position: Vec3,
+//!     #[serde(with = "ultraviolet::compact_serde")]
+//!     normal: Vec3,
+//! }
+//! ```
+//!
+//! Wide vectors are stored as an array of their components, each of which is an array of lanes.
+use crate::*;
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A type which has a compact, array-like serde representation.
+pub trait CompactSerde: Sized {
+    /// The compact representation of `Self`.
+    type Repr: Serialize + DeserializeOwned;
+
+    fn to_compact(&self) -> Self::Repr;
+
+    fn from_compact(repr: Self::Repr) -> Self;
+}
+
+/// Serialize `value` using its compact representation. For use with `#[serde(with = "...")]`.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: CompactSerde,
+    S: Serializer,
+{
+    value.to_compact().serialize(serializer)
+}
+
+/// Deserialize a value from its compact representation. For use with `#[serde(with = "...")]`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: CompactSerde,
+    D: Deserializer<'de>,
+{
+    T::Repr::deserialize(deserializer).map(T::from_compact)
+}
+
+macro_rules! compact_vecs {
+    ($($n:ident => [$t:ident; $len:expr]),+) => {
+        $(impl CompactSerde for $n {
+            type Repr = [$t; $len];
+
+            #[inline]
+            fn to_compact(&self) -> Self::Repr {
+                (*self).into()
+            }
+
+            #[inline]
+            fn from_compact(repr: Self::Repr) -> Self {
+                repr.into()
+            }
+        })+
+    };
+}
+
+compact_vecs!(
+    Vec2 => [f32; 2],
+    Vec3 => [f32; 3],
+    Vec4 => [f32; 4],
+    Vec2x4 => [f32x4; 2],
+    Vec3x4 => [f32x4; 3],
+    Vec4x4 => [f32x4; 4],
+    Vec2x8 => [f32x8; 2],
+    Vec3x8 => [f32x8; 3],
+    Vec4x8 => [f32x8; 4]
+);
+
+#[cfg(feature = "f64")]
+compact_vecs!(
+    DVec2 => [f64; 2],
+    DVec3 => [f64; 3],
+    DVec4 => [f64; 4],
+    DVec2x2 => [f64x2; 2],
+    DVec3x2 => [f64x2; 3],
+    DVec4x2 => [f64x2; 4],
+    DVec2x4 => [f64x4; 2],
+    DVec3x4 => [f64x4; 3],
+    DVec4x4 => [f64x4; 4]
+);
+
+#[cfg(feature = "int")]
+compact_vecs!(
+    IVec2 => [i32; 2],
+    IVec3 => [i32; 3],
+    IVec4 => [i32; 4],
+    UVec2 => [u32; 2],
+    UVec3 => [u32; 3],
+    UVec4 => [u32; 4],
+    U8Vec2 => [u8; 2],
+    U8Vec3 => [u8; 3],
+    U8Vec4 => [u8; 4],
+    U16Vec2 => [u16; 2],
+    U16Vec3 => [u16; 3],
+    U16Vec4 => [u16; 4],
+    I64Vec2 => [i64; 2],
+    I64Vec3 => [i64; 3],
+    I64Vec4 => [i64; 4]
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_test::{assert_tokens, Token};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Vertex {
+        #[serde(with = "crate::compact_serde")]
+        position: Vec3,
+    }
+
+    #[test]
+    fn vec3_as_array() {
+        let vertex = Vertex {
+            position: Vec3::new(1., 2., 3.),
+        };
+
+        assert_tokens(
+            &vertex,
+            &[
+                Token::Struct {
+                    name: "Vertex",
+                    len: 1,
+                },
+                Token::Str("position"),
+                Token::Tuple { len: 3 },
+                Token::F32(1.),
+                Token::F32(2.),
+                Token::F32(3.),
+                Token::TupleEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
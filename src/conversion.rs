@@ -6,8 +6,7 @@
 use crate::util::{TryFromExt, TryIntoExt};
 use crate::*;
 use core::convert::TryFrom;
-use std::error::Error;
-use std::fmt;
+use core::fmt;
 
 /// The error type that may happen when converting a `f32` or `f64` to any other numerical
 /// representation.
@@ -30,14 +29,16 @@ impl fmt::Display for FloatConversionError {
     }
 }
 
-impl Error for FloatConversionError {}
+#[cfg(feature = "std")]
+impl std::error::Error for FloatConversionError {}
 
 macro_rules! impl_try_from_float {
     ($source:ty => $($target:ident),*) => {$(
         impl TryFromExt<$source> for $target {
             type Error = FloatConversionError;
 
-            /// Tries to convert the source to Self in a lossy way, flooring any float value.
+            /// Tries to convert the source to Self in a lossy way, rounding any float value towards
+            /// zero.
             ///
             /// # Errors
             /// * `NaN` - If the float value is `NaN`.
@@ -69,8 +70,8 @@ macro_rules! impl_try_from_float {
     )*}
 }
 
-impl_try_from_float!(f32 => i32, u32);
-impl_try_from_float!(f64 => i32, u32);
+impl_try_from_float!(f32 => u8, u16, i32, u32, i64);
+impl_try_from_float!(f64 => u8, u16, i32, u32, i64);
 
 macro_rules! impl_try_from_float_vec {
     ($(($name:ident => $target:ident, [$($var:ident),*])),+) => {
@@ -78,7 +79,8 @@ macro_rules! impl_try_from_float_vec {
         impl TryFrom<$name> for $target {
             type Error = FloatConversionError;
 
-            /// Tries to convert the source to Self in a lossy way, flooring any float value.
+            /// Tries to convert the source to Self in a lossy way, rounding any float value towards
+            /// zero. To choose how to round instead, see e.g. `to_ivec_floor`.
             ///
             /// # Errors
             /// * `NaN` - If a float value is `NaN`.
@@ -95,7 +97,7 @@ macro_rules! impl_try_from_float_vec {
 }
 
 macro_rules! impl_from_int_vec {
-    ($(($name:ident => $target:ident, $target_type:ident, [$($var:ident),*])),+) => {
+    ($as_fn:ident; $(($name:ident => $target:ident, $target_type:ident, [$($var:ident),*])),+) => {
         $(
         impl From<$name> for $target {
             #[inline]
@@ -103,6 +105,54 @@ macro_rules! impl_from_int_vec {
                 Self::new($(v.$var as $target_type,)*)
             }
         }
+
+        impl $name {
+            #[doc = concat!("Convert `self` to a [`", stringify!($target), "`], which is exact unless the components are too large to be represented precisely.")]
+            #[inline]
+            pub fn $as_fn(&self) -> $target {
+                $target::from(*self)
+            }
+        }
+        )+
+    };
+}
+
+macro_rules! impl_float_to_ivec {
+    ($(($name:ident => $target:ident, [$($var:ident),*])),+) => {
+        $(
+        impl $name {
+            /// Convert `self` to an integer vector, rounding each component down, i.e. towards
+            /// negative infinity.
+            ///
+            /// This is the rounding to use for finding the cell of a grid that contains a point,
+            /// also for negative coordinates. Components which are out of range saturate to
+            /// `i32::MIN` or `i32::MAX`, and `NaN` components become zero.
+            #[inline]
+            pub fn to_ivec_floor(&self) -> $target {
+                $target::new($(self.$var.floor() as i32,)*)
+            }
+
+            /// Convert `self` to an integer vector, rounding each component up, i.e. towards
+            /// positive infinity. Saturates like [`Self::to_ivec_floor`].
+            #[inline]
+            pub fn to_ivec_ceil(&self) -> $target {
+                $target::new($(self.$var.ceil() as i32,)*)
+            }
+
+            /// Convert `self` to an integer vector, rounding each component to the nearest
+            /// integer with ties away from zero. Saturates like [`Self::to_ivec_floor`].
+            #[inline]
+            pub fn to_ivec_round(&self) -> $target {
+                $target::new($(self.$var.round() as i32,)*)
+            }
+
+            /// Convert `self` to an integer vector, rounding each component towards zero.
+            /// Saturates like [`Self::to_ivec_floor`].
+            #[inline]
+            pub fn to_ivec_trunc(&self) -> $target {
+                $target::new($(self.$var as i32,)*)
+            }
+        }
         )+
     };
 }
@@ -114,7 +164,19 @@ impl_try_from_float_vec!(
 
     (Vec2 => UVec2, [x, y]),
     (Vec3 => UVec3, [x, y, z]),
-    (Vec4 => UVec4, [x, y, z, w])
+    (Vec4 => UVec4, [x, y, z, w]),
+
+    (Vec2 => U8Vec2, [x, y]),
+    (Vec3 => U8Vec3, [x, y, z]),
+    (Vec4 => U8Vec4, [x, y, z, w]),
+
+    (Vec2 => U16Vec2, [x, y]),
+    (Vec3 => U16Vec3, [x, y, z]),
+    (Vec4 => U16Vec4, [x, y, z, w]),
+
+    (Vec2 => I64Vec2, [x, y]),
+    (Vec3 => I64Vec3, [x, y, z]),
+    (Vec4 => I64Vec4, [x, y, z, w])
 );
 
 #[cfg(feature = "f64")]
@@ -125,28 +187,79 @@ impl_try_from_float_vec!(
 
     (DVec2 => UVec2, [x, y]),
     (DVec3 => UVec3, [x, y, z]),
-    (DVec4 => UVec4, [x, y, z, w])
+    (DVec4 => UVec4, [x, y, z, w]),
+
+    (DVec2 => U8Vec2, [x, y]),
+    (DVec3 => U8Vec3, [x, y, z]),
+    (DVec4 => U8Vec4, [x, y, z, w]),
+
+    (DVec2 => U16Vec2, [x, y]),
+    (DVec3 => U16Vec3, [x, y, z]),
+    (DVec4 => U16Vec4, [x, y, z, w]),
+
+    (DVec2 => I64Vec2, [x, y]),
+    (DVec3 => I64Vec3, [x, y, z]),
+    (DVec4 => I64Vec4, [x, y, z, w])
 );
 
 impl_from_int_vec!(
+    as_vec;
     (IVec2 => Vec2, f32, [x, y]),
     (IVec3 => Vec3, f32, [x, y, z]),
     (IVec4 => Vec4, f32, [x, y, z, w]),
 
     (UVec2 => Vec2, f32, [x, y]),
     (UVec3 => Vec3, f32, [x, y, z]),
-    (UVec4 => Vec4, f32, [x, y, z, w])
+    (UVec4 => Vec4, f32, [x, y, z, w]),
+
+    (U8Vec2 => Vec2, f32, [x, y]),
+    (U8Vec3 => Vec3, f32, [x, y, z]),
+    (U8Vec4 => Vec4, f32, [x, y, z, w]),
+
+    (U16Vec2 => Vec2, f32, [x, y]),
+    (U16Vec3 => Vec3, f32, [x, y, z]),
+    (U16Vec4 => Vec4, f32, [x, y, z, w]),
+
+    (I64Vec2 => Vec2, f32, [x, y]),
+    (I64Vec3 => Vec3, f32, [x, y, z]),
+    (I64Vec4 => Vec4, f32, [x, y, z, w])
 );
 
 #[cfg(feature = "f64")]
 impl_from_int_vec!(
+    as_dvec;
     (IVec2 => DVec2, f64, [x, y]),
     (IVec3 => DVec3, f64, [x, y, z]),
     (IVec4 => DVec4, f64, [x, y, z, w]),
 
     (UVec2 => DVec2, f64, [x, y]),
     (UVec3 => DVec3, f64, [x, y, z]),
-    (UVec4 => DVec4, f64, [x, y, z, w])
+    (UVec4 => DVec4, f64, [x, y, z, w]),
+
+    (U8Vec2 => DVec2, f64, [x, y]),
+    (U8Vec3 => DVec3, f64, [x, y, z]),
+    (U8Vec4 => DVec4, f64, [x, y, z, w]),
+
+    (U16Vec2 => DVec2, f64, [x, y]),
+    (U16Vec3 => DVec3, f64, [x, y, z]),
+    (U16Vec4 => DVec4, f64, [x, y, z, w]),
+
+    (I64Vec2 => DVec2, f64, [x, y]),
+    (I64Vec3 => DVec3, f64, [x, y, z]),
+    (I64Vec4 => DVec4, f64, [x, y, z, w])
+);
+
+impl_float_to_ivec!(
+    (Vec2 => IVec2, [x, y]),
+    (Vec3 => IVec3, [x, y, z]),
+    (Vec4 => IVec4, [x, y, z, w])
+);
+
+#[cfg(feature = "f64")]
+impl_float_to_ivec!(
+    (DVec2 => IVec2, [x, y]),
+    (DVec3 => IVec3, [x, y, z]),
+    (DVec4 => IVec4, [x, y, z, w])
 );
 
 // tests only for Vec2
@@ -235,4 +348,41 @@ mod tests {
 
         assert_eq!(uvec2.err().unwrap(), FloatConversionError::NegOverflow);
     }
+
+    #[test]
+    #[cfg(feature = "int")]
+    fn vec4_to_u8vec4_pos_overflow() {
+        let vec4 = Vec4::new(255.0, 256.0, 0.0, 0.0);
+        let u8vec4 = U8Vec4::try_from(vec4);
+
+        assert_eq!(u8vec4.err().unwrap(), FloatConversionError::PosOverflow);
+    }
+
+    #[test]
+    #[cfg(feature = "int")]
+    fn widen_to_i64vec3() {
+        let u8vec3 = U8Vec3::new(1, 2, 255);
+        let i64vec3 = I64Vec3::from(UVec3::from(u8vec3)) * (1 << 40);
+
+        assert_eq!(
+            Vec3::from(i64vec3),
+            Vec3::new(1.0, 2.0, 255.0) * (1u64 << 40) as f32
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "int")]
+    fn vec2_to_ivec2_rounding_modes() {
+        let vec2 = Vec2::new(-1.5, 2.5);
+
+        assert_eq!(vec2.to_ivec_floor(), IVec2::new(-2, 2));
+        assert_eq!(vec2.to_ivec_ceil(), IVec2::new(-1, 3));
+        assert_eq!(vec2.to_ivec_round(), IVec2::new(-2, 3));
+        assert_eq!(vec2.to_ivec_trunc(), IVec2::new(-1, 2));
+        assert_eq!(
+            Vec2::new(f32::NAN, 1e20).to_ivec_floor(),
+            IVec2::new(0, i32::MAX)
+        );
+        assert_eq!(IVec2::new(-3, 4).as_vec(), Vec2::new(-3.0, 4.0));
+    }
 }
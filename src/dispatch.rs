@@ -0,0 +1,170 @@
+//! Choosing between the x4 and x8 wide types at runtime.
+//!
+//! A binary built for a baseline target such as `x86_64` still runs on CPUs with 256-bit
+//! registers. [`simd_width`] detects the widest lane count the running CPU handles natively, and
+//! [`dispatch_wide!`](crate::dispatch_wide) runs the same code with either the x4 or the x8 types
+//! depending on it.
+//!
+//! On x86, the x8 code path is compiled inside a function marked
+//! `#[target_feature(enable = "avx")]`, so it may use 256-bit instructions even when the rest of
+//! the binary is built for the baseline target.
+
+/// The number of `f32` lanes to use for the wide types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Width {
+    /// The x4 types, such as `Vec3x4`.
+    X4,
+    /// The x8 types, such as `Vec3x8`.
+    X8,
+}
+
+impl Width {
+    /// The number of lanes, 4 or 8.
+    #[inline]
+    pub const fn lanes(self) -> usize {
+        match self {
+            Width::X4 => 4,
+            Width::X8 => 8,
+        }
+    }
+}
+
+/// Detects the widest lane count of `f32` the current CPU handles natively.
+///
+/// This is [`Width::X8`] on x86 CPUs with AVX and [`Width::X4`] otherwise. The detection happens
+/// at runtime with `std`, and falls back to the target features enabled at compile time without
+/// it.
+#[inline]
+pub fn simd_width() -> Width {
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    let avx = std::is_x86_feature_detected!("avx");
+    #[cfg(not(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64"))))]
+    let avx = cfg!(target_feature = "avx");
+
+    if avx {
+        Width::X8
+    } else {
+        Width::X4
+    }
+}
+
+/// Calls `f` from a function compiled with AVX enabled, so that `f` can be inlined into it and
+/// use 256-bit instructions. On targets other than x86 this just calls `f`.
+///
+/// # Safety
+///
+/// On x86, the CPU must support AVX, e.g. because [`simd_width`] returned [`Width::X8`].
+#[inline]
+pub unsafe fn run_x8<R, F: FnOnce() -> R>(f: F) -> R {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        #[target_feature(enable = "avx")]
+        unsafe fn with_avx<R, F: FnOnce() -> R>(f: F) -> R {
+            f()
+        }
+        with_avx(f)
+    }
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        f()
+    }
+}
+
+/// Calls `f`; the 4-lane counterpart of [`run_x8`], used by [`dispatch_wide!`](crate::dispatch_wide).
+#[inline]
+pub fn run_x4<R, F: FnOnce() -> R>(f: F) -> R {
+    f()
+}
+
+/// Evaluates an expression with either the x4 or the x8 wide types, based on [`simd_width`].
+///
+/// The expression is evaluated in a closure, which for 8 lanes is called from a function compiled
+/// with AVX enabled (see [`run_x8`]). `return` and `?` therefore apply to the closure rather than
+/// to the enclosing function.
+///
+/// Each `Alias = X4Type, X8Type;` pair defines a type alias for the expression, bound to the
+/// first type when dispatching to 4 lanes and to the second one when dispatching to 8 lanes. An
+/// optional leading `const NAME;` also defines a `usize` constant with the number of lanes, e.g.
+/// for chunking the input.
+///
+/// ```rust
+/// # use ultraviolet::*;
+/// use std::convert::TryFrom;
+///
+/// let points = [Vec3::one(); 13];
+///
+/// let sum = ultraviolet::dispatch_wide!(const LANES; V = Vec3x4, Vec3x8; F = f32x4, f32x8 => {
+///     let mut acc = F::ZERO;
+///     let chunks = points.chunks_exact(LANES);
+///     let rest: f32 = chunks.remainder().iter().map(|p| p.mag_sq()).sum();
+///     for chunk in chunks {
+///         acc += V::from(<[Vec3; LANES]>::try_from(chunk).unwrap()).mag_sq();
+///     }
+///     acc.reduce_add() + rest
+/// });
+/// assert_eq!(sum, 39.0);
+/// ```
+#[macro_export]
+macro_rules! dispatch_wide {
+    (const $lanes:ident; $($alias:ident = $x4:ty, $x8:ty);+ => $body:expr) => {
+        match $crate::dispatch::simd_width() {
+            $crate::dispatch::Width::X8 => {
+                const $lanes: usize = 8;
+                $(type $alias = $x8;)+
+                // SAFETY: `simd_width` only returns `Width::X8` if the CPU supports AVX.
+                unsafe { $crate::dispatch::run_x8(|| $body) }
+            }
+            $crate::dispatch::Width::X4 => {
+                const $lanes: usize = 4;
+                $(type $alias = $x4;)+
+                $crate::dispatch::run_x4(|| $body)
+            }
+        }
+    };
+    ($($alias:ident = $x4:ty, $x8:ty);+ => $body:expr) => {
+        match $crate::dispatch::simd_width() {
+            $crate::dispatch::Width::X8 => {
+                $(type $alias = $x8;)+
+                // SAFETY: `simd_width` only returns `Width::X8` if the CPU supports AVX.
+                unsafe { $crate::dispatch::run_x8(|| $body) }
+            }
+            $crate::dispatch::Width::X4 => {
+                $(type $alias = $x4;)+
+                $crate::dispatch::run_x4(|| $body)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn dispatch_matches_detected_width() {
+        let width = simd_width();
+        let lanes = dispatch_wide!(F = f32x4, f32x8 => F::ZERO.to_array().len());
+        assert_eq!(lanes, width.lanes());
+        let matches = dispatch_wide!(const LANES; F = f32x4, f32x8 => {
+            LANES == lanes && F::ZERO.to_array().len() == LANES
+        });
+        assert!(matches);
+
+        let sum = dispatch_wide!(V = Vec3x4, Vec3x8 => {
+            V::splat(Vec3::one()).dot(V::one()).reduce_add()
+        });
+        assert_eq!(sum, 3.0 * width.lanes() as f32);
+    }
+
+    #[test]
+    fn run_x8_on_detected_avx() {
+        if simd_width() == Width::X8 {
+            let v = Vec3x8::splat(Vec3::new(1.0, 2.0, 3.0));
+            // SAFETY: the CPU supports AVX.
+            let mag_sq = unsafe { run_x8(|| v.mag_sq()) };
+            assert_eq!(mag_sq.to_array(), [14.0; 8]);
+        }
+        assert_eq!(run_x4(|| f32x4::ONE.reduce_add()), 4.0);
+    }
+}
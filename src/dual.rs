@@ -0,0 +1,473 @@
+//! Dual numbers for forward mode automatic differentiation.
+//!
+//! A [`DualF32`] carries a value together with its gradient with respect to a 3d input point,
+//! and a [`DualVec3`] is a vector of them. Evaluating a function written against these types at
+//! [`DualVec3::variable`] gives both the value of the function and its exact gradient in a
+//! single pass, with no finite difference step to tune. This is most useful for getting the
+//! normals of signed distance fields, or the gradients of constraint functions.
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! use ultraviolet::dual::{DualF32, DualVec3};
+//!
+//! // The signed distance to a sphere of radius 2.
+//! fn sphere(p: DualVec3) -> DualF32 {
+//!     p.mag() - 2.0
+//! }
+//!
+//! let d = sphere(DualVec3::variable(Vec3::new(0.0, 3.0, 4.0)));
+//! assert_eq!(d.value, 3.0);
+//! assert!((d.grad - Vec3::new(0.0, 0.6, 0.8)).mag() < 1e-6);
+//! ```
+use crate::*;
+
+use core::ops::*;
+
+/// A scalar value along with its gradient with respect to a 3d input.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct DualF32 {
+    pub value: f32,
+    pub grad: Vec3,
+}
+
+impl DualF32 {
+    #[inline]
+    pub const fn new(value: f32, grad: Vec3) -> Self {
+        Self { value, grad }
+    }
+
+    /// A value which does not depend on the input, i.e. which has a zero gradient.
+    #[inline]
+    pub const fn constant(value: f32) -> Self {
+        Self::new(value, Vec3::zero())
+    }
+
+    /// Apply a function with the given value and derivative at `self.value`, using the chain
+    /// rule for the gradient.
+    #[inline]
+    fn chain(&self, value: f32, derivative: f32) -> Self {
+        Self::new(value, self.grad * derivative)
+    }
+
+    #[inline]
+    pub fn sqrt(&self) -> Self {
+        let value = self.value.sqrt();
+        self.chain(value, 0.5 / value)
+    }
+
+    #[inline]
+    pub fn recip(&self) -> Self {
+        let value = self.value.recip();
+        self.chain(value, -value * value)
+    }
+
+    #[inline]
+    pub fn powf(&self, n: f32) -> Self {
+        self.chain(
+            Transcendental::powf(self.value, n),
+            n * Transcendental::powf(self.value, n - 1.0),
+        )
+    }
+
+    #[inline]
+    pub fn exp(&self) -> Self {
+        let value = Transcendental::exp(self.value);
+        self.chain(value, value)
+    }
+
+    #[inline]
+    pub fn ln(&self) -> Self {
+        self.chain(Transcendental::ln(self.value), self.value.recip())
+    }
+
+    #[inline]
+    pub fn sin(&self) -> Self {
+        let (sin, cos) = Transcendental::sin_cos(self.value);
+        self.chain(sin, cos)
+    }
+
+    #[inline]
+    pub fn cos(&self) -> Self {
+        let (sin, cos) = Transcendental::sin_cos(self.value);
+        self.chain(cos, -sin)
+    }
+
+    /// The absolute value. At zero, the gradient of the positive side is used.
+    #[inline]
+    pub fn abs(&self) -> Self {
+        if self.value < 0.0 {
+            -*self
+        } else {
+            *self
+        }
+    }
+
+    /// The larger of `self` and `other`, along with its gradient.
+    #[inline]
+    pub fn max(&self, other: Self) -> Self {
+        if other.value > self.value {
+            other
+        } else {
+            *self
+        }
+    }
+
+    /// The smaller of `self` and `other`, along with its gradient.
+    #[inline]
+    pub fn min(&self, other: Self) -> Self {
+        if other.value < self.value {
+            other
+        } else {
+            *self
+        }
+    }
+
+    #[inline]
+    pub fn clamp(&self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+}
+
+impl From<f32> for DualF32 {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl Add for DualF32 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value + rhs.value, self.grad + rhs.grad)
+    }
+}
+
+impl Sub for DualF32 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value - rhs.value, self.grad - rhs.grad)
+    }
+}
+
+impl Mul for DualF32 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.value * rhs.value,
+            self.grad * rhs.value + rhs.grad * self.value,
+        )
+    }
+}
+
+impl Div for DualF32 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let r = rhs.value.recip();
+        Self::new(
+            self.value * r,
+            (self.grad * rhs.value - rhs.grad * self.value) * (r * r),
+        )
+    }
+}
+
+impl Neg for DualF32 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.value, -self.grad)
+    }
+}
+
+macro_rules! dual_scalar_ops {
+    ($($op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident);+) => {
+        $(impl $op<f32> for DualF32 {
+            type Output = Self;
+            #[inline]
+            fn $op_fn(self, rhs: f32) -> Self {
+                self.$op_fn(DualF32::constant(rhs))
+            }
+        }
+
+        impl $op<DualF32> for f32 {
+            type Output = DualF32;
+            #[inline]
+            fn $op_fn(self, rhs: DualF32) -> DualF32 {
+                DualF32::constant(self).$op_fn(rhs)
+            }
+        }
+
+        impl $assign for DualF32 {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = self.$op_fn(rhs);
+            }
+        }
+
+        impl $assign<f32> for DualF32 {
+            #[inline]
+            fn $assign_fn(&mut self, rhs: f32) {
+                *self = self.$op_fn(rhs);
+            }
+        })+
+    };
+}
+
+dual_scalar_ops!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign
+);
+
+/// A 3d vector of dual numbers, each with its gradient with respect to the same 3d input.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct DualVec3 {
+    pub x: DualF32,
+    pub y: DualF32,
+    pub z: DualF32,
+}
+
+impl DualVec3 {
+    #[inline]
+    pub const fn new(x: DualF32, y: DualF32, z: DualF32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The input point of a function to differentiate, i.e. a vector whose gradient with
+    /// respect to itself is the identity.
+    #[inline]
+    pub const fn variable(p: Vec3) -> Self {
+        Self::new(
+            DualF32::new(p.x, Vec3::new(1.0, 0.0, 0.0)),
+            DualF32::new(p.y, Vec3::new(0.0, 1.0, 0.0)),
+            DualF32::new(p.z, Vec3::new(0.0, 0.0, 1.0)),
+        )
+    }
+
+    /// A vector which does not depend on the input.
+    #[inline]
+    pub const fn constant(v: Vec3) -> Self {
+        Self::new(
+            DualF32::constant(v.x),
+            DualF32::constant(v.y),
+            DualF32::constant(v.z),
+        )
+    }
+
+    /// The value of each component, without the gradients.
+    #[inline]
+    pub fn value(&self) -> Vec3 {
+        Vec3::new(self.x.value, self.y.value, self.z.value)
+    }
+
+    /// The Jacobian of this vector with respect to the input, with the gradient of each
+    /// component in the corresponding row.
+    #[inline]
+    pub fn jacobian(&self) -> Mat3 {
+        Mat3::new(self.x.grad, self.y.grad, self.z.grad).transposed()
+    }
+
+    #[inline]
+    pub fn dot(&self, other: Self) -> DualF32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[inline]
+    pub fn cross(&self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    #[inline]
+    pub fn mag_sq(&self) -> DualF32 {
+        self.dot(*self)
+    }
+
+    /// The magnitude of `self`. The gradient of the magnitude of a zero vector is taken to be
+    /// zero rather than `NaN`, so that functions like `p.max_by_component(zero).mag()` stay
+    /// differentiable where they are constant.
+    #[inline]
+    pub fn mag(&self) -> DualF32 {
+        let mag_sq = self.mag_sq();
+        if mag_sq.value == 0.0 {
+            DualF32::constant(0.0)
+        } else {
+            mag_sq.sqrt()
+        }
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        *self / self.mag()
+    }
+
+    #[inline]
+    pub fn abs(&self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    #[inline]
+    pub fn max_by_component(&self, other: Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    #[inline]
+    pub fn min_by_component(&self, other: Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    #[inline]
+    pub fn component_max(&self) -> DualF32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    #[inline]
+    pub fn component_min(&self) -> DualF32 {
+        self.x.min(self.y).min(self.z)
+    }
+}
+
+impl From<Vec3> for DualVec3 {
+    #[inline]
+    fn from(v: Vec3) -> Self {
+        Self::constant(v)
+    }
+}
+
+impl Add for DualVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Add<Vec3> for DualVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Vec3) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl Sub for DualVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Sub<Vec3> for DualVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Vec3) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl Mul<DualF32> for DualVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: DualF32) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Mul<f32> for DualVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl Mul<DualVec3> for Mat3 {
+    type Output = DualVec3;
+    #[inline]
+    fn mul(self, rhs: DualVec3) -> DualVec3 {
+        let [a, b, c] = self.cols;
+        DualVec3::new(
+            rhs.x * a.x + rhs.y * b.x + rhs.z * c.x,
+            rhs.x * a.y + rhs.y * b.y + rhs.z * c.y,
+            rhs.x * a.z + rhs.y * b.z + rhs.z * c.z,
+        )
+    }
+}
+
+impl Div<DualF32> for DualVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: DualF32) -> Self {
+        let r = DualF32::constant(1.0) / rhs;
+        self * r
+    }
+}
+
+impl Neg for DualVec3 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    #[test]
+    fn gradients() {
+        // The signed distance to a box, which has a kink along its edges.
+        let half_extents = Vec3::new(1.0, 2.0, 3.0);
+        let sd_box = |p: DualVec3| {
+            let q = p.abs() - half_extents;
+            let outside = q.max_by_component(DualVec3::constant(Vec3::zero())).mag();
+            outside + q.component_max().min(DualF32::constant(0.0))
+        };
+        let d = sd_box(DualVec3::variable(Vec3::new(4.0, 0.5, 7.0)));
+        assert!(d.value.eq_eps(5.0));
+        assert!(d.grad.eq_eps(Vec3::new(0.6, 0.0, 0.8)));
+        let d = sd_box(DualVec3::variable(Vec3::new(0.25, -1.5, 0.0)));
+        assert!(d.value.eq_eps(-0.5));
+        assert!(d.grad.eq_eps(-Vec3::unit_y()));
+
+        // Compare the quotient and chain rules against a central difference.
+        let f = |p: DualVec3| (p.x * p.y).sin() / (p.z.exp() + 1.0) + p.mag().powf(1.5);
+        let p = Vec3::new(0.3, -0.7, 0.2);
+        let grad = f(DualVec3::variable(p)).grad;
+        let h = 1e-3;
+        for axis in [Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z()] {
+            let fd = (f(DualVec3::constant(p + axis * h)).value
+                - f(DualVec3::constant(p - axis * h)).value)
+                / (2.0 * h);
+            assert!((grad.dot(axis) - fd).abs() < 1e-3);
+        }
+
+        let rotation = Rotor3::from_rotation_xz(0.4).into_matrix();
+        let rotated = rotation * DualVec3::variable(p);
+        assert!(rotated.value().eq_eps(rotation * p));
+        let jacobian = rotated.jacobian();
+        for i in 0..3 {
+            assert!(jacobian.cols[i].eq_eps(rotation.cols[i]));
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Fast approximations of math functions on the wide float types.
+//!
+//! The functions of `wide` such as `sin_cos` are already computed for all lanes at once, but
+//! they aim for close to full precision. When a few correct digits are enough, e.g. for
+//! particles or visualizations, the approximations in [`FastMath`] trade that precision for
+//! fewer instructions. Each method documents its maximum error.
+use crate::*;
+
+use wide::CmpLt;
+
+/// Fast, lower precision versions of common math functions, computed for all lanes at once.
+///
+/// ```rust
+/// # use ultraviolet::*;
+/// use ultraviolet::fast_math::FastMath;
+///
+/// let angles = f32x4::from([0.0, 0.5, 1.0, 3.0]);
+/// let (sin, cos) = angles.sin_cos_approx();
+/// let (exact_sin, exact_cos) = angles.sin_cos();
+/// for error in ((sin - exact_sin).abs().max((cos - exact_cos).abs())).to_array() {
+///     assert!(error < 2e-6);
+/// }
+/// ```
+pub trait FastMath: Sized {
+    /// The sine of each lane, in radians.
+    ///
+    /// The maximum absolute error is `2e-6` for inputs within `[-100, 100]`. Outside of that
+    /// range, precision is lost to the reduction of the input to a single turn.
+    fn sin_approx(self) -> Self;
+
+    /// The cosine of each lane, in radians, with the same error as [`FastMath::sin_approx`].
+    fn cos_approx(self) -> Self;
+
+    /// Both [`FastMath::sin_approx`] and [`FastMath::cos_approx`] of each lane.
+    fn sin_cos_approx(self) -> (Self, Self);
+
+    /// The arccosine of each lane, in radians.
+    ///
+    /// The maximum absolute error is `5e-5` radians. Lanes outside of `[-1, 1]` result in `NaN`.
+    fn acos_approx(self) -> Self;
+
+    /// The reciprocal of the square root of each lane, i.e. `1.0 / self.sqrt()`.
+    ///
+    /// The maximum relative error is `1e-6` for positive, finite lanes. A lane of zero results in
+    /// `NaN` rather than infinity.
+    fn rsqrt_approx(self) -> Self;
+}
+
+/// The sine of `$x + $offset`, where `$offset` is added after reducing `$x` to a single turn so
+/// that it doesn't lose precision for large inputs.
+macro_rules! sin_approx {
+    ($t:ident, $x:expr, $offset:expr) => {{
+        use core::f32::consts::{FRAC_1_PI, PI};
+
+        // Reduce to [-pi, pi], subtracting whole turns in two parts so the first product is
+        // exact.
+        let offset = $t::splat($offset);
+        let turns = (($x + offset) * $t::splat(0.5 * FRAC_1_PI)).round();
+        let x = $x - turns * $t::splat(6.28125) - turns * $t::splat(0.0019353072) + offset;
+        // Reflect into [-pi/2, pi/2], using sin(x) = sin(pi - x).
+        let pi = $t::splat(PI);
+        let x = x.min(pi - x).max(-pi - x);
+
+        // Minimax polynomial for sine on [-pi/2, pi/2].
+        let x2 = x * x;
+        let p = $t::splat(-1.8363654e-4);
+        let p = p * x2 + $t::splat(8.306325e-3);
+        let p = p * x2 + $t::splat(-0.16664828);
+        let p = p * x2 + $t::splat(0.9999966);
+        p * x
+    }};
+}
+
+macro_rules! impl_fast_math {
+    ($($t:ident),+) => {
+        $(impl FastMath for $t {
+            #[inline]
+            fn sin_approx(self) -> Self {
+                sin_approx!($t, self, 0.0)
+            }
+
+            #[inline]
+            fn cos_approx(self) -> Self {
+                sin_approx!($t, self, core::f32::consts::FRAC_PI_2)
+            }
+
+            #[inline]
+            fn sin_cos_approx(self) -> (Self, Self) {
+                (self.sin_approx(), self.cos_approx())
+            }
+
+            #[inline]
+            fn acos_approx(self) -> Self {
+                // acos(x) = sqrt(1 - x) * p(x) for x in [0, 1], with a minimax polynomial p, and
+                // acos(-x) = pi - acos(x).
+                let a = self.abs();
+                let p = $t::splat(-0.020892036);
+                let p = p * a + $t::splat(0.07689738);
+                let p = p * a + $t::splat(-0.21287518);
+                let p = p * a + $t::splat(1.5707583);
+                let r = ($t::ONE - a).sqrt() * p;
+                self.cmp_lt($t::ZERO).blend($t::splat(core::f32::consts::PI) - r, r)
+            }
+
+            #[inline]
+            fn rsqrt_approx(self) -> Self {
+                // Refine the hardware estimate where available with a step of Newton's method.
+                let y = self.recip_sqrt();
+                y * ($t::splat(1.5) - $t::splat(0.5) * self * y * y)
+            }
+        })+
+    };
+}
+
+impl_fast_math!(f32x4, f32x8);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn approximation_errors() {
+        let mut max_sin = 0.0f64;
+        let mut max_cos = 0.0f64;
+        let mut max_acos = 0.0f64;
+        let mut max_rsqrt = 0.0f64;
+        for i in 0..20000 {
+            let x = -100.0 + i as f32 * 0.01;
+            let (sin, cos) = f32x8::splat(x).sin_cos_approx();
+            max_sin = max_sin.max((sin.as_array_ref()[0] as f64 - (x as f64).sin()).abs());
+            max_cos = max_cos.max((cos.as_array_ref()[0] as f64 - (x as f64).cos()).abs());
+
+            let c = -1.0 + i as f32 * 1e-4;
+            let acos = f32x4::splat(c).acos_approx().as_array_ref()[0] as f64;
+            max_acos = max_acos.max((acos - (c as f64).acos()).abs());
+
+            let r = 1e-3 + i as f32 * 0.37;
+            let rsqrt = f32x4::splat(r).rsqrt_approx().as_array_ref()[0] as f64;
+            let exact = 1.0 / (r as f64).sqrt();
+            max_rsqrt = max_rsqrt.max(((rsqrt - exact) / exact).abs());
+        }
+        assert!(max_sin < 2e-6);
+        assert!(max_cos < 2e-6);
+        assert!(max_acos < 5e-5);
+        assert!(max_rsqrt < 1e-6);
+        assert_eq!(f32x4::splat(1.0).acos_approx().as_array_ref()[0], 0.0);
+    }
+}
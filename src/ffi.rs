@@ -0,0 +1,121 @@
+//! A C interface to the most common operations on the scalar `f32` types, for embedding
+//! `ultraviolet` math in C or C++ code such as engine plugins.
+//!
+//! All functions take and return the types by value, relying on their `#[repr(C)]` layout,
+//! which is checked at compile time: for example, a `Vec3` is three `float`s and a `Mat4` is
+//! sixteen `float`s in column-major order. A C header for this module can be generated with
+//! [`cbindgen`](https://github.com/mozilla/cbindgen) using the `cbindgen.toml` at the root of
+//! the repository:
+//!
+//! ```sh
+//! cbindgen --config cbindgen.toml --output ultraviolet.h
+//! ```
+use crate::*;
+
+/// The dot product of `a` and `b`.
+#[no_mangle]
+pub extern "C" fn uv_vec3_dot(a: Vec3, b: Vec3) -> f32 {
+    a.dot(b)
+}
+
+/// The cross product of `a` and `b`.
+#[no_mangle]
+pub extern "C" fn uv_vec3_cross(a: Vec3, b: Vec3) -> Vec3 {
+    a.cross(b)
+}
+
+/// `v` scaled to a length of one.
+#[no_mangle]
+pub extern "C" fn uv_vec3_normalized(v: Vec3) -> Vec3 {
+    v.normalized()
+}
+
+/// The product `a * b`, which applies `b` first.
+#[no_mangle]
+pub extern "C" fn uv_mat4_mul(a: Mat4, b: Mat4) -> Mat4 {
+    a * b
+}
+
+/// The inverse of `m`.
+#[no_mangle]
+pub extern "C" fn uv_mat4_inversed(m: Mat4) -> Mat4 {
+    m.inversed()
+}
+
+/// Transform `point` by `m`, see [`Mat4::transform_point3`].
+#[no_mangle]
+pub extern "C" fn uv_mat4_transform_point3(m: Mat4, point: Vec3) -> Vec3 {
+    m.transform_point3(point)
+}
+
+/// Transform the direction `vec` by `m`, ignoring its translation.
+#[no_mangle]
+pub extern "C" fn uv_mat4_transform_vec3(m: Mat4, vec: Vec3) -> Vec3 {
+    m.transform_vec3(vec)
+}
+
+/// A rotor from angles in radians, see [`Rotor3::from_euler_angles`].
+#[no_mangle]
+pub extern "C" fn uv_rotor3_from_euler_angles(roll: f32, pitch: f32, yaw: f32) -> Rotor3 {
+    Rotor3::from_euler_angles(roll, pitch, yaw)
+}
+
+/// The composition `a * b`, which applies `b` first.
+#[no_mangle]
+pub extern "C" fn uv_rotor3_mul(a: Rotor3, b: Rotor3) -> Rotor3 {
+    a * b
+}
+
+/// Rotate `vec` by `rotor`.
+#[no_mangle]
+pub extern "C" fn uv_rotor3_rotate_vec3(rotor: Rotor3, vec: Vec3) -> Vec3 {
+    rotor * vec
+}
+
+/// The rotation matrix of `rotor`.
+#[no_mangle]
+pub extern "C" fn uv_rotor3_into_mat3(rotor: Rotor3) -> Mat3 {
+    rotor.into_matrix()
+}
+
+/// The spherical linear interpolation between `a` and `b`.
+#[no_mangle]
+pub extern "C" fn uv_rotor3_slerp(a: Rotor3, b: Rotor3, t: f32) -> Rotor3 {
+    a.slerp(b, t)
+}
+
+/// The homogeneous matrix of `iso`.
+#[no_mangle]
+pub extern "C" fn uv_isometry3_into_mat4(iso: Isometry3) -> Mat4 {
+    iso.into_homogeneous_matrix()
+}
+
+/// The inverse of `iso`.
+#[no_mangle]
+pub extern "C" fn uv_isometry3_inversed(iso: Isometry3) -> Isometry3 {
+    iso.inversed()
+}
+
+/// Transform `point` by `iso`.
+#[no_mangle]
+pub extern "C" fn uv_isometry3_transform_point3(iso: Isometry3, point: Vec3) -> Vec3 {
+    iso.transform_vec(point)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn matches_rust_api() {
+        let rotor = uv_rotor3_from_euler_angles(0.3, -0.7, 1.2);
+        let iso = Isometry3::new(Vec3::new(1.0, 2.0, 3.0), rotor);
+        let p = Vec3::new(-1.0, 0.5, 2.0);
+
+        let mat = uv_isometry3_into_mat4(iso);
+        assert!(uv_mat4_transform_point3(mat, p).eq_eps(uv_isometry3_transform_point3(iso, p)));
+        assert!(uv_mat4_transform_point3(uv_mat4_mul(uv_mat4_inversed(mat), mat), p).eq_eps(p));
+        assert!((uv_rotor3_into_mat3(rotor) * p).eq_eps(uv_rotor3_rotate_vec3(rotor, p)));
+    }
+}
@@ -0,0 +1,385 @@
+//! A generic facade over the concrete types, for writing code once for every precision and
+//! lane count.
+//!
+//! The concrete types such as [`Vec3`] and [`DVec3x4`] remain the primary API. This module
+//! adds the [`GScalar`] trait, implemented for `f32`, `f32x4`, `f32x8` and, with the `f64`
+//! feature, `f64`, `f64x2` and `f64x4`, which maps each scalar type to its vector, matrix and
+//! rotor types. The aliases [`GVec3<T>`], [`GMat4<T>`] and [`GRotor3<T>`] name those types, and
+//! the traits [`GVec3Ops`], [`GMat4Ops`] and [`GRotor3Ops`] expose their common methods, so
+//! that generic code compiles down to exactly the same calls as concrete code.
+//!
+//! ```rust
+//! use ultraviolet::generic::*;
+//! use ultraviolet::{f32x8, Vec3, Vec3x8};
+//!
+//! /// The distance of `point` from a plane through the origin, for any scalar type.
+//! fn plane_distance<T: GScalar>(normal: GVec3<T>, point: GVec3<T>) -> T {
+//!     normal.normalized().dot(point)
+//! }
+//!
+//! let normal = Vec3::new(0.0, 2.0, 0.0);
+//! assert_eq!(plane_distance::<f32>(normal, Vec3::new(1.0, 3.0, 5.0)), 3.0);
+//! let wide = plane_distance::<f32x8>(Vec3x8::splat(normal), Vec3x8::splat(Vec3::unit_y()));
+//! assert_eq!(wide, f32x8::splat(1.0));
+//! ```
+use core::fmt::Debug;
+use core::ops::*;
+
+/// A scalar type, i.e. `f32` or `f64` or one of their wide versions, along with the vector,
+/// matrix and rotor types built from it.
+pub trait GScalar:
+    Copy
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// The 3d vector type with components of this type.
+    type Vec3: GVec3Ops<Self>;
+    /// The 4x4 matrix type with components of this type.
+    type Mat4: GMat4Ops<Self>;
+    /// The 3d rotor type with components of this type.
+    type Rotor3: GRotor3Ops<Self>;
+
+    /// `value`, in every lane for the wide types. Converting to an `f32` type rounds.
+    fn splat(value: f64) -> Self;
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+}
+
+/// The 3d vector type of the scalar type `T`, e.g. `GVec3<f32x8>` is `Vec3x8`.
+pub type GVec3<T> = <T as GScalar>::Vec3;
+/// The 4x4 matrix type of the scalar type `T`, e.g. `GMat4<f64>` is `DMat4`.
+pub type GMat4<T> = <T as GScalar>::Mat4;
+/// The 3d rotor type of the scalar type `T`, e.g. `GRotor3<f32>` is `Rotor3`.
+pub type GRotor3<T> = <T as GScalar>::Rotor3;
+
+/// The methods and operators common to the 3d vector types.
+pub trait GVec3Ops<T>:
+    Copy
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<T, Output = Self>
+    + Mul<Output = Self>
+    + Div<T, Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign<T>
+{
+    fn new(x: T, y: T, z: T) -> Self;
+    fn broadcast(value: T) -> Self;
+    fn x(&self) -> T;
+    fn y(&self) -> T;
+    fn z(&self) -> T;
+    fn dot(&self, other: Self) -> T;
+    fn cross(&self, other: Self) -> Self;
+    fn mag_sq(&self) -> T;
+    fn mag(&self) -> T;
+    fn normalized(&self) -> Self;
+}
+
+/// The methods and operators common to the 4x4 matrix types.
+pub trait GMat4Ops<T: GScalar>: Copy + Debug + Mul<Output = Self> {
+    fn identity() -> Self;
+    fn from_translation(translation: GVec3<T>) -> Self;
+    fn from_scale(scale: T) -> Self;
+    fn from_nonuniform_scale(scale: GVec3<T>) -> Self;
+    fn transform_point3(&self, point: GVec3<T>) -> GVec3<T>;
+    fn transform_vec3(&self, vec: GVec3<T>) -> GVec3<T>;
+    fn transposed(&self) -> Self;
+    fn inversed(&self) -> Self;
+}
+
+/// The methods and operators common to the 3d rotor types.
+pub trait GRotor3Ops<T: GScalar>: Copy + Debug + Mul<Output = Self> {
+    fn identity() -> Self;
+    fn from_rotation_between(from: GVec3<T>, to: GVec3<T>) -> Self;
+    fn from_euler_angles(roll: T, pitch: T, yaw: T) -> Self;
+    fn normalized(&self) -> Self;
+    fn reversed(&self) -> Self;
+    fn rotate_vec3(&self, vec: GVec3<T>) -> GVec3<T>;
+    fn into_mat4(self) -> GMat4<T>;
+}
+
+macro_rules! impl_generic {
+    ($($t:ident => ($vt:ident, $mt:ident, $rt:ident, $from:ident)),+) => {
+        $(impl super::GScalar for $t {
+            type Vec3 = $vt;
+            type Mat4 = $mt;
+            type Rotor3 = $rt;
+
+            #[inline]
+            fn splat(value: f64) -> Self {
+                <$t as Inherent<$from>>::inherent_splat(value as $from)
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                <$t as Inherent<$from>>::inherent_sqrt(self)
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                <$t as Inherent<$from>>::inherent_abs(self)
+            }
+
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                <$t as Inherent<$from>>::inherent_min(self, other)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                <$t as Inherent<$from>>::inherent_max(self, other)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                Transcendental::sin_cos(self)
+            }
+        }
+
+        impl super::GVec3Ops<$t> for $vt {
+            #[inline]
+            fn new(x: $t, y: $t, z: $t) -> Self {
+                $vt::new(x, y, z)
+            }
+
+            #[inline]
+            fn broadcast(value: $t) -> Self {
+                $vt::broadcast(value)
+            }
+
+            #[inline]
+            fn x(&self) -> $t {
+                self.x
+            }
+
+            #[inline]
+            fn y(&self) -> $t {
+                self.y
+            }
+
+            #[inline]
+            fn z(&self) -> $t {
+                self.z
+            }
+
+            #[inline]
+            fn dot(&self, other: Self) -> $t {
+                $vt::dot(self, other)
+            }
+
+            #[inline]
+            fn cross(&self, other: Self) -> Self {
+                $vt::cross(self, other)
+            }
+
+            #[inline]
+            fn mag_sq(&self) -> $t {
+                $vt::mag_sq(self)
+            }
+
+            #[inline]
+            fn mag(&self) -> $t {
+                $vt::mag(self)
+            }
+
+            #[inline]
+            fn normalized(&self) -> Self {
+                $vt::normalized(self)
+            }
+        }
+
+        impl super::GMat4Ops<$t> for $mt {
+            #[inline]
+            fn identity() -> Self {
+                $mt::identity()
+            }
+
+            #[inline]
+            fn from_translation(translation: $vt) -> Self {
+                $mt::from_translation(translation)
+            }
+
+            #[inline]
+            fn from_scale(scale: $t) -> Self {
+                $mt::from_scale(scale)
+            }
+
+            #[inline]
+            fn from_nonuniform_scale(scale: $vt) -> Self {
+                $mt::from_nonuniform_scale(scale)
+            }
+
+            #[inline]
+            fn transform_point3(&self, point: $vt) -> $vt {
+                $mt::transform_point3(self, point)
+            }
+
+            #[inline]
+            fn transform_vec3(&self, vec: $vt) -> $vt {
+                $mt::transform_vec3(self, vec)
+            }
+
+            #[inline]
+            fn transposed(&self) -> Self {
+                $mt::transposed(self)
+            }
+
+            #[inline]
+            fn inversed(&self) -> Self {
+                $mt::inversed(self)
+            }
+        }
+
+        impl super::GRotor3Ops<$t> for $rt {
+            #[inline]
+            fn identity() -> Self {
+                $rt::identity()
+            }
+
+            #[inline]
+            fn from_rotation_between(from: $vt, to: $vt) -> Self {
+                $rt::from_rotation_between(from, to)
+            }
+
+            #[inline]
+            fn from_euler_angles(roll: $t, pitch: $t, yaw: $t) -> Self {
+                $rt::from_euler_angles(roll, pitch, yaw)
+            }
+
+            #[inline]
+            fn normalized(&self) -> Self {
+                $rt::normalized(self)
+            }
+
+            #[inline]
+            fn reversed(&self) -> Self {
+                $rt::reversed(self)
+            }
+
+            #[inline]
+            fn rotate_vec3(&self, vec: $vt) -> $vt {
+                *self * vec
+            }
+
+            #[inline]
+            fn into_mat4(self) -> $mt {
+                self.into_matrix().into_homogeneous()
+            }
+        })+
+    };
+}
+
+mod impls {
+    use crate::*;
+
+    /// The methods of the concrete scalar types under other names, as calling them by their own
+    /// names from the implementations of `GScalar` would be ambiguous with its methods.
+    trait Inherent<F> {
+        fn inherent_splat(value: F) -> Self;
+        fn inherent_sqrt(self) -> Self;
+        fn inherent_abs(self) -> Self;
+        fn inherent_min(self, other: Self) -> Self;
+        fn inherent_max(self, other: Self) -> Self;
+    }
+
+    macro_rules! impl_inherent {
+        ($($t:ident => $from:ident),+) => {
+            $(impl Inherent<$from> for $t {
+                #[inline]
+                fn inherent_splat(value: $from) -> Self {
+                    $t::splat(value)
+                }
+
+                #[inline]
+                fn inherent_sqrt(self) -> Self {
+                    self.sqrt()
+                }
+
+                #[inline]
+                fn inherent_abs(self) -> Self {
+                    self.abs()
+                }
+
+                #[inline]
+                fn inherent_min(self, other: Self) -> Self {
+                    self.min(other)
+                }
+
+                #[inline]
+                fn inherent_max(self, other: Self) -> Self {
+                    self.max(other)
+                }
+            })+
+        };
+    }
+
+    impl_inherent!(f32 => f32, f32x4 => f32, f32x8 => f32);
+
+    #[cfg(feature = "f64")]
+    impl_inherent!(f64 => f64, f64x2 => f64, f64x4 => f64);
+
+    impl_generic!(
+        f32 => (Vec3, Mat4, Rotor3, f32),
+        f32x4 => (Vec3x4, Mat4x4, Rotor3x4, f32),
+        f32x8 => (Vec3x8, Mat4x8, Rotor3x8, f32)
+    );
+
+    #[cfg(feature = "f64")]
+    impl_generic!(
+        f64 => (DVec3, DMat4, DRotor3, f64),
+        f64x2 => (DVec3x2, DMat4x2, DRotor3x2, f64),
+        f64x4 => (DVec3x4, DMat4x4, DRotor3x4, f64)
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::*;
+    use crate::*;
+
+    /// Rotate `point` about `pivot` and then move it by `offset`, through the generic API only.
+    fn orbit<T: GScalar>(point: GVec3<T>, pivot: GVec3<T>, angle: T, offset: GVec3<T>) -> GVec3<T> {
+        let rotor = GRotor3::<T>::from_euler_angles(T::splat(0.0), T::splat(0.0), angle);
+        let rotated = rotor.rotate_vec3(point - pivot) + pivot;
+        GMat4::<T>::from_translation(offset).transform_point3(rotated)
+    }
+
+    #[test]
+    fn generic_matches_concrete() {
+        let point = Vec3::new(1.0, 2.0, 3.0);
+        let pivot = Vec3::new(0.5, -1.0, 0.0);
+        let offset = Vec3::new(0.0, 0.0, 10.0);
+        let expected = Rotor3::from_euler_angles(0.0, 0.0, 0.7) * (point - pivot) + pivot + offset;
+
+        assert!(orbit::<f32>(point, pivot, 0.7, offset).eq_eps(expected));
+        let wide = orbit::<f32x4>(
+            Vec3x4::splat(point),
+            Vec3x4::splat(pivot),
+            f32x4::splat(0.7),
+            Vec3x4::splat(offset),
+        );
+        let lanes: [Vec3; 4] = wide.into();
+        assert!(lanes[3].eq_eps(expected));
+
+        let rotor = GRotor3::<f32>::from_rotation_between(Vec3::unit_x(), Vec3::unit_y());
+        assert!(rotor
+            .into_mat4()
+            .transform_vec3(Vec3::unit_x())
+            .eq_eps(Vec3::unit_y()));
+    }
+}
@@ -0,0 +1,121 @@
+use crate::*;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+/// Compare a field of `self` and `other` with the given method. For matrices the field is an
+/// array of columns, which are compared one by one.
+macro_rules! cmp_field {
+    ($self:ident, $other:ident, cols, $method:ident($($arg:ident),+)) => {
+        $self.cols.iter().zip($other.cols.iter()).all(|(a, b)| a.$method(b, $($arg),+))
+    };
+    ($self:ident, $other:ident, $f:ident, $method:ident($($arg:ident),+)) => {
+        $self.$f.$method(&$other.$f, $($arg),+)
+    };
+}
+
+macro_rules! impl_approx {
+    ($($t:ident => ($($n:ident { $($f:ident),+ }),+)),+) => {
+        $($(impl AbsDiffEq for $n {
+            type Epsilon = $t;
+
+            #[inline]
+            fn default_epsilon() -> $t {
+                $t::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                true $(&& cmp_field!(self, other, $f, abs_diff_eq(epsilon)))+
+            }
+        }
+
+        impl RelativeEq for $n {
+            #[inline]
+            fn default_max_relative() -> $t {
+                $t::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                true $(&& cmp_field!(self, other, $f, relative_eq(epsilon, max_relative)))+
+            }
+        }
+
+        impl UlpsEq for $n {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                $t::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                true $(&& cmp_field!(self, other, $f, ulps_eq(epsilon, max_ulps)))+
+            }
+        })+)+
+    };
+}
+
+impl_approx!(
+    f32 => (
+        Vec2 { x, y },
+        Vec3 { x, y, z },
+        Vec3A { x, y, z },
+        Vec4 { x, y, z, w },
+        Bivec2 { xy },
+        Bivec3 { xy, xz, yz },
+        Rotor2 { s, bv },
+        Rotor3 { s, bv },
+        Mat2 { cols },
+        Mat3 { cols },
+        Mat4 { cols },
+        Isometry2 { translation, rotation },
+        Isometry3 { translation, rotation },
+        Similarity2 { translation, rotation, scale },
+        Similarity3 { translation, rotation, scale },
+        Transform3 { translation, rotation, scale }
+    )
+);
+
+#[cfg(feature = "f64")]
+impl_approx!(
+    f64 => (
+        DVec2 { x, y },
+        DVec3 { x, y, z },
+        DVec4 { x, y, z, w },
+        DBivec2 { xy },
+        DBivec3 { xy, xz, yz },
+        DRotor2 { s, bv },
+        DRotor3 { s, bv },
+        DMat2 { cols },
+        DMat3 { cols },
+        DMat4 { cols },
+        DIsometry2 { translation, rotation },
+        DIsometry3 { translation, rotation },
+        DSimilarity2 { translation, rotation, scale },
+        DSimilarity3 { translation, rotation, scale },
+        DTransform3 { translation, rotation, scale }
+    )
+);
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_ne};
+
+    #[test]
+    fn approx_eq() {
+        let r = Rotor3::from_rotation_xz(0.5);
+        assert_relative_eq!(r * r.reversed(), Rotor3::identity(), epsilon = 1e-6);
+        assert_relative_eq!(
+            r.into_matrix().into_homogeneous(),
+            Mat4::from_rotation_y(-0.5),
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            Isometry3::new(Vec3::one(), r) * Isometry3::new(Vec3::one(), r).inversed(),
+            Isometry3::identity(),
+            epsilon = 1e-6
+        );
+        assert_ulps_ne!(Vec2::new(1.0, 2.0), Vec2::new(1.0, 2.001));
+    }
+}
@@ -0,0 +1,73 @@
+//! `AsRef` and `AsMut` implementations viewing the vector and matrix types as arrays of their
+//! components, and `TryFrom` implementations copying them out of slices of exactly as many
+//! elements as the type has components, e.g. out of a flat buffer of shader uniforms.
+//!
+//! A matrix is viewed in column-major order, like [`Mat4::as_array`].
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! use core::convert::TryFrom;
+//!
+//! let buffer = [1.0, 2.0, 3.0, 4.0, 5.0];
+//! let v = Vec3::try_from(&buffer[2..]).unwrap();
+//! assert_eq!(v, Vec3::new(3.0, 4.0, 5.0));
+//! assert!(Vec3::try_from(&buffer[3..]).is_err());
+//!
+//! let components: &[f32; 3] = v.as_ref();
+//! assert_eq!(components, &buffer[2..]);
+//! ```
+use crate::*;
+
+use core::array::TryFromSliceError;
+use core::convert::TryFrom;
+
+macro_rules! impl_array_conversions {
+    ($($n:ident => [$t:ident; $len:expr]),+) => {
+        $(impl AsRef<[$t; $len]> for $n {
+            #[inline]
+            fn as_ref(&self) -> &[$t; $len] {
+                self.as_array()
+            }
+        }
+
+        impl AsMut<[$t; $len]> for $n {
+            #[inline]
+            fn as_mut(&mut self) -> &mut [$t; $len] {
+                self.as_mut_array()
+            }
+        }
+
+        /// Copy the components out of `slice`, failing if its length isn't exactly the number of
+        /// components.
+        impl TryFrom<&[$t]> for $n {
+            type Error = TryFromSliceError;
+
+            #[inline]
+            fn try_from(slice: &[$t]) -> Result<Self, Self::Error> {
+                let array = <&[$t; $len]>::try_from(slice)?;
+                let mut result = Self::default();
+                *result.as_mut_array() = *array;
+                Ok(result)
+            }
+        })+
+    };
+}
+
+impl_array_conversions!(
+    Vec2 => [f32; 2], Vec2x4 => [f32x4; 2], Vec2x8 => [f32x8; 2],
+    Vec3 => [f32; 3], Vec3x4 => [f32x4; 3], Vec3x8 => [f32x8; 3], Vec3A => [f32; 3],
+    Vec4 => [f32; 4], Vec4x4 => [f32x4; 4], Vec4x8 => [f32x8; 4],
+    Mat2 => [f32; 4], Mat2x4 => [f32x4; 4], Mat2x8 => [f32x8; 4],
+    Mat3 => [f32; 9], Mat3x4 => [f32x4; 9], Mat3x8 => [f32x8; 9],
+    Mat4 => [f32; 16], Mat4x4 => [f32x4; 16], Mat4x8 => [f32x8; 16]
+);
+
+#[cfg(feature = "f64")]
+impl_array_conversions!(
+    DVec2 => [f64; 2], DVec2x2 => [f64x2; 2], DVec2x4 => [f64x4; 2],
+    DVec3 => [f64; 3], DVec3x2 => [f64x2; 3], DVec3x4 => [f64x4; 3],
+    DVec4 => [f64; 4], DVec4x2 => [f64x2; 4], DVec4x4 => [f64x4; 4],
+    DMat2 => [f64; 4], DMat2x2 => [f64x2; 4], DMat2x4 => [f64x4; 4],
+    DMat3 => [f64; 9], DMat3x2 => [f64x2; 9], DMat3x4 => [f64x4; 9],
+    DMat4 => [f64; 16], DMat4x2 => [f64x2; 16], DMat4x4 => [f64x4; 16]
+);
@@ -10,6 +10,9 @@ unsafe impl Zeroable for Vec2 {}
 unsafe impl Pod for Vec3 {}
 unsafe impl Zeroable for Vec3 {}
 
+// The fourth lane of a `Vec3A` must be zero, so it is `Zeroable` but not `Pod`.
+unsafe impl Zeroable for Vec3A {}
+
 unsafe impl Pod for Vec4 {}
 unsafe impl Zeroable for Vec4 {}
 
@@ -34,6 +37,9 @@ unsafe impl Zeroable for Mat3 {}
 unsafe impl Pod for Mat4 {}
 unsafe impl Zeroable for Mat4 {}
 
+unsafe impl Pod for Mat3Std140 {}
+unsafe impl Zeroable for Mat3Std140 {}
+
 unsafe impl Pod for Isometry2 {}
 unsafe impl Zeroable for Isometry2 {}
 
@@ -46,6 +52,130 @@ unsafe impl Zeroable for Similarity2 {}
 unsafe impl Pod for Similarity3 {}
 unsafe impl Zeroable for Similarity3 {}
 
+unsafe impl Pod for Transform3 {}
+unsafe impl Zeroable for Transform3 {}
+
+unsafe impl Pod for Aabb2 {}
+unsafe impl Zeroable for Aabb2 {}
+
+unsafe impl Pod for Aabb3 {}
+unsafe impl Zeroable for Aabb3 {}
+
+unsafe impl Pod for Ray3 {}
+unsafe impl Zeroable for Ray3 {}
+
+unsafe impl Pod for Line3 {}
+unsafe impl Zeroable for Line3 {}
+
+// ...
+
+unsafe impl Pod for Vec2x4 {}
+unsafe impl Zeroable for Vec2x4 {}
+
+unsafe impl Pod for Vec3x4 {}
+unsafe impl Zeroable for Vec3x4 {}
+
+unsafe impl Pod for Vec4x4 {}
+unsafe impl Zeroable for Vec4x4 {}
+
+unsafe impl Pod for Bivec2x4 {}
+unsafe impl Zeroable for Bivec2x4 {}
+
+unsafe impl Pod for Bivec3x4 {}
+unsafe impl Zeroable for Bivec3x4 {}
+
+unsafe impl Pod for Rotor2x4 {}
+unsafe impl Zeroable for Rotor2x4 {}
+
+unsafe impl Pod for Rotor3x4 {}
+unsafe impl Zeroable for Rotor3x4 {}
+
+unsafe impl Pod for Mat2x4 {}
+unsafe impl Zeroable for Mat2x4 {}
+
+unsafe impl Pod for Mat3x4 {}
+unsafe impl Zeroable for Mat3x4 {}
+
+unsafe impl Pod for Mat4x4 {}
+unsafe impl Zeroable for Mat4x4 {}
+
+unsafe impl Pod for Isometry2x4 {}
+unsafe impl Zeroable for Isometry2x4 {}
+
+unsafe impl Pod for Isometry3x4 {}
+unsafe impl Zeroable for Isometry3x4 {}
+
+unsafe impl Pod for Similarity2x4 {}
+unsafe impl Zeroable for Similarity2x4 {}
+
+unsafe impl Pod for Similarity3x4 {}
+unsafe impl Zeroable for Similarity3x4 {}
+
+unsafe impl Pod for Transform3x4 {}
+unsafe impl Zeroable for Transform3x4 {}
+
+unsafe impl Pod for Vec2x8 {}
+unsafe impl Zeroable for Vec2x8 {}
+
+unsafe impl Pod for Vec3x8 {}
+unsafe impl Zeroable for Vec3x8 {}
+
+unsafe impl Pod for Vec4x8 {}
+unsafe impl Zeroable for Vec4x8 {}
+
+unsafe impl Pod for Bivec2x8 {}
+unsafe impl Zeroable for Bivec2x8 {}
+
+unsafe impl Pod for Bivec3x8 {}
+unsafe impl Zeroable for Bivec3x8 {}
+
+unsafe impl Pod for Rotor2x8 {}
+unsafe impl Zeroable for Rotor2x8 {}
+
+unsafe impl Pod for Rotor3x8 {}
+unsafe impl Zeroable for Rotor3x8 {}
+
+unsafe impl Pod for Mat2x8 {}
+unsafe impl Zeroable for Mat2x8 {}
+
+unsafe impl Pod for Mat3x8 {}
+unsafe impl Zeroable for Mat3x8 {}
+
+unsafe impl Pod for Mat4x8 {}
+unsafe impl Zeroable for Mat4x8 {}
+
+unsafe impl Pod for Isometry2x8 {}
+unsafe impl Zeroable for Isometry2x8 {}
+
+unsafe impl Pod for Isometry3x8 {}
+unsafe impl Zeroable for Isometry3x8 {}
+
+unsafe impl Pod for Similarity2x8 {}
+unsafe impl Zeroable for Similarity2x8 {}
+
+unsafe impl Pod for Similarity3x8 {}
+unsafe impl Zeroable for Similarity3x8 {}
+
+unsafe impl Pod for Transform3x8 {}
+unsafe impl Zeroable for Transform3x8 {}
+
+// ...
+
+#[cfg(feature = "half")]
+unsafe impl Pod for HVec2 {}
+#[cfg(feature = "half")]
+unsafe impl Zeroable for HVec2 {}
+
+#[cfg(feature = "half")]
+unsafe impl Pod for HVec3 {}
+#[cfg(feature = "half")]
+unsafe impl Zeroable for HVec3 {}
+
+#[cfg(feature = "half")]
+unsafe impl Pod for HVec4 {}
+#[cfg(feature = "half")]
+unsafe impl Zeroable for HVec4 {}
+
 // ...
 
 #[cfg(feature = "f64")]
@@ -118,6 +248,183 @@ unsafe impl Pod for DSimilarity3 {}
 #[cfg(feature = "f64")]
 unsafe impl Zeroable for DSimilarity3 {}
 
+#[cfg(feature = "f64")]
+unsafe impl Pod for DTransform3 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DTransform3 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DAabb2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DAabb2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DAabb3 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DAabb3 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DRay3 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DRay3 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DLine3 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DLine3 {}
+
+// ...
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DVec2x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DVec2x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DVec3x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DVec3x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DVec4x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DVec4x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DBivec2x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DBivec2x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DBivec3x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DBivec3x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DRotor2x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DRotor2x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DRotor3x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DRotor3x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DMat2x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DMat2x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DMat3x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DMat3x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DMat4x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DMat4x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DIsometry2x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DIsometry2x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DIsometry3x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DIsometry3x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DSimilarity2x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DSimilarity2x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DSimilarity3x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DSimilarity3x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DTransform3x2 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DTransform3x2 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DVec2x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DVec2x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DVec3x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DVec3x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DVec4x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DVec4x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DBivec2x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DBivec2x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DBivec3x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DBivec3x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DRotor2x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DRotor2x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DRotor3x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DRotor3x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DMat2x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DMat2x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DMat3x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DMat3x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DMat4x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DMat4x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DIsometry2x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DIsometry2x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DIsometry3x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DIsometry3x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DSimilarity2x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DSimilarity2x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DSimilarity3x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DSimilarity3x4 {}
+
+#[cfg(feature = "f64")]
+unsafe impl Pod for DTransform3x4 {}
+#[cfg(feature = "f64")]
+unsafe impl Zeroable for DTransform3x4 {}
+
 // ...
 
 #[cfg(feature = "int")]
@@ -149,3 +456,126 @@ unsafe impl Zeroable for UVec3 {}
 unsafe impl Pod for UVec4 {}
 #[cfg(feature = "int")]
 unsafe impl Zeroable for UVec4 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for U8Vec2 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for U8Vec2 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for U8Vec3 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for U8Vec3 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for U8Vec4 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for U8Vec4 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for U16Vec2 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for U16Vec2 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for U16Vec3 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for U16Vec3 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for U16Vec4 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for U16Vec4 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for I64Vec2 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for I64Vec2 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for I64Vec3 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for I64Vec3 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for I64Vec4 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for I64Vec4 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for IVec2x4 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for IVec2x4 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for IVec3x4 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for IVec3x4 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for IVec4x4 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for IVec4x4 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for IVec2x8 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for IVec2x8 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for IVec3x8 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for IVec3x8 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for IVec4x8 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for IVec4x8 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for UVec2x4 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for UVec2x4 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for UVec3x4 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for UVec3x4 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for UVec4x4 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for UVec4x4 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for UVec2x8 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for UVec2x8 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for UVec3x8 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for UVec3x8 {}
+
+#[cfg(feature = "int")]
+unsafe impl Pod for UVec4x8 {}
+#[cfg(feature = "int")]
+unsafe impl Zeroable for UVec4x8 {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::mem::size_of;
+
+    #[test]
+    fn wide_types_have_no_padding() {
+        assert_eq!(size_of::<Vec3x4>(), 3 * size_of::<f32x4>());
+        assert_eq!(size_of::<Isometry3x8>(), 7 * size_of::<f32x8>());
+        assert_eq!(size_of::<Similarity3x4>(), 8 * size_of::<f32x4>());
+        assert_eq!(size_of::<Transform3x8>(), 10 * size_of::<f32x8>());
+
+        let vecs = [Vec3x4::one(); 2];
+        let floats: &[f32] = bytemuck::cast_slice(&vecs);
+        assert_eq!(floats, &[1.0; 24]);
+    }
+}
@@ -0,0 +1,70 @@
+//! `encase` implementations, so that the scalar `f32` vectors and matrices can be used as fields
+//! of structs deriving `encase::ShaderType`, e.g. for `wgpu` uniform and storage buffers.
+//!
+//! `encase` takes care of the alignment and padding rules of WGSL, so a `Mat3` or `Vec3` is
+//! written with the padding the shader expects.
+//!
+//! ```rust
+//! use encase::{ShaderType, UniformBuffer};
+//! use ultraviolet::*;
+//!
+//! #[derive(ShaderType)]
+//! struct Uniforms {
+//!     normal_matrix: Mat3,
+//!     light_dir: Vec3,
+//!     intensity: f32,
+//! }
+//!
+//! let mut buffer = UniformBuffer::new(Vec::<u8>::new());
+//! buffer
+//!     .write(&Uniforms {
+//!         normal_matrix: Mat3::identity(),
+//!         light_dir: Vec3::unit_y(),
+//!         intensity: 1.0,
+//!     })
+//!     .unwrap();
+//! // Each column of the `mat3x3` is padded to 16 bytes, and `intensity` fills the padding of
+//! // `light_dir`.
+//! assert_eq!(buffer.into_inner().len(), 64);
+//! ```
+use crate::*;
+
+use encase::matrix::{AsMutMatrixParts, AsRefMatrixParts, FromMatrixParts};
+
+encase::impl_vector!(2, Vec2, f32; using AsRef AsMut From);
+encase::impl_vector!(3, Vec3, f32; using AsRef AsMut From);
+encase::impl_vector!(3, Vec3A, f32; using AsRef AsMut From);
+encase::impl_vector!(4, Vec4, f32; using AsRef AsMut From);
+
+macro_rules! impl_matrix_parts {
+    ($($mt:ident => $vt:ident, $n:literal),+) => {
+        $(impl AsRefMatrixParts<f32, $n, $n> for $mt {
+            #[inline]
+            fn as_ref_parts(&self) -> &[[f32; $n]; $n] {
+                let ptr = &self.cols as *const [$vt; $n] as *const [[f32; $n]; $n];
+                // SAFETY: the vectors are `repr(C)` structs of `$n` `f32`s, without padding.
+                unsafe { &*ptr }
+            }
+        }
+
+        impl AsMutMatrixParts<f32, $n, $n> for $mt {
+            #[inline]
+            fn as_mut_parts(&mut self) -> &mut [[f32; $n]; $n] {
+                let ptr = &mut self.cols as *mut [$vt; $n] as *mut [[f32; $n]; $n];
+                // SAFETY: the vectors are `repr(C)` structs of `$n` `f32`s, without padding.
+                unsafe { &mut *ptr }
+            }
+        }
+
+        impl FromMatrixParts<f32, $n, $n> for $mt {
+            #[inline]
+            fn from_parts(parts: [[f32; $n]; $n]) -> Self {
+                Self { cols: parts.map($vt::from) }
+            }
+        }
+
+        encase::impl_matrix!($n, $n, $mt, f32);)+
+    };
+}
+
+impl_matrix_parts!(Mat2 => Vec2, 2, Mat3 => Vec3, 3, Mat4 => Vec4, 4);
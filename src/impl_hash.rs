@@ -0,0 +1,134 @@
+//! `Hash` and `Eq` implementations for the scalar float types, so they can be used as keys in
+//! hash maps, e.g. for welding mesh vertices.
+//!
+//! Components are hashed by their bit patterns, with `-0.0` hashed like `0.0` since the two
+//! compare equal. Equality stays the usual floating point comparison, so a value containing
+//! `NaN` is not equal to itself and will never be found when used as a key.
+use crate::*;
+
+use core::hash::{Hash, Hasher};
+
+/// Crate-private bit patterns of floats that are equal for values comparing equal, except `NaN`.
+trait CanonicalBits {
+    type Bits: Hash;
+    fn canonical_bits(self) -> Self::Bits;
+}
+
+impl CanonicalBits for f32 {
+    type Bits = u32;
+
+    #[inline]
+    fn canonical_bits(self) -> u32 {
+        if self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        }
+    }
+}
+
+impl CanonicalBits for f64 {
+    type Bits = u64;
+
+    #[inline]
+    fn canonical_bits(self) -> u64 {
+        if self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        }
+    }
+}
+
+/// Hash a field of `self`. Matrix columns and rotor bivectors are hashed with their own `Hash`
+/// implementations.
+macro_rules! hash_field {
+    ($self:ident, $state:ident, cols) => {
+        $self.cols.hash($state)
+    };
+    ($self:ident, $state:ident, bv) => {
+        $self.bv.hash($state)
+    };
+    ($self:ident, $state:ident, $f:ident) => {
+        $self.$f.canonical_bits().hash($state)
+    };
+}
+
+macro_rules! impl_hash {
+    ($($n:ident { $($f:ident),+ }),+) => {
+        $(impl Hash for $n {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                $(hash_field!(self, state, $f);)+
+            }
+        }
+
+        /// **Not reflexive for `NaN`:** equality is still the floating point comparison, so a
+        /// value with a `NaN` component is not equal to itself. Never use such a value as a
+        /// hash map key, since it can't be found again.
+        impl Eq for $n {})+
+    };
+}
+
+impl_hash!(
+    Vec2 { x, y },
+    Vec3 { x, y, z },
+    Vec3A { x, y, z },
+    Vec4 { x, y, z, w },
+    Bivec2 { xy },
+    Bivec3 { xy, xz, yz },
+    Rotor2 { s, bv },
+    Rotor3 { s, bv },
+    Mat2 { cols },
+    Mat3 { cols },
+    Mat4 { cols }
+);
+
+#[cfg(feature = "f64")]
+impl_hash!(
+    DVec2 { x, y },
+    DVec3 { x, y, z },
+    DVec4 { x, y, z, w },
+    DBivec2 { xy },
+    DBivec3 { xy, xz, yz },
+    DRotor2 { s, bv },
+    DRotor3 { s, bv },
+    DMat2 { cols },
+    DMat3 { cols },
+    DMat4 { cols }
+);
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    use std::collections::HashMap;
+
+    #[test]
+    fn weld_vertices() {
+        let positions = [
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(-0.0, 1.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+        ];
+        let mut welded = HashMap::new();
+        let indices: Vec<usize> = positions
+            .iter()
+            .map(|p| {
+                let next = welded.len();
+                *welded.entry(*p).or_insert(next)
+            })
+            .collect();
+        assert_eq!(indices, [0, 1, 0, 1]);
+
+        let mut rotors = HashMap::new();
+        rotors.insert(Rotor3::identity().into_matrix(), 1);
+        assert_eq!(rotors.get(&Mat3::identity()), Some(&1));
+
+        // The documented caveat: a key with a `NaN` component can't be found again.
+        let nan = Vec3::new(f32::NAN, 0.0, 0.0);
+        welded.insert(nan, 2);
+        assert_eq!(welded.get(&nan), None);
+    }
+}
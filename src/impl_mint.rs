@@ -75,7 +75,13 @@ from_vec2s!(
     mint::Vector2<i32> => IVec2,
     mint::Point2<i32> => IVec2,
     mint::Vector2<u32> => UVec2,
-    mint::Point2<u32> => UVec2
+    mint::Point2<u32> => UVec2,
+    mint::Vector2<u8> => U8Vec2,
+    mint::Point2<u8> => U8Vec2,
+    mint::Vector2<u16> => U16Vec2,
+    mint::Point2<u16> => U16Vec2,
+    mint::Vector2<i64> => I64Vec2,
+    mint::Point2<i64> => I64Vec2
 );
 #[cfg(feature = "f64")]
 from_vec2s!(
@@ -85,14 +91,22 @@ from_vec2s!(
 
 from_vec3s!(
     mint::Vector3<f32> => Vec3,
-    mint::Point3<f32> => Vec3
+    mint::Point3<f32> => Vec3,
+    mint::Vector3<f32> => Vec3A,
+    mint::Point3<f32> => Vec3A
 );
 #[cfg(feature = "int")]
 from_vec3s!(
     mint::Vector3<i32> => IVec3,
     mint::Point3<i32> => IVec3,
     mint::Vector3<u32> => UVec3,
-    mint::Point3<u32> => UVec3
+    mint::Point3<u32> => UVec3,
+    mint::Vector3<u8> => U8Vec3,
+    mint::Point3<u8> => U8Vec3,
+    mint::Vector3<u16> => U16Vec3,
+    mint::Point3<u16> => U16Vec3,
+    mint::Vector3<i64> => I64Vec3,
+    mint::Point3<i64> => I64Vec3
 );
 #[cfg(feature = "f64")]
 from_vec3s!(
@@ -106,13 +120,51 @@ from_vec4s!(
 #[cfg(feature = "int")]
 from_vec4s!(
     mint::Vector4<i32> => IVec4,
-    mint::Vector4<u32> => UVec4
+    mint::Vector4<u32> => UVec4,
+    mint::Vector4<u8> => U8Vec4,
+    mint::Vector4<u16> => U16Vec4,
+    mint::Vector4<i64> => I64Vec4
 );
 #[cfg(feature = "f64")]
 from_vec4s!(
     mint::Vector4<f64> => DVec4
 );
 
+macro_rules! from_wide_vecs {
+    ($($minttype:ty => $nonwidet:ident => $($wt:ident, $lanes:expr);+),+) => {
+        $($(impl From<[$minttype; $lanes]> for $wt {
+            #[inline]
+            fn from(vecs: [$minttype; $lanes]) -> Self {
+                Self::from(vecs.map($nonwidet::from))
+            }
+        }
+
+        impl From<$wt> for [$minttype; $lanes] {
+            #[inline]
+            fn from(v: $wt) -> Self {
+                let vecs: [$nonwidet; $lanes] = v.into();
+                vecs.map(<$minttype>::from)
+            }
+        })+)+
+    }
+}
+
+from_wide_vecs!(
+    mint::Vector2<f32> => Vec2 => Vec2x4, 4; Vec2x8, 8,
+    mint::Point2<f32> => Vec2 => Vec2x4, 4; Vec2x8, 8,
+    mint::Vector3<f32> => Vec3 => Vec3x4, 4; Vec3x8, 8,
+    mint::Point3<f32> => Vec3 => Vec3x4, 4; Vec3x8, 8,
+    mint::Vector4<f32> => Vec4 => Vec4x4, 4; Vec4x8, 8
+);
+#[cfg(feature = "f64")]
+from_wide_vecs!(
+    mint::Vector2<f64> => DVec2 => DVec2x2, 2; DVec2x4, 4,
+    mint::Point2<f64> => DVec2 => DVec2x2, 2; DVec2x4, 4,
+    mint::Vector3<f64> => DVec3 => DVec3x2, 2; DVec3x4, 4,
+    mint::Point3<f64> => DVec3 => DVec3x2, 2; DVec3x4, 4,
+    mint::Vector4<f64> => DVec4 => DVec4x2, 2; DVec4x4, 4
+);
+
 macro_rules! from_mat2s {
     ($($minttype:ty => $uvtype:ty),+) => {
         $(impl From<$minttype> for $uvtype {
@@ -191,15 +243,103 @@ from_mat4s!(mint::ColumnMatrix4<f32> => Mat4);
 #[cfg(feature = "f64")]
 from_mat4s!(mint::ColumnMatrix4<f64> => DMat4);
 
+macro_rules! from_row_mats {
+    ($($minttype:ty => $colmatt:ty => $uvtype:ty),+) => {
+        $(impl From<$minttype> for $uvtype {
+            #[inline]
+            fn from(m: $minttype) -> Self {
+                <$colmatt>::from(m).into()
+            }
+        }
+
+        impl From<$uvtype> for $minttype {
+            #[inline]
+            fn from(m: $uvtype) -> Self {
+                <$colmatt>::from(m).into()
+            }
+        })+
+    }
+}
+
+from_row_mats!(
+    mint::RowMatrix2<f32> => mint::ColumnMatrix2<f32> => Mat2,
+    mint::RowMatrix3<f32> => mint::ColumnMatrix3<f32> => Mat3,
+    mint::RowMatrix4<f32> => mint::ColumnMatrix4<f32> => Mat4
+);
+#[cfg(feature = "f64")]
+from_row_mats!(
+    mint::RowMatrix2<f64> => mint::ColumnMatrix2<f64> => DMat2,
+    mint::RowMatrix3<f64> => mint::ColumnMatrix3<f64> => DMat3,
+    mint::RowMatrix4<f64> => mint::ColumnMatrix4<f64> => DMat4
+);
+
+macro_rules! from_wide_mats {
+    ($($minttype:ty => $nonwidet:ident, [$($col:literal),+] => $wt:ident, $lanes:expr),+) => {
+        $(impl From<[$minttype; $lanes]> for $wt {
+            #[inline]
+            fn from(mats: [$minttype; $lanes]) -> Self {
+                let mats = mats.map($nonwidet::from);
+                Self::new($(mats.map(|m| m.cols[$col]).into()),+)
+            }
+        }
+
+        impl From<$wt> for [$minttype; $lanes] {
+            #[inline]
+            fn from(m: $wt) -> Self {
+                let cols = [$(<[_; $lanes]>::from(m.cols[$col])),+];
+                core::array::from_fn(|lane| $nonwidet::new($(cols[$col][lane]),+).into())
+            }
+        })+
+    }
+}
+
+from_wide_mats!(
+    mint::ColumnMatrix2<f32> => Mat2, [0, 1] => Mat2x4, 4,
+    mint::ColumnMatrix2<f32> => Mat2, [0, 1] => Mat2x8, 8,
+    mint::ColumnMatrix3<f32> => Mat3, [0, 1, 2] => Mat3x4, 4,
+    mint::ColumnMatrix3<f32> => Mat3, [0, 1, 2] => Mat3x8, 8,
+    mint::ColumnMatrix4<f32> => Mat4, [0, 1, 2, 3] => Mat4x4, 4,
+    mint::ColumnMatrix4<f32> => Mat4, [0, 1, 2, 3] => Mat4x8, 8,
+    mint::RowMatrix2<f32> => Mat2, [0, 1] => Mat2x4, 4,
+    mint::RowMatrix2<f32> => Mat2, [0, 1] => Mat2x8, 8,
+    mint::RowMatrix3<f32> => Mat3, [0, 1, 2] => Mat3x4, 4,
+    mint::RowMatrix3<f32> => Mat3, [0, 1, 2] => Mat3x8, 8,
+    mint::RowMatrix4<f32> => Mat4, [0, 1, 2, 3] => Mat4x4, 4,
+    mint::RowMatrix4<f32> => Mat4, [0, 1, 2, 3] => Mat4x8, 8
+);
+#[cfg(feature = "f64")]
+from_wide_mats!(
+    mint::ColumnMatrix2<f64> => DMat2, [0, 1] => DMat2x2, 2,
+    mint::ColumnMatrix2<f64> => DMat2, [0, 1] => DMat2x4, 4,
+    mint::ColumnMatrix3<f64> => DMat3, [0, 1, 2] => DMat3x2, 2,
+    mint::ColumnMatrix3<f64> => DMat3, [0, 1, 2] => DMat3x4, 4,
+    mint::ColumnMatrix4<f64> => DMat4, [0, 1, 2, 3] => DMat4x2, 2,
+    mint::ColumnMatrix4<f64> => DMat4, [0, 1, 2, 3] => DMat4x4, 4,
+    mint::RowMatrix2<f64> => DMat2, [0, 1] => DMat2x2, 2,
+    mint::RowMatrix2<f64> => DMat2, [0, 1] => DMat2x4, 4,
+    mint::RowMatrix3<f64> => DMat3, [0, 1, 2] => DMat3x2, 2,
+    mint::RowMatrix3<f64> => DMat3, [0, 1, 2] => DMat3x4, 4,
+    mint::RowMatrix4<f64> => DMat4, [0, 1, 2, 3] => DMat4x2, 2,
+    mint::RowMatrix4<f64> => DMat4, [0, 1, 2, 3] => DMat4x4, 4
+);
+
 macro_rules! from_quat {
     ($($minttype:ty => $uvtype:ty),+) => {
-        $(impl From<$minttype> for $uvtype {
+        $(/// Convert a unit quaternion into a rotor representing the same rotation.
+        ///
+        /// The quaternion's vector part `(x, y, z)` corresponds to the bivector
+        /// `(xy, xz, yz) = (-z, y, -x)`, and its scalar part to the rotor's scalar part,
+        /// such that rotating a vector by either gives the same result. See
+        /// `from_quaternion_array`.
+        impl From<$minttype> for $uvtype {
             #[inline]
             fn from(q: $minttype) -> Self {
                 Self::from_quaternion_array([q.v.x, q.v.y, q.v.z, q.s])
             }
         }
 
+        /// Convert a rotor into a unit quaternion representing the same rotation, using
+        /// the inverse of the correspondence described on the opposite conversion.
         impl From<$uvtype> for $minttype {
             #[inline]
             fn from(r: $uvtype) -> Self {
@@ -220,3 +360,62 @@ macro_rules! from_quat {
 from_quat!(mint::Quaternion<f32> => Rotor3);
 #[cfg(feature = "f64")]
 from_quat!(mint::Quaternion<f64> => DRotor3);
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn mint_roundtrips() {
+        let mat = Mat3::from_row_major([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        let rows: mint::RowMatrix3<f32> = mat.into();
+        assert_eq!(
+            rows.x,
+            mint::Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0
+            }
+        );
+        assert_eq!(Mat3::from(rows), mat);
+
+        let points = [
+            mint::Point3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+            mint::Point3 {
+                x: 4.0,
+                y: 5.0,
+                z: 6.0,
+            },
+            mint::Point3 {
+                x: 7.0,
+                y: 8.0,
+                z: 9.0,
+            },
+            mint::Point3 {
+                x: 0.0,
+                y: -1.0,
+                z: -2.0,
+            },
+        ];
+        let wide = Vec3x4::from(points);
+        assert_eq!(wide.y, f32x4::from([2.0, 5.0, 8.0, -1.0]));
+        assert_eq!(<[mint::Point3<f32>; 4]>::from(wide), points);
+
+        let rotor = Rotor3::from_euler_angles(0.3, -0.7, 0.2);
+        let quat: mint::Quaternion<f32> = rotor.into();
+        assert_eq!(Rotor3::from(quat), rotor);
+
+        let mats = [mat, mat * 2.0, mat.transposed(), Mat3::identity()];
+        let cols: [mint::ColumnMatrix3<f32>; 4] = mats.map(Into::into);
+        let wide = Mat3x4::from(cols);
+        assert_eq!(wide.cols[0].x, f32x4::from([1.0, 2.0, 1.0, 1.0]));
+        assert_eq!(<[mint::ColumnMatrix3<f32>; 4]>::from(wide), cols);
+        let rows: [mint::RowMatrix3<f32>; 4] = mats.map(Into::into);
+        assert_eq!(Mat3x4::from(rows), wide);
+        assert_eq!(<[mint::RowMatrix3<f32>; 4]>::from(wide), rows);
+    }
+}
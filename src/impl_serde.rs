@@ -42,8 +42,8 @@ macro_rules! impl_serde_vec2 {
 
                             fn expecting(
                                 &self,
-                                formatter: &mut std::fmt::Formatter<'_>,
-                            ) -> std::fmt::Result {
+                                formatter: &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result {
                                 formatter.write_str("`x` or `y`")
                             }
 
@@ -70,8 +70,8 @@ macro_rules! impl_serde_vec2 {
 
                     fn expecting(
                         &self,
-                        formatter: &mut std::fmt::Formatter<'_>,
-                    ) -> std::fmt::Result {
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
                         formatter.write_str(&["struct ", stringify!($type)].concat())
                     }
 
@@ -161,8 +161,8 @@ macro_rules! impl_serde_vec3 {
 
                             fn expecting(
                                 &self,
-                                formatter: &mut std::fmt::Formatter<'_>,
-                            ) -> std::fmt::Result {
+                                formatter: &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result {
                                 formatter.write_str("`x` or `y` or `z`")
                             }
 
@@ -190,8 +190,8 @@ macro_rules! impl_serde_vec3 {
 
                     fn expecting(
                         &self,
-                        formatter: &mut std::fmt::Formatter<'_>,
-                    ) -> std::fmt::Result {
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
                         formatter.write_str(&["struct ", stringify!($name)].concat())
                     }
 
@@ -294,8 +294,8 @@ macro_rules! impl_serde_vec4 {
 
                             fn expecting(
                                 &self,
-                                formatter: &mut std::fmt::Formatter<'_>,
-                            ) -> std::fmt::Result {
+                                formatter: &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result {
                                 formatter.write_str("`x` or `y` or `z` or `w`")
                             }
 
@@ -324,8 +324,8 @@ macro_rules! impl_serde_vec4 {
 
                     fn expecting(
                         &self,
-                        formatter: &mut std::fmt::Formatter<'_>,
-                    ) -> std::fmt::Result {
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
                         formatter.write_str(&["struct ", stringify!($name)].concat())
                     }
 
@@ -405,25 +405,65 @@ impl_serde_vec2!(Vec2);
 impl_serde_vec2!(UVec2);
 #[cfg(feature = "int")]
 impl_serde_vec2!(IVec2);
+#[cfg(feature = "int")]
+impl_serde_vec2!(U8Vec2);
+#[cfg(feature = "int")]
+impl_serde_vec2!(U16Vec2);
+#[cfg(feature = "int")]
+impl_serde_vec2!(I64Vec2);
 #[cfg(feature = "f64")]
 impl_serde_vec2!(DVec2);
 
+impl_serde_vec2!(Vec2x4);
+impl_serde_vec2!(Vec2x8);
+#[cfg(feature = "f64")]
+impl_serde_vec2!(DVec2x2);
+#[cfg(feature = "f64")]
+impl_serde_vec2!(DVec2x4);
+
 impl_serde_vec3!(Vec3);
+impl_serde_vec3!(Vec3A);
 #[cfg(feature = "int")]
 impl_serde_vec3!(UVec3);
 #[cfg(feature = "int")]
 impl_serde_vec3!(IVec3);
+#[cfg(feature = "int")]
+impl_serde_vec3!(U8Vec3);
+#[cfg(feature = "int")]
+impl_serde_vec3!(U16Vec3);
+#[cfg(feature = "int")]
+impl_serde_vec3!(I64Vec3);
 #[cfg(feature = "f64")]
 impl_serde_vec3!(DVec3);
 
+impl_serde_vec3!(Vec3x4);
+impl_serde_vec3!(Vec3x8);
+#[cfg(feature = "f64")]
+impl_serde_vec3!(DVec3x2);
+#[cfg(feature = "f64")]
+impl_serde_vec3!(DVec3x4);
+
 impl_serde_vec4!(Vec4);
 #[cfg(feature = "int")]
 impl_serde_vec4!(UVec4);
 #[cfg(feature = "int")]
 impl_serde_vec4!(IVec4);
+#[cfg(feature = "int")]
+impl_serde_vec4!(U8Vec4);
+#[cfg(feature = "int")]
+impl_serde_vec4!(U16Vec4);
+#[cfg(feature = "int")]
+impl_serde_vec4!(I64Vec4);
 #[cfg(feature = "f64")]
 impl_serde_vec4!(DVec4);
 
+impl_serde_vec4!(Vec4x4);
+impl_serde_vec4!(Vec4x8);
+#[cfg(feature = "f64")]
+impl_serde_vec4!(DVec4x2);
+#[cfg(feature = "f64")]
+impl_serde_vec4!(DVec4x4);
+
 #[cfg(test)]
 mod vec_serde_tests {
     use crate::vec::{Vec2, Vec3, Vec4};
@@ -666,8 +706,8 @@ macro_rules! impl_serde_mat2 {
 
                     fn expecting(
                         &self,
-                        formatter: &mut std::fmt::Formatter<'_>,
-                    ) -> std::fmt::Result {
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
                         formatter.write_str($expecting)
                     }
 
@@ -757,8 +797,8 @@ macro_rules! impl_serde_mat3 {
 
                     fn expecting(
                         &self,
-                        formatter: &mut std::fmt::Formatter<'_>,
-                    ) -> std::fmt::Result {
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
                         formatter.write_str($expecting)
                     }
 
@@ -877,8 +917,8 @@ macro_rules! impl_serde_mat4 {
 
                     fn expecting(
                         &self,
-                        formatter: &mut std::fmt::Formatter<'_>,
-                    ) -> std::fmt::Result {
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
                         formatter.write_str($expecting)
                     }
 
@@ -990,14 +1030,35 @@ impl_serde_mat2!(Mat2, Vec2, f32, "tuple of 4 floats");
 #[cfg(feature = "f64")]
 impl_serde_mat2!(DMat2, DVec2, f64, "tuple of 4 floats");
 
+impl_serde_mat2!(Mat2x4, Vec2x4, f32x4, "tuple of 4 f32x4s");
+impl_serde_mat2!(Mat2x8, Vec2x8, f32x8, "tuple of 4 f32x8s");
+#[cfg(feature = "f64")]
+impl_serde_mat2!(DMat2x2, DVec2x2, f64x2, "tuple of 4 f64x2s");
+#[cfg(feature = "f64")]
+impl_serde_mat2!(DMat2x4, DVec2x4, f64x4, "tuple of 4 f64x4s");
+
 impl_serde_mat3!(Mat3, Vec3, f32, "tuple of 9 floats");
 #[cfg(feature = "f64")]
 impl_serde_mat3!(DMat3, DVec3, f64, "tuple of 9 floats");
 
+impl_serde_mat3!(Mat3x4, Vec3x4, f32x4, "tuple of 9 f32x4s");
+impl_serde_mat3!(Mat3x8, Vec3x8, f32x8, "tuple of 9 f32x8s");
+#[cfg(feature = "f64")]
+impl_serde_mat3!(DMat3x2, DVec3x2, f64x2, "tuple of 9 f64x2s");
+#[cfg(feature = "f64")]
+impl_serde_mat3!(DMat3x4, DVec3x4, f64x4, "tuple of 9 f64x4s");
+
 impl_serde_mat4!(Mat4, Vec4, f32, "tuple of 16 floats");
 #[cfg(feature = "f64")]
 impl_serde_mat4!(DMat4, DVec4, f64, "tuple of 16 floats");
 
+impl_serde_mat4!(Mat4x4, Vec4x4, f32x4, "tuple of 16 f32x4s");
+impl_serde_mat4!(Mat4x8, Vec4x8, f32x8, "tuple of 16 f32x8s");
+#[cfg(feature = "f64")]
+impl_serde_mat4!(DMat4x2, DVec4x2, f64x2, "tuple of 16 f64x2s");
+#[cfg(feature = "f64")]
+impl_serde_mat4!(DMat4x4, DVec4x4, f64x4, "tuple of 16 f64x4s");
+
 #[cfg(test)]
 mod mat_serde_tests {
     use crate::mat::{Mat2, Mat3, Mat4};
@@ -1116,8 +1177,8 @@ macro_rules! impl_serde_bivec2 {
 
                             fn expecting(
                                 &self,
-                                formatter: &mut std::fmt::Formatter<'_>,
-                            ) -> std::fmt::Result {
+                                formatter: &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result {
                                 formatter.write_str("`xy`")
                             }
 
@@ -1143,8 +1204,8 @@ macro_rules! impl_serde_bivec2 {
 
                     fn expecting(
                         &self,
-                        formatter: &mut std::fmt::Formatter<'_>,
-                    ) -> std::fmt::Result {
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
                         formatter.write_str(&["struct ", stringify!($name)].concat())
                     }
 
@@ -1224,8 +1285,8 @@ macro_rules! impl_serde_bivec3 {
 
                             fn expecting(
                                 &self,
-                                formatter: &mut std::fmt::Formatter<'_>,
-                            ) -> std::fmt::Result {
+                                formatter: &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result {
                                 formatter.write_str("`xy` or `xz` or `yz`")
                             }
 
@@ -1253,8 +1314,8 @@ macro_rules! impl_serde_bivec3 {
 
                     fn expecting(
                         &self,
-                        formatter: &mut std::fmt::Formatter<'_>,
-                    ) -> std::fmt::Result {
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
                         formatter.write_str(&["struct ", stringify!($name)].concat())
                     }
 
@@ -1322,10 +1383,24 @@ impl_serde_bivec2!(Bivec2);
 #[cfg(feature = "f64")]
 impl_serde_bivec2!(DBivec2);
 
+impl_serde_bivec2!(Bivec2x4);
+impl_serde_bivec2!(Bivec2x8);
+#[cfg(feature = "f64")]
+impl_serde_bivec2!(DBivec2x2);
+#[cfg(feature = "f64")]
+impl_serde_bivec2!(DBivec2x4);
+
 impl_serde_bivec3!(Bivec3);
 #[cfg(feature = "f64")]
 impl_serde_bivec3!(DBivec3);
 
+impl_serde_bivec3!(Bivec3x4);
+impl_serde_bivec3!(Bivec3x8);
+#[cfg(feature = "f64")]
+impl_serde_bivec3!(DBivec3x2);
+#[cfg(feature = "f64")]
+impl_serde_bivec3!(DBivec3x4);
+
 #[cfg(test)]
 mod bivec_serde_tests {
     use crate::bivec::{Bivec2, Bivec3};
@@ -1408,8 +1483,8 @@ macro_rules! impl_serde_rotor {
 
                             fn expecting(
                                 &self,
-                                formatter: &mut std::fmt::Formatter<'_>,
-                            ) -> std::fmt::Result {
+                                formatter: &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result {
                                 formatter.write_str("`s` or `bv`")
                             }
 
@@ -1436,8 +1511,8 @@ macro_rules! impl_serde_rotor {
 
                     fn expecting(
                         &self,
-                        formatter: &mut std::fmt::Formatter<'_>,
-                    ) -> std::fmt::Result {
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
                         formatter.write_str(&["struct ", stringify!($name)].concat())
                     }
 
@@ -1494,10 +1569,24 @@ impl_serde_rotor!(Rotor2);
 #[cfg(feature = "f64")]
 impl_serde_rotor!(DRotor2);
 
+impl_serde_rotor!(Rotor2x4);
+impl_serde_rotor!(Rotor2x8);
+#[cfg(feature = "f64")]
+impl_serde_rotor!(DRotor2x2);
+#[cfg(feature = "f64")]
+impl_serde_rotor!(DRotor2x4);
+
 impl_serde_rotor!(Rotor3);
 #[cfg(feature = "f64")]
 impl_serde_rotor!(DRotor3);
 
+impl_serde_rotor!(Rotor3x4);
+impl_serde_rotor!(Rotor3x8);
+#[cfg(feature = "f64")]
+impl_serde_rotor!(DRotor3x2);
+#[cfg(feature = "f64")]
+impl_serde_rotor!(DRotor3x4);
+
 #[cfg(test)]
 mod rotor_serde_tests {
     use crate::bivec::{Bivec2, Bivec3};
@@ -1597,8 +1686,8 @@ macro_rules! impl_serde_isometry {
 
                             fn expecting(
                                 &self,
-                                formatter: &mut std::fmt::Formatter<'_>,
-                            ) -> std::fmt::Result {
+                                formatter: &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result {
                                 formatter.write_str("`translation` or `rotation`")
                             }
 
@@ -1625,8 +1714,8 @@ macro_rules! impl_serde_isometry {
 
                     fn expecting(
                         &self,
-                        formatter: &mut std::fmt::Formatter<'_>,
-                    ) -> std::fmt::Result {
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
                         formatter.write_str(&["struct ", stringify!($name)].concat())
                     }
 
@@ -1687,10 +1776,24 @@ impl_serde_isometry!(Isometry2);
 #[cfg(feature = "f64")]
 impl_serde_isometry!(DIsometry2);
 
+impl_serde_isometry!(Isometry2x4);
+impl_serde_isometry!(Isometry2x8);
+#[cfg(feature = "f64")]
+impl_serde_isometry!(DIsometry2x2);
+#[cfg(feature = "f64")]
+impl_serde_isometry!(DIsometry2x4);
+
 impl_serde_isometry!(Isometry3);
 #[cfg(feature = "f64")]
 impl_serde_isometry!(DIsometry3);
 
+impl_serde_isometry!(Isometry3x4);
+impl_serde_isometry!(Isometry3x8);
+#[cfg(feature = "f64")]
+impl_serde_isometry!(DIsometry3x2);
+#[cfg(feature = "f64")]
+impl_serde_isometry!(DIsometry3x4);
+
 #[cfg(test)]
 mod isometry_serde_tests {
     use crate::rotor::{Rotor2, Rotor3};
@@ -1827,8 +1930,8 @@ macro_rules! impl_serde_similarity {
 
                             fn expecting(
                                 &self,
-                                formatter: &mut std::fmt::Formatter<'_>,
-                            ) -> std::fmt::Result {
+                                formatter: &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result {
                                 formatter.write_str("`translation`, `rotation` or `scale`")
                             }
 
@@ -1856,8 +1959,8 @@ macro_rules! impl_serde_similarity {
 
                     fn expecting(
                         &self,
-                        formatter: &mut std::fmt::Formatter<'_>,
-                    ) -> std::fmt::Result {
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
                         formatter.write_str(&["struct ", stringify!($name)].concat())
                     }
 
@@ -1930,10 +2033,172 @@ impl_serde_similarity!(Similarity2);
 #[cfg(feature = "f64")]
 impl_serde_similarity!(DSimilarity2);
 
+impl_serde_similarity!(Similarity2x4);
+impl_serde_similarity!(Similarity2x8);
+#[cfg(feature = "f64")]
+impl_serde_similarity!(DSimilarity2x2);
+#[cfg(feature = "f64")]
+impl_serde_similarity!(DSimilarity2x4);
+
 impl_serde_similarity!(Similarity3);
 #[cfg(feature = "f64")]
 impl_serde_similarity!(DSimilarity3);
 
+impl_serde_similarity!(Similarity3x4);
+impl_serde_similarity!(Similarity3x8);
+#[cfg(feature = "f64")]
+impl_serde_similarity!(DSimilarity3x2);
+#[cfg(feature = "f64")]
+impl_serde_similarity!(DSimilarity3x4);
+
+macro_rules! impl_serde_transform {
+    ($name:ident) => {
+        impl Serialize for $name {
+            fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+            where
+                T: Serializer,
+            {
+                let mut state = serializer.serialize_struct(stringify!($name), 3)?;
+                state.serialize_field("translation", &self.translation)?;
+                state.serialize_field("rotation", &self.rotation)?;
+                state.serialize_field("scale", &self.scale)?;
+                state.end()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                enum Field {
+                    Translation,
+                    Rotation,
+                    Scale,
+                }
+
+                impl<'de> Deserialize<'de> for Field {
+                    fn deserialize<D>(deserializer: D) -> Result<Field, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        struct FieldVisitor;
+
+                        impl<'de> Visitor<'de> for FieldVisitor {
+                            type Value = Field;
+
+                            fn expecting(
+                                &self,
+                                formatter: &mut core::fmt::Formatter<'_>,
+                            ) -> core::fmt::Result {
+                                formatter.write_str("`translation`, `rotation` or `scale`")
+                            }
+
+                            fn visit_str<E>(self, value: &str) -> Result<Field, E>
+                            where
+                                E: serde::de::Error,
+                            {
+                                match value {
+                                    "translation" => Ok(Field::Translation),
+                                    "rotation" => Ok(Field::Rotation),
+                                    "scale" => Ok(Field::Scale),
+                                    _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                                }
+                            }
+                        }
+
+                        deserializer.deserialize_identifier(FieldVisitor)
+                    }
+                }
+
+                struct TVisitor;
+
+                impl<'de> Visitor<'de> for TVisitor {
+                    type Value = $name;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut core::fmt::Formatter<'_>,
+                    ) -> core::fmt::Result {
+                        formatter.write_str(&["struct ", stringify!($name)].concat())
+                    }
+
+                    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+                    where
+                        V: SeqAccess<'de>,
+                    {
+                        let translation = seq
+                            .next_element()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                        let rotation = seq
+                            .next_element()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+                        let scale = seq
+                            .next_element()?
+                            .ok_or_else(|| serde::de::Error::invalid_length(2, &self))?;
+                        Ok(Self::Value::new(translation, rotation, scale))
+                    }
+
+                    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+                    where
+                        V: MapAccess<'de>,
+                    {
+                        let mut translation = None;
+                        let mut rotation = None;
+                        let mut scale = None;
+                        while let Some(key) = map.next_key()? {
+                            match key {
+                                Field::Translation => {
+                                    if translation.is_some() {
+                                        return Err(serde::de::Error::duplicate_field(
+                                            "translation",
+                                        ));
+                                    }
+                                    translation = Some(map.next_value()?);
+                                }
+                                Field::Rotation => {
+                                    if rotation.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("rotation"));
+                                    }
+                                    rotation = Some(map.next_value()?);
+                                }
+                                Field::Scale => {
+                                    if scale.is_some() {
+                                        return Err(serde::de::Error::duplicate_field("scale"));
+                                    }
+                                    scale = Some(map.next_value()?);
+                                }
+                            }
+                        }
+                        let translation = translation
+                            .ok_or_else(|| serde::de::Error::missing_field("translation"))?;
+                        let rotation =
+                            rotation.ok_or_else(|| serde::de::Error::missing_field("rotation"))?;
+                        let scale =
+                            scale.ok_or_else(|| serde::de::Error::missing_field("scale"))?;
+                        Ok(Self::Value::new(translation, rotation, scale))
+                    }
+                }
+
+                const FIELDS: &[&str] = &["translation", "rotation", "scale"];
+
+                deserializer.deserialize_struct(stringify!($name), FIELDS, TVisitor)
+            }
+        }
+    };
+}
+
+impl_serde_transform!(Transform3);
+#[cfg(feature = "f64")]
+impl_serde_transform!(DTransform3);
+
+impl_serde_transform!(Transform3x4);
+impl_serde_transform!(Transform3x8);
+#[cfg(feature = "f64")]
+impl_serde_transform!(DTransform3x2);
+#[cfg(feature = "f64")]
+impl_serde_transform!(DTransform3x4);
+
 #[cfg(test)]
 mod similarity_serde_tests {
     use crate::rotor::{Rotor2, Rotor3};
@@ -2035,3 +2300,131 @@ mod similarity_serde_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod transform_serde_tests {
+    use crate::rotor::Rotor3;
+    use crate::transform::Transform3;
+    use crate::Vec3;
+    use serde_test::{assert_tokens, Token};
+
+    #[test]
+    fn transform3() {
+        let transform3 = Transform3::new(
+            Vec3::new(1., 2., 3.),
+            Rotor3::from_rotation_xy(0.),
+            Vec3::new(4., 5., 6.),
+        );
+
+        assert_tokens(
+            &transform3,
+            &[
+                Token::Struct {
+                    name: "Transform3",
+                    len: 3,
+                },
+                Token::Str("translation"),
+                Token::Struct {
+                    name: "Vec3",
+                    len: 3,
+                },
+                Token::Str("x"),
+                Token::F32(1.),
+                Token::Str("y"),
+                Token::F32(2.),
+                Token::Str("z"),
+                Token::F32(3.),
+                Token::StructEnd,
+                Token::Str("rotation"),
+                Token::Struct {
+                    name: "Rotor3",
+                    len: 2,
+                },
+                Token::Str("s"),
+                Token::F32(1.),
+                Token::Str("bv"),
+                Token::Struct {
+                    name: "Bivec3",
+                    len: 3,
+                },
+                Token::Str("xy"),
+                Token::F32(0.),
+                Token::Str("xz"),
+                Token::F32(0.),
+                Token::Str("yz"),
+                Token::F32(0.),
+                Token::StructEnd,
+                Token::StructEnd,
+                Token::Str("scale"),
+                Token::Struct {
+                    name: "Vec3",
+                    len: 3,
+                },
+                Token::Str("x"),
+                Token::F32(4.),
+                Token::Str("y"),
+                Token::F32(5.),
+                Token::Str("z"),
+                Token::F32(6.),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
+
+#[cfg(test)]
+mod wide_serde_tests {
+    use crate::mat::Mat2x4;
+    use crate::vec::{Vec2, Vec2x4};
+    use serde_test::{assert_tokens, Token};
+
+    fn lanes(values: [f32; 4]) -> [Token; 6] {
+        [
+            Token::Tuple { len: 4 },
+            Token::F32(values[0]),
+            Token::F32(values[1]),
+            Token::F32(values[2]),
+            Token::F32(values[3]),
+            Token::TupleEnd,
+        ]
+    }
+
+    #[test]
+    fn vec2x4() {
+        let vec2x4 = Vec2x4::from([
+            Vec2::new(1., 2.),
+            Vec2::new(3., 4.),
+            Vec2::new(5., 6.),
+            Vec2::new(7., 8.),
+        ]);
+
+        let mut tokens = vec![
+            Token::Struct {
+                name: "Vec2x4",
+                len: 2,
+            },
+            Token::Str("x"),
+        ];
+        tokens.extend(lanes([1., 3., 5., 7.]));
+        tokens.push(Token::Str("y"));
+        tokens.extend(lanes([2., 4., 6., 8.]));
+        tokens.push(Token::StructEnd);
+
+        assert_tokens(&vec2x4, &tokens);
+    }
+
+    #[test]
+    fn mat2x4() {
+        let mat2x4 = Mat2x4::identity();
+
+        let mut tokens = vec![Token::Tuple { len: 4 }];
+        tokens.extend(lanes([1.; 4]));
+        tokens.extend(lanes([0.; 4]));
+        tokens.extend(lanes([0.; 4]));
+        tokens.extend(lanes([1.; 4]));
+        tokens.push(Token::TupleEnd);
+
+        assert_tokens(&mat2x4, &tokens);
+    }
+}
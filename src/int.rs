@@ -1,6 +1,6 @@
 use crate::*;
-use std::convert::{TryFrom, TryInto};
-use std::ops::*;
+use core::convert::{TryFrom, TryInto};
+use core::ops::*;
 
 pub trait MulAdd<A = Self, B = Self> {
     /// The resulting type after applying the fused multiply-add.
@@ -10,21 +10,19 @@ pub trait MulAdd<A = Self, B = Self> {
     fn mul_add(self, a: A, b: B) -> Self::Output;
 }
 
-impl MulAdd<u32, u32> for u32 {
-    type Output = u32;
+macro_rules! impl_mul_add {
+    ($($t:ident),+) => {
+        $(impl MulAdd<$t, $t> for $t {
+            type Output = $t;
 
-    fn mul_add(self, a: u32, b: u32) -> Self::Output {
-        (self * a) + b
-    }
+            fn mul_add(self, a: $t, b: $t) -> Self::Output {
+                (self * a) + b
+            }
+        })+
+    };
 }
 
-impl MulAdd<i32, i32> for i32 {
-    type Output = i32;
-
-    fn mul_add(self, a: i32, b: i32) -> Self::Output {
-        (self * a) + b
-    }
-}
+impl_mul_add!(u8, u16, u32, i32, i64);
 
 macro_rules! ivec2s {
     ($(($n:ident, $v3t:ident, $v4t:ident) => $t:ident),+) => {
@@ -49,17 +47,17 @@ macro_rules! ivec2s {
             }
 
             #[inline]
-            pub fn broadcast(val: $t) -> Self {
+            pub const fn broadcast(val: $t) -> Self {
                 Self::new(val, val)
             }
 
             #[inline]
-            pub fn unit_x() -> Self {
+            pub const fn unit_x() -> Self {
                 $n{ x: 1, y: 0 }
             }
 
             #[inline]
-            pub fn unit_y() -> Self {
+            pub const fn unit_y() -> Self {
                 $n{ x: 0, y: 1 }
             }
 
@@ -98,6 +96,14 @@ macro_rules! ivec2s {
                 (self.x * other.x) + (self.y * other.y)
             }
 
+            /// The wedge product of `self` and `other`, i.e. the 2d cross product `x1 * y2 - y1 * x2`,
+            /// which is positive when `other` is counter-clockwise from `self`. For the unsigned
+            /// vectors, a negative result wraps around.
+            #[inline]
+            pub fn wedge(&self, other: $n) -> $t {
+                (self.x * other.y).wrapping_sub(self.y * other.x)
+            }
+
             #[inline]
             pub fn reflected(&self, normal: $n) -> Self {
                 *self - (2 * self.dot(normal) * normal)
@@ -176,12 +182,12 @@ macro_rules! ivec2s {
             }
 
             #[inline]
-            pub fn zero() -> Self {
+            pub const fn zero() -> Self {
                 Self::broadcast(0)
             }
 
             #[inline]
-            pub fn one() -> Self {
+            pub const fn one() -> Self {
                 Self::broadcast(1)
             }
 
@@ -197,7 +203,7 @@ macro_rules! ivec2s {
 
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$t>()).unwrap()
             }
 
             #[inline]
@@ -205,13 +211,13 @@ macro_rules! ivec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $t, 2)
+                    core::slice::from_raw_parts(self as *const $n as *const $t, 2)
                 }
             }
 
             #[inline]
             pub fn as_array(&self) -> [$t; 2] {
-                use std::convert::TryInto;
+                use core::convert::TryInto;
                 self.as_slice().try_into().unwrap()
             }
 
@@ -220,7 +226,7 @@ macro_rules! ivec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const u8, 2 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts(self as *const $n as *const u8, 2 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -229,7 +235,7 @@ macro_rules! ivec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 2)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 2)
                 }
             }
 
@@ -238,7 +244,7 @@ macro_rules! ivec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 2 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 2 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -427,7 +433,7 @@ macro_rules! ivec2s {
                 match index {
                     0 => &self.x,
                     1 => &self.y,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
@@ -437,7 +443,7 @@ macro_rules! ivec2s {
                 match index {
                     0 => &mut self.x,
                     1 => &mut self.y,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
@@ -468,22 +474,22 @@ macro_rules! ivec3s {
             }
 
             #[inline]
-            pub fn broadcast(val: $t) -> Self {
+            pub const fn broadcast(val: $t) -> Self {
                 Self::new(val, val, val)
             }
 
             #[inline]
-            pub fn unit_x() -> Self {
+            pub const fn unit_x() -> Self {
                 $n{ x: 1, y: 0, z: 0 }
             }
 
             #[inline]
-            pub fn unit_y() -> Self {
+            pub const fn unit_y() -> Self {
                 $n{ x: 0, y: 1, z: 0 }
             }
 
             #[inline]
-            pub fn unit_z() -> Self {
+            pub const fn unit_z() -> Self {
                 $n{ x: 0, y: 0, z: 1 }
             }
 
@@ -623,12 +629,12 @@ macro_rules! ivec3s {
             }
 
             #[inline]
-            pub fn zero() -> Self {
+            pub const fn zero() -> Self {
                 Self::broadcast(0)
             }
 
             #[inline]
-            pub fn one() -> Self {
+            pub const fn one() -> Self {
                 Self::broadcast(1)
             }
 
@@ -645,7 +651,7 @@ macro_rules! ivec3s {
 
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$t>()).unwrap()
             }
 
             #[inline]
@@ -653,13 +659,13 @@ macro_rules! ivec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $t, 3)
+                    core::slice::from_raw_parts(self as *const $n as *const $t, 3)
                 }
             }
 
             #[inline]
             pub fn as_array(&self) -> [$t; 3] {
-                use std::convert::TryInto;
+                use core::convert::TryInto;
                 self.as_slice().try_into().unwrap()
             }
 
@@ -668,7 +674,7 @@ macro_rules! ivec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const u8, 3 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts(self as *const $n as *const u8, 3 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -677,7 +683,7 @@ macro_rules! ivec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 3)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 3)
                 }
             }
 
@@ -686,7 +692,7 @@ macro_rules! ivec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 3 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 3 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -882,7 +888,7 @@ macro_rules! ivec3s {
                     0 => &self.x,
                     1 => &self.y,
                     2 => &self.z,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
@@ -893,7 +899,7 @@ macro_rules! ivec3s {
                     0 => &mut self.x,
                     1 => &mut self.y,
                     2 => &mut self.z,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
@@ -925,27 +931,27 @@ macro_rules! ivec4s {
             }
 
             #[inline]
-            pub fn broadcast(val: $t) -> Self {
+            pub const fn broadcast(val: $t) -> Self {
                 Self::new(val, val, val, val)
             }
 
             #[inline]
-            pub fn unit_x() -> Self {
+            pub const fn unit_x() -> Self {
                 $n{ x: 1, y: 0, z: 0, w: 0 }
             }
 
             #[inline]
-            pub fn unit_y() -> Self {
+            pub const fn unit_y() -> Self {
                 $n{ x: 0, y: 1, z: 0, w: 0 }
             }
 
             #[inline]
-            pub fn unit_z() -> Self {
+            pub const fn unit_z() -> Self {
                 $n{ x: 0, y: 0, z: 1, w: 0 }
             }
 
             #[inline]
-            pub fn unit_w() -> Self {
+            pub const fn unit_w() -> Self {
                 $n{ x: 0, y: 0, z: 0, w: 1 }
             }
 
@@ -1051,12 +1057,12 @@ macro_rules! ivec4s {
             }
 
             #[inline]
-            pub fn zero() -> Self {
+            pub const fn zero() -> Self {
                 Self::broadcast(0 as $t)
             }
 
             #[inline]
-            pub fn one() -> Self {
+            pub const fn one() -> Self {
                 Self::broadcast(1 as $t)
             }
 
@@ -1073,7 +1079,7 @@ macro_rules! ivec4s {
 
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$t>()).unwrap()
             }
 
             #[inline]
@@ -1081,13 +1087,13 @@ macro_rules! ivec4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $t, 4)
+                    core::slice::from_raw_parts(self as *const $n as *const $t, 4)
                 }
             }
 
             #[inline]
             pub fn as_array(&self) -> [$t; 4] {
-                use std::convert::TryInto;
+                use core::convert::TryInto;
                 self.as_slice().try_into().unwrap()
             }
 
@@ -1096,7 +1102,7 @@ macro_rules! ivec4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const u8, 4 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts(self as *const $n as *const u8, 4 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -1105,7 +1111,7 @@ macro_rules! ivec4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 4)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 4)
                 }
             }
 
@@ -1114,7 +1120,7 @@ macro_rules! ivec4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 4 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 4 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -1317,7 +1323,7 @@ macro_rules! ivec4s {
                     1 => &self.y,
                     2 => &self.z,
                     3 => &self.w,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
@@ -1329,12 +1335,12 @@ macro_rules! ivec4s {
                     1 => &mut self.y,
                     2 => &mut self.z,
                     3 => &mut self.w,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
 
-        impl std::iter::Sum<$n> for $n {
+        impl core::iter::Sum<$n> for $n {
             fn sum<I>(iter: I) -> Self where I: Iterator<Item = Self> {
                 iter.fold($n::zero(), Add::add)
             }
@@ -1343,103 +1349,65 @@ macro_rules! ivec4s {
     }
 }
 
-impl Neg for IVec2 {
-    type Output = Self;
-
-    #[inline]
-    fn neg(self) -> Self::Output {
-        Self {
-            x: -self.x,
-            y: -self.y,
-        }
-    }
-}
-
-impl Neg for IVec3 {
-    type Output = Self;
-
-    #[inline]
-    fn neg(self) -> Self::Output {
-        Self {
-            x: -self.x,
-            y: -self.y,
-            z: -self.z,
-        }
-    }
-}
-
-impl Neg for IVec4 {
-    type Output = Self;
-
-    #[inline]
-    fn neg(self) -> Self::Output {
-        Self {
-            x: -self.x,
-            y: -self.y,
-            z: -self.z,
-            w: -self.w,
-        }
-    }
-}
+macro_rules! impl_neg {
+    ($($n:ident => [$($var:ident),*]),+) => {
+        $(impl Neg for $n {
+            type Output = Self;
 
-impl From<UVec3> for UVec2 {
-    #[inline]
-    fn from(vec: UVec3) -> Self {
-        Self { x: vec.x, y: vec.y }
-    }
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self {
+                    $($var: -self.$var,)*
+                }
+            }
+        })+
+    };
 }
 
-impl From<UVec3> for UVec4 {
-    #[inline]
-    fn from(vec: UVec3) -> Self {
-        Self {
-            x: vec.x,
-            y: vec.y,
-            z: vec.z,
-            w: 0,
+macro_rules! impl_truncate_extend {
+    ($(($v2t:ident, $v3t:ident, $v4t:ident)),+) => {
+        $(impl From<$v3t> for $v2t {
+            #[inline]
+            fn from(vec: $v3t) -> Self {
+                Self { x: vec.x, y: vec.y }
+            }
         }
-    }
-}
 
-impl From<UVec4> for UVec3 {
-    #[inline]
-    fn from(vec: UVec4) -> Self {
-        Self {
-            x: vec.x,
-            y: vec.y,
-            z: vec.z,
+        impl From<$v3t> for $v4t {
+            #[inline]
+            fn from(vec: $v3t) -> Self {
+                Self {
+                    x: vec.x,
+                    y: vec.y,
+                    z: vec.z,
+                    w: 0,
+                }
+            }
         }
-    }
-}
 
-impl From<IVec3> for IVec2 {
-    #[inline]
-    fn from(vec: IVec3) -> Self {
-        Self { x: vec.x, y: vec.y }
-    }
-}
-
-impl From<IVec3> for IVec4 {
-    #[inline]
-    fn from(vec: IVec3) -> Self {
-        Self {
-            x: vec.x,
-            y: vec.y,
-            z: vec.z,
-            w: 0,
-        }
-    }
+        impl From<$v4t> for $v3t {
+            #[inline]
+            fn from(vec: $v4t) -> Self {
+                Self {
+                    x: vec.x,
+                    y: vec.y,
+                    z: vec.z,
+                }
+            }
+        })+
+    };
 }
 
-impl From<IVec4> for IVec3 {
-    #[inline]
-    fn from(vec: IVec4) -> Self {
-        Self {
-            x: vec.x,
-            y: vec.y,
-            z: vec.z,
-        }
-    }
+/// Lossless conversions from vectors of a narrower integer type to a wider one.
+macro_rules! impl_widen {
+    ($(($source:ident => $target:ident, $target_type:ident, [$($var:ident),*])),+) => {
+        $(impl From<$source> for $target {
+            #[inline]
+            fn from(v: $source) -> Self {
+                Self::new($(<$target_type as From<_>>::from(v.$var),)*)
+            }
+        })+
+    };
 }
 
 impl TryFrom<UVec3> for IVec3 {
@@ -1491,14 +1459,290 @@ macro_rules! impl_abs {
     }
 }
 
+/// Componentwise remainder, and Euclidean division and remainder.
+macro_rules! impl_rem {
+    ($($n:ident => $t:ident [$($var:ident),*]),+) => {
+        $(impl $n {
+            /// Componentwise Euclidean division, rounding the quotient such that the remainder is
+            /// never negative, see [`i32::div_euclid`].
+            #[inline]
+            pub fn div_euclid(&self, rhs: $n) -> Self {
+                Self::new($(self.$var.div_euclid(rhs.$var),)*)
+            }
+
+            /// Componentwise Euclidean remainder, which is never negative, see [`i32::rem_euclid`].
+            ///
+            /// This maps e.g. a voxel coordinate to its position inside of the chunk, also for
+            /// negative coordinates, which `%` doesn't.
+            #[inline]
+            pub fn rem_euclid(&self, rhs: $n) -> Self {
+                Self::new($(self.$var.rem_euclid(rhs.$var),)*)
+            }
+        }
+
+        impl Rem for $n {
+            type Output = Self;
+            #[inline]
+            fn rem(self, rhs: $n) -> Self {
+                Self::new($(self.$var % rhs.$var,)*)
+            }
+        }
+
+        impl Rem<$t> for $n {
+            type Output = Self;
+            #[inline]
+            fn rem(self, rhs: $t) -> Self {
+                Self::new($(self.$var % rhs,)*)
+            }
+        }
+
+        impl RemAssign for $n {
+            #[inline]
+            fn rem_assign(&mut self, rhs: $n) {
+                $(self.$var %= rhs.$var;)*
+            }
+        }
+
+        impl RemAssign<$t> for $n {
+            #[inline]
+            fn rem_assign(&mut self, rhs: $t) {
+                $(self.$var %= rhs;)*
+            }
+        })+
+    };
+}
+
+/// Componentwise arithmetic with explicit overflow behavior, mirroring the methods of the
+/// primitive integer types.
+macro_rules! impl_overflow_ops {
+    ($($n:ident => [$($var:ident),*]),+) => {
+        $(impl $n {
+            impl_overflow_ops!(@ops [$($var),*] wrapping_add checked_add saturating_add);
+            impl_overflow_ops!(@ops [$($var),*] wrapping_sub checked_sub saturating_sub);
+            impl_overflow_ops!(@ops [$($var),*] wrapping_mul checked_mul saturating_mul);
+
+            /// Componentwise division, returning `None` if any component of `rhs` is zero or
+            /// any division overflows.
+            #[inline]
+            pub fn checked_div(&self, rhs: $n) -> Option<Self> {
+                Some(Self::new($(self.$var.checked_div(rhs.$var)?,)*))
+            }
+        })+
+    };
+    (@ops [$($var:ident),*] $wrapping:ident $checked:ident $saturating:ident) => {
+        #[doc = concat!("Componentwise `", stringify!($wrapping), "`, wrapping around at the bounds of the component type.")]
+        #[inline]
+        pub fn $wrapping(&self, rhs: Self) -> Self {
+            Self::new($(self.$var.$wrapping(rhs.$var),)*)
+        }
+
+        #[doc = concat!("Componentwise `", stringify!($checked), "`, returning `None` if any component overflows.")]
+        #[inline]
+        pub fn $checked(&self, rhs: Self) -> Option<Self> {
+            Some(Self::new($(self.$var.$checked(rhs.$var)?,)*))
+        }
+
+        #[doc = concat!("Componentwise `", stringify!($saturating), "`, clamping to the bounds of the component type.")]
+        #[inline]
+        pub fn $saturating(&self, rhs: Self) -> Self {
+            Self::new($(self.$var.$saturating(rhs.$var),)*)
+        }
+    };
+}
+
+/// Componentwise bitwise operators, and shifts by either a single amount or an amount per
+/// component.
+macro_rules! impl_bit_ops {
+    ($($n:ident => [$($var:ident),*]),+) => {
+        $(impl BitAnd for $n {
+            type Output = Self;
+            #[inline]
+            fn bitand(self, rhs: $n) -> Self {
+                Self::new($(self.$var & rhs.$var,)*)
+            }
+        }
+
+        impl BitAndAssign for $n {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: $n) {
+                $(self.$var &= rhs.$var;)*
+            }
+        }
+
+        impl BitOr for $n {
+            type Output = Self;
+            #[inline]
+            fn bitor(self, rhs: $n) -> Self {
+                Self::new($(self.$var | rhs.$var,)*)
+            }
+        }
+
+        impl BitOrAssign for $n {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: $n) {
+                $(self.$var |= rhs.$var;)*
+            }
+        }
+
+        impl BitXor for $n {
+            type Output = Self;
+            #[inline]
+            fn bitxor(self, rhs: $n) -> Self {
+                Self::new($(self.$var ^ rhs.$var,)*)
+            }
+        }
+
+        impl BitXorAssign for $n {
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: $n) {
+                $(self.$var ^= rhs.$var;)*
+            }
+        }
+
+        impl Not for $n {
+            type Output = Self;
+            #[inline]
+            fn not(self) -> Self {
+                Self::new($(!self.$var,)*)
+            }
+        }
+
+        /// Shift every component by the same amount.
+        impl Shl<u32> for $n {
+            type Output = Self;
+            #[inline]
+            fn shl(self, rhs: u32) -> Self {
+                Self::new($(self.$var << rhs,)*)
+            }
+        }
+
+        impl ShlAssign<u32> for $n {
+            #[inline]
+            fn shl_assign(&mut self, rhs: u32) {
+                $(self.$var <<= rhs;)*
+            }
+        }
+
+        /// Shift every component by the same amount.
+        impl Shr<u32> for $n {
+            type Output = Self;
+            #[inline]
+            fn shr(self, rhs: u32) -> Self {
+                Self::new($(self.$var >> rhs,)*)
+            }
+        }
+
+        impl ShrAssign<u32> for $n {
+            #[inline]
+            fn shr_assign(&mut self, rhs: u32) {
+                $(self.$var >>= rhs;)*
+            }
+        }
+
+        /// Shift each component by the corresponding component of `rhs`.
+        impl Shl for $n {
+            type Output = Self;
+            #[inline]
+            fn shl(self, rhs: $n) -> Self {
+                Self::new($(self.$var << rhs.$var,)*)
+            }
+        }
+
+        /// Shift each component by the corresponding component of `rhs`.
+        impl Shr for $n {
+            type Output = Self;
+            #[inline]
+            fn shr(self, rhs: $n) -> Self {
+                Self::new($(self.$var >> rhs.$var,)*)
+            }
+        })+
+    };
+}
+
+/// The multiplier used to combine the components in `grid_hash`.
+pub(crate) const GRID_HASH_MUL: u32 = 0x9e37_79b9;
+
+/// Finalize a hash such that every bit of the input affects every bit of the output, using
+/// the `lowbias32` function of Chris Wellons.
+#[inline]
+pub(crate) fn mix32(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7feb_352d);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846c_a68b);
+    h ^ (h >> 16)
+}
+
+macro_rules! impl_grid_hash {
+    ($($n:ident => [$($var:ident),*]),+) => {
+        $(impl $n {
+            /// A well-mixed 32-bit hash of the components, e.g. for looking up cells of a grid in
+            /// a hash table with a power-of-two number of buckets by masking off the low bits.
+            ///
+            /// The hash is the same for every platform and version of this crate, and
+            /// the wide integer vectors compute the same hash for each of their lanes.
+            #[inline]
+            pub fn grid_hash(&self) -> u32 {
+                let mut h = 0u32;
+                $(h = (h.rotate_left(5) ^ self.$var as u32).wrapping_mul(GRID_HASH_MUL);)*
+                mix32(h)
+            }
+        })+
+    };
+}
+
+ivec2s!((U8Vec2, U8Vec3, U8Vec4) => u8);
+ivec2s!((U16Vec2, U16Vec3, U16Vec4) => u16);
 ivec2s!((UVec2, UVec3, UVec4) => u32);
 ivec2s!((IVec2, IVec3, IVec4) => i32);
+ivec2s!((I64Vec2, I64Vec3, I64Vec4) => i64);
 
+ivec3s!((U8Vec2, U8Vec3, U8Vec4) => u8);
+ivec3s!((U16Vec2, U16Vec3, U16Vec4) => u16);
 ivec3s!((UVec2, UVec3, UVec4) => u32);
 ivec3s!((IVec2, IVec3, IVec4) => i32);
+ivec3s!((I64Vec2, I64Vec3, I64Vec4) => i64);
 
+ivec4s!(U8Vec4, U8Vec2, U8Vec3 => u8);
+ivec4s!(U16Vec4, U16Vec2, U16Vec3 => u16);
 ivec4s!(UVec4, UVec2, UVec3 => u32);
 ivec4s!(IVec4, IVec2, IVec3 => i32);
+ivec4s!(I64Vec4, I64Vec2, I64Vec3 => i64);
+
+impl_neg!(
+    IVec2 => [x, y],
+    IVec3 => [x, y, z],
+    IVec4 => [x, y, z, w],
+    I64Vec2 => [x, y],
+    I64Vec3 => [x, y, z],
+    I64Vec4 => [x, y, z, w]
+);
+
+impl_truncate_extend!(
+    (U8Vec2, U8Vec3, U8Vec4),
+    (U16Vec2, U16Vec3, U16Vec4),
+    (UVec2, UVec3, UVec4),
+    (IVec2, IVec3, IVec4),
+    (I64Vec2, I64Vec3, I64Vec4)
+);
+
+impl_widen!(
+    (U8Vec2 => U16Vec2, u16, [x, y]),
+    (U8Vec3 => U16Vec3, u16, [x, y, z]),
+    (U8Vec4 => U16Vec4, u16, [x, y, z, w]),
+    (U8Vec2 => UVec2, u32, [x, y]),
+    (U8Vec3 => UVec3, u32, [x, y, z]),
+    (U8Vec4 => UVec4, u32, [x, y, z, w]),
+    (U16Vec2 => UVec2, u32, [x, y]),
+    (U16Vec3 => UVec3, u32, [x, y, z]),
+    (U16Vec4 => UVec4, u32, [x, y, z, w]),
+    (UVec2 => I64Vec2, i64, [x, y]),
+    (UVec3 => I64Vec3, i64, [x, y, z]),
+    (UVec4 => I64Vec4, i64, [x, y, z, w]),
+    (IVec2 => I64Vec2, i64, [x, y]),
+    (IVec3 => I64Vec3, i64, [x, y, z]),
+    (IVec4 => I64Vec4, i64, [x, y, z, w])
+);
 
 impl_abs!(IVec2 => [x, y]);
 impl_abs!(IVec3 => [x, y, z]);
@@ -1506,3 +1750,142 @@ impl_abs!(IVec4 => [x, y, z, w]);
 impl_abs!(UVec2 => [x, y] nosign);
 impl_abs!(UVec3 => [x, y, z] nosign);
 impl_abs!(UVec4 => [x, y, z, w] nosign);
+impl_abs!(I64Vec2 => [x, y]);
+impl_abs!(I64Vec3 => [x, y, z]);
+impl_abs!(I64Vec4 => [x, y, z, w]);
+impl_abs!(U8Vec2 => [x, y] nosign);
+impl_abs!(U8Vec3 => [x, y, z] nosign);
+impl_abs!(U8Vec4 => [x, y, z, w] nosign);
+impl_abs!(U16Vec2 => [x, y] nosign);
+impl_abs!(U16Vec3 => [x, y, z] nosign);
+impl_abs!(U16Vec4 => [x, y, z, w] nosign);
+
+impl_rem!(
+    U8Vec2 => u8 [x, y],
+    U8Vec3 => u8 [x, y, z],
+    U8Vec4 => u8 [x, y, z, w],
+    U16Vec2 => u16 [x, y],
+    U16Vec3 => u16 [x, y, z],
+    U16Vec4 => u16 [x, y, z, w],
+    UVec2 => u32 [x, y],
+    UVec3 => u32 [x, y, z],
+    UVec4 => u32 [x, y, z, w],
+    IVec2 => i32 [x, y],
+    IVec3 => i32 [x, y, z],
+    IVec4 => i32 [x, y, z, w],
+    I64Vec2 => i64 [x, y],
+    I64Vec3 => i64 [x, y, z],
+    I64Vec4 => i64 [x, y, z, w]
+);
+
+impl_overflow_ops!(
+    U8Vec2 => [x, y],
+    U8Vec3 => [x, y, z],
+    U8Vec4 => [x, y, z, w],
+    U16Vec2 => [x, y],
+    U16Vec3 => [x, y, z],
+    U16Vec4 => [x, y, z, w],
+    UVec2 => [x, y],
+    UVec3 => [x, y, z],
+    UVec4 => [x, y, z, w],
+    IVec2 => [x, y],
+    IVec3 => [x, y, z],
+    IVec4 => [x, y, z, w],
+    I64Vec2 => [x, y],
+    I64Vec3 => [x, y, z],
+    I64Vec4 => [x, y, z, w]
+);
+
+impl_bit_ops!(
+    U8Vec2 => [x, y],
+    U8Vec3 => [x, y, z],
+    U8Vec4 => [x, y, z, w],
+    U16Vec2 => [x, y],
+    U16Vec3 => [x, y, z],
+    U16Vec4 => [x, y, z, w],
+    UVec2 => [x, y],
+    UVec3 => [x, y, z],
+    UVec4 => [x, y, z, w],
+    IVec2 => [x, y],
+    IVec3 => [x, y, z],
+    IVec4 => [x, y, z, w],
+    I64Vec2 => [x, y],
+    I64Vec3 => [x, y, z],
+    I64Vec4 => [x, y, z, w]
+);
+
+impl_grid_hash!(
+    U8Vec2 => [x, y],
+    U8Vec3 => [x, y, z],
+    U8Vec4 => [x, y, z, w],
+    U16Vec2 => [x, y],
+    U16Vec3 => [x, y, z],
+    U16Vec4 => [x, y, z, w],
+    UVec2 => [x, y],
+    UVec3 => [x, y, z],
+    UVec4 => [x, y, z, w],
+    IVec2 => [x, y],
+    IVec3 => [x, y, z],
+    IVec4 => [x, y, z, w]
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn signed_integer_ops() {
+        let a = IVec3::new(-7, 8, 3);
+        let b = IVec3::new(4, 3, -2);
+        assert_eq!(a % b, IVec3::new(-3, 2, 1));
+        assert_eq!(a.rem_euclid(b), IVec3::new(1, 2, 1));
+        assert_eq!(a.div_euclid(b), IVec3::new(-2, 2, -1));
+        assert_eq!(a.div_euclid(b) * b + a.rem_euclid(b), a);
+        assert_eq!(-a, IVec3::new(7, -8, -3));
+        assert_eq!(a.abs().component_max(), 8);
+        assert_eq!(IVec3::unit_x().cross(IVec3::unit_y()), IVec3::unit_z());
+        assert_eq!(IVec2::unit_x().wedge(IVec2::unit_y()), 1);
+    }
+
+    #[test]
+    fn overflow_ops() {
+        let a = U8Vec3::new(250, 3, 0);
+        let b = U8Vec3::new(10, 2, 1);
+        assert_eq!(a.wrapping_add(b), U8Vec3::new(4, 5, 1));
+        assert_eq!(a.saturating_add(b), U8Vec3::new(255, 5, 1));
+        assert_eq!(a.saturating_sub(b), U8Vec3::new(240, 1, 0));
+        assert_eq!(a.checked_sub(b), None);
+        assert_eq!(b.checked_mul(b), Some(U8Vec3::new(100, 4, 1)));
+        assert_eq!(IVec2::new(i32::MIN, 1).checked_div(IVec2::new(-1, 1)), None);
+        assert_eq!(IVec2::new(6, 1).checked_div(IVec2::new(0, 1)), None);
+        assert_eq!(
+            IVec2::new(6, 1).checked_div(IVec2::new(3, 1)),
+            Some(IVec2::new(2, 1))
+        );
+    }
+
+    #[test]
+    fn bit_ops() {
+        let a = UVec3::new(0b1100, 0b1010, 1);
+        let b = UVec3::new(0b1010, 0b0110, 3);
+        assert_eq!(a & b, UVec3::new(0b1000, 0b0010, 1));
+        assert_eq!(a | b, UVec3::new(0b1110, 0b1110, 3));
+        assert_eq!(a ^ b, UVec3::new(0b0110, 0b1100, 2));
+        assert_eq!(!UVec3::zero(), UVec3::broadcast(u32::MAX));
+        assert_eq!(a << 1, a * 2);
+        assert_eq!(a >> UVec3::new(2, 1, 0), UVec3::new(0b11, 0b101, 1));
+
+        let cells = [
+            IVec3::new(0, 0, 0),
+            IVec3::new(1, 0, 0),
+            IVec3::new(0, 1, 0),
+            IVec3::new(-1, 0, 0),
+        ];
+        let hashes = cells.map(|c| c.grid_hash());
+        for i in 0..4 {
+            for j in i + 1..4 {
+                assert_ne!(hashes[i], hashes[j]);
+            }
+        }
+    }
+}
@@ -0,0 +1,502 @@
+//! Wide integer vectors, the integer counterparts of the wide float vectors such as `Vec3x8`.
+//!
+//! These allow code like voxel meshing or spatial hashing to keep its integer coordinates in
+//! the same structure of arrays layout as the float data it is derived from.
+use crate::*;
+use core::ops::*;
+
+use wide::{i32x4, i32x8, u32x4, u32x8};
+
+/// Fold the components of `$v` with the method `$f`, starting from the first one.
+macro_rules! fold_components {
+    ($f:ident, $v:expr, $first:ident $(, $rest:ident)*) => {
+        $v.$first $(.$f($v.$rest))*
+    };
+}
+
+macro_rules! wide_ivecs {
+    ($($n:ident => ($nonwidet:ident, $t:ident, $tt:ident, $lanes:expr) { $($field:ident),+ }),+) => {
+        $(/// A wide integer vector, holding one component of each of its lanes' vectors per field.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        #[repr(C)]
+        pub struct $n {
+            $(pub $field: $t),+
+        }
+
+        impl $n {
+            #[inline]
+            pub const fn new($($field: $t),+) -> Self {
+                $n { $($field),+ }
+            }
+
+            #[inline]
+            pub const fn broadcast(val: $t) -> Self {
+                $n { $($field: val),+ }
+            }
+
+            #[inline]
+            pub fn new_splat($($field: $tt),+) -> Self {
+                $n { $($field: $t::splat($field)),+ }
+            }
+
+            #[inline]
+            pub fn splat(vec: $nonwidet) -> Self {
+                $n { $($field: $t::splat(vec.$field)),+ }
+            }
+
+            #[inline]
+            pub fn zero() -> Self {
+                Self::broadcast($t::splat(0))
+            }
+
+            #[inline]
+            pub fn one() -> Self {
+                Self::broadcast($t::splat(1))
+            }
+
+            /// Blend two vectors together lanewise using `mask` as a mask.
+            ///
+            /// Lanes where `mask` is all ones take their value from `tru`, and lanes where it is
+            /// all zeros take their value from `fals`.
+            #[inline]
+            pub fn blend(mask: $t, tru: Self, fals: Self) -> Self {
+                $n { $($field: mask.blend(tru.$field, fals.$field)),+ }
+            }
+
+            #[inline]
+            pub fn dot(&self, other: Self) -> $t {
+                $t::splat(0) $(+ self.$field * other.$field)+
+            }
+
+            #[inline]
+            pub fn mag_sq(&self) -> $t {
+                self.dot(*self)
+            }
+
+            #[inline]
+            pub fn max_by_component(&self, other: Self) -> Self {
+                $n { $($field: self.$field.max(other.$field)),+ }
+            }
+
+            #[inline]
+            pub fn min_by_component(&self, other: Self) -> Self {
+                $n { $($field: self.$field.min(other.$field)),+ }
+            }
+
+            /// The largest component of each lane.
+            #[inline]
+            pub fn component_max(&self) -> $t {
+                fold_components!(max, self, $($field),+)
+            }
+
+            /// The smallest component of each lane.
+            #[inline]
+            pub fn component_min(&self) -> $t {
+                fold_components!(min, self, $($field),+)
+            }
+
+            #[inline]
+            pub fn clamped(&self, min: Self, max: Self) -> Self {
+                self.max_by_component(min).min_by_component(max)
+            }
+        }
+
+        impl From<[$nonwidet; $lanes]> for $n {
+            #[inline]
+            fn from(vecs: [$nonwidet; $lanes]) -> Self {
+                $n { $($field: $t::from(vecs.map(|v| v.$field))),+ }
+            }
+        }
+
+        impl From<$n> for [$nonwidet; $lanes] {
+            #[inline]
+            fn from(v: $n) -> Self {
+                $(let $field = v.$field.to_array();)+
+                core::array::from_fn(|i| $nonwidet::new($($field[i]),+))
+            }
+        }
+
+        impl Add for $n {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: $n) -> Self {
+                $n { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl AddAssign for $n {
+            #[inline]
+            fn add_assign(&mut self, rhs: $n) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl Sub for $n {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: $n) -> Self {
+                $n { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl SubAssign for $n {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $n) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl Mul for $n {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: $n) -> Self {
+                $n { $($field: self.$field * rhs.$field),+ }
+            }
+        }
+
+        impl Mul<$t> for $n {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: $t) -> Self {
+                $n { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl Mul<$n> for $t {
+            type Output = $n;
+            #[inline]
+            fn mul(self, rhs: $n) -> $n {
+                rhs * self
+            }
+        }
+
+        impl MulAssign for $n {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $n) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl MulAssign<$t> for $n {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $t) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl BitAnd for $n {
+            type Output = Self;
+            #[inline]
+            fn bitand(self, rhs: $n) -> Self {
+                $n { $($field: self.$field & rhs.$field),+ }
+            }
+        }
+
+        impl BitOr for $n {
+            type Output = Self;
+            #[inline]
+            fn bitor(self, rhs: $n) -> Self {
+                $n { $($field: self.$field | rhs.$field),+ }
+            }
+        }
+
+        impl BitXor for $n {
+            type Output = Self;
+            #[inline]
+            fn bitxor(self, rhs: $n) -> Self {
+                $n { $($field: self.$field ^ rhs.$field),+ }
+            }
+        }
+
+        impl Not for $n {
+            type Output = Self;
+            #[inline]
+            fn not(self) -> Self {
+                $n { $($field: !self.$field),+ }
+            }
+        }
+
+        /// Shift every lane of every component by the same amount.
+        impl Shl<u32> for $n {
+            type Output = Self;
+            #[inline]
+            fn shl(self, rhs: u32) -> Self {
+                $n { $($field: self.$field << rhs),+ }
+            }
+        }
+
+        /// Shift every lane of every component by the same amount.
+        impl Shr<u32> for $n {
+            type Output = Self;
+            #[inline]
+            fn shr(self, rhs: u32) -> Self {
+                $n { $($field: self.$field >> rhs),+ }
+            }
+        }
+
+        /// Shift each lane of each component by the corresponding lane of `rhs`.
+        impl Shl for $n {
+            type Output = Self;
+            #[inline]
+            fn shl(self, rhs: $n) -> Self {
+                $n { $($field: self.$field << rhs.$field),+ }
+            }
+        }
+
+        /// Shift each lane of each component by the corresponding lane of `rhs`.
+        impl Shr for $n {
+            type Output = Self;
+            #[inline]
+            fn shr(self, rhs: $n) -> Self {
+                $n { $($field: self.$field >> rhs.$field),+ }
+            }
+        })+
+    };
+}
+
+macro_rules! impl_signed_wide_ivecs {
+    ($($n:ident => ($ft:ident, $lanet:ident, $from_int:ident) { $($field:ident),+ }),+) => {
+        $(impl $n {
+            #[inline]
+            pub fn abs(&self) -> Self {
+                $n { $($field: self.$field.abs()),+ }
+            }
+        }
+
+        impl Neg for $n {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                $n { $($field: -self.$field),+ }
+            }
+        }
+
+        impl From<$n> for $ft {
+            #[inline]
+            fn from(v: $n) -> Self {
+                $ft::new($($lanet::$from_int(v.$field)),+)
+            }
+        }
+
+        impl $ft {
+            /// Convert each lane of each component to an integer, rounding to the nearest
+            /// integer with half-way cases rounded away from zero, like [`Self::round`].
+            ///
+            /// Lanes which are out of range saturate to `i32::MIN` or `i32::MAX`, and `NaN`
+            /// lanes produce zero.
+            #[inline]
+            pub fn round_int(&self) -> $n {
+                let rounded = self.round();
+                $n { $($field: rounded.$field.trunc_int()),+ }
+            }
+
+            /// Convert each lane of each component to an integer, rounding towards zero.
+            ///
+            /// Lanes which are out of range saturate to `i32::MIN` or `i32::MAX`, and `NaN`
+            /// lanes produce zero.
+            #[inline]
+            pub fn trunc_int(&self) -> $n {
+                $n { $($field: self.$field.trunc_int()),+ }
+            }
+        })+
+    };
+}
+
+macro_rules! impl_unsigned_wide_ivecs {
+    ($($n:ident => ($ft:ident, $lanet:ident) { $($field:ident),+ }),+) => {
+        $(impl From<$n> for $ft {
+            #[inline]
+            fn from(v: $n) -> Self {
+                $ft::new($($lanet::from(v.$field.to_array().map(|l| l as f32))),+)
+            }
+        })+
+    };
+}
+
+#[cfg(feature = "f64")]
+macro_rules! impl_double_wide_ivecs {
+    ($($n:ident => $ft:ident { $($field:ident),+ }),+) => {
+        $(impl From<$n> for $ft {
+            #[inline]
+            fn from(v: $n) -> Self {
+                $ft::new($(f64x4::from(v.$field)),+)
+            }
+        }
+
+        impl $ft {
+            /// Convert each lane of each component to an integer, rounding to the nearest
+            /// integer with half-way cases rounded away from zero, like [`Self::round`].
+            ///
+            /// Lanes which are out of range saturate to `i32::MIN` or `i32::MAX`, and `NaN`
+            /// lanes produce zero.
+            #[inline]
+            pub fn round_int(&self) -> $n {
+                let rounded = self.round();
+                $n { $($field: i32x4::from(rounded.$field.to_array().map(|l| l as i32))),+ }
+            }
+
+            /// Convert each lane of each component to an integer, rounding towards zero.
+            ///
+            /// Lanes which are out of range saturate to `i32::MIN` or `i32::MAX`, and `NaN`
+            /// lanes produce zero.
+            #[inline]
+            pub fn trunc_int(&self) -> $n {
+                $n { $($field: i32x4::from(self.$field.to_array().map(|l| l as i32))),+ }
+            }
+        })+
+    };
+}
+
+wide_ivecs!(
+    IVec2x4 => (IVec2, i32x4, i32, 4) { x, y },
+    IVec3x4 => (IVec3, i32x4, i32, 4) { x, y, z },
+    IVec4x4 => (IVec4, i32x4, i32, 4) { x, y, z, w },
+    IVec2x8 => (IVec2, i32x8, i32, 8) { x, y },
+    IVec3x8 => (IVec3, i32x8, i32, 8) { x, y, z },
+    IVec4x8 => (IVec4, i32x8, i32, 8) { x, y, z, w },
+    UVec2x4 => (UVec2, u32x4, u32, 4) { x, y },
+    UVec3x4 => (UVec3, u32x4, u32, 4) { x, y, z },
+    UVec4x4 => (UVec4, u32x4, u32, 4) { x, y, z, w },
+    UVec2x8 => (UVec2, u32x8, u32, 8) { x, y },
+    UVec3x8 => (UVec3, u32x8, u32, 8) { x, y, z },
+    UVec4x8 => (UVec4, u32x8, u32, 8) { x, y, z, w }
+);
+
+impl_signed_wide_ivecs!(
+    IVec2x4 => (Vec2x4, f32x4, from_i32x4) { x, y },
+    IVec3x4 => (Vec3x4, f32x4, from_i32x4) { x, y, z },
+    IVec4x4 => (Vec4x4, f32x4, from_i32x4) { x, y, z, w },
+    IVec2x8 => (Vec2x8, f32x8, from_i32x8) { x, y },
+    IVec3x8 => (Vec3x8, f32x8, from_i32x8) { x, y, z },
+    IVec4x8 => (Vec4x8, f32x8, from_i32x8) { x, y, z, w }
+);
+
+impl_unsigned_wide_ivecs!(
+    UVec2x4 => (Vec2x4, f32x4) { x, y },
+    UVec3x4 => (Vec3x4, f32x4) { x, y, z },
+    UVec4x4 => (Vec4x4, f32x4) { x, y, z, w },
+    UVec2x8 => (Vec2x8, f32x8) { x, y },
+    UVec3x8 => (Vec3x8, f32x8) { x, y, z },
+    UVec4x8 => (Vec4x8, f32x8) { x, y, z, w }
+);
+
+#[cfg(feature = "f64")]
+impl_double_wide_ivecs!(
+    IVec2x4 => DVec2x4 { x, y },
+    IVec3x4 => DVec3x4 { x, y, z },
+    IVec4x4 => DVec4x4 { x, y, z, w }
+);
+
+macro_rules! impl_wide_grid_hash {
+    ($($n:ident => $ut:ident { $($field:ident),+ }),+) => {
+        $(impl $n {
+            /// The [`grid_hash`](crate::int::IVec3::grid_hash) of each lane.
+            #[inline]
+            pub fn grid_hash(&self) -> $ut {
+                let mul = $ut::splat(crate::int::GRID_HASH_MUL);
+                let mut h = $ut::splat(0);
+                $(let c = $ut::from(self.$field.to_array().map(|l| l as u32));
+                h = (((h << 5u32) | (h >> 27u32)) ^ c) * mul;)+
+                h ^= h >> 16u32;
+                h = h * $ut::splat(0x7feb_352d);
+                h ^= h >> 15u32;
+                h = h * $ut::splat(0x846c_a68b);
+                h ^ (h >> 16u32)
+            }
+        })+
+    };
+}
+
+impl_wide_grid_hash!(
+    IVec2x4 => u32x4 { x, y },
+    IVec3x4 => u32x4 { x, y, z },
+    IVec4x4 => u32x4 { x, y, z, w },
+    IVec2x8 => u32x8 { x, y },
+    IVec3x8 => u32x8 { x, y, z },
+    IVec4x8 => u32x8 { x, y, z, w },
+    UVec2x4 => u32x4 { x, y },
+    UVec3x4 => u32x4 { x, y, z },
+    UVec4x4 => u32x4 { x, y, z, w },
+    UVec2x8 => u32x8 { x, y },
+    UVec3x8 => u32x8 { x, y, z },
+    UVec4x8 => u32x8 { x, y, z, w }
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn voxel_coords_roundtrip() {
+        let points = Vec3x8::from([
+            Vec3::new(0.5, 1.5, -2.5),
+            Vec3::new(3.25, -0.75, 8.0),
+            Vec3::new(-1.0, 0.0, 1.0),
+            Vec3::new(100.9, 2.1, -7.6),
+            Vec3::zero(),
+            Vec3::one(),
+            Vec3::new(-3.5, 4.5, 5.5),
+            Vec3::new(16.0, 32.0, 64.0),
+        ]);
+        let cells = points.trunc_int();
+        let cells: [IVec3; 8] = cells.into();
+        assert_eq!(cells[0], IVec3::new(0, 1, -2));
+        assert_eq!(cells[3], IVec3::new(100, 2, -7));
+
+        let cells = IVec3x8::from(cells);
+        let hashed = (cells << 2) ^ IVec3x8::splat(IVec3::new(1, 2, 3));
+        let hashed: [IVec3; 8] = hashed.into();
+        assert_eq!(hashed[7], IVec3::new(65, 130, 259));
+        assert_eq!(Vec3x8::from(-cells.abs()), -Vec3x8::from(cells.abs()));
+
+        let big = UVec2x8::splat(UVec2::new(u32::MAX, 7));
+        let as_float: [Vec2; 8] = Vec2x8::from(big >> 1).into();
+        assert_eq!(as_float[0], Vec2::new((u32::MAX >> 1) as f32, 3.0));
+
+        assert_eq!(cells.component_min().to_array()[3], -7);
+        assert_eq!(cells.horizontal_max(), IVec3::new(100, 32, 64));
+
+        let extreme = Vec3x4::splat(Vec3::new(1e12, -1e12, f32::NAN));
+        let expected = IVec3::new(i32::MAX, i32::MIN, 0);
+        assert_eq!(<[IVec3; 4]>::from(extreme.trunc_int())[0], expected);
+        assert_eq!(<[IVec3; 4]>::from(extreme.round_int())[0], expected);
+        let ties = Vec3x4::splat(Vec3::new(0.5, -2.5, 1.5));
+        assert_eq!(
+            <[IVec3; 4]>::from(ties.round_int())[0],
+            IVec3::new(1, -3, 2)
+        );
+    }
+
+    #[cfg(feature = "f64")]
+    #[test]
+    fn double_wide_conversions() {
+        let points = DVec3x4::from([
+            DVec3::new(0.5, 1.5, -2.5),
+            DVec3::new(1e12, -1e12, f64::NAN),
+            DVec3::new(-1.75, 2.25, 3.0),
+            DVec3::zero(),
+        ]);
+        let cells: [IVec3; 4] = points.trunc_int().into();
+        assert_eq!(cells[0], IVec3::new(0, 1, -2));
+        assert_eq!(cells[1], IVec3::new(i32::MAX, i32::MIN, 0));
+        let rounded: [IVec3; 4] = points.round_int().into();
+        assert_eq!(rounded[2], IVec3::new(-2, 2, 3));
+
+        let back: [DVec3; 4] = DVec3x4::from(IVec3x4::from(cells)).into();
+        assert_eq!(back[0], DVec3::new(0.0, 1.0, -2.0));
+    }
+
+    #[test]
+    fn grid_hash_lanes() {
+        let cells = [
+            IVec3::new(0, 0, 0),
+            IVec3::new(-5, 7, 1 << 20),
+            IVec3::new(i32::MIN, i32::MAX, -1),
+            IVec3::new(3, 3, 3),
+        ];
+        let hashes = IVec3x4::from(cells).grid_hash().to_array();
+        for (cell, hash) in cells.iter().zip(&hashes) {
+            assert_eq!(cell.grid_hash(), *hash);
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Interpolation on types for which it makes sense.
 use crate::*;
 
+use wide::CmpGt;
+
 /// Pure linear interpolation, i.e. `(1.0 - t) * self + (t) * end`.
 ///
 /// For interpolating `Rotor`s with linear interpolation, you almost certainly
@@ -13,6 +15,10 @@ use crate::*;
 /// what you want to use. However, there are situations in which you really want
 /// the interpolation between two `Rotor`s to be of constant angular velocity. In this
 /// case, check out `Slerp`.
+///
+/// Wide types implement this both for their wide float type, which interpolates each lane by
+/// its own `t`, e.g. eight independent animations sampled at different times, and for the
+/// scalar float type, which interpolates all lanes by the same `t`.
 pub trait Lerp<T> {
     fn lerp(&self, end: Self, t: T) -> Self;
 }
@@ -37,6 +43,13 @@ macro_rules! impl_lerp {
             fn lerp(&self, end: Self, t: $tt) -> Self {
                 *self * ($tt::splat(1.0) - t) + end * t
             }
+        }
+
+        impl LerpExpDecay<$tt> for $vt {
+            #[inline]
+            fn lerp_exp_decay(&self, end: Self, rate: $tt, dt: $tt) -> Self {
+                self.lerp(end, $tt::splat(1.0) - Transcendental::exp(-(rate * dt)))
+            }
         })+)+
     };
 }
@@ -54,6 +67,28 @@ impl_lerp!(
     f64x4 => (f64x4, DVec2x4, DVec3x4, DVec4x4, DBivec2x4, DBivec3x4, DRotor2x4, DRotor3x4)
 );
 
+/// Frame-rate independent exponential smoothing, for moving a value towards a target by a
+/// fraction of the remaining distance every frame.
+///
+/// The common `value = value.lerp(target, 0.1)` approaches the target faster at higher frame
+/// rates. `value = value.lerp_exp_decay(target, rate, dt)` instead lerps by
+/// `1.0 - exp(-rate * dt)`, so that the remaining distance decays by a factor of `e` every
+/// `1.0 / rate` seconds regardless of how the time is split into frames.
+///
+/// ```rust
+/// # use ultraviolet::*;
+/// let target = Vec2::new(1.0, 2.0);
+/// let mut fast = Vec2::zero();
+/// for _ in 0..4 {
+///     fast = fast.lerp_exp_decay(target, 3.0, 0.25);
+/// }
+/// let slow = Vec2::zero().lerp_exp_decay(target, 3.0, 1.0);
+/// assert!((fast - slow).mag() < 1e-5);
+/// ```
+pub trait LerpExpDecay<T>: Lerp<T> {
+    fn lerp_exp_decay(&self, end: Self, rate: T, dt: T) -> Self;
+}
+
 /// Spherical-linear interpolation.
 ///
 /// Basically, interpolation that maintains a constant angular velocity
@@ -62,6 +97,8 @@ impl_lerp!(
 /// 3d normal vectors.
 ///
 /// Note that you should often normalize the result returned by this operation, when working with `Rotor`s, etc!
+///
+/// Like [`Lerp`], wide types can be interpolated by a per-lane wide `t` or a scalar `t`.
 pub trait Slerp<T> {
     fn slerp(&self, end: Self, t: T) -> Self;
 }
@@ -95,12 +132,12 @@ macro_rules! impl_slerp_rotor3 {
 
                 let dot = dot.min(1.0).max(-1.0);
 
-                let theta_0 = dot.acos(); // angle between inputs
+                let theta_0 = Transcendental::acos(dot); // angle between inputs
                 let theta = theta_0 * t; // amount of said angle to travel
 
                 let v2 = (end - (*self * dot)).normalized(); // create orthonormal basis between self and `v2`
 
-                let (s, c) = theta.sin_cos();
+                let (s, c) = Transcendental::sin_cos(theta);
 
                 let mut n = *self;
 
@@ -131,8 +168,9 @@ macro_rules! impl_slerp_rotor3_wide {
             ///
             /// `self` and `end` should both be normalized or something bad will happen!
             ///
-            /// The implementation for SIMD types also requires that the two things being interpolated between
-            /// are not exactly aligned, or else the result is undefined.
+            /// Like the scalar implementation, each lane takes the shortest path and falls back
+            /// to normalized linear interpolation when its two rotors are nearly aligned, without
+            /// leaving SIMD.
             ///
             /// Basically, interpolation that maintains a constant angular velocity
             /// from one orientation on a unit hypersphere to another. This is sorta the "high quality" interpolation
@@ -142,23 +180,30 @@ macro_rules! impl_slerp_rotor3_wide {
             /// Note that you should often normalize the result returned by this operation, when working with `Rotor`s, etc!
             #[inline]
             fn slerp(&self, end: Self, t: $tt) -> Self {
+                let one = $tt::splat(1.0);
                 let dot = self.dot(end);
 
-                let dot = dot.min($tt::splat(1.0)).max($tt::splat(-1.0));
+                // make sure interpolation takes shortest path in lanes where the dot product is negative
+                let end = end * one.copysign(dot);
+                let dot = dot.abs().min(one);
 
-                let theta_0 = dot.acos(); // angle between inputs
+                let theta_0 = Transcendental::acos(dot); // angle between inputs
                 let theta = theta_0 * t; // amount of said angle to travel
 
                 let v2 = (end - (*self * dot)).normalized(); // create orthonormal basis between self and `v2`
 
-                let (s, c) = theta.sin_cos();
+                let (s, c) = Transcendental::sin_cos(theta);
+
+                let slerped = *self * c + v2 * s;
+                let nlerped = self.lerp(end, t).normalized();
 
+                let aligned = dot.cmp_gt($tt::splat(0.9995));
                 let mut n = *self;
 
-                n.s = (c * self.s) + (s * v2.s);
-                n.bv.xy = (c * self.bv.xy) + (s * v2.bv.xy);
-                n.bv.xz = (c * self.bv.xz) + (s * v2.bv.xz);
-                n.bv.yz = (c * self.bv.yz) + (s * v2.bv.yz);
+                n.s = aligned.blend(nlerped.s, slerped.s);
+                n.bv.xy = aligned.blend(nlerped.bv.xy, slerped.bv.xy);
+                n.bv.xz = aligned.blend(nlerped.bv.xz, slerped.bv.xz);
+                n.bv.yz = aligned.blend(nlerped.bv.yz, slerped.bv.yz);
 
                 n
             }
@@ -177,6 +222,37 @@ impl_slerp_rotor3_wide!(
     f64x4 => (DRotor3x4)
 );
 
+macro_rules! impl_nlerp_rotor3 {
+    ($($tt:ident => ($($vt:ident),+)),+) => {
+        $($(impl $vt {
+            /// Normalized linear interpolation between `self` and `end` based on `t` from 0.0
+            /// to 1.0, taking the shortest path.
+            ///
+            /// This is much cheaper than [`Slerp::slerp`] and close to it for small angles, but
+            /// does not have a constant angular velocity. `self` and `end` should both be
+            /// normalized. For wide types, the shortest path is chosen per lane.
+            #[inline]
+            pub fn nlerp(&self, end: Self, t: $tt) -> Self {
+                let sign = $tt::splat(1.0).copysign(self.dot(end));
+                self.lerp(end * sign, t).normalized()
+            }
+        })+)+
+    };
+}
+
+impl_nlerp_rotor3!(
+    f32 => (Rotor3),
+    f32x4 => (Rotor3x4),
+    f32x8 => (Rotor3x8)
+);
+
+#[cfg(feature = "f64")]
+impl_nlerp_rotor3!(
+    f64 => (DRotor3),
+    f64x2 => (DRotor3x2),
+    f64x4 => (DRotor3x4)
+);
+
 macro_rules! impl_slerp_gen {
     ($($tt:ident => ($($vt:ident),+)),+) => {
         $($(impl Slerp<$tt> for $vt {
@@ -199,12 +275,12 @@ macro_rules! impl_slerp_gen {
 
                 let dot = dot.min($tt::splat(1.0)).max($tt::splat(-1.0));
 
-                let theta_0 = dot.acos(); // angle between inputs
+                let theta_0 = Transcendental::acos(dot); // angle between inputs
                 let theta = theta_0 * t; // amount of said angle to travel
 
                 let v2 = (end - (*self * dot)).normalized(); // create orthonormal basis between self and `v2`
 
-                let (s, c) = theta.sin_cos();
+                let (s, c) = Transcendental::sin_cos(theta);
 
                 *self * c + v2 * s
             }
@@ -224,3 +300,449 @@ impl_slerp_gen!(
     f64x2 => (DVec2x2, DVec3x2, DVec4x2, DBivec2x2, DBivec3x2, DRotor2x2),
     f64x4 => (DVec2x4, DVec3x4, DVec4x4, DBivec2x4, DBivec3x4, DRotor2x4)
 );
+
+macro_rules! impl_interp_splat {
+    ($($t:ident => $tt:ident => ($($vt:ident),+)),+) => {
+        $($(impl Lerp<$t> for $vt {
+            /// Linearly interpolate every lane between `self` and `end` by the same `t`.
+            ///
+            /// To interpolate each lane by a different amount, pass a wide `t` instead.
+            #[inline]
+            fn lerp(&self, end: Self, t: $t) -> Self {
+                self.lerp(end, $tt::splat(t))
+            }
+        }
+
+        impl LerpExpDecay<$t> for $vt {
+            #[inline]
+            fn lerp_exp_decay(&self, end: Self, rate: $t, dt: $t) -> Self {
+                self.lerp_exp_decay(end, $tt::splat(rate), $tt::splat(dt))
+            }
+        }
+
+        impl Slerp<$t> for $vt {
+            /// Spherical-linear interpolation of every lane between `self` and `end` by the same
+            /// `t`.
+            ///
+            /// To interpolate each lane by a different amount, pass a wide `t` instead.
+            #[inline]
+            fn slerp(&self, end: Self, t: $t) -> Self {
+                self.slerp(end, $tt::splat(t))
+            }
+        })+)+
+    };
+}
+
+impl_interp_splat!(
+    f32 => f32x4 => (Vec2x4, Vec3x4, Vec4x4, Bivec2x4, Bivec3x4, Rotor2x4, Rotor3x4),
+    f32 => f32x8 => (Vec2x8, Vec3x8, Vec4x8, Bivec2x8, Bivec3x8, Rotor2x8, Rotor3x8)
+);
+
+#[cfg(feature = "f64")]
+impl_interp_splat!(
+    f64 => f64x2 => (DVec2x2, DVec3x2, DVec4x2, DBivec2x2, DBivec3x2, DRotor2x2, DRotor3x2),
+    f64 => f64x4 => (DVec2x4, DVec3x4, DVec4x4, DBivec2x4, DBivec3x4, DRotor2x4, DRotor3x4)
+);
+
+/// Range and step functions in the style of shading languages such as GLSL and HLSL.
+///
+/// Implemented for scalar and wide floats as well as all vector types, where each
+/// operation is applied componentwise.
+pub trait ShaderInterp: Sized {
+    /// The inverse of linear interpolation, i.e. the `t` for which `a.lerp(b, t) == v`.
+    ///
+    /// The result is not clamped, so values of `v` outside of `a..b` give a `t` outside of
+    /// `0.0..1.0`.
+    fn inverse_lerp(a: Self, b: Self, v: Self) -> Self;
+
+    /// Linearly map `self` from the range `from` onto the range `to`. The result is not clamped.
+    fn remap(&self, from: (Self, Self), to: (Self, Self)) -> Self;
+
+    /// Smooth Hermite interpolation from 0.0 to 1.0 as `self` goes from `edge0` to `edge1`,
+    /// which is clamped to 0.0 below `edge0` and 1.0 above `edge1`.
+    fn smoothstep(&self, edge0: Self, edge1: Self) -> Self;
+
+    /// 0.0 if `self` is less than `edge`, and 1.0 otherwise.
+    fn step(&self, edge: Self) -> Self;
+
+    /// Clamp `self` to the range `0.0..=1.0`.
+    fn saturate(&self) -> Self;
+}
+
+macro_rules! impl_shader_interp_common {
+    () => {
+        #[inline]
+        fn inverse_lerp(a: Self, b: Self, v: Self) -> Self {
+            (v - a) / (b - a)
+        }
+
+        #[inline]
+        fn remap(&self, from: (Self, Self), to: (Self, Self)) -> Self {
+            let t = Self::inverse_lerp(from.0, from.1, *self);
+            to.0 + (to.1 - to.0) * t
+        }
+
+        #[inline]
+        fn smoothstep(&self, edge0: Self, edge1: Self) -> Self {
+            let t = Self::inverse_lerp(edge0, edge1, *self).saturate();
+            t * t * (Self::splat(3.0) - Self::splat(2.0) * t)
+        }
+    };
+}
+
+macro_rules! impl_shader_interp_scalar {
+    ($($t:ident),+) => {
+        $(impl ShaderInterp for $t {
+            impl_shader_interp_common!();
+
+            #[inline]
+            fn step(&self, edge: Self) -> Self {
+                if *self < edge {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+
+            #[inline]
+            fn saturate(&self) -> Self {
+                self.max(0.0).min(1.0)
+            }
+        })+
+    };
+}
+
+macro_rules! impl_shader_interp_wide {
+    ($($t:ident),+) => {
+        $(impl ShaderInterp for $t {
+            impl_shader_interp_common!();
+
+            #[inline]
+            fn step(&self, edge: Self) -> Self {
+                self.cmp_lt(edge).blend($t::splat(0.0), $t::splat(1.0))
+            }
+
+            #[inline]
+            fn saturate(&self) -> Self {
+                self.max($t::splat(0.0)).min($t::splat(1.0))
+            }
+        })+
+    };
+}
+
+macro_rules! impl_shader_interp_vec {
+    ($($vt:ident => ($($f:ident),+)),+) => {
+        $(impl ShaderInterp for $vt {
+            #[inline]
+            fn inverse_lerp(a: Self, b: Self, v: Self) -> Self {
+                Self { $($f: ShaderInterp::inverse_lerp(a.$f, b.$f, v.$f)),+ }
+            }
+
+            #[inline]
+            fn remap(&self, from: (Self, Self), to: (Self, Self)) -> Self {
+                Self { $($f: self.$f.remap((from.0.$f, from.1.$f), (to.0.$f, to.1.$f))),+ }
+            }
+
+            #[inline]
+            fn smoothstep(&self, edge0: Self, edge1: Self) -> Self {
+                Self { $($f: self.$f.smoothstep(edge0.$f, edge1.$f)),+ }
+            }
+
+            #[inline]
+            fn step(&self, edge: Self) -> Self {
+                Self { $($f: self.$f.step(edge.$f)),+ }
+            }
+
+            #[inline]
+            fn saturate(&self) -> Self {
+                Self { $($f: self.$f.saturate()),+ }
+            }
+        })+
+    };
+}
+
+impl_shader_interp_scalar!(f32);
+impl_shader_interp_wide!(f32x4, f32x8);
+
+impl_shader_interp_vec!(
+    Vec2 => (x, y), Vec3 => (x, y, z), Vec4 => (x, y, z, w),
+    Vec2x4 => (x, y), Vec3x4 => (x, y, z), Vec4x4 => (x, y, z, w),
+    Vec2x8 => (x, y), Vec3x8 => (x, y, z), Vec4x8 => (x, y, z, w)
+);
+
+#[cfg(feature = "f64")]
+impl_shader_interp_scalar!(f64);
+#[cfg(feature = "f64")]
+impl_shader_interp_wide!(f64x2, f64x4);
+
+#[cfg(feature = "f64")]
+impl_shader_interp_vec!(
+    DVec2 => (x, y), DVec3 => (x, y, z), DVec4 => (x, y, z, w),
+    DVec2x2 => (x, y), DVec3x2 => (x, y, z), DVec4x2 => (x, y, z, w),
+    DVec2x4 => (x, y), DVec3x4 => (x, y, z), DVec4x4 => (x, y, z, w)
+);
+
+/// Linearly interpolate between `a` and `b` by `t`, i.e. `a.lerp(b, t)`.
+///
+/// ```rust
+/// # use ultraviolet::*;
+/// assert_eq!(lerp(Vec2::zero(), Vec2::new(2.0, 4.0), 0.25), Vec2::new(0.5, 1.0));
+/// ```
+#[inline]
+pub fn lerp<T: Lerp<S>, S>(a: T, b: T, t: S) -> T {
+    a.lerp(b, t)
+}
+
+/// The `t` for which `lerp(a, b, t) == v`, see [`ShaderInterp::inverse_lerp`].
+#[inline]
+pub fn inverse_lerp<T: ShaderInterp>(a: T, b: T, v: T) -> T {
+    T::inverse_lerp(a, b, v)
+}
+
+/// Linearly map `v` from the range `from` onto the range `to`, see [`ShaderInterp::remap`].
+///
+/// ```rust
+/// # use ultraviolet::*;
+/// assert_eq!(remap(5.0, (0.0, 10.0), (-1.0, 1.0)), 0.0);
+/// ```
+#[inline]
+pub fn remap<T: ShaderInterp>(v: T, from: (T, T), to: (T, T)) -> T {
+    v.remap(from, to)
+}
+
+/// One step of a critically damped spring, as in Unity's `SmoothDamp`.
+///
+/// `omega` is the natural frequency of the spring, and the decay factor uses a cheap
+/// polynomial approximation of `exp(-omega * dt)`.
+macro_rules! smooth_damp_step {
+    ($t:ident, $current:expr, $target:expr, $velocity:expr, $smooth_time:expr, $dt:expr) => {{
+        let omega = $t::splat(2.0) / $smooth_time;
+        let x = omega * $dt;
+        let decay = $t::splat(1.0)
+            / ($t::splat(1.0) + x + $t::splat(0.48) * x * x + $t::splat(0.235) * x * x * x);
+        let change = $current - $target;
+        let temp = (*$velocity + change * omega) * $dt;
+        *$velocity = (*$velocity - temp * omega) * decay;
+        $target + (change + temp) * decay
+    }};
+}
+
+macro_rules! impl_smooth_damp_vec {
+    ($($t:ident => ($($vt:ident),+)),+) => {
+        $($(impl $vt {
+            /// Move `current` towards `target` with a critically damped spring, which reaches
+            /// the target in roughly `smooth_time` seconds without overshooting or oscillating.
+            ///
+            /// `velocity` is the state of the spring; start it at zero and pass the same one to
+            /// every call. The result is consistent across frame rates, which makes this suited
+            /// to smoothing cameras and UI towards a moving target each frame.
+            #[inline]
+            pub fn smooth_damp(current: Self, target: Self, velocity: &mut Self, smooth_time: $t, dt: $t) -> Self {
+                smooth_damp_step!($t, current, target, velocity, smooth_time, dt)
+            }
+        })+)+
+    };
+}
+
+impl_smooth_damp_vec!(
+    f32 => (Vec2, Vec3, Vec4),
+    f32x4 => (Vec2x4, Vec3x4, Vec4x4),
+    f32x8 => (Vec2x8, Vec3x8, Vec4x8)
+);
+
+#[cfg(feature = "f64")]
+impl_smooth_damp_vec!(
+    f64 => (DVec2, DVec3, DVec4),
+    f64x2 => (DVec2x2, DVec3x2, DVec4x2),
+    f64x4 => (DVec2x4, DVec3x4, DVec4x4)
+);
+
+macro_rules! impl_smooth_damp_rotor3 {
+    ($($t:ident => ($($rt:ident),+)),+) => {
+        $($(impl $rt {
+            /// Rotate `current` towards `target` with a critically damped spring, taking the
+            /// shortest path, see [`Vec3::smooth_damp`].
+            ///
+            /// `velocity` is the rate of change of the rotor's components; start it at zero and
+            /// pass the same one to every call. `current` and `target` should be normalized, and
+            /// the result is.
+            #[inline]
+            pub fn smooth_damp(current: Self, target: Self, velocity: &mut Self, smooth_time: $t, dt: $t) -> Self {
+                let target = target * $t::splat(1.0).copysign(current.dot(target));
+                let damped: Self = smooth_damp_step!($t, current, target, velocity, smooth_time, dt);
+                damped.normalized()
+            }
+        })+)+
+    };
+}
+
+impl_smooth_damp_rotor3!(
+    f32 => (Rotor3),
+    f32x4 => (Rotor3x4),
+    f32x8 => (Rotor3x8)
+);
+
+#[cfg(feature = "f64")]
+impl_smooth_damp_rotor3!(
+    f64 => (DRotor3),
+    f64x2 => (DRotor3x2),
+    f64x4 => (DRotor3x4)
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shader_interp() {
+        assert_eq!(f32::inverse_lerp(2.0, 4.0, 3.0), 0.5);
+        assert_eq!(5.0f32.remap((0.0, 10.0), (-1.0, 1.0)), 0.0);
+        assert_eq!(0.5f32.smoothstep(0.0, 1.0), 0.5);
+        assert_eq!(2.0f32.smoothstep(0.0, 1.0), 1.0);
+
+        let v = Vec3::new(-0.5, 0.25, 1.5);
+        assert_eq!(v.saturate(), Vec3::new(0.0, 0.25, 1.0));
+        assert_eq!(v.step(Vec3::broadcast(0.25)), Vec3::new(0.0, 1.0, 1.0));
+        assert_eq!(
+            Vec3::inverse_lerp(Vec3::zero(), Vec3::broadcast(2.0), v),
+            Vec3::new(-0.25, 0.125, 0.75)
+        );
+
+        let w = Vec3x4::from([v; 4]);
+        assert_eq!(w.saturate(), Vec3x4::from([v.saturate(); 4]));
+        assert_eq!(
+            w.step(Vec3x4::broadcast(f32x4::splat(0.25))),
+            Vec3x4::from([Vec3::new(0.0, 1.0, 1.0); 4])
+        );
+        assert_eq!(
+            w.smoothstep(Vec3x4::zero(), Vec3x4::one()),
+            Vec3x4::from([v.smoothstep(Vec3::zero(), Vec3::one()); 4])
+        );
+    }
+
+    #[test]
+    fn free_functions() {
+        let (a, b) = (Vec3::new(1.0, 2.0, 3.0), Vec3::new(3.0, 6.0, -1.0));
+        assert_eq!(lerp(a, b, 0.5), a.lerp(b, 0.5));
+        assert_eq!(lerp(1.0f32, 3.0, 0.25), 1.5);
+        assert_eq!(inverse_lerp(a, b, lerp(a, b, 0.75)), Vec3::broadcast(0.75));
+        assert_eq!(inverse_lerp(2.0f32, 4.0, 3.0), 0.5);
+        assert_eq!(remap(a, (a, b), (b, a)), b);
+
+        let w = Vec3x4::from([a; 4]);
+        let t = f32x4::from([0.0, 0.25, 0.5, 1.0]);
+        assert_eq!(
+            lerp(w, Vec3x4::from([b; 4]), t),
+            w.lerp(Vec3x4::from([b; 4]), t)
+        );
+    }
+
+    #[test]
+    fn per_lane_and_scalar_t() {
+        let a = Vec3x4::from([Vec3::zero(); 4]);
+        let b = Vec3x4::from([Vec3::new(4.0, -4.0, 8.0); 4]);
+        let t = f32x4::from([0.0, 0.25, 0.5, 1.0]);
+        let lanes: [Vec3; 4] = a.lerp(b, t).into();
+        assert_eq!(lanes[1], Vec3::new(1.0, -1.0, 2.0));
+        assert_eq!(lanes[3], Vec3::new(4.0, -4.0, 8.0));
+        assert_eq!(a.lerp(b, 0.5), a.lerp(b, f32x4::splat(0.5)));
+
+        let from = Rotor3x4::from_rotation_xy(f32x4::splat(0.0));
+        let to = Rotor3x4::from_rotation_xy(f32x4::splat(1.0));
+        let angles: [f32; 4] = from.slerp(to, t).bv.xy.into();
+        let expected = [0.0f32, 0.25, 0.5, 1.0].map(|t| Rotor3::from_rotation_xy(t).bv.xy);
+        for (angle, expected) in angles.iter().zip(expected) {
+            assert!((angle - expected).abs() < 1e-5);
+        }
+        assert_eq!(from.slerp(to, 0.25), from.slerp(to, f32x4::splat(0.25)));
+    }
+
+    #[test]
+    fn smooth_damp() {
+        let target = Vec3::new(10.0, -4.0, 2.0);
+        let mut v = Vec3::zero();
+        let mut current = Vec3::zero();
+        let mut wide_v = Vec3x4::zero();
+        let mut wide = Vec3x4::zero();
+        for _ in 0..120 {
+            let before = current;
+            current = Vec3::smooth_damp(current, target, &mut v, 0.25, 1.0 / 60.0);
+            wide = Vec3x4::smooth_damp(
+                wide,
+                Vec3x4::splat(target),
+                &mut wide_v,
+                f32x4::splat(0.25),
+                f32x4::splat(1.0 / 60.0),
+            );
+            // Critically damped: approaches monotonically without overshooting.
+            assert!((target - current).mag() <= (target - before).mag());
+            assert!(current.x <= target.x);
+        }
+        assert!((current - target).mag() < 1e-3);
+        let lanes: [Vec3; 4] = wide.into();
+        assert!((lanes[2] - current).mag() < 1e-5);
+
+        // The same smoothing at a lower frame rate ends up close to the same place.
+        let mut v = Vec3::zero();
+        let mut coarse = Vec3::zero();
+        for _ in 0..15 {
+            coarse = Vec3::smooth_damp(coarse, target, &mut v, 0.5, 1.0 / 30.0);
+        }
+        let mut v = Vec3::zero();
+        let mut fine = Vec3::zero();
+        for _ in 0..60 {
+            fine = Vec3::smooth_damp(fine, target, &mut v, 0.5, 1.0 / 120.0);
+        }
+        assert!((coarse - fine).mag() < 0.1);
+
+        let target = Rotor3::from_rotation_xz(2.0);
+        let mut v = Rotor3::new(0.0, Bivec3::zero());
+        let mut current = Rotor3::identity();
+        for _ in 0..120 {
+            current = Rotor3::smooth_damp(current, target, &mut v, 0.25, 1.0 / 60.0);
+        }
+        assert!(current.dot(target).abs() > 0.9999);
+        assert!((current.mag() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn wide_rotor_slerp_matches_scalar() {
+        use crate::util::EqualsEps;
+
+        let a = [
+            Rotor3::identity(),
+            Rotor3::from_rotation_xz(0.5),
+            Rotor3::from_euler_angles(0.3, 1.0, -2.0),
+            Rotor3::from_rotation_yz(2.5),
+            Rotor3::from_rotation_xy(-1.0),
+            Rotor3::from_euler_angles(-0.7, 0.2, 0.1),
+            Rotor3::from_rotation_xz(1.0),
+            Rotor3::identity(),
+        ];
+        let b = [
+            Rotor3::from_rotation_xy(1.0),
+            Rotor3::from_rotation_xz(0.5001),
+            // The negated rotors represent the same rotation, so the short way around is tiny.
+            Rotor3::from_euler_angles(0.3, 1.1, -2.0) * -1.0,
+            Rotor3::from_rotation_yz(-2.5),
+            Rotor3::from_rotation_xy(2.0) * -1.0,
+            Rotor3::from_euler_angles(0.7, -0.2, 1.1),
+            Rotor3::from_rotation_xz(1.0),
+            Rotor3::from_rotation_yz(3.0),
+        ];
+        let t = [0.0, 0.5, 0.25, 0.75, 1.0, 0.3, 0.6, 0.9];
+
+        let wide_t = f32x8::from(t);
+        let slerped = Rotor3x8::from(a).slerp(Rotor3x8::from(b), wide_t);
+        let nlerped = Rotor3x8::from(a).nlerp(Rotor3x8::from(b), wide_t);
+        for i in 0..8 {
+            let lane = |w: Rotor3x8| {
+                let l = |x: f32x8| x.as_array_ref()[i];
+                Rotor3::new(l(w.s), Bivec3::new(l(w.bv.xy), l(w.bv.xz), l(w.bv.yz)))
+            };
+            assert!(lane(slerped).eq_eps(a[i].slerp(b[i], t[i])));
+            assert!(lane(nlerped).eq_eps(a[i].nlerp(b[i], t[i])));
+        }
+        assert!(a[2].nlerp(b[2], 0.5).dot(a[2]) > 0.99);
+    }
+}
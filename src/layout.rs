@@ -0,0 +1,153 @@
+//! Compile-time checks of the memory layout of the public types.
+//!
+//! All vector, bivector, rotor, matrix and transform types are `#[repr(C)]` structs of their
+//! component type, without padding: a `Vec3` is three `f32`s, a `Mat4` is sixteen `f32`s in
+//! column-major order, a `Rotor3` is its scalar part followed by the `xy`, `xz` and `yz`
+//! components of its bivector, and an `Isometry3` is its translation followed by its rotation.
+//! The alignment is that of the component type. The exceptions are `Vec3A` and `Mat3Std140`,
+//! which are padded and aligned to 16 bytes. Code sharing these types with C, C++ or the GPU
+//! relies on this, so a change to the size, alignment or field offsets checked here is a compile
+//! error rather than a silent break.
+use crate::*;
+
+use core::mem::{align_of, offset_of, size_of};
+
+macro_rules! assert_layouts {
+    ($($n:ty => ($size:expr, $align:expr)),+ $(,)?) => {
+        $(const _: () = assert!(
+            size_of::<$n>() == $size && align_of::<$n>() == $align,
+            concat!("unexpected layout of `", stringify!($n), "`"),
+        );)+
+    };
+}
+
+assert_layouts!(
+    Vec2 => (8, 4),
+    Vec3 => (12, 4),
+    Vec3A => (16, 16),
+    Vec4 => (16, 4),
+    Bivec2 => (4, 4),
+    Bivec3 => (12, 4),
+    Rotor2 => (8, 4),
+    Rotor3 => (16, 4),
+    Mat2 => (16, 4),
+    Mat3 => (36, 4),
+    Mat3Std140 => (48, 16),
+    Mat4 => (64, 4),
+    Isometry2 => (16, 4),
+    Isometry3 => (28, 4),
+    Similarity2 => (20, 4),
+    Similarity3 => (32, 4),
+    Transform3 => (40, 4),
+    Vec3x4 => (48, 16),
+    Vec3x8 => (96, 32),
+    Rotor3x4 => (64, 16),
+    Mat4x4 => (256, 16),
+);
+
+macro_rules! assert_offsets {
+    ($($n:ty { $($f:ident: $offset:expr),+ }),+ $(,)?) => {
+        $($(const _: () = assert!(
+            offset_of!($n, $f) == $offset,
+            concat!("unexpected offset of `", stringify!($n), "::", stringify!($f), "`"),
+        );)+)+
+    };
+}
+
+assert_offsets!(
+    Vec2 { x: 0, y: 4 },
+    Vec3 { x: 0, y: 4, z: 8 },
+    Vec4 {
+        x: 0,
+        y: 4,
+        z: 8,
+        w: 12
+    },
+    Bivec2 { xy: 0 },
+    Bivec3 {
+        xy: 0,
+        xz: 4,
+        yz: 8
+    },
+    Rotor2 { s: 0, bv: 4 },
+    Rotor3 { s: 0, bv: 4 },
+    Mat2 { cols: 0 },
+    Mat3 { cols: 0 },
+    Mat3Std140 { cols: 0 },
+    Mat4 { cols: 0 },
+    Isometry2 {
+        translation: 0,
+        rotation: 8
+    },
+    Isometry3 {
+        translation: 0,
+        rotation: 12
+    },
+    Similarity2 {
+        translation: 0,
+        rotation: 8,
+        scale: 16
+    },
+    Similarity3 {
+        translation: 0,
+        rotation: 12,
+        scale: 28
+    },
+    Transform3 {
+        translation: 0,
+        rotation: 12,
+        scale: 28
+    },
+    Vec3x4 { x: 0, y: 16, z: 32 },
+    Rotor3x4 { s: 0, bv: 16 },
+);
+
+#[cfg(feature = "f64")]
+assert_offsets!(
+    DVec3 { x: 0, y: 8, z: 16 },
+    DRotor3 { s: 0, bv: 8 },
+    DIsometry3 {
+        translation: 0,
+        rotation: 24
+    },
+    DSimilarity3 {
+        translation: 0,
+        rotation: 24,
+        scale: 56
+    },
+);
+
+#[cfg(feature = "int")]
+assert_offsets!(
+    IVec3 { x: 0, y: 4, z: 8 },
+    U8Vec4 {
+        x: 0,
+        y: 1,
+        z: 2,
+        w: 3
+    },
+);
+
+#[cfg(feature = "f64")]
+assert_layouts!(
+    DVec2 => (16, 8),
+    DVec3 => (24, 8),
+    DVec4 => (32, 8),
+    DBivec3 => (24, 8),
+    DRotor3 => (32, 8),
+    DMat3 => (72, 8),
+    DMat4 => (128, 8),
+    DIsometry3 => (56, 8),
+    DSimilarity3 => (64, 8),
+);
+
+#[cfg(feature = "int")]
+assert_layouts!(
+    IVec2 => (8, 4),
+    IVec3 => (12, 4),
+    IVec4 => (16, 4),
+    UVec3 => (12, 4),
+    U8Vec4 => (4, 1),
+    U16Vec2 => (4, 2),
+    I64Vec3 => (24, 8),
+);
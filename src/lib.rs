@@ -42,12 +42,25 @@
 //!
 //! Will enable the `f64` and `int` features. Here's a list of the available features:
 //!
+//! * `std` – Enabled by default. Disable it to use `ultraviolet` in `no_std` environments, in which case the `libm` feature must be enabled instead.
+//! * `libm` – Use the `libm` crate for scalar floating point functions such as `sqrt` and `sin_cos` when `std` is disabled.
+//! * `deterministic` – Compute transcendental functions such as `sin_cos`, `acos` and `powf` with the `libm` crate, and multiply-adds with a single rounding, for all types including each lane of the wide types, so results are bit-identical across platforms and target features, e.g. for lockstep simulations. The approximations in `fast_math` are not covered. This is slower for the wide types. Implies `libm`.
+//! * `ffi` – Enable the `ffi` module of `extern "C"` functions on the scalar types, for generating a C header with `cbindgen`.
 //! * `f64` – Enable `f64` bit wide floating point support. Naming convention is `D[Type]`, such as `DVec3x4` would be a collection of 4 3d vectors with `f64` precision each.
-//! * `int` – Enable integer vector types.
+//! * `int` – Enable integer vector types: `IVec`, `UVec`, `U8Vec`, `U16Vec` and `I64Vec`, as well as the wide `IVec3x8` and similar.
+//! * `generic` – Enable the `generic` module, with the `GScalar` trait and aliases such as `GVec3<T>` for writing code once for `f32`, `f64` and their wide versions.
+//! * `half` – Enable the half precision `HVec2`, `HVec3` and `HVec4` types for storage and GPU interchange, using the `half` crate.
+//! * `color` – Enable sRGB conversion, luminance and hue rotation helpers for using `Vec3` and `Vec4` as colors.
+//! * `hash-float` – Enable `Hash` and `Eq` implementations for the scalar vector, bivector, rotor and matrix types, hashing the bits of their components with `-0.0` treated as `0.0`, so they can be used as hash map keys. **Caveat:** `Eq` is not reflexive for values with `NaN` components, which are never equal to themselves, so never use them as keys.
+//! * `approx` – Enable `approx` crate trait implementations, for comparing floating point types in tests.
 //! * `bytemuck` – Enable casting of many types to byte arrays, for use with graphics APIs.
+//! * `encase` – Implement the `encase` traits for the scalar `f32` vectors and matrices, so they can be used in structs deriving `ShaderType` for GPU buffers.
 //! * `mint` – Enable interoperation with other math crates through the `mint` interface.
+//! * `portable-simd` – Implement the wide float types over `core::simd` instead of with `wide`, add `f32x16` along with the x16 vectors, bivectors, rotors, matrices and `Isometry3x16` built on it, and enable the `portable_simd` module for converting between the wide types and `Simd`. Requires a nightly compiler.
 //! * `num-traits` – Enable [identity traits](https://docs.rs/num-traits/latest/num_traits/identities/index.html) for interoperation with other math crates.
-//! * `serde` – Enable `Serialize` and `Deserialize` implementations for many scalar types.
+//! * `rand` – Enable sampling of random directions, points and rotations in the `sample` module, as well as `rand` `Distribution` implementations.
+//! * `rayon` – Enable parallel versions of the bulk slice operations such as `par_dot_slices` and `par_transform_points`, which split the work across the `rayon` thread pool. Implies `std`.
+//! * `serde` – Enable `Serialize` and `Deserialize` implementations for most scalar and wide types, as well as the opt-in compact array representation for vectors in the `compact_serde` module.
 //!
 //! ## Crate Features
 //!
@@ -77,6 +90,8 @@
 //! If it's missing something you need it to do, bug me on the [GitHub issue tracker](https://github.com/termhn/ultraviolet/issues) and/or Rust community discord server
 //! (I'm Fusha there) and I'll try to add it for you, if I believe it fits with the vision of the lib :)
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 #![deny(
     rust_2018_compatibility,
     rust_2018_idioms,
@@ -86,61 +101,128 @@
     clippy::all
 )]
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("ultraviolet requires either the `std` or the `libm` feature to be enabled");
+
 extern crate alloc;
 #[cfg(feature = "serde")]
 extern crate serde;
 
 mod util;
 
+#[cfg(feature = "portable-simd")]
+mod simd;
+
+pub(crate) use util::Consts;
+pub(crate) use util::FusedMulAdd;
 pub(crate) use util::Splat;
+pub(crate) use util::Transcendental;
+// Unused if `std` is linked in by a dependency, since the inherent methods then take precedence.
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+pub(crate) use util::FloatExt;
 
+pub mod aabb;
+pub mod anim;
 pub mod bivec;
+pub mod camera;
+#[cfg(feature = "color")]
+pub mod color;
+#[cfg(feature = "serde")]
+pub mod compact_serde;
 #[cfg(feature = "int")]
 pub mod conversion;
+pub mod dispatch;
+pub mod dual;
+pub mod fast_math;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "generic")]
+pub mod generic;
 #[cfg(feature = "int")]
 pub mod int;
+#[cfg(feature = "int")]
+pub mod int_wide;
 pub mod interp;
+pub mod line;
+pub mod mask;
 pub mod mat;
+pub mod mesh;
+pub mod morton;
+pub mod physics;
+#[cfg(feature = "portable-simd")]
+pub mod portable_simd;
 pub mod projection;
+pub mod ray;
 pub mod rotor;
+#[cfg(feature = "rand")]
+pub mod sample;
+pub mod sdf;
+pub mod soa;
 pub mod transform;
+pub mod units;
 pub mod vec;
 
 #[cfg(feature = "serde")]
 mod impl_serde;
-#[cfg(feature = "serde")]
-pub use impl_serde::*;
 
 #[cfg(feature = "mint")]
 mod impl_mint;
-#[cfg(feature = "mint")]
-pub use impl_mint::*;
+
+#[cfg(feature = "encase")]
+mod impl_encase;
 
 #[cfg(feature = "bytemuck")]
 mod impl_bytemuck;
 
-#[cfg(feature = "bytemuck")]
-pub use impl_bytemuck::*;
+#[cfg(feature = "approx")]
+mod impl_approx;
 
+#[cfg(feature = "hash-float")]
+mod impl_hash;
+
+#[cfg(feature = "f64")]
+mod precision;
+
+mod validity;
+
+mod impl_array;
+mod layout;
+
+pub use aabb::*;
 pub use bivec::*;
 #[cfg(feature = "int")]
 pub use conversion::*;
 #[cfg(feature = "int")]
+pub use int::MulAdd;
+#[cfg(feature = "int")]
 pub use int::*;
+#[cfg(feature = "int")]
+pub use int_wide::*;
 pub use interp::*;
+pub use line::*;
+pub use mask::*;
 pub use mat::*;
+pub use morton::*;
+pub use ray::*;
 pub use rotor::*;
 pub use transform::*;
+pub use units::*;
 pub use vec::*;
 
-pub use wide::f32x4;
-pub use wide::f32x8;
-pub use wide::f64x2;
-pub use wide::f64x4;
+#[cfg(not(feature = "portable-simd"))]
+pub use wide::{f32x4, f32x8, f64x2, f64x4};
+#[cfg(feature = "int")]
+pub use wide::{i32x4, i32x8, u32x4, u32x8};
+
+#[cfg(feature = "portable-simd")]
+pub use simd::{f32x16, f32x4, f32x8, f64x2, f64x4};
 
-pub use wide::f32x4 as m32x4;
-pub use wide::f32x8 as m32x8;
-pub use wide::f64x2 as m64x2;
-pub use wide::f64x4 as m64x4;
+#[cfg(feature = "portable-simd")]
+pub use f32x16 as m32x16;
+pub use f32x4 as m32x4;
+pub use f32x8 as m32x8;
+pub use f64x2 as m64x2;
+pub use f64x4 as m64x4;
 
 pub(crate) use wide::{CmpGe, CmpLt};
@@ -0,0 +1,163 @@
+//! Infinite lines in 3d space, stored as Plücker coordinates.
+//!
+//! A line through the point `p` with direction `d` is stored as the pair `(d, p.cross(d))`,
+//! the direction and the moment of the line. Unlike a point and a direction, this doesn't
+//! depend on which point of the line was chosen, and some queries between two lines become
+//! a single product, see [`Line3::side`].
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! let edge = Line3::from_points(Vec3::new(0.0, 0.0, 1.0), Vec3::new(1.0, 0.0, 1.0));
+//! let ray = Line3::from_points(Vec3::zero(), Vec3::unit_y());
+//! assert_eq!(edge.distance_to_line(&ray), 1.0);
+//! assert!(edge.side(&ray) > 0.0);
+//! ```
+use crate::*;
+
+macro_rules! lines {
+    ($($n:ident => ($rt:ident, $vt:ident, $t:ident)),+) => {
+        $(/// An infinite, oriented line in 3d space in Plücker coordinates.
+        ///
+        /// `moment` is `p.cross(direction)` for any point `p` on the line, so the two are always
+        /// orthogonal. `direction` is not required to be normalized, and it being zero means
+        /// the line is degenerate.
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[repr(C)]
+        pub struct $n {
+            pub direction: $vt,
+            pub moment: $vt,
+        }
+
+        impl $n {
+            #[inline]
+            pub const fn new(direction: $vt, moment: $vt) -> Self {
+                Self { direction, moment }
+            }
+
+            /// The line through `a` and `b`, oriented from `a` to `b`.
+            #[inline]
+            pub fn from_points(a: $vt, b: $vt) -> Self {
+                Self::from_point_direction(a, b - a)
+            }
+
+            /// The line through `point` along `direction`.
+            #[inline]
+            pub fn from_point_direction(point: $vt, direction: $vt) -> Self {
+                Self::new(direction, point.cross(direction))
+            }
+
+            /// The point on the line closest to the origin.
+            #[inline]
+            pub fn point(&self) -> $vt {
+                self.direction.cross(self.moment) / self.direction.mag_sq()
+            }
+
+            /// The point on the line closest to `point`.
+            #[inline]
+            pub fn closest_point(&self, point: $vt) -> $vt {
+                let origin = self.point();
+                let t = (point - origin).dot(self.direction) / self.direction.mag_sq();
+                origin + self.direction * t
+            }
+
+            /// The distance from `point` to the line.
+            #[inline]
+            pub fn distance_to_point(&self, point: $vt) -> $t {
+                (self.moment - point.cross(self.direction)).mag() / self.direction.mag()
+            }
+
+            /// The permuted inner product of two lines, also known as their reciprocal
+            /// product, `self.direction.dot(other.moment) + other.direction.dot(self.moment)`.
+            ///
+            /// It is zero exactly when the lines are coplanar, i.e. they intersect or are
+            /// parallel. Otherwise its sign says which way `other` passes around `self`, which
+            /// is an exact test for which side of an edge a ray passes: a ray crosses a triangle
+            /// if its side with each of the three edges, oriented around the triangle, has the
+            /// same sign.
+            #[inline]
+            pub fn side(&self, other: &Self) -> $t {
+                self.direction.dot(other.moment) + other.direction.dot(self.moment)
+            }
+
+            /// The shortest distance between two lines.
+            #[inline]
+            pub fn distance_to_line(&self, other: &Self) -> $t {
+                let n = self.direction.cross(other.direction);
+                let n_mag_sq = n.mag_sq();
+                if n_mag_sq == 0.0 {
+                    self.distance_to_point(other.point())
+                } else {
+                    self.side(other).abs() / n_mag_sq.sqrt()
+                }
+            }
+
+            /// The pair of points, on `self` and on `other` respectively, where the two lines are
+            /// closest to each other, or `None` if the lines are parallel.
+            ///
+            /// The result becomes inaccurate as the lines get close to parallel.
+            #[inline]
+            pub fn closest_points(&self, other: &Self) -> Option<($vt, $vt)> {
+                let n = self.direction.cross(other.direction);
+                let n_mag_sq = n.mag_sq();
+                if n_mag_sq == 0.0 {
+                    return None;
+                }
+                let p1 = self.point();
+                let p2 = other.point();
+                let offset = p2 - p1;
+                let t1 = offset.dot(other.direction.cross(n)) / n_mag_sq;
+                let t2 = offset.dot(self.direction.cross(n)) / n_mag_sq;
+                Some((p1 + self.direction * t1, p2 + other.direction * t2))
+            }
+
+            /// The same line with the opposite orientation.
+            #[inline]
+            pub fn reversed(&self) -> Self {
+                Self::new(-self.direction, -self.moment)
+            }
+        }
+
+        impl From<$rt> for $n {
+            #[inline]
+            fn from(ray: $rt) -> Self {
+                Self::from_point_direction(ray.origin, ray.direction)
+            }
+        }
+        )+
+    };
+}
+
+lines!(Line3 => (Ray3, Vec3, f32));
+
+#[cfg(feature = "f64")]
+lines!(DLine3 => (DRay3, DVec3, f64));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    #[test]
+    fn line_queries() {
+        let a = Line3::from_points(Vec3::new(1.0, 2.0, 0.0), Vec3::new(3.0, 2.0, 0.0));
+        assert!(a.point().eq_eps(Vec3::new(0.0, 2.0, 0.0)));
+        assert!(a.distance_to_point(Vec3::new(5.0, 2.0, 3.0)).eq_eps(3.0));
+        assert!(a
+            .closest_point(Vec3::new(5.0, 0.0, 3.0))
+            .eq_eps(Vec3::new(5.0, 2.0, 0.0)));
+
+        let b = Line3::from(Ray3::new(Vec3::new(4.0, 0.0, 1.0), Vec3::unit_y()));
+        let (on_a, on_b) = a.closest_points(&b).unwrap();
+        assert!(on_a.eq_eps(Vec3::new(4.0, 2.0, 0.0)));
+        assert!(on_b.eq_eps(Vec3::new(4.0, 2.0, 1.0)));
+        assert!(a.distance_to_line(&b).eq_eps(1.0));
+        assert!(a.side(&b) < 0.0);
+        assert!(a.side(&b.reversed()) > 0.0);
+
+        let parallel = Line3::from_point_direction(Vec3::new(0.0, 5.0, 4.0), Vec3::unit_x() * 3.0);
+        assert_eq!(a.closest_points(&parallel), None);
+        assert!(a.distance_to_line(&parallel).eq_eps(5.0));
+        let crossing = Line3::from_points(Vec3::new(2.0, 0.0, 0.0), Vec3::new(2.0, 1.0, 0.0));
+        assert_eq!(a.side(&crossing), 0.0);
+    }
+}
@@ -0,0 +1,576 @@
+//! Boolean masks, the result of per-component comparisons between vectors.
+//!
+//! Scalar vectors produce masks of `bool`s, such as [`Mask3`], while wide vectors produce masks
+//! of lane masks, such as [`Mask3x8`], which are set in every lane for which the comparison holds.
+//! Either kind can be reduced with `all` and `any`, or used to pick components from two vectors
+//! with `select`, which makes it possible to write the same branchless code for both.
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! let a = Vec3::new(1.0, 5.0, 3.0);
+//! let b = Vec3::new(2.0, 4.0, 3.0);
+//! let mask = a.cmp_lt(b);
+//! assert!(mask.any() && !mask.all());
+//! assert_eq!(mask.select(a, b), Vec3::new(1.0, 4.0, 3.0));
+//! ```
+use crate::*;
+
+use core::ops::*;
+use wide::{CmpEq, CmpGt, CmpLe, CmpNe};
+
+/// Vector types which can have their components selected by a mask of type `M`.
+///
+/// This is what makes `select` work on every mask type, see e.g. [`Mask3::select`].
+pub trait Select<M>: Sized {
+    /// Take each component from `tru` where `mask` is set and from `fals` where it is not.
+    fn select(mask: M, tru: Self, fals: Self) -> Self;
+}
+
+macro_rules! scalar_masks {
+    ($($n:ident => $len:expr, { $($field:ident),+ }),+) => {
+        $(/// A mask of `bool`s, one for each component of a scalar vector.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $n {
+            $(pub $field: bool),+
+        }
+
+        impl $n {
+            #[inline]
+            pub const fn new($($field: bool),+) -> Self {
+                $n { $($field),+ }
+            }
+
+            #[inline]
+            pub const fn broadcast(val: bool) -> Self {
+                $n { $($field: val),+ }
+            }
+
+            /// Whether every component is set.
+            #[inline]
+            pub fn all(&self) -> bool {
+                [$(self.$field),+].iter().all(|&b| b)
+            }
+
+            /// Whether any component is set.
+            #[inline]
+            pub fn any(&self) -> bool {
+                [$(self.$field),+].iter().any(|&b| b)
+            }
+
+            /// Whether no component is set.
+            #[inline]
+            pub fn none(&self) -> bool {
+                !self.any()
+            }
+
+            /// Take each component from `tru` where `self` is set and from `fals` where it is not.
+            #[inline]
+            pub fn select<V: Select<Self>>(self, tru: V, fals: V) -> V {
+                V::select(self, tru, fals)
+            }
+        }
+
+        impl From<[bool; $len]> for $n {
+            #[inline]
+            fn from(comps: [bool; $len]) -> Self {
+                let [$($field),+] = comps;
+                $n { $($field),+ }
+            }
+        }
+
+        impl From<$n> for [bool; $len] {
+            #[inline]
+            fn from(m: $n) -> Self {
+                [$(m.$field),+]
+            }
+        }
+
+        impl BitAnd for $n {
+            type Output = Self;
+            #[inline]
+            fn bitand(self, rhs: $n) -> Self {
+                $n { $($field: self.$field & rhs.$field),+ }
+            }
+        }
+
+        impl BitOr for $n {
+            type Output = Self;
+            #[inline]
+            fn bitor(self, rhs: $n) -> Self {
+                $n { $($field: self.$field | rhs.$field),+ }
+            }
+        }
+
+        impl BitXor for $n {
+            type Output = Self;
+            #[inline]
+            fn bitxor(self, rhs: $n) -> Self {
+                $n { $($field: self.$field ^ rhs.$field),+ }
+            }
+        }
+
+        impl Not for $n {
+            type Output = Self;
+            #[inline]
+            fn not(self) -> Self {
+                $n { $($field: !self.$field),+ }
+            }
+        })+
+    };
+}
+
+macro_rules! wide_masks {
+    ($($n:ident => $t:ident { $($field:ident),+ }),+) => {
+        $(/// A mask of lane masks, one for each component of a wide vector.
+        ///
+        /// Two masks are equal if all of their lanes have the same bits. A set lane is all ones,
+        /// which is a NaN as a float, so comparing the lanes as floats would never be equal.
+        #[derive(Clone, Copy, Debug, Default)]
+        #[repr(C)]
+        pub struct $n {
+            $(pub $field: $t),+
+        }
+
+        impl $n {
+            #[inline]
+            pub const fn new($($field: $t),+) -> Self {
+                $n { $($field),+ }
+            }
+
+            #[inline]
+            pub const fn broadcast(val: $t) -> Self {
+                $n { $($field: val),+ }
+            }
+
+            /// A lane mask which is set in each lane where every component is set.
+            #[inline]
+            pub fn all_components(&self) -> $t {
+                let ones = $t::splat(0.0).cmp_eq($t::splat(0.0));
+                ones $(& self.$field)+
+            }
+
+            /// A lane mask which is set in each lane where any component is set.
+            #[inline]
+            pub fn any_component(&self) -> $t {
+                $t::splat(0.0) $(| self.$field)+
+            }
+
+            /// Whether every component is set in every lane.
+            #[inline]
+            pub fn all(&self) -> bool {
+                self.all_components().all()
+            }
+
+            /// Whether any component is set in any lane.
+            #[inline]
+            pub fn any(&self) -> bool {
+                self.any_component().any()
+            }
+
+            /// Whether no component is set in any lane.
+            #[inline]
+            pub fn none(&self) -> bool {
+                !self.any()
+            }
+
+            /// Take each component from `tru` in the lanes where `self` is set and from `fals` in
+            /// the lanes where it is not.
+            #[inline]
+            pub fn select<V: Select<Self>>(self, tru: V, fals: V) -> V {
+                V::select(self, tru, fals)
+            }
+        }
+
+        impl PartialEq for $n {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                true $(&& self.$field.to_array().map(|l| l.to_bits())
+                    == other.$field.to_array().map(|l| l.to_bits()))+
+            }
+        }
+
+        impl Eq for $n {}
+
+        impl BitAnd for $n {
+            type Output = Self;
+            #[inline]
+            fn bitand(self, rhs: $n) -> Self {
+                $n { $($field: self.$field & rhs.$field),+ }
+            }
+        }
+
+        impl BitOr for $n {
+            type Output = Self;
+            #[inline]
+            fn bitor(self, rhs: $n) -> Self {
+                $n { $($field: self.$field | rhs.$field),+ }
+            }
+        }
+
+        impl BitXor for $n {
+            type Output = Self;
+            #[inline]
+            fn bitxor(self, rhs: $n) -> Self {
+                $n { $($field: self.$field ^ rhs.$field),+ }
+            }
+        }
+
+        impl Not for $n {
+            type Output = Self;
+            #[inline]
+            fn not(self) -> Self {
+                let ones = $t::splat(0.0).cmp_eq($t::splat(0.0));
+                $n { $($field: self.$field ^ ones),+ }
+            }
+        })+
+    };
+}
+
+macro_rules! impl_cmp_scalar {
+    ($($vt:ident => $mask:ident { $($field:ident),+ }),+) => {
+        $(impl $vt {
+            /// Per-component `==`.
+            #[inline]
+            pub fn cmp_eq(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field == other.$field),+ }
+            }
+
+            /// Per-component `!=`.
+            #[inline]
+            pub fn cmp_ne(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field != other.$field),+ }
+            }
+
+            /// Per-component `<`.
+            #[inline]
+            pub fn cmp_lt(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field < other.$field),+ }
+            }
+
+            /// Per-component `<=`.
+            #[inline]
+            pub fn cmp_le(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field <= other.$field),+ }
+            }
+
+            /// Per-component `>`.
+            #[inline]
+            pub fn cmp_gt(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field > other.$field),+ }
+            }
+
+            /// Per-component `>=`.
+            #[inline]
+            pub fn cmp_ge(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field >= other.$field),+ }
+            }
+        }
+
+        impl Select<$mask> for $vt {
+            #[inline]
+            fn select(mask: $mask, tru: Self, fals: Self) -> Self {
+                $vt { $($field: if mask.$field { tru.$field } else { fals.$field }),+ }
+            }
+        })+
+    };
+}
+
+macro_rules! impl_cmp_wide {
+    ($($vt:ident => $mask:ident { $($field:ident),+ }),+) => {
+        $(impl $vt {
+            /// Per-component `==`.
+            #[inline]
+            pub fn cmp_eq(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field.cmp_eq(other.$field)),+ }
+            }
+
+            /// Per-component `!=`.
+            #[inline]
+            pub fn cmp_ne(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field.cmp_ne(other.$field)),+ }
+            }
+
+            /// Per-component `<`.
+            #[inline]
+            pub fn cmp_lt(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field.cmp_lt(other.$field)),+ }
+            }
+
+            /// Per-component `<=`.
+            #[inline]
+            pub fn cmp_le(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field.cmp_le(other.$field)),+ }
+            }
+
+            /// Per-component `>`.
+            #[inline]
+            pub fn cmp_gt(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field.cmp_gt(other.$field)),+ }
+            }
+
+            /// Per-component `>=`.
+            #[inline]
+            pub fn cmp_ge(&self, other: $vt) -> $mask {
+                $mask { $($field: self.$field.cmp_ge(other.$field)),+ }
+            }
+        }
+
+        impl Select<$mask> for $vt {
+            #[inline]
+            fn select(mask: $mask, tru: Self, fals: Self) -> Self {
+                $vt { $($field: mask.$field.blend(tru.$field, fals.$field)),+ }
+            }
+        })+
+    };
+}
+
+/// The `*_by_component` spellings of the comparisons, matching `max_by_component` and
+/// `min_by_component`, so generic code can use the same names on scalar and wide vectors.
+macro_rules! impl_by_component {
+    ($($vt:ident => $mask:ident),+) => {
+        $(impl $vt {
+            /// Per-component `==`, the same as `cmp_eq`.
+            #[inline]
+            pub fn eq_by_component(&self, other: $vt) -> $mask {
+                self.cmp_eq(other)
+            }
+
+            /// Per-component `!=`, the same as `cmp_ne`.
+            #[inline]
+            pub fn ne_by_component(&self, other: $vt) -> $mask {
+                self.cmp_ne(other)
+            }
+
+            /// Per-component `<`, the same as `cmp_lt`.
+            #[inline]
+            pub fn lt_by_component(&self, other: $vt) -> $mask {
+                self.cmp_lt(other)
+            }
+
+            /// Per-component `<=`, the same as `cmp_le`.
+            #[inline]
+            pub fn le_by_component(&self, other: $vt) -> $mask {
+                self.cmp_le(other)
+            }
+
+            /// Per-component `>`, the same as `cmp_gt`.
+            #[inline]
+            pub fn gt_by_component(&self, other: $vt) -> $mask {
+                self.cmp_gt(other)
+            }
+
+            /// Per-component `>=`, the same as `cmp_ge`.
+            #[inline]
+            pub fn ge_by_component(&self, other: $vt) -> $mask {
+                self.cmp_ge(other)
+            }
+        })+
+    };
+}
+
+scalar_masks!(
+    Mask2 => 2, { x, y },
+    Mask3 => 3, { x, y, z },
+    Mask4 => 4, { x, y, z, w }
+);
+
+wide_masks!(
+    Mask2x4 => m32x4 { x, y },
+    Mask3x4 => m32x4 { x, y, z },
+    Mask4x4 => m32x4 { x, y, z, w },
+    Mask2x8 => m32x8 { x, y },
+    Mask3x8 => m32x8 { x, y, z },
+    Mask4x8 => m32x8 { x, y, z, w }
+);
+
+#[cfg(feature = "portable-simd")]
+wide_masks!(
+    Mask2x16 => m32x16 { x, y },
+    Mask3x16 => m32x16 { x, y, z },
+    Mask4x16 => m32x16 { x, y, z, w }
+);
+
+#[cfg(feature = "f64")]
+wide_masks!(
+    DMask2x2 => m64x2 { x, y },
+    DMask3x2 => m64x2 { x, y, z },
+    DMask4x2 => m64x2 { x, y, z, w },
+    DMask2x4 => m64x4 { x, y },
+    DMask3x4 => m64x4 { x, y, z },
+    DMask4x4 => m64x4 { x, y, z, w }
+);
+
+impl_cmp_scalar!(
+    Vec2 => Mask2 { x, y },
+    Vec3 => Mask3 { x, y, z },
+    Vec4 => Mask4 { x, y, z, w }
+);
+
+#[cfg(feature = "f64")]
+impl_cmp_scalar!(
+    DVec2 => Mask2 { x, y },
+    DVec3 => Mask3 { x, y, z },
+    DVec4 => Mask4 { x, y, z, w }
+);
+
+impl_cmp_wide!(
+    Vec2x4 => Mask2x4 { x, y },
+    Vec3x4 => Mask3x4 { x, y, z },
+    Vec4x4 => Mask4x4 { x, y, z, w },
+    Vec2x8 => Mask2x8 { x, y },
+    Vec3x8 => Mask3x8 { x, y, z },
+    Vec4x8 => Mask4x8 { x, y, z, w }
+);
+
+#[cfg(feature = "portable-simd")]
+impl_cmp_wide!(
+    Vec2x16 => Mask2x16 { x, y },
+    Vec3x16 => Mask3x16 { x, y, z },
+    Vec4x16 => Mask4x16 { x, y, z, w }
+);
+
+#[cfg(feature = "f64")]
+impl_cmp_wide!(
+    DVec2x2 => DMask2x2 { x, y },
+    DVec3x2 => DMask3x2 { x, y, z },
+    DVec4x2 => DMask4x2 { x, y, z, w },
+    DVec2x4 => DMask2x4 { x, y },
+    DVec3x4 => DMask3x4 { x, y, z },
+    DVec4x4 => DMask4x4 { x, y, z, w }
+);
+
+#[cfg(feature = "int")]
+impl_cmp_scalar!(
+    IVec2 => Mask2 { x, y },
+    IVec3 => Mask3 { x, y, z },
+    IVec4 => Mask4 { x, y, z, w },
+    UVec2 => Mask2 { x, y },
+    UVec3 => Mask3 { x, y, z },
+    UVec4 => Mask4 { x, y, z, w },
+    U8Vec2 => Mask2 { x, y },
+    U8Vec3 => Mask3 { x, y, z },
+    U8Vec4 => Mask4 { x, y, z, w },
+    U16Vec2 => Mask2 { x, y },
+    U16Vec3 => Mask3 { x, y, z },
+    U16Vec4 => Mask4 { x, y, z, w },
+    I64Vec2 => Mask2 { x, y },
+    I64Vec3 => Mask3 { x, y, z },
+    I64Vec4 => Mask4 { x, y, z, w }
+);
+
+impl_by_component!(
+    Vec2 => Mask2,
+    Vec3 => Mask3,
+    Vec4 => Mask4,
+    Vec2x4 => Mask2x4,
+    Vec3x4 => Mask3x4,
+    Vec4x4 => Mask4x4,
+    Vec2x8 => Mask2x8,
+    Vec3x8 => Mask3x8,
+    Vec4x8 => Mask4x8
+);
+
+#[cfg(feature = "portable-simd")]
+impl_by_component!(
+    Vec2x16 => Mask2x16,
+    Vec3x16 => Mask3x16,
+    Vec4x16 => Mask4x16
+);
+
+#[cfg(feature = "f64")]
+impl_by_component!(
+    DVec2 => Mask2,
+    DVec3 => Mask3,
+    DVec4 => Mask4,
+    DVec2x2 => DMask2x2,
+    DVec3x2 => DMask3x2,
+    DVec4x2 => DMask4x2,
+    DVec2x4 => DMask2x4,
+    DVec3x4 => DMask3x4,
+    DVec4x4 => DMask4x4
+);
+
+#[cfg(feature = "int")]
+impl_by_component!(
+    IVec2 => Mask2,
+    IVec3 => Mask3,
+    IVec4 => Mask4,
+    UVec2 => Mask2,
+    UVec3 => Mask3,
+    UVec4 => Mask4,
+    U8Vec2 => Mask2,
+    U8Vec3 => Mask3,
+    U8Vec4 => Mask4,
+    U16Vec2 => Mask2,
+    U16Vec3 => Mask3,
+    U16Vec4 => Mask4,
+    I64Vec2 => Mask2,
+    I64Vec3 => Mask3,
+    I64Vec4 => Mask4
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn wide_select_matches_scalar() {
+        let a = [
+            Vec3::new(1.0, 5.0, -3.0),
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 2.0, 2.0),
+            Vec3::one(),
+        ];
+        let b = [
+            Vec3::new(2.0, 4.0, -3.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::one(),
+            Vec3::new(1.0, 1.0, 2.0),
+        ];
+        let mask = Vec3x4::from(a).cmp_le(Vec3x4::from(b));
+        let picked: [Vec3; 4] = mask.select(Vec3x4::from(b), Vec3x4::from(a)).into();
+        for i in 0..4 {
+            let scalar = a[i].cmp_le(b[i]);
+            assert_eq!(picked[i], scalar.select(b[i], a[i]));
+        }
+
+        assert!(!mask.all() && mask.any());
+        assert!((mask | !mask).all());
+        assert!((mask & !mask).none());
+        let lanes = mask.all_components().to_array();
+        assert_eq!(lanes.map(|l| l.to_bits() != 0), [false, true, false, true]);
+        assert_eq!(
+            !Mask3::new(true, false, true),
+            Mask3::from([false, true, false])
+        );
+
+        // Set lanes are NaNs as floats, but masks still compare by their bits.
+        assert!(mask.any());
+        assert_eq!(mask, mask);
+        assert_ne!(mask, !mask);
+        assert_eq!(mask | !mask, !(mask & !mask));
+    }
+
+    /// Written once against the `*_by_component` names and instantiated for both kinds of vector.
+    macro_rules! count_inside {
+        ($vt:ident, $p:expr, $min:expr, $max:expr) => {
+            ($p.ge_by_component($min) & $p.le_by_component($max)).select($vt::one(), $vt::zero())
+        };
+    }
+
+    #[test]
+    fn by_component_generic_source() {
+        let min = Vec2::zero();
+        let max = Vec2::new(1.0, 2.0);
+        let p = Vec2::new(0.5, 3.0);
+        assert_eq!(count_inside!(Vec2, p, min, max), Vec2::new(1.0, 0.0));
+
+        let wide = count_inside!(
+            Vec2x8,
+            Vec2x8::splat(p),
+            Vec2x8::splat(min),
+            Vec2x8::splat(max)
+        );
+        assert_eq!(wide, Vec2x8::splat(Vec2::new(1.0, 0.0)));
+    }
+}
@@ -1,5 +1,7 @@
 //! Square matrices.
-use std::ops::*;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::*;
 
 use crate::*;
 
@@ -25,10 +27,10 @@ macro_rules! mat2s {
             }
 
             #[inline]
-            pub fn identity() -> Self {
+            pub const fn identity() -> Self {
                 Self::new(
-                    $vt::new($t::splat(1.0), $t::splat(0.0)),
-                    $vt::new($t::splat(0.0), $t::splat(1.0)),
+                    $vt::new(<$t as Consts>::ONE, <$t as Consts>::ZERO),
+                    $vt::new(<$t as Consts>::ZERO, <$t as Consts>::ONE),
                 )
             }
 
@@ -57,6 +59,44 @@ macro_rules! mat2s {
                 )
             }
 
+            /// Get the row at `index` of this matrix.
+            ///
+            /// Panics if `index` is out of bounds.
+            #[inline]
+            pub fn row(&self, index: usize) -> $vt {
+                $vt::new(self.cols[0][index], self.cols[1][index])
+            }
+
+            /// Set the row at `index` of this matrix to `row`.
+            ///
+            /// Panics if `index` is out of bounds.
+            #[inline]
+            pub fn set_row(&mut self, index: usize, row: $vt) {
+                self.cols[0][index] = row[0];
+                self.cols[1][index] = row[1];
+            }
+
+            /// Create a matrix from an array of its components in *row-major* order, as used by
+            /// e.g. Direct3D constant buffers by default. Note that converting from an array with
+            /// `From` instead takes the components in column-major order.
+            #[inline]
+            pub fn from_row_major(comps: [$t; 4]) -> Self {
+                Self::new(
+                    $vt::new(comps[0], comps[2]),
+                    $vt::new(comps[1], comps[3])
+                )
+            }
+
+            /// The components of this matrix as an array in *row-major* order, i.e. the inverse
+            /// of [`Self::from_row_major`]. Use [`Self::as_array`] for column-major order.
+            #[inline]
+            pub fn as_row_major_array(&self) -> [$t; 4] {
+                [
+                    self.cols[0][0], self.cols[1][0],
+                    self.cols[0][1], self.cols[1][1],
+                ]
+            }
+
             #[inline]
             pub fn determinant(&self) -> $t {
                 (self.cols[0].x * self.cols[1].y) - (self.cols[1].x * self.cols[0].y)
@@ -102,7 +142,7 @@ macro_rules! mat2s {
             /// Get the [`core::alloc::Layout`] of `Self`
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$vt>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$vt>()).unwrap()
             }
 
             /// Interpret `self` as a statically-sized array of its base numeric type
@@ -139,7 +179,7 @@ macro_rules! mat2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $t, 4)
+                    core::slice::from_raw_parts(self as *const $n as *const $t, 4)
                 }
             }
 
@@ -149,7 +189,7 @@ macro_rules! mat2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 4)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 4)
                 }
             }
 
@@ -159,7 +199,7 @@ macro_rules! mat2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $vt, 2)
+                    core::slice::from_raw_parts(self as *const $n as *const $vt, 2)
                 }
             }
 
@@ -169,7 +209,7 @@ macro_rules! mat2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $vt, 2)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $vt, 2)
                 }
             }
 
@@ -179,7 +219,7 @@ macro_rules! mat2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const u8, 4 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts(self as *const $n as *const u8, 4 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -189,7 +229,7 @@ macro_rules! mat2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 4 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 4 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -342,6 +382,24 @@ macro_rules! mat2s {
                 &mut self.cols[index]
             }
         }
+
+        /// Index into the matrix by `(row, column)`.
+        ///
+        /// Note that this is the opposite order to indexing with `mat[column][row]`, which
+        /// indexes the column first since matrices are stored in column-major order.
+        impl Index<(usize, usize)> for $n {
+            type Output = $t;
+
+            fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+                &self.cols[col][row]
+            }
+        }
+
+        impl IndexMut<(usize, usize)> for $n {
+            fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+                &mut self.cols[col][row]
+            }
+        }
         )+
     }
 }
@@ -352,6 +410,9 @@ mat2s!(
     Mat2x8 => Mat3x8, Vec3x8, Vec2x8, f32x8
 );
 
+#[cfg(feature = "portable-simd")]
+mat2s!(Mat2x16 => Mat3x16, Vec3x16, Vec2x16, f32x16);
+
 #[cfg(feature = "f64")]
 mat2s!(
     DMat2 => DMat3, DVec3, DVec2, f64,
@@ -360,7 +421,7 @@ mat2s!(
 );
 
 macro_rules! mat3s {
-    ($($n:ident => $rt:ident, $bt:ident, $m4t:ident, $v4t:ident, $v2t:ident, $vt:ident, $t:ident),+) => {
+    ($($n:ident => $r2t:ident, $rt:ident, $bt:ident, $m4t:ident, $v4t:ident, $v2t:ident, $vt:ident, $t:ident),+) => {
         $(/// A 3x3 square matrix.
         ///
         /// Useful for performing linear transformations (rotation, scaling) on 3d vectors,
@@ -382,6 +443,25 @@ macro_rules! mat3s {
                 }
             }
 
+            /// A tangent frame, with `tangent`, `bitangent` and `normal` as its columns. It
+            /// transforms normal map samples from tangent space to the space the frame is
+            /// expressed in.
+            #[inline]
+            pub fn tbn(tangent: $vt, bitangent: $vt, normal: $vt) -> Self {
+                Self::new(tangent, bitangent, normal)
+            }
+
+            /// A tangent frame from a vertex `normal` and a `tangent` with its handedness in `w`,
+            /// as stored in glTF and produced by mikktspace.
+            ///
+            /// The bitangent is `normal.cross(tangent.xyz()) * tangent.w`, so `tangent` should
+            /// already be orthogonal to `normal`, see [`crate::mesh::orthonormalize_tangent`].
+            #[inline]
+            pub fn tbn_from_tangent(tangent: $v4t, normal: $vt) -> Self {
+                let t = tangent.xyz();
+                Self::tbn(t, normal.cross(t) * tangent.w, normal)
+            }
+
             /// Assumes homogeneous 2d coordinates.
             #[inline]
             pub fn from_translation(trans: $v2t) -> Self {
@@ -413,10 +493,25 @@ macro_rules! mat3s {
                 )
             }
 
+            /// Construct a homogeneous 2d transformation matrix which scales by `scale`, then
+            /// rotates by `rotation`, then translates by `translation`.
+            ///
+            /// This is equivalent to, but faster than,
+            /// `from_translation(translation) * rotation.into_matrix().into_homogeneous() * from_nonuniform_scale_homogeneous(scale)`.
+            #[inline]
+            pub fn from_translation_rotation_scale(translation: $v2t, rotation: $r2t, scale: $v2t) -> Self {
+                let rot = rotation.into_matrix();
+                Self::new(
+                    (rot.cols[0] * scale.x).into_homogeneous_vector(),
+                    (rot.cols[1] * scale.y).into_homogeneous_vector(),
+                    translation.into_homogeneous_point(),
+                )
+            }
+
             /// Builds a homogeneous 2d rotation matrix (in the xy plane) from a given angle in radians.
             #[inline]
             pub fn from_rotation_homogeneous(angle: $t) -> Self {
-                let (s, c) = angle.sin_cos();
+                let (s, c) = Transcendental::sin_cos(angle);
                 let zero = $t::splat(0.0);
                 Self::new(
                     $vt::new(c, s, zero),
@@ -445,12 +540,23 @@ macro_rules! mat3s {
                 )
             }
 
+            /// The angular velocity tensor of a body spinning with the angular velocity `omega`,
+            /// i.e. the matrix which maps a point of the body, relative to its center of rotation,
+            /// to that point's velocity.
+            ///
+            /// `omega` is a bivector as in the [`physics`](crate::physics) module, spinning at
+            /// `omega.mag()` radians per second in its plane.
+            #[inline]
+            pub fn from_angular_velocity(omega: $bt) -> Self {
+                $vt::new(omega.yz, -omega.xz, omega.xy).skew_symmetric_matrix()
+            }
+
             #[inline]
-            pub fn identity() -> Self {
+            pub const fn identity() -> Self {
                 Self::new(
-                    $vt::new($t::splat(1.0), $t::splat(0.0), $t::splat(0.0)),
-                    $vt::new($t::splat(0.0), $t::splat(1.0), $t::splat(0.0)),
-                    $vt::new($t::splat(0.0), $t::splat(0.0), $t::splat(1.0)))
+                    $vt::new(<$t as Consts>::ONE, <$t as Consts>::ZERO, <$t as Consts>::ZERO),
+                    $vt::new(<$t as Consts>::ZERO, <$t as Consts>::ONE, <$t as Consts>::ZERO),
+                    $vt::new(<$t as Consts>::ZERO, <$t as Consts>::ZERO, <$t as Consts>::ONE))
             }
 
             /// Angles are applied in the order roll -> pitch -> yaw.
@@ -461,9 +567,9 @@ macro_rules! mat3s {
             #[inline]
             #[allow(unused_variables)]
             pub fn from_euler_angles(roll: $t, pitch: $t, yaw: $t) -> Self {
-                let (sin_yaw, cos_yaw) = yaw.sin_cos();
-                let (sin_pitch, cos_pitch) = pitch.sin_cos();
-                let (sin_roll, cos_roll) = roll.sin_cos();
+                let (sin_yaw, cos_yaw) = Transcendental::sin_cos(yaw);
+                let (sin_pitch, cos_pitch) = Transcendental::sin_cos(pitch);
+                let (sin_roll, cos_roll) = Transcendental::sin_cos(roll);
 
                 let sin_pitch_sin_roll = sin_pitch * sin_roll;
                 let sin_pitch_cos_roll = sin_pitch * cos_roll;
@@ -491,7 +597,7 @@ macro_rules! mat3s {
             /// more proper to think of this as a rotation *in the yz plane*.
             #[inline]
             pub fn from_rotation_x(angle: $t) -> Self {
-                let (sin, cos) = angle.sin_cos();
+                let (sin, cos) = Transcendental::sin_cos(angle);
                 let zero = $t::splat(0.0);
                 let one = $t::splat(1.0);
 
@@ -508,7 +614,7 @@ macro_rules! mat3s {
             /// more proper to think of this as a rotation *in the xz plane*.
             #[inline]
             pub fn from_rotation_y(angle: $t) -> Self {
-                let (sin, cos) = angle.sin_cos();
+                let (sin, cos) = Transcendental::sin_cos(angle);
                 let zero = $t::splat(0.0);
                 let one = $t::splat(1.0);
 
@@ -525,7 +631,7 @@ macro_rules! mat3s {
             /// more proper to think of this as a rotation *in the xy plane*.
             #[inline]
             pub fn from_rotation_z(angle: $t) -> Self {
-                let (sin, cos) = angle.sin_cos();
+                let (sin, cos) = Transcendental::sin_cos(angle);
                 let zero = $t::splat(0.0);
                 let one = $t::splat(1.0);
 
@@ -541,7 +647,7 @@ macro_rules! mat3s {
             /// This is here as a convenience function for users coming from other libraries.
             #[inline]
             pub fn from_rotation_around(axis: $vt, angle: $t) -> Self {
-                let (sin, cos) = angle.sin_cos();
+                let (sin, cos) = Transcendental::sin_cos(angle);
                 let mul = $t::splat(1.0) - cos;
 
                 let x_sin = axis.x * sin;
@@ -552,15 +658,15 @@ macro_rules! mat3s {
                 let xz_mul = axis.x * axis.z * mul;
                 let yz_mul = axis.y * axis.z * mul;
 
-                let m00 = (axis.x * axis.x).mul_add(mul, cos);
+                let m00 = (axis.x * axis.x).fused_mul_add(mul, cos);
                 let m10 = xy_mul + z_sin;
                 let m20 = xz_mul - y_sin;
                 let m01 = xy_mul - z_sin;
-                let m11 = (axis.y * axis.y).mul_add(mul, cos);
+                let m11 = (axis.y * axis.y).fused_mul_add(mul, cos);
                 let m21 = yz_mul + x_sin;
                 let m02 = xz_mul + y_sin;
                 let m12 = yz_mul - x_sin;
-                let m22 = (axis.z * axis.z).mul_add(mul, cos);
+                let m22 = (axis.z * axis.z).fused_mul_add(mul, cos);
 
                 // think transposed as arguments are columns
                 Self::new(
@@ -581,6 +687,51 @@ macro_rules! mat3s {
                 $rt::from_angle_plane(angle, plane).into_matrix()
             }
 
+            /// Construct an orthonormal frame whose third column is `normal`, with the first two
+            /// columns being tangent vectors as computed by [`Vec3::orthonormal_basis`].
+            ///
+            /// This is useful as a TBN-style matrix for transforming between tangent space and
+            /// the space `normal` is expressed in. `normal` *must* be normalized.
+            #[inline]
+            pub fn from_normal(normal: $vt) -> Self {
+                let (t, b) = normal.orthonormal_basis();
+                Self::new(t, b, normal)
+            }
+
+            /// Construct a rotation matrix which orients a billboard placed at `object_pos` so that
+            /// it fully faces `camera_pos`.
+            ///
+            /// The billboard's local +z axis will point towards the camera, and its local +y axis
+            /// will be as close as possible to `camera_up`.
+            ///
+            /// This function assumes a right-handed, y-up coordinate space.
+            #[inline]
+            pub fn billboard_spherical(object_pos: $vt, camera_pos: $vt, camera_up: $vt) -> Self {
+                let f = (camera_pos - object_pos).normalized();
+                let r = camera_up.cross(f).normalized();
+                let u = f.cross(r);
+                Self::new(r, u, f)
+            }
+
+            /// Construct a rotation matrix which orients a billboard placed at `object_pos` so that
+            /// it faces `camera_pos` while only rotating around the world y axis, as is commonly
+            /// used for trees, grass, etc.
+            ///
+            /// The billboard's local +y axis will stay aligned with world +y, while its local +z
+            /// axis will point towards the camera as much as possible. The result is undefined if
+            /// the camera is directly above or below the object.
+            ///
+            /// This function assumes a right-handed, y-up coordinate space.
+            #[inline]
+            pub fn billboard_cylindrical(object_pos: $vt, camera_pos: $vt) -> Self {
+                let zero = $t::splat(0.0);
+                let d = camera_pos - object_pos;
+                let f = $vt::new(d.x, zero, d.z).normalized();
+                let u = $vt::unit_y();
+                let r = u.cross(f);
+                Self::new(r, u, f)
+            }
+
             #[inline]
             pub fn into_homogeneous(self) -> $m4t {
                 let zero = $t::splat(0.0);
@@ -595,15 +746,26 @@ macro_rules! mat3s {
 
             #[inline]
             pub fn determinant(&self) -> $t {
-                self.cols[0].x.mul_add(
-                    self.cols[1].y.mul_add(self.cols[2].z, -(self.cols[2].y * self.cols[1].z)),
-                    -(self.cols[1].x.mul_add(
-                        self.cols[0].y.mul_add(self.cols[2].z, -(self.cols[2].y * self.cols[0].z)),
-                        -(self.cols[2].x * self.cols[0].y.mul_add(self.cols[1].z, -(self.cols[1].y * self.cols[0].z)))
+                self.cols[0].x.fused_mul_add(
+                    self.cols[1].y.fused_mul_add(self.cols[2].z, -(self.cols[2].y * self.cols[1].z)),
+                    -(self.cols[1].x.fused_mul_add(
+                        self.cols[0].y.fused_mul_add(self.cols[2].z, -(self.cols[2].y * self.cols[0].z)),
+                        -(self.cols[2].x * self.cols[0].y.fused_mul_add(self.cols[1].z, -(self.cols[1].y * self.cols[0].z)))
                     ))
                 )
             }
 
+            /// Whether `self` is a rotation matrix, i.e. orthonormal with a positive determinant,
+            /// up to a tolerance of `eps` per component. Reflections are not rotations.
+            #[inline]
+            pub(crate) fn is_rotation(&self, eps: $t) -> bool {
+                let eps = $vt::broadcast(eps);
+                let product = *self * self.transposed();
+                let identity = $n::identity();
+                (0..3).all(|i| (product.cols[i] - identity.cols[i]).abs().cmp_le(eps).all())
+                    && $vt::broadcast(self.determinant()).cmp_gt($vt::zero()).all()
+            }
+
             /// The adjugate of this matrix, i.e. the transpose of
             /// the cofactor matrix.
             ///
@@ -659,6 +821,47 @@ macro_rules! mat3s {
                 )
             }
 
+            /// Get the row at `index` of this matrix.
+            ///
+            /// Panics if `index` is out of bounds.
+            #[inline]
+            pub fn row(&self, index: usize) -> $vt {
+                $vt::new(self.cols[0][index], self.cols[1][index], self.cols[2][index])
+            }
+
+            /// Set the row at `index` of this matrix to `row`.
+            ///
+            /// Panics if `index` is out of bounds.
+            #[inline]
+            pub fn set_row(&mut self, index: usize, row: $vt) {
+                self.cols[0][index] = row[0];
+                self.cols[1][index] = row[1];
+                self.cols[2][index] = row[2];
+            }
+
+            /// Create a matrix from an array of its components in *row-major* order, as used by
+            /// e.g. Direct3D constant buffers by default. Note that converting from an array with
+            /// `From` instead takes the components in column-major order.
+            #[inline]
+            pub fn from_row_major(comps: [$t; 9]) -> Self {
+                Self::new(
+                    $vt::new(comps[0], comps[3], comps[6]),
+                    $vt::new(comps[1], comps[4], comps[7]),
+                    $vt::new(comps[2], comps[5], comps[8])
+                )
+            }
+
+            /// The components of this matrix as an array in *row-major* order, i.e. the inverse
+            /// of [`Self::from_row_major`]. Use [`Self::as_array`] for column-major order.
+            #[inline]
+            pub fn as_row_major_array(&self) -> [$t; 9] {
+                [
+                    self.cols[0][0], self.cols[1][0], self.cols[2][0],
+                    self.cols[0][1], self.cols[1][1], self.cols[2][1],
+                    self.cols[0][2], self.cols[1][2], self.cols[2][2],
+                ]
+            }
+
             /// Transform a Vec2 by self, interpreting it as a vector.
             #[inline]
             pub fn transform_vec2(&self, vec: $v2t) -> $v2t {
@@ -674,7 +877,7 @@ macro_rules! mat3s {
             /// Get the [`core::alloc::Layout`] of `Self`
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$t>()).unwrap()
             }
 
             /// Interpret `self` as a statically sized array of the base numeric type.
@@ -711,7 +914,7 @@ macro_rules! mat3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $t, 9)
+                    core::slice::from_raw_parts(self as *const $n as *const $t, 9)
                 }
             }
 
@@ -721,7 +924,7 @@ macro_rules! mat3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $vt, 3)
+                    core::slice::from_raw_parts(self as *const $n as *const $vt, 3)
                 }
             }
 
@@ -731,7 +934,7 @@ macro_rules! mat3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const u8, 9 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts(self as *const $n as *const u8, 9 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -741,7 +944,7 @@ macro_rules! mat3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 9)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 9)
                 }
             }
 
@@ -751,7 +954,7 @@ macro_rules! mat3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $vt, 3)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $vt, 3)
                 }
             }
 
@@ -761,7 +964,7 @@ macro_rules! mat3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 9 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 9 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -820,6 +1023,20 @@ macro_rules! mat3s {
             }
         }
 
+        impl $vt {
+            /// The skew-symmetric cross product matrix of `self`, also known as the hat
+            /// operator, such that `v.skew_symmetric_matrix() * w == v.cross(w)`.
+            #[inline]
+            pub fn skew_symmetric_matrix(&self) -> $n {
+                let zero = $t::splat(0.0);
+                $n::new(
+                    $vt::new(zero, self.z, -self.y),
+                    $vt::new(-self.z, zero, self.x),
+                    $vt::new(self.y, -self.x, zero),
+                )
+            }
+        }
+
         impl Mul<$vt> for $n {
             type Output = $vt;
             #[inline]
@@ -859,6 +1076,29 @@ macro_rules! mat3s {
             }
         }
 
+        /// Rotate each column of `rhs` by `self`, the same as `self.into_matrix() * rhs`.
+        impl Mul<$n> for $rt {
+            type Output = $n;
+            #[inline]
+            fn mul(self, rhs: $n) -> $n {
+                self.into_matrix() * rhs
+            }
+        }
+
+        /// Compose the rotation matrix `self` with `rhs`, which is applied first, the same as
+        /// `self.into_rotor3() * rhs`.
+        ///
+        /// Whether `self` is a rotation matrix is checked in debug builds only, with a tolerance
+        /// for accumulated rounding error.
+        impl Mul<$rt> for $n {
+            type Output = $rt;
+            #[inline]
+            fn mul(self, rhs: $rt) -> $rt {
+                debug_assert!(self.is_rotation($t::splat(1e-4)), "matrix is not a rotation");
+                self.into_rotor3() * rhs
+            }
+        }
+
         impl Add for $n {
             type Output = Self;
             #[inline]
@@ -931,21 +1171,42 @@ macro_rules! mat3s {
                 &mut self.cols[index]
             }
         }
+
+        /// Index into the matrix by `(row, column)`.
+        ///
+        /// Note that this is the opposite order to indexing with `mat[column][row]`, which
+        /// indexes the column first since matrices are stored in column-major order.
+        impl Index<(usize, usize)> for $n {
+            type Output = $t;
+
+            fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+                &self.cols[col][row]
+            }
+        }
+
+        impl IndexMut<(usize, usize)> for $n {
+            fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+                &mut self.cols[col][row]
+            }
+        }
         )+
     }
 }
 
 mat3s!(
-    Mat3 => Rotor3, Bivec3, Mat4, Vec4, Vec2, Vec3, f32,
-    Mat3x4 => Rotor3x4, Bivec3x4, Mat4x4, Vec4x4, Vec2x4, Vec3x4, f32x4,
-    Mat3x8 => Rotor3x8, Bivec3x8, Mat4x8, Vec4x8, Vec2x8, Vec3x8, f32x8
+    Mat3 => Rotor2, Rotor3, Bivec3, Mat4, Vec4, Vec2, Vec3, f32,
+    Mat3x4 => Rotor2x4, Rotor3x4, Bivec3x4, Mat4x4, Vec4x4, Vec2x4, Vec3x4, f32x4,
+    Mat3x8 => Rotor2x8, Rotor3x8, Bivec3x8, Mat4x8, Vec4x8, Vec2x8, Vec3x8, f32x8
 );
 
+#[cfg(feature = "portable-simd")]
+mat3s!(Mat3x16 => Rotor2x16, Rotor3x16, Bivec3x16, Mat4x16, Vec4x16, Vec2x16, Vec3x16, f32x16);
+
 #[cfg(feature = "f64")]
 mat3s!(
-    DMat3 => DRotor3, DBivec3, DMat4, DVec4, DVec2, DVec3, f64,
-    DMat3x2 => DRotor3x2, DBivec3x2, DMat4x2, DVec4x2, DVec2x2, DVec3x2, f64x2,
-    DMat3x4 => DRotor3x4, DBivec3x4, DMat4x4, DVec4x4, DVec2x4, DVec3x4, f64x4
+    DMat3 => DRotor2, DRotor3, DBivec3, DMat4, DVec4, DVec2, DVec3, f64,
+    DMat3x2 => DRotor2x2, DRotor3x2, DBivec3x2, DMat4x2, DVec4x2, DVec2x2, DVec3x2, f64x2,
+    DMat3x4 => DRotor2x4, DRotor3x4, DBivec3x4, DMat4x4, DVec4x4, DVec2x4, DVec3x4, f64x4
 );
 
 macro_rules! impl_mat3 {
@@ -957,25 +1218,27 @@ macro_rules! impl_mat3 {
             /// properties. The fact that `self` is a rotation matrix is not checked by the
             /// library.
             pub fn into_rotor3(self) -> $rt {
-                // Adapted from http://www.euclideanspace.com/maths/geometry/rotations/conversions/matrixToQuaternion/
-                let w = ($t::splat(1.0) + self[0][0] + self[1][1] + self[2][2]).max($t::splat(0.0)).sqrt() * $t::splat(0.5);
-
-                let yz = {
-                    let s = ($t::splat(1.0) + self[0][0] - self[1][1] - self[2][2]).max($t::splat(0.0)).sqrt() * $t::splat(0.5);
-                    s.copysign(self[2][1] - self[1][2])
+                // Shepperd's method: solve for the largest of the four quaternion components
+                // from the diagonal and derive the others from the off-diagonal elements
+                // divided by it, so that no division is by a value close to zero.
+                let m = |col: usize, row: usize| self[col][row];
+                let trace = m(0, 0) + m(1, 1) + m(2, 2);
+
+                let (w, x, y, z) = if trace >= m(0, 0) && trace >= m(1, 1) && trace >= m(2, 2) {
+                    let s = (1.0 + trace).sqrt() * 2.0;
+                    (0.25 * s, (m(1, 2) - m(2, 1)) / s, (m(2, 0) - m(0, 2)) / s, (m(0, 1) - m(1, 0)) / s)
+                } else if m(0, 0) >= m(1, 1) && m(0, 0) >= m(2, 2) {
+                    let s = (1.0 + m(0, 0) - m(1, 1) - m(2, 2)).sqrt() * 2.0;
+                    ((m(1, 2) - m(2, 1)) / s, 0.25 * s, (m(1, 0) + m(0, 1)) / s, (m(2, 0) + m(0, 2)) / s)
+                } else if m(1, 1) >= m(2, 2) {
+                    let s = (1.0 + m(1, 1) - m(0, 0) - m(2, 2)).sqrt() * 2.0;
+                    ((m(2, 0) - m(0, 2)) / s, (m(1, 0) + m(0, 1)) / s, 0.25 * s, (m(2, 1) + m(1, 2)) / s)
+                } else {
+                    let s = (1.0 + m(2, 2) - m(0, 0) - m(1, 1)).sqrt() * 2.0;
+                    ((m(0, 1) - m(1, 0)) / s, (m(2, 0) + m(0, 2)) / s, (m(2, 1) + m(1, 2)) / s, 0.25 * s)
                 };
 
-                let xz = {
-                    let s = ($t::splat(1.0) - self[0][0] + self[1][1] - self[2][2]).max($t::splat(0.0)).sqrt() * $t::splat(0.5);
-                    s.copysign(self[2][0] - self[0][2])
-                };
-
-                let xy = {
-                    let s = ($t::splat(1.0) - self[0][0] - self[1][1] + self[2][2]).max($t::splat(0.0)).sqrt() * $t::splat(0.5);
-                    s.copysign(self[1][0] - self[0][1])
-                };
-
-                $rt::new(w, $bt::new(xy, xz, yz))
+                $rt::new(w, $bt::new(-z, y, -x))
             }
         })+
     }
@@ -986,6 +1249,47 @@ impl_mat3!(Mat3, f32, Rotor3, Bivec3);
 #[cfg(feature = "f64")]
 impl_mat3!(DMat3, f64, DRotor3, DBivec3);
 
+/// A [`Mat3`] in the layout GLSL and WGSL use for a `mat3` in uniform and storage buffers.
+///
+/// Under both the std140 and std430 rules, and in WGSL, each column of a `mat3` is aligned to
+/// 16 bytes, so the matrix takes up three `vec4`s rather than nine tightly packed floats. Use
+/// this type for the `mat3` fields of structs uploaded to the GPU.
+///
+/// The padding components, i.e. the `w` of each column, are zeroed by `From<Mat3>` and
+/// [`Mat3::to_std140`], and ignored when converting back. Code writing to `cols` directly is
+/// responsible for keeping them zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C, align(16))]
+pub struct Mat3Std140 {
+    pub cols: [Vec4; 3],
+}
+
+impl Mat3 {
+    /// Convert `self` to the padded layout of a `mat3` in GPU buffers.
+    #[inline]
+    pub fn to_std140(&self) -> Mat3Std140 {
+        Mat3Std140::from(*self)
+    }
+}
+
+impl From<Mat3> for Mat3Std140 {
+    #[inline]
+    fn from(mat: Mat3) -> Self {
+        Self {
+            cols: mat.cols.map(|col| col.extend(0.0)),
+        }
+    }
+}
+
+impl From<Mat3Std140> for Mat3 {
+    #[inline]
+    fn from(mat: Mat3Std140) -> Self {
+        Self {
+            cols: mat.cols.map(Vec4::truncate),
+        }
+    }
+}
+
 macro_rules! impl_mat3_wide {
     ($($mt:ident => $t:ident, $rt:ident, $bt:ident),+) => {
         $(impl $mt {
@@ -995,25 +1299,42 @@ macro_rules! impl_mat3_wide {
             /// properties. The fact that `self` is a rotation matrix is not checked by the
             /// library.
             pub fn into_rotor3(self) -> $rt {
-                // Adapted from http://www.euclideanspace.com/maths/geometry/rotations/conversions/matrixToQuaternion/
-                let w = ($t::splat(1.0) + self[0][0] + self[1][1] + self[2][2]).max($t::splat(0.0)).sqrt() * $t::splat(0.5);
+                // Shepperd's method, as for the scalar matrices, computing all four cases and
+                // picking the one for the largest quaternion component in each lane.
+                let m = |col: usize, row: usize| self[col][row];
+                let one = $t::splat(1.0);
+                let quarter = $t::splat(0.25);
+                let two = $t::splat(2.0);
+                let trace = m(0, 0) + m(1, 1) + m(2, 2);
 
-                let yz = {
-                    let s = ($t::splat(1.0) + self[0][0] - self[1][1] - self[2][2]).max($t::splat(0.0)).sqrt() * $t::splat(0.5);
-                    s.flip_signs(self[2][1] - self[1][2])
+                let from_w = {
+                    let s = (one + trace).sqrt() * two;
+                    [quarter * s, (m(1, 2) - m(2, 1)) / s, (m(2, 0) - m(0, 2)) / s, (m(0, 1) - m(1, 0)) / s]
                 };
-
-                let xz = {
-                    let s = ($t::splat(1.0) - self[0][0] + self[1][1] - self[2][2]).max($t::splat(0.0)).sqrt() * $t::splat(0.5);
-                    s.flip_signs(self[2][0] - self[0][2])
+                let from_x = {
+                    let s = (one + m(0, 0) - m(1, 1) - m(2, 2)).sqrt() * two;
+                    [(m(1, 2) - m(2, 1)) / s, quarter * s, (m(1, 0) + m(0, 1)) / s, (m(2, 0) + m(0, 2)) / s]
                 };
-
-                let xy = {
-                    let s = ($t::splat(1.0) - self[0][0] - self[1][1] + self[2][2]).max($t::splat(0.0)).sqrt() * $t::splat(0.5);
-                    s.flip_signs(self[1][0] - self[0][1])
+                let from_y = {
+                    let s = (one + m(1, 1) - m(0, 0) - m(2, 2)).sqrt() * two;
+                    [(m(2, 0) - m(0, 2)) / s, (m(1, 0) + m(0, 1)) / s, quarter * s, (m(2, 1) + m(1, 2)) / s]
+                };
+                let from_z = {
+                    let s = (one + m(2, 2) - m(0, 0) - m(1, 1)).sqrt() * two;
+                    [(m(0, 1) - m(1, 0)) / s, (m(2, 0) + m(0, 2)) / s, (m(2, 1) + m(1, 2)) / s, quarter * s]
                 };
 
-                $rt::new(w, $bt::new(xy, xz, yz))
+                // Later blends take precedence, matching the order of the scalar branches.
+                let use_y = m(1, 1).cmp_ge(m(2, 2));
+                let use_x = m(0, 0).cmp_ge(m(1, 1)) & m(0, 0).cmp_ge(m(2, 2));
+                let use_w = trace.cmp_ge(m(0, 0)) & trace.cmp_ge(m(1, 1)) & trace.cmp_ge(m(2, 2));
+                let q: [$t; 4] = core::array::from_fn(|i| {
+                    let q = use_y.blend(from_y[i], from_z[i]);
+                    let q = use_x.blend(from_x[i], q);
+                    use_w.blend(from_w[i], q)
+                });
+
+                $rt::new(q[0], $bt::new(-q[3], q[2], -q[1]))
             }
         })+
     }
@@ -1022,6 +1343,9 @@ macro_rules! impl_mat3_wide {
 impl_mat3_wide!(Mat3x4 => f32x4, Rotor3x4, Bivec3x4,
                 Mat3x8 => f32x8, Rotor3x8, Bivec3x8);
 
+#[cfg(feature = "portable-simd")]
+impl_mat3_wide!(Mat3x16 => f32x16, Rotor3x16, Bivec3x16);
+
 #[cfg(feature = "f64")]
 impl_mat3_wide!(DMat3x2 => f64x2, DRotor3x2, DBivec3x2,
                 DMat3x4 => f64x4, DRotor3x4, DBivec3x4);
@@ -1053,12 +1377,12 @@ macro_rules! mat4s {
             }
 
             #[inline]
-            pub fn identity() -> Self {
+            pub const fn identity() -> Self {
                 Self::new(
-                    $vt::new($t::splat(1.0), $t::splat(0.0), $t::splat(0.0), $t::splat(0.0)),
-                    $vt::new($t::splat(0.0), $t::splat(1.0), $t::splat(0.0), $t::splat(0.0)),
-                    $vt::new($t::splat(0.0), $t::splat(0.0), $t::splat(1.0), $t::splat(0.0)),
-                    $vt::new($t::splat(0.0), $t::splat(0.0), $t::splat(0.0), $t::splat(1.0)))
+                    $vt::new(<$t as Consts>::ONE, <$t as Consts>::ZERO, <$t as Consts>::ZERO, <$t as Consts>::ZERO),
+                    $vt::new(<$t as Consts>::ZERO, <$t as Consts>::ONE, <$t as Consts>::ZERO, <$t as Consts>::ZERO),
+                    $vt::new(<$t as Consts>::ZERO, <$t as Consts>::ZERO, <$t as Consts>::ONE, <$t as Consts>::ZERO),
+                    $vt::new(<$t as Consts>::ZERO, <$t as Consts>::ZERO, <$t as Consts>::ZERO, <$t as Consts>::ONE))
             }
 
             /// Assumes homogeneous 3d coordinates.
@@ -1071,6 +1395,22 @@ macro_rules! mat4s {
                     $vt::new(trans.x, trans.y, trans.z, $t::splat(1.0)))
             }
 
+            /// Construct a homogeneous 3d transformation matrix which scales by `scale`, then
+            /// rotates by `rotation`, then translates by `translation`.
+            ///
+            /// This is equivalent to, but faster than,
+            /// `from_translation(translation) * rotation.into_matrix().into_homogeneous() * from_nonuniform_scale(scale)`.
+            #[inline]
+            pub fn from_translation_rotation_scale(translation: $v3t, rotation: $rt, scale: $v3t) -> Self {
+                let rot = rotation.into_matrix();
+                Self::new(
+                    (rot.cols[0] * scale.x).into_homogeneous_vector(),
+                    (rot.cols[1] * scale.y).into_homogeneous_vector(),
+                    (rot.cols[2] * scale.z).into_homogeneous_vector(),
+                    translation.into_homogeneous_point(),
+                )
+            }
+
             /// Assumes homogeneous 3d coordinates.
             #[inline]
             pub fn from_scale(scale: $t) -> Self {
@@ -1137,9 +1477,9 @@ macro_rules! mat4s {
             /// projection matrix in ```projection``` module to fit your use case to remedy this.
             #[inline]
             pub fn from_euler_angles(roll: $t, pitch: $t, yaw: $t) -> Self {
-                let (sin_yaw, cos_yaw) = yaw.sin_cos();
-                let (sin_pitch, cos_pitch) = pitch.sin_cos();
-                let (sin_roll, cos_roll) = roll.sin_cos();
+                let (sin_yaw, cos_yaw) = Transcendental::sin_cos(yaw);
+                let (sin_pitch, cos_pitch) = Transcendental::sin_cos(pitch);
+                let (sin_roll, cos_roll) = Transcendental::sin_cos(roll);
 
                 let zero = $t::splat(0.0);
 
@@ -1169,7 +1509,7 @@ macro_rules! mat4s {
             /// Assumes homogeneous 3d coordinates.
             #[inline]
             pub fn from_rotation_x(angle: $t) -> Self {
-                let (sin, cos) = angle.sin_cos();
+                let (sin, cos) = Transcendental::sin_cos(angle);
                 let zero = $t::splat(0.0);
                 let one = $t::splat(1.0);
 
@@ -1189,7 +1529,7 @@ macro_rules! mat4s {
             /// Assumes homogeneous 3d coordinates.
             #[inline]
             pub fn from_rotation_y(angle: $t) -> Self {
-                let (sin, cos) = angle.sin_cos();
+                let (sin, cos) = Transcendental::sin_cos(angle);
                 let zero = $t::splat(0.0);
                 let one = $t::splat(1.0);
 
@@ -1209,7 +1549,7 @@ macro_rules! mat4s {
             /// Assumes homogeneous 3d coordinates.
             #[inline]
             pub fn from_rotation_z(angle: $t) -> Self {
-                let (sin, cos) = angle.sin_cos();
+                let (sin, cos) = Transcendental::sin_cos(angle);
                 let zero = $t::splat(0.0);
                 let one = $t::splat(1.0);
 
@@ -1229,7 +1569,7 @@ macro_rules! mat4s {
             /// Assumes homogeneous 3d coordinates.
             #[inline]
             pub fn from_rotation_around(axis: $vt, angle: $t) -> Self {
-                let (sin, cos) = angle.sin_cos();
+                let (sin, cos) = Transcendental::sin_cos(angle);
                 let zero = $t::splat(0.0);
                 let one = $t::splat(1.0);
                 let mul = one - cos;
@@ -1243,15 +1583,15 @@ macro_rules! mat4s {
                 let xz_mul = axis.x * axis.z * mul;
                 let yz_mul = axis.y * axis.z * mul;
 
-                let m00 = (axis.x * axis.x).mul_add(mul, cos);
+                let m00 = (axis.x * axis.x).fused_mul_add(mul, cos);
                 let m10 = xy_mul + z_sin;
                 let m20 = xz_mul - y_sin;
                 let m01 = xy_mul - z_sin;
-                let m11 = (axis.y * axis.y).mul_add(mul, cos);
+                let m11 = (axis.y * axis.y).fused_mul_add(mul, cos);
                 let m21 = yz_mul + x_sin;
                 let m02 = xz_mul + y_sin;
                 let m12 = yz_mul - x_sin;
-                let m22 = (axis.z * axis.z).mul_add(mul, cos);
+                let m22 = (axis.z * axis.z).fused_mul_add(mul, cos);
 
                 // think transposed as arguments are columns
                 Self::new(
@@ -1343,6 +1683,50 @@ macro_rules! mat4s {
                 )
             }
 
+            /// Get the row at `index` of this matrix.
+            ///
+            /// Panics if `index` is out of bounds.
+            #[inline]
+            pub fn row(&self, index: usize) -> $vt {
+                $vt::new(self.cols[0][index], self.cols[1][index], self.cols[2][index], self.cols[3][index])
+            }
+
+            /// Set the row at `index` of this matrix to `row`.
+            ///
+            /// Panics if `index` is out of bounds.
+            #[inline]
+            pub fn set_row(&mut self, index: usize, row: $vt) {
+                self.cols[0][index] = row[0];
+                self.cols[1][index] = row[1];
+                self.cols[2][index] = row[2];
+                self.cols[3][index] = row[3];
+            }
+
+            /// Create a matrix from an array of its components in *row-major* order, as used by
+            /// e.g. Direct3D constant buffers by default. Note that converting from an array with
+            /// `From` instead takes the components in column-major order.
+            #[inline]
+            pub fn from_row_major(comps: [$t; 16]) -> Self {
+                Self::new(
+                    $vt::new(comps[0], comps[4], comps[8], comps[12]),
+                    $vt::new(comps[1], comps[5], comps[9], comps[13]),
+                    $vt::new(comps[2], comps[6], comps[10], comps[14]),
+                    $vt::new(comps[3], comps[7], comps[11], comps[15])
+                )
+            }
+
+            /// The components of this matrix as an array in *row-major* order, i.e. the inverse
+            /// of [`Self::from_row_major`]. Use [`Self::as_array`] for column-major order.
+            #[inline]
+            pub fn as_row_major_array(&self) -> [$t; 16] {
+                [
+                    self.cols[0][0], self.cols[1][0], self.cols[2][0], self.cols[3][0],
+                    self.cols[0][1], self.cols[1][1], self.cols[2][1], self.cols[3][1],
+                    self.cols[0][2], self.cols[1][2], self.cols[2][2], self.cols[3][2],
+                    self.cols[0][3], self.cols[1][3], self.cols[2][3], self.cols[3][3],
+                ]
+            }
+
             /// If this matrix is not currently invertable, this function will return
             /// an invalid inverse. This status is not checked by the library.
             #[inline]
@@ -1463,6 +1847,51 @@ macro_rules! mat4s {
                 adjugate * rcp_det
             }
 
+            /// The inverse of `self`, assuming it is an affine transformation, i.e. its last row
+            /// is `(0, 0, 0, 1)`. This only inverts the 3x3 linear part, so it is much cheaper
+            /// than [`Self::inversed`].
+            ///
+            /// Whether `self` is affine is checked in debug builds only.
+            #[inline]
+            pub fn inversed_affine(&self) -> Self {
+                debug_assert!(self.is_affine(), "matrix is not affine");
+                let linear = self.truncate().inversed();
+                Self::from_linear_translation(linear, -(linear * self.extract_translation()))
+            }
+
+            /// The inverse of `self`, assuming it is a rigid transformation, i.e. a rotation
+            /// followed by a translation. The rotation is inverted by transposing it, so this is
+            /// cheaper still than [`Self::inversed_affine`].
+            ///
+            /// Whether `self` is rigid is checked in debug builds only, with a tolerance for
+            /// accumulated rounding error.
+            #[inline]
+            pub fn inversed_rigid(&self) -> Self {
+                debug_assert!(self.is_affine(), "matrix is not affine");
+                debug_assert!(
+                    self.truncate().is_rotation($t::splat(1e-4)),
+                    "matrix is not a rigid transformation"
+                );
+                let rotation = self.truncate().transposed();
+                Self::from_linear_translation(rotation, -(rotation * self.extract_translation()))
+            }
+
+            #[inline]
+            fn is_affine(&self) -> bool {
+                let last_row = $vt::new($t::splat(0.0), $t::splat(0.0), $t::splat(0.0), $t::splat(1.0));
+                (self.row(3) - last_row).abs().cmp_le($vt::broadcast($t::splat(1e-6))).all()
+            }
+
+            #[inline]
+            fn from_linear_translation(linear: $m3t, translation: $v3t) -> Self {
+                Self::new(
+                    linear.cols[0].into_homogeneous_vector(),
+                    linear.cols[1].into_homogeneous_vector(),
+                    linear.cols[2].into_homogeneous_vector(),
+                    translation.into_homogeneous_point(),
+                )
+            }
+
             /// Transform a Vec3 by self, interpreting it as a vector.
             #[inline]
             pub fn transform_vec3(&self, vec: $v3t) -> $v3t {
@@ -1511,7 +1940,7 @@ macro_rules! mat4s {
             /// Get the [`core::alloc::Layout`] of `Self`
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$t>()).unwrap()
             }
 
             /// Interpret `self` as a statically sized array of the base numeric type.
@@ -1548,7 +1977,7 @@ macro_rules! mat4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $t, 16)
+                    core::slice::from_raw_parts(self as *const $n as *const $t, 16)
                 }
             }
 
@@ -1558,7 +1987,7 @@ macro_rules! mat4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 16)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 16)
                 }
             }
 
@@ -1568,7 +1997,7 @@ macro_rules! mat4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $vt, 4)
+                    core::slice::from_raw_parts(self as *const $n as *const $vt, 4)
                 }
             }
 
@@ -1578,7 +2007,7 @@ macro_rules! mat4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $vt, 4)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $vt, 4)
                 }
             }
 
@@ -1588,7 +2017,7 @@ macro_rules! mat4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const u8, 16 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts(self as *const $n as *const u8, 16 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -1598,7 +2027,7 @@ macro_rules! mat4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 16 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 16 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -1627,47 +2056,6 @@ macro_rules! mat4s {
             }
         }
 
-        impl Mul for $n {
-            type Output = Self;
-            #[inline]
-            fn mul(self, rhs: Self) -> Self {
-                let sa = self.cols[0];
-                let sb = self.cols[1];
-                let sc = self.cols[2];
-                let sd = self.cols[3];
-                let oa = rhs.cols[0];
-                let ob = rhs.cols[1];
-                let oc = rhs.cols[2];
-                let od = rhs.cols[3];
-                Self::new(
-                    $vt::new(
-                        (sa.x * oa.x) + (sb.x * oa.y) + (sc.x * oa.z) + (sd.x * oa.w),
-                        (sa.y * oa.x) + (sb.y * oa.y) + (sc.y * oa.z) + (sd.y * oa.w),
-                        (sa.z * oa.x) + (sb.z * oa.y) + (sc.z * oa.z) + (sd.z * oa.w),
-                        (sa.w * oa.x) + (sb.w * oa.y) + (sc.w * oa.z) + (sd.w * oa.w),
-                    ),
-                    $vt::new(
-                        (sa.x * ob.x) + (sb.x * ob.y) + (sc.x * ob.z) + (sd.x * ob.w),
-                        (sa.y * ob.x) + (sb.y * ob.y) + (sc.y * ob.z) + (sd.y * ob.w),
-                        (sa.z * ob.x) + (sb.z * ob.y) + (sc.z * ob.z) + (sd.z * ob.w),
-                        (sa.w * ob.x) + (sb.w * ob.y) + (sc.w * ob.z) + (sd.w * ob.w),
-                    ),
-                    $vt::new(
-                        (sa.x * oc.x) + (sb.x * oc.y) + (sc.x * oc.z) + (sd.x * oc.w),
-                        (sa.y * oc.x) + (sb.y * oc.y) + (sc.y * oc.z) + (sd.y * oc.w),
-                        (sa.z * oc.x) + (sb.z * oc.y) + (sc.z * oc.z) + (sd.z * oc.w),
-                        (sa.w * oc.x) + (sb.w * oc.y) + (sc.w * oc.z) + (sd.w * oc.w),
-                    ),
-                    $vt::new(
-                        (sa.x * od.x) + (sb.x * od.y) + (sc.x * od.z) + (sd.x * od.w),
-                        (sa.y * od.x) + (sb.y * od.y) + (sc.y * od.z) + (sd.y * od.w),
-                        (sa.z * od.x) + (sb.z * od.y) + (sc.z * od.z) + (sd.z * od.w),
-                        (sa.w * od.x) + (sb.w * od.y) + (sc.w * od.z) + (sd.w * od.w),
-                    ),
-                )
-            }
-        }
-
         impl Mul<$vt> for $n {
             type Output = $vt;
             #[inline]
@@ -1788,6 +2176,24 @@ macro_rules! mat4s {
             }
         }
 
+        /// Index into the matrix by `(row, column)`.
+        ///
+        /// Note that this is the opposite order to indexing with `mat[column][row]`, which
+        /// indexes the column first since matrices are stored in column-major order.
+        impl Index<(usize, usize)> for $n {
+            type Output = $t;
+
+            fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+                &self.cols[col][row]
+            }
+        }
+
+        impl IndexMut<(usize, usize)> for $n {
+            fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+                &mut self.cols[col][row]
+            }
+        }
+
         )+
     }
 }
@@ -1798,6 +2204,9 @@ mat4s!(
     Mat4x8 => Rotor3x8, Bivec3x8, Vec4x8, Vec3x8, Mat3x8, Isometry3x8, f32x8
 );
 
+#[cfg(feature = "portable-simd")]
+mat4s!(Mat4x16 => Rotor3x16, Bivec3x16, Vec4x16, Vec3x16, Mat3x16, Isometry3x16, f32x16);
+
 #[cfg(feature = "f64")]
 mat4s!(
     DMat4 => DRotor3, DBivec3, DVec4, DVec3, DMat3, DIsometry3, f64,
@@ -1805,69 +2214,522 @@ mat4s!(
     DMat4x4 => DRotor3x4, DBivec3x4, DVec4x4, DVec3x4, DMat3x4, DIsometry3x4, f64x4
 );
 
+macro_rules! impl_mat4_mul {
+    ($($n:ident => $vt:ident),+) => {
+        $(impl Mul for $n {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                let sa = self.cols[0];
+                let sb = self.cols[1];
+                let sc = self.cols[2];
+                let sd = self.cols[3];
+                let oa = rhs.cols[0];
+                let ob = rhs.cols[1];
+                let oc = rhs.cols[2];
+                let od = rhs.cols[3];
+                Self::new(
+                    $vt::new(
+                        (sa.x * oa.x) + (sb.x * oa.y) + (sc.x * oa.z) + (sd.x * oa.w),
+                        (sa.y * oa.x) + (sb.y * oa.y) + (sc.y * oa.z) + (sd.y * oa.w),
+                        (sa.z * oa.x) + (sb.z * oa.y) + (sc.z * oa.z) + (sd.z * oa.w),
+                        (sa.w * oa.x) + (sb.w * oa.y) + (sc.w * oa.z) + (sd.w * oa.w),
+                    ),
+                    $vt::new(
+                        (sa.x * ob.x) + (sb.x * ob.y) + (sc.x * ob.z) + (sd.x * ob.w),
+                        (sa.y * ob.x) + (sb.y * ob.y) + (sc.y * ob.z) + (sd.y * ob.w),
+                        (sa.z * ob.x) + (sb.z * ob.y) + (sc.z * ob.z) + (sd.z * ob.w),
+                        (sa.w * ob.x) + (sb.w * ob.y) + (sc.w * ob.z) + (sd.w * ob.w),
+                    ),
+                    $vt::new(
+                        (sa.x * oc.x) + (sb.x * oc.y) + (sc.x * oc.z) + (sd.x * oc.w),
+                        (sa.y * oc.x) + (sb.y * oc.y) + (sc.y * oc.z) + (sd.y * oc.w),
+                        (sa.z * oc.x) + (sb.z * oc.y) + (sc.z * oc.z) + (sd.z * oc.w),
+                        (sa.w * oc.x) + (sb.w * oc.y) + (sc.w * oc.z) + (sd.w * oc.w),
+                    ),
+                    $vt::new(
+                        (sa.x * od.x) + (sb.x * od.y) + (sc.x * od.z) + (sd.x * od.w),
+                        (sa.y * od.x) + (sb.y * od.y) + (sc.y * od.z) + (sd.y * od.w),
+                        (sa.z * od.x) + (sb.z * od.y) + (sc.z * od.z) + (sd.z * od.w),
+                        (sa.w * od.x) + (sb.w * od.y) + (sc.w * od.z) + (sd.w * od.w),
+                    ),
+                )
+            }
+        })+
+    };
+}
+
+impl_mat4_mul!(Mat4x4 => Vec4x4, Mat4x8 => Vec4x8);
+
+#[cfg(feature = "portable-simd")]
+impl_mat4_mul!(Mat4x16 => Vec4x16);
+
+#[cfg(feature = "f64")]
+impl_mat4_mul!(DMat4 => DVec4, DMat4x2 => DVec4x2, DMat4x4 => DVec4x4);
+
+impl Mul for Mat4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        // Each column of the result is a combination of the columns of `self`, so do the
+        // arithmetic for a whole column at once. The sums are evaluated in the same order as
+        // for the other matrix types, so the result is identical to doing it component-wise.
+        let cols = self.cols.map(|c| f32x4::from([c.x, c.y, c.z, c.w]));
+        let col = |o: Vec4| {
+            let r = cols[0] * f32x4::splat(o.x)
+                + cols[1] * f32x4::splat(o.y)
+                + cols[2] * f32x4::splat(o.z)
+                + cols[3] * f32x4::splat(o.w);
+            Vec4::from(r.to_array())
+        };
+        Self::new(
+            col(rhs.cols[0]),
+            col(rhs.cols[1]),
+            col(rhs.cols[2]),
+            col(rhs.cols[3]),
+        )
+    }
+}
+
+/// The componentwise and assigning operators of the matrices, which are defined in terms of
+/// the operators of their columns and of the products above.
+macro_rules! impl_mat_ops {
+    ($($n:ident => $t:ident),+) => {
+        $(impl Sub for $n {
+            type Output = Self;
+            #[inline]
+            fn sub(mut self, rhs: $n) -> Self {
+                self -= rhs;
+                self
+            }
+        }
+
+        impl SubAssign for $n {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $n) {
+                for (col, rhs) in self.cols.iter_mut().zip(rhs.cols.iter()) {
+                    *col -= *rhs;
+                }
+            }
+        }
+
+        impl Neg for $n {
+            type Output = Self;
+            #[inline]
+            fn neg(mut self) -> Self {
+                for col in self.cols.iter_mut() {
+                    *col = -*col;
+                }
+                self
+            }
+        }
+
+        impl MulAssign for $n {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $n) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl MulAssign<$t> for $n {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $t) {
+                for col in self.cols.iter_mut() {
+                    *col *= rhs;
+                }
+            }
+        }
+
+        impl Div<$t> for $n {
+            type Output = Self;
+            #[inline]
+            fn div(mut self, rhs: $t) -> Self {
+                self /= rhs;
+                self
+            }
+        }
+
+        impl DivAssign<$t> for $n {
+            #[inline]
+            fn div_assign(&mut self, rhs: $t) {
+                for col in self.cols.iter_mut() {
+                    *col /= rhs;
+                }
+            }
+        })+
+    };
+}
+
+impl_mat_ops!(
+    Mat2 => f32, Mat2x4 => f32x4, Mat2x8 => f32x8,
+    Mat3 => f32, Mat3x4 => f32x4, Mat3x8 => f32x8,
+    Mat4 => f32, Mat4x4 => f32x4, Mat4x8 => f32x8
+);
+
+#[cfg(feature = "portable-simd")]
+impl_mat_ops!(Mat2x16 => f32x16, Mat3x16 => f32x16, Mat4x16 => f32x16);
+
+#[cfg(feature = "f64")]
+impl_mat_ops!(
+    DMat2 => f64, DMat2x2 => f64x2, DMat2x4 => f64x4,
+    DMat3 => f64, DMat3x2 => f64x2, DMat3x4 => f64x4,
+    DMat4 => f64, DMat4x2 => f64x2, DMat4x4 => f64x4
+);
+
+/// The error returned when decomposing a matrix into a transform type fails because
+/// the matrix is not of the required form.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DecompositionError {
+    /// The bottom row of the matrix is not `(0, 0, 0, 1)`, e.g. because it contains a projection.
+    NotAffine,
+    /// One of the basis vectors has zero, infinite or NaN length, so no rotation can be recovered.
+    Degenerate,
+    /// The basis vectors are not orthogonal, i.e. the matrix contains shear.
+    NonOrthogonal,
+    /// The basis vectors do not all have the same length, but a uniform scale was requested.
+    NonUniformScale,
+}
+
+impl fmt::Display for DecompositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecompositionError::NotAffine => f.write_str("NotAffine"),
+            DecompositionError::Degenerate => f.write_str("Degenerate"),
+            DecompositionError::NonOrthogonal => f.write_str("NonOrthogonal"),
+            DecompositionError::NonUniformScale => f.write_str("NonUniformScale"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecompositionError {}
+
+macro_rules! impl_mat4_decompose {
+    ($($mt:ident => $t:ident, $vt:ident, $m3t:ident, $rt:ident, $st:ident, $tt:ident, $tolerance:literal),+) => {
+        $(impl $mt {
+            /// Construct the local transform of a glTF node from its `translation`, `rotation`
+            /// and `scale` properties, as they are stored in the file.
+            ///
+            /// glTF stores rotations as unit quaternions in `[x, y, z, w]` order and uses the
+            /// same right handed, y up coordinate system as ultraviolet, so `rotation` is
+            /// converted with `from_quaternion_array` as is, without flipping any axes.
+            /// A node which stores a `matrix` instead can be converted with `From<[f32; 16]>`,
+            /// since both are column major.
+            #[inline]
+            pub fn from_gltf_trs(translation: [$t; 3], rotation: [$t; 4], scale: [$t; 3]) -> Self {
+                Self::from_translation_rotation_scale(
+                    $vt::from(translation),
+                    $rt::from_quaternion_array(rotation),
+                    $vt::from(scale),
+                )
+            }
+
+            /// The scale along each axis, i.e. the lengths of the first three columns.
+            ///
+            /// If the 3x3 left upper block has a negative determinant, i.e. contains a
+            /// reflection, the x scale is negative, as in [`Self::try_into_transform3`]. If
+            /// `self` is not an affine transformation without shear, the returned value has
+            /// undefined properties.
+            #[inline]
+            pub fn extract_scale(&self) -> $vt {
+                let basis = self.truncate();
+                let scale = $vt::new(basis.cols[0].mag(), basis.cols[1].mag(), basis.cols[2].mag());
+                if basis.determinant() < 0.0 {
+                    $vt::new(-scale.x, scale.y, scale.z)
+                } else {
+                    scale
+                }
+            }
+
+            /// Split an affine `self` into its translation, unit length basis vectors and
+            /// per-axis scale, checking each step against `tolerance`.
+            ///
+            /// A reflection is folded into the sign of the x scale, so that the returned
+            /// basis is always a proper rotation.
+            fn decompose(self, tolerance: $t) -> Result<($vt, $m3t, $vt), DecompositionError> {
+                if self.cols[0].w.abs() > tolerance
+                    || self.cols[1].w.abs() > tolerance
+                    || self.cols[2].w.abs() > tolerance
+                    || (self.cols[3].w - 1.0).abs() > tolerance
+                {
+                    return Err(DecompositionError::NotAffine);
+                }
+
+                let mut basis = self.truncate();
+                let mut scale = $vt::new(basis.cols[0].mag(), basis.cols[1].mag(), basis.cols[2].mag());
+                for (col, s) in basis.cols.iter_mut().zip(scale.as_array()) {
+                    if *s <= 0.0 || !s.is_finite() {
+                        return Err(DecompositionError::Degenerate);
+                    }
+                    *col /= *s;
+                }
+
+                let [x, y, z] = basis.cols;
+                if x.dot(y).abs() > tolerance || x.dot(z).abs() > tolerance || y.dot(z).abs() > tolerance {
+                    return Err(DecompositionError::NonOrthogonal);
+                }
+
+                if x.cross(y).dot(z) < 0.0 {
+                    basis.cols[0] = -x;
+                    scale.x = -scale.x;
+                }
+
+                Ok((self.extract_translation(), basis, scale))
+            }
+
+            /// Decompose `self` into a similarity, i.e. a translation, rotation and uniform scale.
+            ///
+            /// Fails if `self` is not affine, if its basis vectors are not orthogonal or not all of
+            /// the same length, all within `tolerance`, or if it is degenerate. Lengths are compared
+            /// relative to the largest scale. A reflection is expressed as a negative scale.
+            pub fn try_into_similarity(self, tolerance: $t) -> Result<$st, DecompositionError> {
+                let (translation, mut basis, scale) = self.decompose(tolerance)?;
+                let (sx, sy, sz) = (scale.x.abs(), scale.y, scale.z);
+                let max = sx.max(sy).max(sz);
+                if (sx - sy).abs() > tolerance * max || (sx - sz).abs() > tolerance * max {
+                    return Err(DecompositionError::NonUniformScale);
+                }
+
+                // The x axis has already been flipped to make the basis a rotation, so
+                // flipping the other two expresses the reflection as a negative scale instead.
+                let mut uniform = (sx + sy + sz) / 3.0;
+                if scale.x < 0.0 {
+                    basis.cols[1] = -basis.cols[1];
+                    basis.cols[2] = -basis.cols[2];
+                    uniform = -uniform;
+                }
+                Ok($st::new(translation, basis.into_rotor3(), uniform))
+            }
+
+            /// Decompose `self` into a translation, rotation and non-uniform scale.
+            ///
+            /// Fails if `self` is not affine, if its basis vectors are not orthogonal within
+            /// `tolerance`, or if it is degenerate. A reflection is expressed as a negative
+            /// x scale.
+            pub fn try_into_transform3(self, tolerance: $t) -> Result<$tt, DecompositionError> {
+                let (translation, basis, scale) = self.decompose(tolerance)?;
+                Ok($tt::new(translation, basis.into_rotor3(), scale))
+            }
+        }
+
+        impl TryFrom<$mt> for $st {
+            type Error = DecompositionError;
+
+            #[doc = concat!("Decompose `mat` with [`", stringify!($mt), "::try_into_similarity`], with a tolerance of `", stringify!($tolerance), "`.")]
+            #[inline]
+            fn try_from(mat: $mt) -> Result<Self, Self::Error> {
+                mat.try_into_similarity($tolerance)
+            }
+        })+
+    }
+}
+
+impl_mat4_decompose!(Mat4 => f32, Vec3, Mat3, Rotor3, Similarity3, Transform3, 1e-5);
+
+#[cfg(feature = "f64")]
+impl_mat4_decompose!(DMat4 => f64, DVec3, DMat3, DRotor3, DSimilarity3, DTransform3, 1e-10);
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::util::*;
 
-    /* TODO:
-    Re-enable these. The current way that Matrix3::into_rotor() works sometimes fails these
-    edge cases based on rounding error accumulated from the round trip due to the way it uses
-
-    use std::f32::consts::FRAC_PI_2;
-    use std::f32::consts::PI;
-
-    copysign()
-        #[test]
-        pub fn mat3_to_rotor_corner_cases(){
-            for i in 0..64 {
-                let alpha = {
-                    match i % 4 {
-                        0 => -FRAC_PI_2,
-                        1 => 0.,
-                        2 => FRAC_PI_2,
-                        3 => PI,
-                        _ => unreachable!()
-                    }
-                };
-                let beta = {
-                    match (i / 4) % 4 {
-                        0 => -FRAC_PI_2,
-                        1 => 0.,
-                        2 => FRAC_PI_2,
-                        3 => PI,
-                        _ => unreachable!()
-                    }
-                };
-                let gamma = {
-                    match (i / 16) % 4 {
-                        0 => -FRAC_PI_2,
-                        1 => 0.,
-                        2 => FRAC_PI_2,
-                        3 => PI,
-                        _ => unreachable!()
-                    }
-                };
-                println!("roll {}, pitch {}, yaw {}", alpha, beta, gamma);
-                let rotor = Rotor3::from_euler_angles(alpha, beta, gamma);
+    #[test]
+    pub fn mat3_to_rotor_corner_cases() {
+        use core::f32::consts::FRAC_PI_2;
+        use core::f32::consts::PI;
+
+        let angles = [-FRAC_PI_2, 0.0, FRAC_PI_2, PI];
+        let mut rotors = Vec::new();
+        for i in 0..64 {
+            let roll = angles[i % 4];
+            let pitch = angles[(i / 4) % 4];
+            let yaw = angles[(i / 16) % 4];
+            let rotor = Rotor3::from_euler_angles(roll, pitch, yaw);
+            let rotor2 = rotor.into_matrix().into_rotor3();
+            // `rotor` and `-rotor` are the same rotation.
+            assert!((1.0 - rotor.dot(rotor2).abs()) < 1e-6);
+            assert!(rotor2.mag().eq_eps(1.0));
+            for v in [Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z()] {
+                assert!(v.rotated_by(rotor).eq_eps(v.rotated_by(rotor2)));
+            }
+            rotors.push(rotor);
+        }
+
+        // Near half turns, where the trace is close to -1.
+        for axis in [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-0.3, 0.1, 0.0),
+            Vec3::unit_y(),
+        ] {
+            let bv = Bivec3::from_normalized_axis(axis.normalized());
+            let rotor = Rotor3::from_angle_plane(PI - 1e-4, bv);
+            let rotor2 = rotor.into_matrix().into_rotor3();
+            assert!((1.0 - rotor.dot(rotor2).abs()) < 1e-6);
+            rotors.push(rotor);
+        }
+
+        for chunk in rotors.chunks(4) {
+            let wide = Rotor3x4::from([0, 1, 2, 3].map(|i| chunk[i % chunk.len()]));
+            let wide2 = wide.into_matrix().into_rotor3();
+            let dots: [f32; 4] = wide.dot(wide2).abs().into();
+            for dot in dots {
+                assert!((1.0 - dot.abs()) < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    pub fn mat3_to_rotor_negative_trace() {
+        use core::f32::consts::PI;
+
+        // Rotations by angles with a non-positive trace, about axes dominated by x, y and z in
+        // turn, so that each of the other three branches of Shepperd's method is taken.
+        let mut rotors = Vec::new();
+        for axis in [
+            Vec3::new(1.0, 0.2, 0.1),
+            Vec3::new(0.1, 1.0, -0.2),
+            Vec3::new(0.2, -0.1, 1.0),
+        ] {
+            let bv = Bivec3::from_normalized_axis(axis.normalized());
+            for angle in [2.2, 2.8, PI] {
+                let rotor = Rotor3::from_angle_plane(angle, bv);
                 let mat = rotor.into_matrix();
+                assert!(mat[0][0] + mat[1][1] + mat[2][2] <= 0.0);
+
                 let rotor2 = mat.into_rotor3();
-                assert!(rotor.eq_eps(rotor2));
-                let xr = Vec3::unit_x().rotated_by(rotor);
-                let xr2 = Vec3::unit_x().rotated_by(rotor2);
-                assert!(xr.eq_eps(xr2));
+                assert!((1.0 - rotor.dot(rotor2).abs()) < 1e-6);
+                let mat2 = rotor2.into_matrix();
+                for (a, b) in mat.as_array().iter().zip(mat2.as_array()) {
+                    assert!((a - b).abs() < 1e-6);
+                }
+                rotors.push(rotor);
+            }
+        }
 
-                let yr = Vec3::unit_y().rotated_by(rotor);
-                let yr2 = Vec3::unit_y().rotated_by(rotor2);
-                assert!(yr.eq_eps(yr2));
+        for chunk in rotors.chunks(3) {
+            let wide = Rotor3x4::from([0, 1, 2, 0].map(|i| chunk[i]));
+            let dots: [f32; 4] = wide.dot(wide.into_matrix().into_rotor3()).into();
+            for dot in dots {
+                assert!((1.0 - dot.abs()) < 1e-6);
+            }
+        }
+    }
 
-                let zr = Vec3::unit_z().rotated_by(rotor);
-                let zr2 = Vec3::unit_z().rotated_by(rotor2);
-                assert!(zr.eq_eps(zr2));
+    #[test]
+    pub fn affine_and_rigid_inverse() {
+        let rotation = Rotor3::from_euler_angles(0.4, -1.1, 2.3);
+        let rigid = Mat4::from_translation(Vec3::new(3.0, -2.0, 7.0))
+            * rotation.into_matrix().into_homogeneous();
+        let affine = rigid * Mat4::from_nonuniform_scale(Vec3::new(2.0, 0.5, -3.0));
+        for (mat, inverse) in [
+            (rigid, rigid.inversed_rigid()),
+            (affine, affine.inversed_affine()),
+        ] {
+            for (a, b) in inverse.cols.iter().zip(mat.inversed().cols.iter()) {
+                assert!(a.eq_eps(*b));
             }
+        }
+        assert!(rigid.inversed_affine().cols[3].eq_eps(rigid.inversed_rigid().cols[3]));
+
+        let wide = Mat4x4::new(
+            Vec4x4::splat(affine.cols[0]),
+            Vec4x4::splat(affine.cols[1]),
+            Vec4x4::splat(affine.cols[2]),
+            Vec4x4::splat(affine.cols[3]),
+        );
+        let lanes: [Vec4; 4] = wide.inversed_affine().cols[3].into();
+        assert!(lanes[1].eq_eps(affine.inversed().cols[3]));
+    }
+
+    #[test]
+    #[should_panic(expected = "not a rigid transformation")]
+    #[cfg(debug_assertions)]
+    pub fn rigid_inverse_of_scaled_matrix() {
+        Mat4::from_scale(2.0).inversed_rigid();
+    }
+
+    #[test]
+    #[should_panic(expected = "not a rigid transformation")]
+    #[cfg(debug_assertions)]
+    pub fn rigid_inverse_of_reflection() {
+        Mat4::from_nonuniform_scale(Vec3::new(1.0, -1.0, 1.0)).inversed_rigid();
+    }
 
+    #[test]
+    pub fn mat3_rotor_products() {
+        let rotor = Rotor3::from_euler_angles(0.4, -1.1, 2.3);
+        let rotation = Mat3::from_euler_angles(-0.2, 0.7, 0.1);
+        let scale = Mat3::from_nonuniform_scale(Vec3::new(2.0, 0.5, -3.0));
+        let v = Vec3::new(0.5, -1.0, 2.0);
+
+        assert!(((rotor * scale) * v).eq_eps(rotor * (scale * v)));
+        assert!(((rotation * rotor) * v).eq_eps(rotation * (rotor * v)));
+
+        let wide = Mat3x4::new(
+            Vec3x4::splat(rotation.cols[0]),
+            Vec3x4::splat(rotation.cols[1]),
+            Vec3x4::splat(rotation.cols[2]),
+        ) * Rotor3x4::splat(rotor);
+        let lanes: [Vec3; 4] = (wide * Vec3x4::splat(v)).into();
+        assert!(lanes[2].eq_eps(rotation * (rotor * v)));
+
+        // Rounding error well within the tolerance of the debug check is accepted.
+        let drifted = rotation * Mat3::from_scale(1.0 + 2e-5);
+        assert!(((drifted * rotor) * v).eq_eps(rotation * (rotor * v)));
+    }
+
+    #[test]
+    #[should_panic(expected = "not a rotation")]
+    #[cfg(debug_assertions)]
+    pub fn mat3_rotor_product_of_reflection() {
+        let _ = Mat3::from_nonuniform_scale(Vec3::new(1.0, 1.0, -1.0)) * Rotor3::identity();
+    }
+
+    #[test]
+    pub fn mat_assign_ops() {
+        let a = Mat3::from_euler_angles(0.4, -1.1, 2.3);
+        let b = Mat3::from_nonuniform_scale(Vec3::new(2.0, 0.5, -3.0));
+
+        let mut m = a;
+        m *= b;
+        assert_eq!(m, a * b);
+        m -= b;
+        assert_eq!(m, a * b - b);
+        m *= 2.0;
+        m /= 4.0;
+        assert_eq!(m, (a * b - b) * 0.5);
+        assert_eq!(-m + m, Mat3::new(Vec3::zero(), Vec3::zero(), Vec3::zero()));
+
+        let mut r = Rotor3::from_rotation_xy(0.5);
+        r *= Rotor3::from_rotation_yz(0.25);
+        assert_eq!(
+            r,
+            Rotor3::from_rotation_xy(0.5) * Rotor3::from_rotation_yz(0.25)
+        );
+    }
 
-        }*/
+    #[test]
+    pub fn mat4_mul_matches_wide() {
+        let a = Mat4::from_translation(Vec3::new(3.0, -2.0, 7.0))
+            * Mat4::from_euler_angles(0.4, -1.1, 2.3)
+            * Mat4::from_nonuniform_scale(Vec3::new(2.0, 0.5, -3.0));
+        let b = crate::projection::rh_yup::perspective_gl(1.2, 1.5, 0.1, 100.0);
+        let splat = |m: Mat4| {
+            Mat4x4::new(
+                Vec4x4::splat(m.cols[0]),
+                Vec4x4::splat(m.cols[1]),
+                Vec4x4::splat(m.cols[2]),
+                Vec4x4::splat(m.cols[3]),
+            )
+        };
+        let wide = splat(a) * splat(b);
+        for (col, wide_col) in (a * b).cols.iter().zip(wide.cols.iter()) {
+            let lanes: [Vec4; 4] = (*wide_col).into();
+            assert_eq!(*col, lanes[2]);
+        }
+        assert_eq!(a * Mat4::identity(), a);
+    }
 
     #[test]
     pub fn isometry_roundtrip() {
@@ -1882,6 +2744,110 @@ mod test {
         assert!(iso_.rotation.eq_eps(r_ab));
     }
 
+    #[test]
+    pub fn skew_symmetric() {
+        let v = Vec3::new(1.0, -2.0, 0.5);
+        let w = Vec3::new(0.3, 4.0, -1.0);
+        assert!((v.skew_symmetric_matrix() * w).eq_eps(v.cross(w)));
+        assert!((v.skew_symmetric_matrix() * v).eq_eps(Vec3::zero()));
+
+        // The velocity of a point matches how a small rotation moves it.
+        let omega = Bivec3::new(0.2, -0.7, 1.1);
+        let dt = 1e-3;
+        let moved = Rotor3::from_angle_plane(omega.mag() * dt, omega.normalized()) * w;
+        let velocity = Mat3::from_angular_velocity(omega) * w;
+        assert!(((moved - w) / dt - velocity).mag() < 1e-2);
+    }
+
+    #[test]
+    pub fn frame_from_normal() {
+        for n in [
+            Vec3::new(0.3, -0.5, 0.8),
+            Vec3::new(0.1, 0.2, -0.9),
+            Vec3::unit_z(),
+            -Vec3::unit_z(),
+        ]
+        .iter()
+        {
+            let n = n.normalized();
+            let m = Mat3::from_normal(n);
+            assert!(m[0].dot(m[1]).eq_eps(0.0));
+            assert!(m[0].mag().eq_eps(1.0));
+            assert!(m[1].mag().eq_eps(1.0));
+            assert!(m[0].cross(m[1]).eq_eps(n));
+            assert!(m.determinant().eq_eps(1.0));
+        }
+
+        let ns = Vec3x4::from([
+            Vec3::new(0.3, -0.5, 0.8).normalized(),
+            Vec3::new(0.1, 0.2, -0.9).normalized(),
+            Vec3::unit_z(),
+            -Vec3::unit_x(),
+        ]);
+        let (t, b) = ns.orthonormal_basis();
+        assert!(t.cross(b).eq_eps(ns));
+    }
+
+    #[test]
+    pub fn translation_rotation_scale() {
+        let t = Vec3::new(1.0, -2.0, 3.0);
+        let r = Rotor3::from_euler_angles(0.3, -0.7, 1.1);
+        let s = Vec3::new(2.0, 0.5, 3.0);
+        let trs = Mat4::from_translation(t)
+            * r.into_matrix().into_homogeneous()
+            * Mat4::from_nonuniform_scale(s);
+        let m = Mat4::from_translation_rotation_scale(t, r, s);
+        for (a, b) in m.cols.iter().zip(trs.cols.iter()) {
+            assert!(a.eq_eps(*b));
+        }
+
+        let t = Vec2::new(1.0, -2.0);
+        let r = Rotor2::from_angle(0.8);
+        let s = Vec2::new(2.0, 0.5);
+        let trs = Mat3::from_translation(t)
+            * r.into_matrix().into_homogeneous()
+            * Mat3::from_nonuniform_scale_homogeneous(s);
+        let m = Mat3::from_translation_rotation_scale(t, r, s);
+        for (a, b) in m.cols.iter().zip(trs.cols.iter()) {
+            assert!(a.eq_eps(*b));
+        }
+    }
+
+    #[test]
+    pub fn row_col_index() {
+        let mut m = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(m[(1, 3)], 2.0);
+        assert_eq!(m[(3, 1)], 0.0);
+        m[(0, 2)] = 5.0;
+        assert_eq!(m.cols[2].x, 5.0);
+
+        let m = Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+        assert_eq!(m[(0, 1)], m[1][0]);
+    }
+
+    #[test]
+    pub fn row_major() {
+        let m = Mat3::new(
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(4.0, 5.0, 6.0),
+            Vec3::new(7.0, 8.0, 9.0),
+        );
+        assert_eq!(m.row(0), Vec3::new(1.0, 4.0, 7.0));
+        assert_eq!(
+            m.as_row_major_array(),
+            [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0]
+        );
+        assert_eq!(Mat3::from_row_major(m.as_row_major_array()), m);
+        assert_eq!(Mat3::from_row_major(*m.as_array()), m.transposed());
+
+        let mut m = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(m.row(1), Vec4::new(0.0, 1.0, 0.0, 2.0));
+        m.set_row(3, Vec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(m.cols[0], Vec4::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(m.cols[3], Vec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(Mat4::from_row_major(m.as_row_major_array()), m);
+    }
+
     #[test]
     pub fn test_euler_angle_conversion() {
         let roll = 0.4;
@@ -1903,4 +2869,151 @@ mod test {
         assert_eq!(mat3[2], mat4[2]);
         assert_eq!(mat3[3], mat4[3]);
     }
+
+    #[test]
+    fn extract_scale_and_similarity_try_from() {
+        let translation = Vec3::new(1.0, -2.0, 0.5);
+        let rotation = Rotor3::from_euler_angles(0.3, -0.7, 0.2);
+        let points = [
+            Vec3::zero(),
+            Vec3::new(0.5, 1.5, -2.0),
+            Vec3::new(-3.0, 0.25, 4.0),
+        ];
+
+        // Uniform scale.
+        let mat =
+            Mat4::from_translation_rotation_scale(translation, rotation, Vec3::broadcast(1.5));
+        assert!(mat.extract_scale().eq_eps(Vec3::broadcast(1.5)));
+        let sim = Similarity3::try_from(mat).unwrap();
+        assert!((sim.scale - 1.5).abs() < 1e-6);
+        assert!(sim.translation.eq_eps(translation));
+        assert!((1.0 - sim.rotation.dot(rotation).abs()) < 1e-6);
+
+        // Non-uniform scale.
+        let stretched =
+            Mat4::from_translation_rotation_scale(translation, rotation, Vec3::new(1.5, 1.5, 2.0));
+        assert!(stretched.extract_scale().eq_eps(Vec3::new(1.5, 1.5, 2.0)));
+        assert_eq!(
+            Similarity3::try_from(stretched),
+            Err(DecompositionError::NonUniformScale)
+        );
+
+        // A reflection, i.e. a negative determinant, becomes a negative scale.
+        let mirrored = mat * Mat4::from_nonuniform_scale(Vec3::new(1.0, -1.0, 1.0));
+        assert!(mirrored.truncate().determinant() < 0.0);
+        assert!(mirrored.extract_scale().eq_eps(Vec3::new(-1.5, 1.5, 1.5)));
+        let sim = Similarity3::try_from(mirrored).unwrap();
+        assert!((sim.scale + 1.5).abs() < 1e-6);
+        for p in points {
+            assert!((sim * p).eq_eps(mirrored.transform_point3(p)));
+        }
+    }
+
+    #[test]
+    fn decompose_into_transforms() {
+        let translation = Vec3::new(1.0, -2.0, 0.5);
+        let rotation = Rotor3::from_euler_angles(0.3, -0.7, 0.2);
+        let v = Vec3::new(0.5, 1.5, -2.0);
+
+        let sim = Similarity3::new(translation, rotation, -2.0);
+        let decomposed = sim
+            .into_homogeneous_matrix()
+            .try_into_similarity(1e-5)
+            .unwrap();
+        assert!((decomposed * v).eq_eps(sim * v));
+
+        let mat =
+            Mat4::from_translation_rotation_scale(translation, rotation, Vec3::new(2.0, 0.5, -3.0));
+        assert_eq!(
+            mat.try_into_similarity(1e-5),
+            Err(DecompositionError::NonUniformScale)
+        );
+        let tf = mat.try_into_transform3(1e-5).unwrap();
+        assert!((tf * v).eq_eps(mat.transform_point3(v)));
+        assert!(mat.extract_scale().eq_eps(Vec3::new(-2.0, 0.5, 3.0)));
+        assert!(sim
+            .into_homogeneous_matrix()
+            .extract_scale()
+            .eq_eps(Vec3::new(-2.0, 2.0, 2.0)));
+        let from_matrix = Similarity3::try_from(sim.into_homogeneous_matrix()).unwrap();
+        assert!((from_matrix * v).eq_eps(sim * v));
+        assert_eq!(
+            Similarity3::try_from(mat),
+            Err(DecompositionError::NonUniformScale)
+        );
+
+        let mut skewed = mat;
+        skewed.cols[1] += skewed.cols[0] * 0.5;
+        assert_eq!(
+            skewed.try_into_transform3(1e-5),
+            Err(DecompositionError::NonOrthogonal)
+        );
+
+        let projection = crate::projection::rh_yup::perspective_gl(1.0, 1.0, 0.1, 10.0);
+        assert_eq!(
+            projection.try_into_transform3(1e-5),
+            Err(DecompositionError::NotAffine)
+        );
+        assert_eq!(
+            Mat4::from_nonuniform_scale(Vec3::new(1.0, 0.0, 1.0)).try_into_transform3(1e-5),
+            Err(DecompositionError::Degenerate)
+        );
+    }
+
+    #[test]
+    fn gltf_node_trs() {
+        // A quarter turn around +y, as exported by Blender, turns +x into -z.
+        let h = core::f32::consts::FRAC_1_SQRT_2;
+        let m = Mat4::from_gltf_trs([1.0, 2.0, 3.0], [0.0, h, 0.0, h], [2.0, 1.0, 1.0]);
+        assert!(m
+            .transform_point3(Vec3::unit_x())
+            .eq_eps(Vec3::new(1.0, 2.0, 1.0)));
+        assert!(m.transform_vec3(Vec3::unit_z()).eq_eps(Vec3::unit_x()));
+    }
+
+    #[test]
+    fn mat3_std140() {
+        let m = Mat3::new(
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(4.0, 5.0, 6.0),
+            Vec3::new(7.0, 8.0, 9.0),
+        );
+        let padded = m.to_std140();
+        assert_eq!(padded.cols[1], Vec4::new(4.0, 5.0, 6.0, 0.0));
+        assert_eq!(Mat3::from(padded), m);
+    }
+
+    #[cfg(feature = "portable-simd")]
+    #[test]
+    fn mat4x16_matches_scalar() {
+        let mats: [Mat4; 16] = core::array::from_fn(|i| {
+            let t = i as f32;
+            Mat4::from_translation(Vec3::new(t, -2.0, 0.5 * t))
+                * Mat4::from_euler_angles(0.1 * t, -0.4, 0.2 * t)
+                * Mat4::from_scale(1.0 + t)
+        });
+        let proj = crate::projection::rh_yup::perspective_gl(1.2, 1.5, 0.1, 100.0);
+        let column = |c: usize| Vec4x16::from(mats.map(|m| m.cols[c]));
+        let wide = Mat4x16::new(column(0), column(1), column(2), column(3));
+        let splat_proj = Mat4x16::new(
+            Vec4x16::splat(proj.cols[0]),
+            Vec4x16::splat(proj.cols[1]),
+            Vec4x16::splat(proj.cols[2]),
+            Vec4x16::splat(proj.cols[3]),
+        );
+
+        let product = splat_proj * wide;
+        let point = Vec4::new(1.0, -1.0, 2.0, 1.0);
+        let transformed: [Vec4; 16] = (wide * Vec4x16::splat(point)).into();
+        let inverse = wide.inversed();
+        for i in 0..16 {
+            for c in 0..4 {
+                let lanes: [Vec4; 16] = product.cols[c].into();
+                assert_eq!(lanes[i], (proj * mats[i]).cols[c]);
+                let lanes: [Vec4; 16] = inverse.cols[c].into();
+                assert!(lanes[i].eq_eps(mats[i].inversed().cols[c]));
+            }
+            assert_eq!(transformed[i], mats[i] * point);
+        }
+    }
 }
@@ -0,0 +1,233 @@
+//! Utilities for working with indexed triangle meshes.
+//!
+//! Meshes are described by a slice of vertex positions and a slice of indices into it, where
+//! each consecutive group of three indices forms one triangle. A triangle is considered to face
+//! the side from which its vertices appear in counter-clockwise order.
+use crate::*;
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::*;
+
+/// Reverse the winding order of every triangle in `indices`, flipping which side each
+/// triangle faces.
+#[inline]
+pub fn flip_winding(indices: &mut [u32]) {
+    for tri in indices.chunks_exact_mut(3) {
+        tri.swap(1, 2);
+    }
+}
+
+/// The signed volume enclosed by the triangles in `indices`.
+///
+/// For a closed mesh this is positive if its triangles face outwards and negative if they face
+/// inwards. For meshes that are not closed the result depends on the position of the mesh
+/// relative to the origin.
+pub fn signed_volume(positions: &[Vec3], indices: &[u32]) -> f32 {
+    indices
+        .chunks_exact(3)
+        .map(|tri| {
+            let a = positions[tri[0] as usize];
+            let b = positions[tri[1] as usize];
+            let c = positions[tri[2] as usize];
+            a.dot(b.cross(c))
+        })
+        .sum::<f32>()
+        / 6.0
+}
+
+/// Make the winding of the triangles in `indices` consistent and facing outwards.
+///
+/// First, the triangles of each connected piece of the mesh are flipped as needed so that every
+/// pair of triangles sharing an edge agrees on orientation. Then each piece whose signed volume
+/// is negative is flipped entirely so that it faces outwards.
+///
+/// This expects each piece to be a closed, manifold surface. Non-manifold edges (shared by more
+/// than two triangles) are handled on a best-effort basis.
+pub fn ensure_outward_winding(positions: &[Vec3], indices: &mut [u32]) {
+    let tri_count = indices.len() / 3;
+
+    let mut edges: BTreeMap<(u32, u32), Vec<usize>> = BTreeMap::new();
+    for (t, tri) in indices.chunks_exact(3).enumerate() {
+        for e in 0..3 {
+            let (a, b) = (tri[e], tri[(e + 1) % 3]);
+            edges.entry((a.min(b), a.max(b))).or_default().push(t);
+        }
+    }
+
+    let mut visited = vec![false; tri_count];
+    let mut stack = Vec::new();
+    let mut component = Vec::new();
+
+    for start in 0..tri_count {
+        if visited[start] {
+            continue;
+        }
+
+        visited[start] = true;
+        stack.push(start);
+        component.clear();
+
+        while let Some(t) = stack.pop() {
+            component.push(t);
+            for e in 0..3 {
+                let a = indices[t * 3 + e];
+                let b = indices[t * 3 + (e + 1) % 3];
+                for &n in &edges[&(a.min(b), a.max(b))] {
+                    if visited[n] {
+                        continue;
+                    }
+                    // A consistently wound neighbor traverses the shared edge in the
+                    // opposite direction.
+                    let tri = &indices[n * 3..n * 3 + 3];
+                    let same_direction = (0..3).any(|i| tri[i] == a && tri[(i + 1) % 3] == b);
+                    if same_direction {
+                        indices.swap(n * 3 + 1, n * 3 + 2);
+                    }
+                    visited[n] = true;
+                    stack.push(n);
+                }
+            }
+        }
+
+        // measure relative to the centroid so that the sign is meaningful even for
+        // pieces that do not quite close up
+        let centroid = component
+            .iter()
+            .flat_map(|&t| indices[t * 3..t * 3 + 3].iter())
+            .map(|&i| positions[i as usize])
+            .fold(Vec3::zero(), Add::add)
+            / (component.len() * 3) as f32;
+
+        let volume: f32 = component
+            .iter()
+            .map(|&t| {
+                let a = positions[indices[t * 3] as usize] - centroid;
+                let b = positions[indices[t * 3 + 1] as usize] - centroid;
+                let c = positions[indices[t * 3 + 2] as usize] - centroid;
+                a.dot(b.cross(c))
+            })
+            .sum();
+
+        if volume < 0.0 {
+            for &t in &component {
+                indices.swap(t * 3 + 1, t * 3 + 2);
+            }
+        }
+    }
+}
+
+/// The tangent of a triangle with the given vertex `positions` and texture coordinates `uvs`,
+/// pointing along increasing u, with its handedness in `w`.
+///
+/// `w` is `1.0` if the direction of increasing v agrees with `normal.cross(tangent)` for the
+/// counter-clockwise face normal, and `-1.0` if the texture is mirrored, which is the sign
+/// convention mikktspace and glTF use. Returns `None` if the triangle or its texture
+/// coordinates are degenerate.
+pub fn compute_tangent(positions: [Vec3; 3], uvs: [Vec2; 3]) -> Option<Vec4> {
+    let e1 = positions[1] - positions[0];
+    let e2 = positions[2] - positions[0];
+    let duv1 = uvs[1] - uvs[0];
+    let duv2 = uvs[2] - uvs[0];
+    let det = duv1.x * duv2.y - duv2.x * duv1.y;
+    if det == 0.0 {
+        return None;
+    }
+    let tangent = (e1 * duv2.y - e2 * duv1.y) / det;
+    let bitangent = (e2 * duv1.x - e1 * duv2.x) / det;
+    let normal = e1.cross(e2);
+    let w = if normal.cross(tangent).dot(bitangent) < 0.0 {
+        -1.0
+    } else {
+        1.0
+    };
+    tangent
+        .try_normalized()
+        .map(|t| Vec4::new(t.x, t.y, t.z, w))
+}
+
+/// Make `tangent` a unit vector orthogonal to the unit `normal`, with Gram-Schmidt, keeping
+/// the handedness in `w` as `1.0` or `-1.0`.
+///
+/// Use this after averaging per-triangle tangents into a vertex, or after interpolating a
+/// tangent frame, before building it with [`Mat3::tbn_from_tangent`].
+#[inline]
+pub fn orthonormalize_tangent(normal: Vec3, tangent: Vec4) -> Vec4 {
+    let t = tangent.xyz();
+    let w = if tangent.w < 0.0 { -1.0 } else { 1.0 };
+    let t = (t - normal * normal.dot(t)).normalized();
+    Vec4::new(t.x, t.y, t.z, w)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    fn tetrahedron() -> (Vec<Vec3>, Vec<u32>) {
+        let positions = vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        ];
+        let indices = vec![0, 2, 1, 0, 1, 3, 0, 3, 2, 1, 2, 3];
+        (positions, indices)
+    }
+
+    #[test]
+    fn tetrahedron_volume() {
+        let (positions, mut indices) = tetrahedron();
+        assert!((signed_volume(&positions, &indices) - 1.0 / 6.0).abs() < 1e-6);
+        flip_winding(&mut indices);
+        assert!((signed_volume(&positions, &indices) + 1.0 / 6.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn fix_inverted_and_inconsistent_winding() {
+        let (positions, expected) = tetrahedron();
+
+        let mut inverted = expected.clone();
+        flip_winding(&mut inverted);
+        ensure_outward_winding(&positions, &mut inverted);
+        assert!(signed_volume(&positions, &inverted) > 0.0);
+
+        let mut inconsistent = expected.clone();
+        flip_winding(&mut inconsistent[3..6]);
+        ensure_outward_winding(&positions, &mut inconsistent);
+        assert!((signed_volume(&positions, &inconsistent) - 1.0 / 6.0).abs() < 1e-6);
+        for (fixed, tri) in inconsistent.chunks(3).zip(expected.chunks(3)) {
+            let n_fixed = (positions[fixed[1] as usize] - positions[fixed[0] as usize])
+                .cross(positions[fixed[2] as usize] - positions[fixed[0] as usize]);
+            let n_expected = (positions[tri[1] as usize] - positions[tri[0] as usize])
+                .cross(positions[tri[2] as usize] - positions[tri[0] as usize]);
+            assert!(n_fixed.dot(n_expected) > 0.0);
+        }
+    }
+
+    #[test]
+    fn tangent_frames() {
+        let positions = [Vec3::zero(), Vec3::unit_x(), Vec3::unit_y()];
+        let uvs = [Vec2::zero(), Vec2::new(0.5, 0.0), Vec2::new(0.0, 0.5)];
+        let tangent = compute_tangent(positions, uvs).unwrap();
+        assert!(tangent.eq_eps(Vec4::new(1.0, 0.0, 0.0, 1.0)));
+
+        // Mirroring the texture flips the handedness but not the tangent.
+        let mirrored = uvs.map(|uv| Vec2::new(uv.x, -uv.y));
+        let flipped = compute_tangent(positions, mirrored).unwrap();
+        assert!(flipped.eq_eps(Vec4::new(1.0, 0.0, 0.0, -1.0)));
+        assert_eq!(compute_tangent(positions, [Vec2::zero(); 3]), None);
+
+        let normal = Vec3::unit_z();
+        let skewed = Vec4::new(2.0, 0.0, 1.0, -0.5);
+        let fixed = orthonormalize_tangent(normal, skewed);
+        assert!(fixed.eq_eps(Vec4::new(1.0, 0.0, 0.0, -1.0)));
+
+        let tbn = Mat3::tbn_from_tangent(tangent, normal);
+        assert!((tbn * Vec3::unit_y()).eq_eps(Vec3::unit_y()));
+        let tbn = Mat3::tbn_from_tangent(flipped, normal);
+        assert!((tbn * Vec3::unit_y()).eq_eps(-Vec3::unit_y()));
+        assert!((tbn * Vec3::unit_z()).eq_eps(normal));
+    }
+}
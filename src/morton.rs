@@ -0,0 +1,163 @@
+//! Morton code (Z-order curve) encoding of points.
+//!
+//! A Morton code interleaves the bits of the coordinates of a point, such that points which
+//! are close together in space tend to also be close together when sorted by their code. This
+//! is useful for building linear bounding volume hierarchies (LBVHs) or for keeping spatially
+//! coherent particle buffers.
+//!
+//! 3d Morton codes are stored in a `u64`, which leaves room for 21 bits per axis.
+use crate::*;
+
+/// The maximum number of bits per axis that fit in a 64-bit 3d Morton code.
+pub const MORTON3_MAX_BITS: u32 = 21;
+
+/// Spread the lower 21 bits of `v` out so that there are two zero bits between each of them.
+#[inline]
+fn part1by2(v: u64) -> u64 {
+    let mut x = v & 0x1f_ffff;
+    x = (x | (x << 32)) & 0x001f_0000_0000_ffff;
+    x = (x | (x << 16)) & 0x001f_0000_ff00_00ff;
+    x = (x | (x << 8)) & 0x100f_00f0_0f00_f00f;
+    x = (x | (x << 4)) & 0x10c3_0c30_c30c_30c3;
+    x = (x | (x << 2)) & 0x1249_2492_4924_9249;
+    x
+}
+
+/// The inverse of `part1by2`, i.e. gather every third bit of `v` into the lower 21 bits.
+#[inline]
+#[cfg(feature = "int")]
+fn compact1by2(v: u64) -> u64 {
+    let mut x = v & 0x1249_2492_4924_9249;
+    x = (x | (x >> 2)) & 0x10c3_0c30_c30c_30c3;
+    x = (x | (x >> 4)) & 0x100f_00f0_0f00_f00f;
+    x = (x | (x >> 8)) & 0x001f_0000_ff00_00ff;
+    x = (x | (x >> 16)) & 0x001f_0000_0000_ffff;
+    x = (x | (x >> 32)) & 0x1f_ffff;
+    x
+}
+
+#[inline]
+fn interleave3(x: u64, y: u64, z: u64) -> u64 {
+    part1by2(x) | (part1by2(y) << 1) | (part1by2(z) << 2)
+}
+
+macro_rules! impl_morton_vec3s {
+    ($($vt:ident => $t:ident),+) => {
+        $(impl $vt {
+            /// Compute the 3d Morton code of `self` after quantizing it to a grid of
+            /// `2^bits` cells per axis spanning the axis-aligned box from `min` to `max`.
+            ///
+            /// Points outside of the box are clamped to its boundary. `bits` must be
+            /// at most [`MORTON3_MAX_BITS`].
+            #[inline]
+            pub fn morton_in(&self, min: $vt, max: $vt, bits: u32) -> u64 {
+                debug_assert!(bits <= MORTON3_MAX_BITS);
+                let cells = ((1u64 << bits) - 1) as $t;
+                let n = ((*self - min) / (max - min)).clamped($vt::zero(), $vt::one()) * cells;
+                interleave3(n.x as u64, n.y as u64, n.z as u64)
+            }
+        })+
+    };
+}
+
+impl_morton_vec3s!(Vec3 => f32);
+
+#[cfg(feature = "f64")]
+impl_morton_vec3s!(DVec3 => f64);
+
+macro_rules! impl_morton_wide_vec3s {
+    ($($vt:ident => $tt:ident, $t:ident, $lanes:expr),+) => {
+        $(impl $vt {
+            /// Compute the 3d Morton code of each lane of `self` after quantizing it to a grid of
+            /// `2^bits` cells per axis spanning the axis-aligned box from `min` to `max`.
+            ///
+            /// Points outside of the box are clamped to its boundary. `bits` must be
+            /// at most [`MORTON3_MAX_BITS`].
+            #[inline]
+            pub fn morton_in(&self, min: $vt, max: $vt, bits: u32) -> [u64; $lanes] {
+                debug_assert!(bits <= MORTON3_MAX_BITS);
+                let cells = $t::splat(((1u64 << bits) - 1) as $tt);
+                let n = ((*self - min) / (max - min)).clamped($vt::zero(), $vt::one()) * cells;
+                let xs: [$tt; $lanes] = n.x.into();
+                let ys: [$tt; $lanes] = n.y.into();
+                let zs: [$tt; $lanes] = n.z.into();
+                let mut codes = [0; $lanes];
+                for (i, code) in codes.iter_mut().enumerate() {
+                    *code = interleave3(xs[i] as u64, ys[i] as u64, zs[i] as u64);
+                }
+                codes
+            }
+        })+
+    };
+}
+
+impl_morton_wide_vec3s!(
+    Vec3x4 => f32, f32x4, 4,
+    Vec3x8 => f32, f32x8, 8
+);
+
+#[cfg(feature = "f64")]
+impl_morton_wide_vec3s!(
+    DVec3x2 => f64, f64x2, 2,
+    DVec3x4 => f64, f64x4, 4
+);
+
+#[cfg(feature = "int")]
+impl UVec3 {
+    /// Interleave the bits of the components of `self` into a 3d Morton code.
+    ///
+    /// Only the lower [`MORTON3_MAX_BITS`] bits of each component are used.
+    #[inline]
+    pub fn to_morton(&self) -> u64 {
+        interleave3(self.x as u64, self.y as u64, self.z as u64)
+    }
+
+    /// Decode a 3d Morton code produced by [`UVec3::to_morton`].
+    #[inline]
+    pub fn from_morton(code: u64) -> Self {
+        Self::new(
+            compact1by2(code) as u32,
+            compact1by2(code >> 1) as u32,
+            compact1by2(code >> 2) as u32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn morton_interleave() {
+        assert_eq!(interleave3(1, 0, 0), 0b001);
+        assert_eq!(interleave3(0, 1, 0), 0b010);
+        assert_eq!(interleave3(0, 0, 1), 0b100);
+        assert_eq!(interleave3(0b11, 0b11, 0b11), 0b111_111);
+        assert_eq!(interleave3(0x1f_ffff, 0x1f_ffff, 0x1f_ffff), (1 << 63) - 1);
+    }
+
+    #[test]
+    #[cfg(feature = "int")]
+    fn morton_roundtrip() {
+        let v = UVec3::new(0x1f_ffff, 12345, 7);
+        assert_eq!(UVec3::from_morton(v.to_morton()), v);
+    }
+
+    #[test]
+    fn morton_in_wide_matches_scalar() {
+        let min = Vec3::new(-1.0, -2.0, -3.0);
+        let max = Vec3::new(1.0, 2.0, 3.0);
+        let points = [
+            Vec3::new(-1.0, -2.0, -3.0),
+            Vec3::new(0.25, 1.5, -0.5),
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(5.0, -5.0, 0.0),
+        ];
+        let wide = Vec3x4::from(points).morton_in(Vec3x4::splat(min), Vec3x4::splat(max), 10);
+        for (p, code) in points.iter().zip(wide.iter()) {
+            assert_eq!(p.morton_in(min, max, 10), *code);
+        }
+        assert_eq!(points[0].morton_in(min, max, 10), 0);
+        assert_eq!(points[2].morton_in(min, max, 10), (1 << 30) - 1);
+    }
+}
@@ -0,0 +1,204 @@
+//! Building blocks for rigid body physics.
+//!
+//! Angular quantities here are expressed as bivectors, in the same way as rotations are
+//! expressed with [`Rotor3`]: the angular velocity `omega` of a body spinning in the plane `B`
+//! at a rate of `w` radians per second is `B * w`, such that after `t` seconds the body has
+//! been rotated by `Rotor3::from_angle_plane(w * t, B)`.
+//!
+//! Inertia tensors are the usual symmetric 3x3 matrices, which act on 'axis vectors'. Conversion
+//! to and from the bivector representation is handled internally.
+use crate::*;
+
+/// Convert a bivector into the 'axis vector' which is normal to the same plane, i.e. the inverse
+/// of [`Bivec3::from_normalized_axis`].
+#[inline]
+fn bivec_to_axis(b: Bivec3) -> Vec3 {
+    Vec3::new(b.yz, -b.xz, b.xy)
+}
+
+/// The rotor which rotates by the angular velocity `omega` applied for `dt` seconds.
+#[inline]
+fn delta_rotation(omega: Bivec3, dt: f32) -> Rotor3 {
+    let rate = omega.mag();
+    if rate > 0.0 {
+        Rotor3::from_angle_plane(rate * dt, omega / rate)
+    } else {
+        Rotor3::identity()
+    }
+}
+
+impl Mat3 {
+    /// The inertia tensor of a solid box of uniform density around its center, extending
+    /// `half_extents` from it along each axis.
+    #[inline]
+    pub fn inertia_tensor_box(half_extents: Vec3, mass: f32) -> Self {
+        let sq = half_extents * half_extents;
+        Self::from_nonuniform_scale(Vec3::new(sq.y + sq.z, sq.x + sq.z, sq.x + sq.y) * (mass / 3.0))
+    }
+
+    /// The inertia tensor of a solid sphere of uniform density around its center.
+    #[inline]
+    pub fn inertia_tensor_sphere(radius: f32, mass: f32) -> Self {
+        Self::from_scale(0.4 * mass * radius * radius)
+    }
+
+    /// The inertia tensor of a solid cylinder of uniform density around its center, with its
+    /// axis along y and extending `half_height` from the center along it.
+    #[inline]
+    pub fn inertia_tensor_cylinder(radius: f32, half_height: f32, mass: f32) -> Self {
+        let r_sq = radius * radius;
+        let across = mass * (r_sq / 4.0 + half_height * half_height / 3.0);
+        Self::from_nonuniform_scale(Vec3::new(across, 0.5 * mass * r_sq, across))
+    }
+
+    /// Shift the inertia tensor `self` of a body with the given `mass`, taken around its center
+    /// of mass, to be around a reference point which the center of mass is at `offset` from.
+    /// This is the parallel axis theorem.
+    ///
+    /// Summing the results for the parts of a compound body gives its total inertia tensor.
+    #[inline]
+    pub fn parallel_axis(&self, offset: Vec3, mass: f32) -> Self {
+        let d = offset;
+        let outer = Self::new(d * d.x, d * d.y, d * d.z);
+        *self + Self::from_scale(d.mag_sq() * mass) + outer * -mass
+    }
+
+    /// The inertia tensor `self` of a body after rotating the body by `rotation`, i.e.
+    /// `R * self * R^T` where `R` is `rotation.into_matrix()`.
+    ///
+    /// This converts a local space inertia tensor to world space, and works the same way for
+    /// the inverse inertia tensor.
+    #[inline]
+    pub fn rotated_inertia(&self, rotation: Rotor3) -> Self {
+        let r = rotation.into_matrix();
+        r * *self * r.transposed()
+    }
+}
+
+/// Advance the orientation `rotor` and `angular_momentum` of a rigid body by a timestep of `dt`
+/// seconds under a constant `torque`.
+///
+/// * `angular_momentum` and `torque` are in world space.
+/// * `inertia_inv_world` is the inverse of the body's inertia tensor in world space *at the
+///   current orientation*, i.e. `inertia_inv_local.rotated_inertia(*rotor)`.
+///
+/// The torque is applied to the angular momentum first (semi-implicit Euler), after which the
+/// orientation is advanced. Because angular momentum rather than angular velocity is integrated,
+/// the gyroscopic term is accounted for by how the world space inertia changes as the body
+/// rotates; to keep the resulting precession stable, the angular velocity is evaluated with the
+/// inertia rotated to the midpoint of the step. `rotor` is renormalized afterwards to prevent
+/// drift.
+///
+/// Remember to recompute `inertia_inv_world` from the new orientation before the next step.
+#[inline]
+pub fn integrate_angular(
+    rotor: &mut Rotor3,
+    angular_momentum: &mut Bivec3,
+    inertia_inv_world: &Mat3,
+    torque: Bivec3,
+    dt: f32,
+) {
+    *angular_momentum += torque * dt;
+    let momentum_axis = bivec_to_axis(*angular_momentum);
+
+    let omega = Bivec3::from_normalized_axis(*inertia_inv_world * momentum_axis);
+    let half = delta_rotation(omega, dt * 0.5).into_matrix();
+    let inertia_inv_mid = half * *inertia_inv_world * half.transposed();
+
+    let omega = Bivec3::from_normalized_axis(inertia_inv_mid * momentum_axis);
+    *rotor = delta_rotation(omega, dt) * *rotor;
+    rotor.normalize();
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn angular_integration() {
+        // A symmetric body spinning freely keeps its momentum and spins at a constant rate.
+        let mut rotor = Rotor3::identity();
+        let mut momentum = Bivec3::unit_xy() * 2.0;
+        let inertia_inv = Mat3::from_scale(0.5);
+        for _ in 0..100 {
+            integrate_angular(
+                &mut rotor,
+                &mut momentum,
+                &inertia_inv,
+                Bivec3::zero(),
+                0.01,
+            );
+        }
+        assert!(momentum.eq_eps(Bivec3::unit_xy() * 2.0));
+        assert!(rotor.eq_eps(Rotor3::from_angle_plane(1.0, Bivec3::unit_xy())));
+
+        // Torque spins a body up from rest.
+        let mut rotor = Rotor3::identity();
+        let mut momentum = Bivec3::zero();
+        integrate_angular(
+            &mut rotor,
+            &mut momentum,
+            &inertia_inv,
+            Bivec3::unit_yz(),
+            0.5,
+        );
+        assert!(momentum.eq_eps(Bivec3::unit_yz() * 0.5));
+        assert!(rotor.eq_eps(Rotor3::from_angle_plane(0.125, Bivec3::unit_yz())));
+
+        // An asymmetric body tumbling about an off-principal axis conserves its kinetic energy.
+        let inertia_inv_local = Mat3::from_nonuniform_scale(Vec3::new(1.0, 0.5, 0.25));
+        let mut rotor = Rotor3::identity();
+        let mut momentum = Bivec3::new(0.3, 1.0, 0.2);
+        let energy = |rotor: Rotor3, momentum: Bivec3| {
+            let r = rotor.into_matrix();
+            let l = bivec_to_axis(momentum);
+            l.dot(r * inertia_inv_local * r.transposed() * l)
+        };
+        let e0 = energy(rotor, momentum);
+        for _ in 0..1000 {
+            let r = rotor.into_matrix();
+            let inertia_inv = r * inertia_inv_local * r.transposed();
+            integrate_angular(
+                &mut rotor,
+                &mut momentum,
+                &inertia_inv,
+                Bivec3::zero(),
+                0.001,
+            );
+        }
+        assert!((energy(rotor, momentum) - e0).abs() < 1e-2 * e0);
+    }
+
+    #[test]
+    fn inertia_tensors() {
+        let cube = Mat3::inertia_tensor_box(Vec3::broadcast(0.5), 6.0);
+        assert!(cube.cols[0].eq_eps(Vec3::new(1.0, 0.0, 0.0)));
+        // A cube is symmetric enough that rotating it doesn't change its inertia.
+        let rotation = Rotor3::from_euler_angles(0.3, -1.2, 2.0);
+        for (a, b) in cube
+            .rotated_inertia(rotation)
+            .cols
+            .iter()
+            .zip(cube.cols.iter())
+        {
+            assert!(a.eq_eps(*b));
+        }
+
+        let rod = Mat3::inertia_tensor_cylinder(0.0, 1.0, 3.0);
+        assert!(rod.cols[0].eq_eps(Vec3::new(1.0, 0.0, 0.0)));
+        assert!(rod.cols[1].eq_eps(Vec3::zero()));
+        let disc = Mat3::inertia_tensor_cylinder(2.0, 0.0, 1.0);
+        assert!(disc.cols[1].y.eq_eps(2.0));
+
+        // A point mass at distance d has inertia m * d^2 around perpendicular axes.
+        let point = Mat3::inertia_tensor_sphere(0.0, 2.0).parallel_axis(Vec3::unit_x() * 3.0, 2.0);
+        assert!(point.cols[0].eq_eps(Vec3::zero()));
+        assert!(point.cols[1].eq_eps(Vec3::new(0.0, 18.0, 0.0)));
+        assert!(point.cols[2].eq_eps(Vec3::new(0.0, 0.0, 18.0)));
+
+        let rotated = rod.rotated_inertia(Rotor3::from_rotation_xy(core::f32::consts::FRAC_PI_2));
+        assert!(rotated.cols[0].eq_eps(Vec3::zero()));
+        assert!(rotated.cols[1].eq_eps(Vec3::new(0.0, 1.0, 0.0)));
+    }
+}
@@ -0,0 +1,132 @@
+//! Interoperation with the portable SIMD types of `core::simd`.
+//!
+//! With the `portable-simd` feature, the wide float types of this crate (`f32x4`, `f32x8`,
+//! `f64x2`, `f64x4` and the additional `f32x16`) are implemented over [`core::simd::Simd`]
+//! instead of with the `wide` crate, while keeping the same API. The [`PortableSimd`] trait
+//! converts them, as well as the wide vectors, to and from their `Simd` representation, e.g.
+//! to use operations of `core::simd` which they don't expose. The conversions only move the
+//! registers, so they compile to nothing.
+//!
+//! Requires a nightly compiler and the `portable-simd` feature.
+use crate::*;
+
+use core::simd::Simd;
+
+/// Conversion between a wide type and its representation in terms of [`core::simd::Simd`].
+///
+/// ```rust
+/// # #![feature(portable_simd)]
+/// # use ultraviolet::*;
+/// use core::simd::f32x4 as Simd4;
+/// use ultraviolet::portable_simd::PortableSimd;
+///
+/// let v = Vec3x4::from([Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z(), Vec3::one()]);
+/// let [x, y, z] = v.to_simd();
+/// assert_eq!(x, Simd4::from_array([1.0, 0.0, 0.0, 1.0]));
+/// assert_eq!(Vec3x4::from_simd([x, y, z]), v);
+/// ```
+pub trait PortableSimd: Sized {
+    /// The `core::simd` representation, a `Simd` for the wide scalars and an array of them, one
+    /// per component, for the wide vectors.
+    type Simd;
+
+    /// Converts `self` to its `core::simd` representation.
+    fn to_simd(self) -> Self::Simd;
+
+    /// Converts from the `core::simd` representation.
+    fn from_simd(simd: Self::Simd) -> Self;
+}
+
+macro_rules! impl_portable_simd_scalars {
+    ($($t:ident => $s:ident, $lanes:literal);+) => {
+        $(impl PortableSimd for $t {
+            type Simd = Simd<$s, $lanes>;
+
+            #[inline]
+            fn to_simd(self) -> Self::Simd {
+                self.into()
+            }
+
+            #[inline]
+            fn from_simd(simd: Self::Simd) -> Self {
+                simd.into()
+            }
+        })+
+    };
+}
+
+macro_rules! impl_portable_simd_vecs {
+    ($($vt:ident => $t:ident, $n:literal, [$($var:ident),+]);+) => {
+        $(impl PortableSimd for $vt {
+            type Simd = [<$t as PortableSimd>::Simd; $n];
+
+            #[inline]
+            fn to_simd(self) -> Self::Simd {
+                [$(self.$var.to_simd()),+]
+            }
+
+            #[inline]
+            fn from_simd(simd: Self::Simd) -> Self {
+                let [$($var),+] = simd;
+                $vt::new($($t::from_simd($var)),+)
+            }
+        })+
+    };
+}
+
+impl_portable_simd_scalars!(
+    f32x4 => f32, 4;
+    f32x8 => f32, 8;
+    f32x16 => f32, 16
+);
+
+impl_portable_simd_vecs!(
+    Vec2x4 => f32x4, 2, [x, y];
+    Vec2x8 => f32x8, 2, [x, y];
+    Vec3x4 => f32x4, 3, [x, y, z];
+    Vec3x8 => f32x8, 3, [x, y, z];
+    Vec4x4 => f32x4, 4, [x, y, z, w];
+    Vec4x8 => f32x8, 4, [x, y, z, w];
+    Vec2x16 => f32x16, 2, [x, y];
+    Vec3x16 => f32x16, 3, [x, y, z];
+    Vec4x16 => f32x16, 4, [x, y, z, w]
+);
+
+#[cfg(feature = "f64")]
+impl_portable_simd_scalars!(
+    f64x2 => f64, 2;
+    f64x4 => f64, 4
+);
+
+#[cfg(feature = "f64")]
+impl_portable_simd_vecs!(
+    DVec2x2 => f64x2, 2, [x, y];
+    DVec2x4 => f64x4, 2, [x, y];
+    DVec3x2 => f64x2, 3, [x, y, z];
+    DVec3x4 => f64x4, 3, [x, y, z];
+    DVec4x2 => f64x2, 4, [x, y, z, w];
+    DVec4x4 => f64x4, 4, [x, y, z, w]
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn simd_round_trip() {
+        let lanes = f32x8::from([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let simd = lanes.to_simd();
+        assert_eq!(simd.to_array(), lanes.to_array());
+        assert_eq!(f32x8::from_simd(simd * Simd::splat(2.0)), lanes * 2.0);
+
+        let v = Vec4x4::new(
+            f32x4::ONE,
+            f32x4::splat(2.0),
+            f32x4::splat(3.0),
+            f32x4::ZERO,
+        );
+        let [x, y, z, w] = v.to_simd();
+        assert_eq!(Vec4x4::from_simd([x, y, z, w]), v);
+        assert_eq!(z, Simd::splat(3.0));
+    }
+}
@@ -0,0 +1,184 @@
+//! Conversions between single and double precision types.
+//!
+//! Every `f32` based type converts losslessly into its `f64` based counterpart of the same
+//! width with `From`/`Into` or the equivalent `to_f64` method, while the reverse direction is an
+//! explicit `to_f32_lossy` method since it rounds each component to the nearest `f32`.
+use crate::*;
+
+/// Crate-private conversion between precisions, which is implemented for the scalar types and
+/// then built up field by field for everything else.
+trait CastPrecision<T> {
+    fn cast(self) -> T;
+}
+
+impl CastPrecision<f64> for f32 {
+    #[inline]
+    fn cast(self) -> f64 {
+        self as f64
+    }
+}
+
+impl CastPrecision<f32> for f64 {
+    #[inline]
+    fn cast(self) -> f32 {
+        self as f32
+    }
+}
+
+impl CastPrecision<f64x4> for f32x4 {
+    #[inline]
+    fn cast(self) -> f64x4 {
+        let a: [f32; 4] = self.into();
+        f64x4::from([a[0] as f64, a[1] as f64, a[2] as f64, a[3] as f64])
+    }
+}
+
+impl CastPrecision<f32x4> for f64x4 {
+    #[inline]
+    fn cast(self) -> f32x4 {
+        let a: [f64; 4] = self.into();
+        f32x4::from([a[0] as f32, a[1] as f32, a[2] as f32, a[3] as f32])
+    }
+}
+
+impl<T: CastPrecision<U>, U, const N: usize> CastPrecision<[U; N]> for [T; N] {
+    #[inline]
+    fn cast(self) -> [U; N] {
+        self.map(CastPrecision::cast)
+    }
+}
+
+macro_rules! precisions {
+    ($($lo:ident <=> $hi:ident { $($f:ident),+ }),+) => {
+        $(impl CastPrecision<$hi> for $lo {
+            #[inline]
+            fn cast(self) -> $hi {
+                $hi { $($f: self.$f.cast()),+ }
+            }
+        }
+
+        impl CastPrecision<$lo> for $hi {
+            #[inline]
+            fn cast(self) -> $lo {
+                $lo { $($f: self.$f.cast()),+ }
+            }
+        }
+
+        impl From<$lo> for $hi {
+            #[inline]
+            fn from(v: $lo) -> Self {
+                v.cast()
+            }
+        }
+
+        impl $lo {
+            /// Convert into the double precision equivalent of this type, which is exact.
+            #[inline]
+            pub fn to_f64(&self) -> $hi {
+                (*self).cast()
+            }
+        }
+
+        impl $hi {
+            /// Convert into the single precision equivalent of this type, rounding each
+            /// component to the nearest `f32`.
+            #[inline]
+            pub fn to_f32_lossy(&self) -> $lo {
+                (*self).cast()
+            }
+        })+
+    };
+}
+
+precisions!(
+    Vec2 <=> DVec2 { x, y },
+    Vec3 <=> DVec3 { x, y, z },
+    Vec4 <=> DVec4 { x, y, z, w },
+    Bivec2 <=> DBivec2 { xy },
+    Bivec3 <=> DBivec3 { xy, xz, yz },
+    Rotor2 <=> DRotor2 { s, bv },
+    Rotor3 <=> DRotor3 { s, bv },
+    Mat2 <=> DMat2 { cols },
+    Mat3 <=> DMat3 { cols },
+    Mat4 <=> DMat4 { cols },
+    Isometry2 <=> DIsometry2 { translation, rotation },
+    Isometry3 <=> DIsometry3 { translation, rotation },
+    Similarity2 <=> DSimilarity2 { translation, rotation, scale },
+    Similarity3 <=> DSimilarity3 { translation, rotation, scale },
+    Transform3 <=> DTransform3 { translation, rotation, scale },
+
+    Vec2x4 <=> DVec2x4 { x, y },
+    Vec3x4 <=> DVec3x4 { x, y, z },
+    Vec4x4 <=> DVec4x4 { x, y, z, w },
+    Bivec2x4 <=> DBivec2x4 { xy },
+    Bivec3x4 <=> DBivec3x4 { xy, xz, yz },
+    Rotor2x4 <=> DRotor2x4 { s, bv },
+    Rotor3x4 <=> DRotor3x4 { s, bv },
+    Mat2x4 <=> DMat2x4 { cols },
+    Mat3x4 <=> DMat3x4 { cols },
+    Mat4x4 <=> DMat4x4 { cols },
+    Isometry2x4 <=> DIsometry2x4 { translation, rotation },
+    Isometry3x4 <=> DIsometry3x4 { translation, rotation },
+    Similarity2x4 <=> DSimilarity2x4 { translation, rotation, scale },
+    Similarity3x4 <=> DSimilarity3x4 { translation, rotation, scale },
+    Transform3x4 <=> DTransform3x4 { translation, rotation, scale }
+);
+
+macro_rules! relative_tos {
+    ($($hi:ident => ($lo:ident, $vt:ident)),+) => {
+        $(impl $hi {
+            /// Rebase this transform around a floating `origin` and convert it to single precision,
+            /// for camera-relative rendering of worlds too large for `f32` positions.
+            ///
+            /// The translation is made relative to `origin` before it is rounded, so that objects
+            /// near the origin (usually the camera) keep their full precision. Use the same
+            /// `origin` for every object and for the view transform in a frame.
+            #[inline]
+            pub fn relative_to(&self, origin: $vt) -> $lo {
+                let mut rebased = *self;
+                rebased.translation -= origin;
+                rebased.to_f32_lossy()
+            }
+        })+
+    };
+}
+
+relative_tos!(
+    DIsometry3 => (Isometry3, DVec3),
+    DSimilarity3 => (Similarity3, DVec3),
+    DTransform3 => (Transform3, DVec3)
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn precision_roundtrip() {
+        let v = Vec3::new(0.1, -2.5, 1e-3);
+        let d: DVec3 = v.into();
+        assert_eq!(d, DVec3::new(0.1f32 as f64, -2.5, 1e-3f32 as f64));
+        assert_eq!(d.to_f32_lossy(), v);
+
+        let iso = Isometry3::new(v, Rotor3::from_rotation_xz(0.3));
+        assert_eq!(DIsometry3::from(iso).to_f32_lossy(), iso);
+
+        let m = Mat4x4::from_translation(Vec3x4::from([v; 4]));
+        assert_eq!(DMat4x4::from(m).to_f32_lossy(), m);
+        assert_eq!(m.to_f64(), DMat4x4::from(m));
+        assert_eq!(DVec2::new(0.1, 0.2).to_f32_lossy(), Vec2::new(0.1, 0.2));
+    }
+
+    #[test]
+    fn camera_relative() {
+        let origin = DVec3::new(1e9, 0.0, -3e8);
+        let iso = DIsometry3::new(
+            origin + DVec3::new(0.25, 1.5, -0.125),
+            DRotor3::from_rotation_xz(0.3),
+        );
+        let rel = iso.relative_to(origin);
+        assert_eq!(rel.translation, Vec3::new(0.25, 1.5, -0.125));
+        assert_eq!(rel.rotation, iso.rotation.to_f32_lossy());
+        assert_eq!(iso.to_f32_lossy().translation.x, 1e9);
+    }
+}
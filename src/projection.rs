@@ -43,8 +43,210 @@
 //! Blender, 3ds max, or Unreal), then we do not currently have a module with projections
 //! suitable for your use case. Contributions to add this are welcome!
 
+/// Variants of the perspective projections which take the field of view in any unit that
+/// converts into radians, such as `Degrees`.
+macro_rules! fov_radians {
+    ($t:ident, $rad:ident, $mt:ident; $($typed:ident => $f:ident($($arg:ident),+)),+) => {
+        $(#[doc = concat!("[`", stringify!($f), "`] with `vertical_fov` in any unit which converts into radians.")]
+        #[inline]
+        pub fn $typed(vertical_fov: impl Into<crate::$rad>, $($arg: $t),+) -> $mt {
+            $f(vertical_fov.into().0, $($arg),+)
+        })+
+    };
+}
+
 pub mod lh_ydown;
 pub mod lh_yup;
 pub mod rh_yup;
 
 pub use rh_yup::*;
+
+use crate::mat::*;
+use crate::vec::*;
+
+/// Offset the projection `proj` by a fraction of a pixel, e.g. for the sub-pixel jitter of
+/// temporal anti-aliasing.
+///
+/// `offset_px` is given in pixels along the axes of normalized device coordinates, so a
+/// positive `y` moves the image up with the `_gl` and `_wgpu_dx` projections and down with the
+/// `_vk` ones, and `viewport` is the size of the render target in pixels. This works for both
+/// perspective and orthographic projections.
+#[inline]
+pub fn jittered(proj: Mat4, offset_px: Vec2, viewport: Vec2) -> Mat4 {
+    let offset = offset_px * 2.0 / viewport;
+    let mut m = proj;
+    for col in m.cols.iter_mut() {
+        col.x += offset.x * col.w;
+        col.y += offset.y * col.w;
+    }
+    m
+}
+
+macro_rules! obliques {
+    ($($mt:ident => ($vt:ident, $t:ident)),+) => {
+        $(impl $mt {
+            /// Replace the near plane of the perspective projection `self` with `clip_plane`, for
+            /// projections with Z (depth) clip extending from -1.0 (close) to 1.0 (far), i.e. those
+            /// meant for OpenGL.
+            ///
+            /// This is the oblique near-plane clipping technique by Eric Lengyel, which clips away
+            /// everything behind a plane like a water surface or mirror without an extra clip
+            /// distance, as is needed when rendering planar reflections. The far plane is moved
+            /// to keep the view frustum enclosed, so depth precision suffers the more the plane is
+            /// tilted relative to the original near plane.
+            ///
+            /// `clip_plane` is given in view space as `(normal, distance)`, such that points `p`
+            /// with `normal.dot(p) + distance >= 0.0` are kept. The camera itself must be on the
+            /// clipped side of the plane. This does not work with reversed-z projections.
+            #[inline]
+            pub fn oblique_near_plane_gl(&self, clip_plane: $vt) -> Self {
+                let (q, row4) = self.oblique_far_corner(clip_plane);
+                let scale = 2.0 * row4.dot(q) / clip_plane.dot(q);
+                self.with_depth_row(clip_plane * scale - row4)
+            }
+
+            /// Replace the near plane of the perspective projection `self` with `clip_plane`, for
+            /// projections with Z (depth) clip extending from 0.0 (close) to 1.0 (far), i.e. those
+            /// meant for Vulkan, WebGPU or DirectX.
+            ///
+            /// See [`Self::oblique_near_plane_gl`] for details.
+            #[inline]
+            pub fn oblique_near_plane_vk_wgpu_dx(&self, clip_plane: $vt) -> Self {
+                let (q, row4) = self.oblique_far_corner(clip_plane);
+                let scale = row4.dot(q) / clip_plane.dot(q);
+                self.with_depth_row(clip_plane * scale)
+            }
+
+            /// The view space position of the frustum corner opposite to `clip_plane`, along
+            /// with the fourth row of `self`.
+            #[inline]
+            fn oblique_far_corner(&self, clip_plane: $vt) -> ($vt, $vt) {
+                let inv = self.inversed();
+                let clip_space_plane = inv.transposed() * clip_plane;
+                let corner = $vt::new(
+                    clip_space_plane.x.signum(),
+                    clip_space_plane.y.signum(),
+                    1.0,
+                    1.0,
+                );
+                (inv * corner, self.transposed().cols[3])
+            }
+
+            #[inline]
+            fn with_depth_row(&self, row: $vt) -> Self {
+                let mut m = *self;
+                m.cols[0].z = row.x;
+                m.cols[1].z = row.y;
+                m.cols[2].z = row.z;
+                m.cols[3].z = row.w;
+                m
+            }
+        })+
+    };
+}
+
+obliques!(Mat4 => (Vec4, f32));
+
+#[cfg(feature = "f64")]
+obliques!(DMat4 => (DVec4, f64));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    fn ndc(proj: Mat4, p: Vec3) -> Vec3 {
+        let p = proj * p.into_homogeneous_point();
+        p.xyz() / p.w
+    }
+
+    #[test]
+    fn off_center_frustum_corners() {
+        let (l, r, b, t, n, f) = (-0.2, 0.6, -0.1, 0.3, 0.5, 20.0);
+        let far_scale = f / n;
+
+        let gl = rh_yup::perspective_off_center_gl(l, r, b, t, n, f);
+        assert!(ndc(gl, Vec3::new(l, b, -n)).eq_eps(Vec3::new(-1.0, -1.0, -1.0)));
+        assert!(ndc(gl, Vec3::new(r, t, -n) * far_scale).eq_eps(Vec3::new(1.0, 1.0, 1.0)));
+
+        let vk = rh_yup::perspective_off_center_vk(l, r, b, t, n, f);
+        assert!(ndc(vk, Vec3::new(l, t, -n)).eq_eps(Vec3::new(-1.0, -1.0, 0.0)));
+
+        let lh = lh_yup::perspective_off_center_wgpu_dx(l, r, b, t, n, f);
+        assert!(ndc(lh, Vec3::new(l, b, n)).eq_eps(Vec3::new(-1.0, -1.0, 0.0)));
+        assert!(ndc(lh, Vec3::new(r, t, n) * far_scale).eq_eps(Vec3::new(1.0, 1.0, 1.0)));
+
+        let symmetric = rh_yup::perspective_off_center_gl(-0.5, 0.5, -0.5, 0.5, 0.5, f);
+        let expected = perspective_gl(core::f32::consts::FRAC_PI_2, 1.0, 0.5, f);
+        for (a, b) in symmetric.cols.iter().zip(expected.cols.iter()) {
+            assert!(a.eq_eps(*b));
+        }
+    }
+
+    #[cfg(feature = "f64")]
+    #[test]
+    fn infinite_z_f64_matches_f32() {
+        let (fov, aspect, near) = (1.2, 1.5, 0.1);
+        let pairs = [
+            (
+                rh_yup::perspective_infinite_z_gl(fov, aspect, near),
+                rh_yup::perspective_infinite_z_gl_f64(fov.into(), aspect.into(), near.into()),
+            ),
+            (
+                rh_yup::perspective_infinite_z_vk(fov, aspect, near),
+                rh_yup::perspective_infinite_z_vk_f64(fov.into(), aspect.into(), near.into()),
+            ),
+            (
+                rh_yup::perspective_infinite_z_wgpu_dx(fov, aspect, near),
+                rh_yup::perspective_infinite_z_wgpu_dx_f64(fov.into(), aspect.into(), near.into()),
+            ),
+            (
+                lh_yup::perspective_infinite_z_gl(fov, aspect, near),
+                lh_yup::perspective_infinite_z_gl_f64(fov.into(), aspect.into(), near.into()),
+            ),
+            (
+                lh_yup::perspective_infinite_z_vk(fov, aspect, near),
+                lh_yup::perspective_infinite_z_vk_f64(fov.into(), aspect.into(), near.into()),
+            ),
+            (
+                lh_yup::perspective_infinite_z_wgpu_dx(fov, aspect, near),
+                lh_yup::perspective_infinite_z_wgpu_dx_f64(fov.into(), aspect.into(), near.into()),
+            ),
+        ];
+        for (single, double) in pairs {
+            for (a, b) in single.cols.iter().zip(double.to_f32_lossy().cols.iter()) {
+                assert!(a.eq_eps(*b));
+            }
+        }
+    }
+
+    #[test]
+    fn jitter_moves_by_pixels() {
+        let viewport = Vec2::new(1920.0, 1080.0);
+        let proj = perspective_wgpu_dx(1.0, viewport.x / viewport.y, 0.1, 100.0);
+        let jittered = jittered(proj, Vec2::new(0.5, -0.25), viewport);
+
+        let p = Vec3::new(1.0, 2.0, -7.0);
+        let shift = (ndc(jittered, p) - ndc(proj, p)).xy() * viewport / 2.0;
+        assert!(shift.eq_eps(Vec2::new(0.5, -0.25)));
+    }
+
+    #[test]
+    fn oblique_near_plane() {
+        // A tilted water plane a few units in front of the camera, keeping what is beyond it.
+        let normal = Vec3::new(0.0, 0.3, -1.0).normalized();
+        let on_plane = Vec3::new(0.5, -0.5, -4.0);
+        let clip_plane = Vec4::new(normal.x, normal.y, normal.z, -normal.dot(on_plane));
+
+        let gl = perspective_infinite_z_gl(1.2, 1.5, 0.1).oblique_near_plane_gl(clip_plane);
+        let p = gl * on_plane.into_homogeneous_point();
+        assert!((p.z / p.w).eq_eps(-1.0));
+
+        let vk = perspective_vk(1.2, 1.5, 0.1, 100.0).oblique_near_plane_vk_wgpu_dx(clip_plane);
+        let p = vk * on_plane.into_homogeneous_point();
+        assert!((p.z / p.w).eq_eps(0.0));
+
+        let beyond = vk * (on_plane + normal).into_homogeneous_point();
+        assert!(beyond.z / beyond.w > 0.0 && beyond.z / beyond.w < 1.0);
+    }
+}
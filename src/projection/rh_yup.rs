@@ -17,6 +17,11 @@
 
 use crate::mat::*;
 use crate::vec::*;
+use crate::Transcendental;
+// Unused if `std` is linked in by a dependency, since the inherent methods then take precedence.
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::FloatExt;
 
 /// Orthographic projection matrix for use with OpenGL.
 ///
@@ -96,7 +101,7 @@ pub fn orthographic_wgpu_dx(
 /// left-handed and y-up with Z (depth) clip extending from -1.0 (close) to 1.0 (far).
 #[inline]
 pub fn perspective_gl(vertical_fov: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Mat4 {
-    let t = (vertical_fov / 2.0).tan();
+    let t = Transcendental::tan(vertical_fov / 2.0);
     let sy = 1.0 / t;
     let sx = sy / aspect_ratio;
     let nmf = z_near - z_far;
@@ -119,7 +124,7 @@ pub fn perspective_gl(vertical_fov: f32, aspect_ratio: f32, z_near: f32, z_far:
 /// left-handed and y-up with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
 #[inline]
 pub fn perspective_wgpu_dx(vertical_fov: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Mat4 {
-    let t = (vertical_fov / 2.0).tan();
+    let t = Transcendental::tan(vertical_fov / 2.0);
     let sy = 1.0 / t;
     let sx = sy / aspect_ratio;
     let nmf = z_near - z_far;
@@ -142,7 +147,7 @@ pub fn perspective_wgpu_dx(vertical_fov: f32, aspect_ratio: f32, z_near: f32, z_
 /// right-handed and y-down with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
 #[inline]
 pub fn perspective_vk(vertical_fov: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Mat4 {
-    let t = (vertical_fov / 2.0).tan();
+    let t = Transcendental::tan(vertical_fov / 2.0);
     let sy = 1.0 / t;
     let sx = sy / aspect_ratio;
     let nmf = z_near - z_far;
@@ -155,6 +160,104 @@ pub fn perspective_vk(vertical_fov: f32, aspect_ratio: f32, z_near: f32, z_far:
     )
 }
 
+/// Perspective projection matrix with an off-center, i.e. asymmetric, view frustum meant to be
+/// used with OpenGL.
+///
+/// `left`, `right`, `bottom` and `top` are the extents of the frustum on the near plane, which
+/// is useful for VR, where each eye's frustum is asymmetric, as well as for tiled and
+/// multi-monitor rendering.
+///
+/// This matrix is meant to be used when the source coordinate space is right-handed and y-up
+/// (the standard computer graphics coordinate space) and the destination
+/// coordinate space is left-handed and y-up with Z (depth) clip extending from -1.0 (close) to 1.0 (far).
+#[inline]
+pub fn perspective_off_center_gl(
+    left: f32,
+    right: f32,
+    bottom: f32,
+    top: f32,
+    near: f32,
+    far: f32,
+) -> Mat4 {
+    let rml = right - left;
+    let tmb = top - bottom;
+    let nmf = near - far;
+
+    Mat4::new(
+        Vec4::new(2.0 * near / rml, 0.0, 0.0, 0.0),
+        Vec4::new(0.0, 2.0 * near / tmb, 0.0, 0.0),
+        Vec4::new(
+            (right + left) / rml,
+            (top + bottom) / tmb,
+            (far + near) / nmf,
+            -1.0,
+        ),
+        Vec4::new(0.0, 0.0, 2.0 * near * far / nmf, 0.0),
+    )
+}
+
+/// Perspective projection matrix with an off-center, i.e. asymmetric, view frustum meant to be
+/// used with Vulkan.
+///
+/// `left`, `right`, `bottom` and `top` are the extents of the frustum on the near plane, which
+/// is useful for VR, where each eye's frustum is asymmetric, as well as for tiled and
+/// multi-monitor rendering.
+///
+/// This matrix is meant to be used when the source coordinate space is right-handed and y-up
+/// (the standard computer graphics coordinate space) and the destination
+/// coordinate space is right-handed and y-down with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
+#[inline]
+pub fn perspective_off_center_vk(
+    left: f32,
+    right: f32,
+    bottom: f32,
+    top: f32,
+    near: f32,
+    far: f32,
+) -> Mat4 {
+    let rml = right - left;
+    let tmb = top - bottom;
+    let nmf = near - far;
+
+    Mat4::new(
+        Vec4::new(2.0 * near / rml, 0.0, 0.0, 0.0),
+        Vec4::new(0.0, -2.0 * near / tmb, 0.0, 0.0),
+        Vec4::new((right + left) / rml, -(top + bottom) / tmb, far / nmf, -1.0),
+        Vec4::new(0.0, 0.0, near * far / nmf, 0.0),
+    )
+}
+
+/// Perspective projection matrix with an off-center, i.e. asymmetric, view frustum meant to be
+/// used with WebGPU or DirectX.
+///
+/// `left`, `right`, `bottom` and `top` are the extents of the frustum on the near plane, which
+/// is useful for VR, where each eye's frustum is asymmetric, as well as for tiled and
+/// multi-monitor rendering.
+///
+/// This matrix is meant to be used when the source coordinate space is right-handed and y-up
+/// (the standard computer graphics coordinate space) and the destination
+/// coordinate space is left-handed and y-up with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
+#[inline]
+pub fn perspective_off_center_wgpu_dx(
+    left: f32,
+    right: f32,
+    bottom: f32,
+    top: f32,
+    near: f32,
+    far: f32,
+) -> Mat4 {
+    let rml = right - left;
+    let tmb = top - bottom;
+    let nmf = near - far;
+
+    Mat4::new(
+        Vec4::new(2.0 * near / rml, 0.0, 0.0, 0.0),
+        Vec4::new(0.0, 2.0 * near / tmb, 0.0, 0.0),
+        Vec4::new((right + left) / rml, (top + bottom) / tmb, far / nmf, -1.0),
+        Vec4::new(0.0, 0.0, near * far / nmf, 0.0),
+    )
+}
+
 /// Perspective projection matrix with infinite z-far plane meant to be used with OpenGL.
 ///
 /// This is useful for extremely large scenes where having a far clip plane is extraneous anyway,
@@ -169,7 +272,7 @@ pub fn perspective_vk(vertical_fov: f32, aspect_ratio: f32, z_near: f32, z_far:
 /// left-handed and y-up with Z (depth) clip extending from -1.0 (close) to 1.0 (far).
 #[inline]
 pub fn perspective_infinite_z_gl(vertical_fov: f32, aspect_ratio: f32, z_near: f32) -> Mat4 {
-    let t = (vertical_fov / 2.0).tan();
+    let t = Transcendental::tan(vertical_fov / 2.0);
     let sy = 1.0 / t;
     let sx = sy / aspect_ratio;
 
@@ -181,6 +284,22 @@ pub fn perspective_infinite_z_gl(vertical_fov: f32, aspect_ratio: f32, z_near: f
     )
 }
 
+/// [`perspective_infinite_z_gl`] with `f64` precision.
+#[cfg(feature = "f64")]
+#[inline]
+pub fn perspective_infinite_z_gl_f64(vertical_fov: f64, aspect_ratio: f64, z_near: f64) -> DMat4 {
+    let t = Transcendental::tan(vertical_fov / 2.0);
+    let sy = 1.0 / t;
+    let sx = sy / aspect_ratio;
+
+    DMat4::new(
+        DVec4::new(sx, 0.0, 0.0, 0.0),
+        DVec4::new(0.0, sy, 0.0, 0.0),
+        DVec4::new(0.0, 0.0, -1.0, -1.0),
+        DVec4::new(0.0, 0.0, -2.0 * z_near, 0.0),
+    )
+}
+
 /// Perspective projection matrix with infinite z-far plane meant to be used with Vulkan.
 ///
 /// This is useful for extremely large scenes where having a far clip plane is extraneous anyway,
@@ -195,7 +314,7 @@ pub fn perspective_infinite_z_gl(vertical_fov: f32, aspect_ratio: f32, z_near: f
 /// right-handed and y-down with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
 #[inline]
 pub fn perspective_infinite_z_vk(vertical_fov: f32, aspect_ratio: f32, z_near: f32) -> Mat4 {
-    let t = (vertical_fov / 2.0).tan();
+    let t = Transcendental::tan(vertical_fov / 2.0);
     let sy = 1.0 / t;
     let sx = sy / aspect_ratio;
 
@@ -207,6 +326,22 @@ pub fn perspective_infinite_z_vk(vertical_fov: f32, aspect_ratio: f32, z_near: f
     )
 }
 
+/// [`perspective_infinite_z_vk`] with `f64` precision.
+#[cfg(feature = "f64")]
+#[inline]
+pub fn perspective_infinite_z_vk_f64(vertical_fov: f64, aspect_ratio: f64, z_near: f64) -> DMat4 {
+    let t = Transcendental::tan(vertical_fov / 2.0);
+    let sy = 1.0 / t;
+    let sx = sy / aspect_ratio;
+
+    DMat4::new(
+        DVec4::new(sx, 0.0, 0.0, 0.0),
+        DVec4::new(0.0, -sy, 0.0, 0.0),
+        DVec4::new(0.0, 0.0, -1.0, -1.0),
+        DVec4::new(0.0, 0.0, -z_near, 0.0),
+    )
+}
+
 /// Perspective projection matrix with infinite z-far plane meant to be used with WebGPU or DirectX.
 ///
 /// This is useful for extremely large scenes where having a far clip plane is extraneous anyway,
@@ -221,7 +356,7 @@ pub fn perspective_infinite_z_vk(vertical_fov: f32, aspect_ratio: f32, z_near: f
 /// left-handed and y-up with Z (depth) clip extending from 0.0 (close) to 1.0 (far).
 #[inline]
 pub fn perspective_infinite_z_wgpu_dx(vertical_fov: f32, aspect_ratio: f32, z_near: f32) -> Mat4 {
-    let t = (vertical_fov / 2.0).tan();
+    let t = Transcendental::tan(vertical_fov / 2.0);
     let sy = 1.0 / t;
     let sx = sy / aspect_ratio;
 
@@ -233,6 +368,26 @@ pub fn perspective_infinite_z_wgpu_dx(vertical_fov: f32, aspect_ratio: f32, z_ne
     )
 }
 
+/// [`perspective_infinite_z_wgpu_dx`] with `f64` precision.
+#[cfg(feature = "f64")]
+#[inline]
+pub fn perspective_infinite_z_wgpu_dx_f64(
+    vertical_fov: f64,
+    aspect_ratio: f64,
+    z_near: f64,
+) -> DMat4 {
+    let t = Transcendental::tan(vertical_fov / 2.0);
+    let sy = 1.0 / t;
+    let sx = sy / aspect_ratio;
+
+    DMat4::new(
+        DVec4::new(sx, 0.0, 0.0, 0.0),
+        DVec4::new(0.0, sy, 0.0, 0.0),
+        DVec4::new(0.0, 0.0, -1.0, -1.0),
+        DVec4::new(0.0, 0.0, -z_near, 0.0),
+    )
+}
+
 /// Perspective projection matrix with reversed z-axis meant to be used with WebGPU, DirectX, or OpenGL.
 ///
 /// Reversed-Z provides significantly better precision and therefore reduced z-fighting
@@ -255,7 +410,7 @@ pub fn perspective_reversed_z_wgpu_dx_gl(
     z_near: f32,
     z_far: f32,
 ) -> Mat4 {
-    let t = (vertical_fov / 2.0).tan();
+    let t = Transcendental::tan(vertical_fov / 2.0);
     let sy = 1.0 / t;
     let sx = sy / aspect_ratio;
     let nmf = z_near - z_far;
@@ -287,7 +442,7 @@ pub fn perspective_reversed_z_vk(
     z_near: f32,
     z_far: f32,
 ) -> Mat4 {
-    let t = (vertical_fov / 2.0).tan();
+    let t = Transcendental::tan(vertical_fov / 2.0);
     let sy = 1.0 / t;
     let sx = sy / aspect_ratio;
     let nmf = z_near - z_far;
@@ -327,7 +482,7 @@ pub fn perspective_reversed_infinite_z_wgpu_dx_gl(
     aspect_ratio: f32,
     z_near: f32,
 ) -> Mat4 {
-    let t = (vertical_fov / 2.0).tan();
+    let t = Transcendental::tan(vertical_fov / 2.0);
     let sy = 1.0 / t;
     let sx = sy / aspect_ratio;
 
@@ -363,7 +518,7 @@ pub fn perspective_reversed_infinite_z_vk(
     aspect_ratio: f32,
     z_near: f32,
 ) -> Mat4 {
-    let t = (vertical_fov / 2.0).tan();
+    let t = Transcendental::tan(vertical_fov / 2.0);
     let sy = 1.0 / t;
     let sx = sy / aspect_ratio;
 
@@ -374,3 +529,19 @@ pub fn perspective_reversed_infinite_z_vk(
         Vec4::new(0.0, 0.0, z_near, 0.0),
     )
 }
+
+fov_radians!(f32, Radians, Mat4;
+    perspective_gl_radians => perspective_gl(aspect_ratio, z_near, z_far),
+    perspective_wgpu_dx_radians => perspective_wgpu_dx(aspect_ratio, z_near, z_far),
+    perspective_vk_radians => perspective_vk(aspect_ratio, z_near, z_far),
+    perspective_infinite_z_gl_radians => perspective_infinite_z_gl(aspect_ratio, z_near),
+    perspective_infinite_z_vk_radians => perspective_infinite_z_vk(aspect_ratio, z_near),
+    perspective_infinite_z_wgpu_dx_radians => perspective_infinite_z_wgpu_dx(aspect_ratio, z_near)
+);
+
+#[cfg(feature = "f64")]
+fov_radians!(f64, DRadians, DMat4;
+    perspective_infinite_z_gl_f64_radians => perspective_infinite_z_gl_f64(aspect_ratio, z_near),
+    perspective_infinite_z_vk_f64_radians => perspective_infinite_z_vk_f64(aspect_ratio, z_near),
+    perspective_infinite_z_wgpu_dx_f64_radians => perspective_infinite_z_wgpu_dx_f64(aspect_ratio, z_near)
+);
@@ -0,0 +1,36 @@
+//! Rays, i.e. half-lines with an origin and a direction.
+use crate::*;
+
+macro_rules! rays {
+    ($($n:ident => ($vt:ident, $t:ident)),+) => {
+        $(/// A ray in 3d space, starting at `origin` and extending infinitely along `direction`.
+        ///
+        /// `direction` is not required to be normalized, but functions in this crate which
+        /// produce rays will always return one with a normalized direction.
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[repr(C)]
+        pub struct $n {
+            pub origin: $vt,
+            pub direction: $vt,
+        }
+
+        impl $n {
+            #[inline]
+            pub const fn new(origin: $vt, direction: $vt) -> Self {
+                Self { origin, direction }
+            }
+
+            /// The point at parameter `t` along the ray, i.e. `origin + direction * t`.
+            #[inline]
+            pub fn at(&self, t: $t) -> $vt {
+                self.origin + self.direction * t
+            }
+        }
+        )+
+    };
+}
+
+rays!(Ray3 => (Vec3, f32));
+
+#[cfg(feature = "f64")]
+rays!(DRay3 => (DVec3, f64));
@@ -54,7 +54,8 @@
 use crate::util::*;
 use crate::*;
 
-use std::ops::*;
+use core::ops::*;
+use wide::CmpLe;
 
 macro_rules! rotor2s {
     ($($rn:ident => ($mt:ident, $vt:ident, $bt:ident, $t:ident)),+) => {
@@ -81,9 +82,9 @@ macro_rules! rotor2s {
             }
 
             #[inline]
-            pub fn identity() -> Self {
+            pub const fn identity() -> Self {
                 Self {
-                    s: $t::splat(1.0),
+                    s: <$t as Consts>::ONE,
                     bv: $bt::zero(),
                 }
             }
@@ -107,7 +108,7 @@ macro_rules! rotor2s {
             #[inline]
             pub fn from_angle_plane(angle: $t, plane: $bt) -> Self {
                 let half_angle = angle * $t::splat(0.5);
-                let (sin, cos) = half_angle.sin_cos();
+                let (sin, cos) = Transcendental::sin_cos(half_angle);
                 Self::new(cos, plane * -sin)
             }
             /// Construct a rotor given only an angle. This is possible in 2d since there is only one
@@ -117,7 +118,7 @@ macro_rules! rotor2s {
             #[inline]
             pub fn from_angle(angle: $t) -> Self {
                 let half_angle = angle / $t::splat(2.0);
-                let (sin, cos) = half_angle.sin_cos();
+                let (sin, cos) = Transcendental::sin_cos(half_angle);
                 Self::new(cos, $bt::new(-sin))
             }
 
@@ -171,7 +172,7 @@ macro_rules! rotor2s {
             pub fn rotate_by(&mut self, other: Self) {
                 let b = *self;
                 let a = other;
-                let sa2_plus_baxy2 = a.s.mul_add(a.s, a.bv.xy * a.bv.xy);
+                let sa2_plus_baxy2 = a.s.fused_mul_add(a.s, a.bv.xy * a.bv.xy);
 
                 self.s = (a.s - b.s) * a.bv.xy * b.bv.xy
                     + b.s * sa2_plus_baxy2;
@@ -200,6 +201,39 @@ macro_rules! rotor2s {
                 vec.y = self.s * fy - (self.bv.xy * fx);
             }
 
+            /// Rotates multiple vectors by this rotor.
+            ///
+            /// This is slightly faster than calling `rotate_vec` on each one since the
+            /// rotation matrix only needs to be computed once.
+            ///
+            /// `self` *must* be normalized!
+            #[inline]
+            pub fn rotate_vecs(self, vecs: &mut [$vt]) {
+                let s2_minus_bxy2 = self.s * self.s - self.bv.xy * self.bv.xy;
+                let two_s_bxy = $t::splat(2.0) * self.s * self.bv.xy;
+
+                for vec in vecs {
+                    let x = vec.x;
+                    vec.x = s2_minus_bxy2 * x + two_s_bxy * vec.y;
+                    vec.y = s2_minus_bxy2 * vec.y - two_s_bxy * x;
+                }
+            }
+
+            /// Return the signed angle of the rotation represented by `self`, between `-PI` and
+            /// `PI`, using the same orientation convention as `from_angle`.
+            ///
+            /// A rotation by more than `PI` is returned as the equivalent rotation by less than
+            /// `PI` the other way around, e.g. `3.0 * PI / 2.0` becomes `-PI / 2.0`.
+            ///
+            /// `self` *must* be normalized!
+            #[inline]
+            pub fn angle(&self) -> $t {
+                // The sine and cosine of the full angle, which are the same for `self` and `-self`.
+                let sin = $t::splat(-2.0) * self.s * self.bv.xy;
+                let cos = self.s * self.s - self.bv.xy * self.bv.xy;
+                Transcendental::atan2(sin, cos)
+            }
+
             #[inline]
             pub fn into_matrix(self) -> $mt {
                 let s2_minus_bxy2 = self.s * self.s - self.bv.xy * self.bv.xy;
@@ -216,7 +250,7 @@ macro_rules! rotor2s {
 
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$t>()).unwrap()
             }
         }
 
@@ -291,6 +325,13 @@ macro_rules! rotor2s {
             }
         }
 
+        impl MulAssign for $rn {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
         impl MulAssign<$t> for $rn {
             #[inline]
             fn mul_assign(&mut self, rhs: $t) {
@@ -342,6 +383,9 @@ rotor2s!(
     Rotor2x8 => (Mat2x8, Vec2x8, Bivec2x8, f32x8)
 );
 
+#[cfg(feature = "portable-simd")]
+rotor2s!(Rotor2x16 => (Mat2x16, Vec2x16, Bivec2x16, f32x16));
+
 #[cfg(feature = "f64")]
 rotor2s!(
     DRotor2 => (DMat2, DVec2, DBivec2, f64),
@@ -374,9 +418,9 @@ macro_rules! rotor3s {
             }
 
             #[inline]
-            pub fn identity() -> Self {
+            pub const fn identity() -> Self {
                 Self {
-                    s: $t::splat(1.0),
+                    s: <$t as Consts>::ONE,
                     bv: $bt::zero(),
                 }
             }
@@ -398,7 +442,7 @@ macro_rules! rotor3s {
             #[inline]
             pub fn from_angle_plane(angle: $t, plane: $bt) -> Self {
                 let half_angle = angle * $t::splat(0.5);
-                let (sin, cos) = half_angle.sin_cos();
+                let (sin, cos) = Transcendental::sin_cos(half_angle);
                 Self::new(cos, plane * -sin)
             }
 
@@ -408,7 +452,7 @@ macro_rules! rotor3s {
             pub fn into_angle_plane(self) -> ($t, $bt) {
                 let cos_half_angle = self.s;
                 let sin_half_angle = self.bv.mag();
-                let half_angle = sin_half_angle.atan2(cos_half_angle);
+                let half_angle = Transcendental::atan2(sin_half_angle, cos_half_angle);
                 (half_angle * 2., -self.bv.normalized())
 
             }
@@ -428,6 +472,39 @@ macro_rules! rotor3s {
                 Self::from_angle_plane(angle * scale, plane)
             }
 
+            /// Return the rotation `self` with its angle limited to at most `max_angle`, which
+            /// should be between 0 and PI, keeping its plane. Useful for joint limits.
+            ///
+            /// `self` should be normalized. The shortest way around is used, i.e. `self` and
+            /// `-self` give the same result.
+            #[inline]
+            #[must_use]
+            pub fn clamped_angle(self, max_angle: $t) -> Self {
+                let sign = $t::splat(1.0).copysign(self.s);
+                let s = self.s * sign;
+                let bv = self.bv * sign;
+                let (sin_max, cos_max) = Transcendental::sin_cos(max_angle * $t::splat(0.5));
+                // Within the limit, `bv.mag() <= sin_max` and this scales by exactly one.
+                let scale = sin_max / bv.mag().max(sin_max).max($t::splat(1e-30));
+                Self::new(s.max(cos_max), bv * scale)
+            }
+
+            /// Return the part of the rotation `self` which lies within `plane`, i.e. the twist
+            /// of a swing-twist decomposition, so that the result only rotates within that plane.
+            /// Useful for turrets and hinges.
+            ///
+            /// `self` and `plane` should be normalized. If `self` is a half turn in a plane
+            /// perpendicular to `plane`, the result is the identity rotation.
+            #[inline]
+            #[must_use]
+            pub fn constrained_to_plane(self, plane: $bt) -> Self {
+                let bv = plane * self.bv.dot(plane);
+                let mag = (self.s * self.s + bv.mag_sq()).sqrt();
+                let inv = $t::splat(1.0) / mag.max($t::splat(1e-30));
+                // Adds one to the scalar part only when `mag` is zero, giving the identity.
+                Self::new(self.s * inv + ($t::splat(1.0) - mag * inv), bv * inv)
+            }
+
             /// Create new Rotor from a rotation in the xy plane (also known as
             /// "around the z axis").
             #[inline]
@@ -461,6 +538,24 @@ macro_rules! rotor3s {
                     * Self::from_angle_plane(roll, $bt::unit_xy())
             }
 
+            /// Construct a rotor which orients a billboard placed at `object_pos` so that
+            /// it fully faces `camera_pos`.
+            ///
+            /// See [`Mat3::billboard_spherical`] for details.
+            #[inline]
+            pub fn billboard_spherical(object_pos: $vt, camera_pos: $vt, camera_up: $vt) -> Self {
+                $mt::billboard_spherical(object_pos, camera_pos, camera_up).into_rotor3()
+            }
+
+            /// Construct a rotor which orients a billboard placed at `object_pos` so that
+            /// it faces `camera_pos` while only rotating around the world y axis.
+            ///
+            /// See [`Mat3::billboard_cylindrical`] for details.
+            #[inline]
+            pub fn billboard_cylindrical(object_pos: $vt, camera_pos: $vt) -> Self {
+                $mt::billboard_cylindrical(object_pos, camera_pos).into_rotor3()
+            }
+
             #[inline]
             pub fn mag_sq(&self) -> $t {
                 self.s * self.s + self.bv.mag_sq()
@@ -488,6 +583,29 @@ macro_rules! rotor3s {
                 s
             }
 
+            /// Renormalize `self` in place with a first order approximation of the inverse
+            /// square root, which avoids the square root and division of [`Self::normalize`].
+            ///
+            /// This is only accurate if `self` is already close to normalized, so it is meant
+            /// for correcting the drift that accumulates when rotors are composed or integrated
+            /// every frame, not for normalizing arbitrary rotors. The error after renormalizing
+            /// is roughly the square of the error before.
+            #[inline]
+            pub fn normalize_fast(&mut self) {
+                let scale = ($t::splat(3.0) - self.mag_sq()) * $t::splat(0.5);
+                self.s *= scale;
+                self.bv *= scale;
+            }
+
+            /// `self` renormalized with [`Self::normalize_fast`].
+            #[inline]
+            #[must_use = "Did you mean to use `.normalize_fast()` to normalize `self` in place?"]
+            pub fn normalized_fast(&self) -> Self {
+                let mut s = *self;
+                s.normalize_fast();
+                s
+            }
+
             #[inline]
             pub fn reverse(&mut self) {
                 self.bv = -self.bv;
@@ -664,6 +782,9 @@ macro_rules! rotor3s {
 
             /// Convert an array that represents a quaternion in the form `[vector, scalar]` into a
             /// rotor.
+            ///
+            /// This is the `[x, y, z, w]` layout used by glTF and most engines, and a right handed
+            /// quaternion rotates vectors the same way as the resulting rotor.
             #[inline]
             pub fn from_quaternion_array(array: [$t; 4]) -> Self {
                 Self::new(array[3], $bt::new(-array[2], array[1], -array[0]))
@@ -671,7 +792,7 @@ macro_rules! rotor3s {
 
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$t>()).unwrap()
             }
         }
 
@@ -752,6 +873,13 @@ macro_rules! rotor3s {
             }
         }
 
+        impl MulAssign for $rn {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
         impl MulAssign<$t> for $rn {
             #[inline]
             fn mul_assign(&mut self, rhs: $t) {
@@ -797,12 +925,37 @@ macro_rules! rotor3s {
     }
 }
 
+macro_rules! rotor3_is_normalized {
+    (scalar: $($rn:ident => $t:ident),+) => {
+        $(impl $rn {
+            /// Whether the squared magnitude of `self` is within `eps` of one.
+            #[inline]
+            pub fn is_normalized(&self, eps: $t) -> bool {
+                (self.mag_sq() - 1.0).abs() <= eps
+            }
+        })+
+    };
+    (wide: $($rn:ident => ($t:ident, $maskt:ident)),+) => {
+        $(impl $rn {
+            /// Whether the squared magnitude of `self` is within `eps` of one, as a mask which
+            /// is set in each lane for which this holds.
+            #[inline]
+            pub fn is_normalized(&self, eps: $t) -> $maskt {
+                (self.mag_sq() - $t::splat(1.0)).abs().cmp_le(eps)
+            }
+        })+
+    };
+}
+
 rotor3s!(
     Rotor3 => (Mat3, Vec3, Bivec3, f32),
     Rotor3x4 => (Mat3x4, Vec3x4, Bivec3x4, f32x4),
     Rotor3x8 => (Mat3x8, Vec3x8, Bivec3x8, f32x8)
 );
 
+#[cfg(feature = "portable-simd")]
+rotor3s!(Rotor3x16 => (Mat3x16, Vec3x16, Bivec3x16, f32x16));
+
 #[cfg(feature = "f64")]
 rotor3s!(
     DRotor3 => (DMat3, DVec3, DBivec3, f64),
@@ -810,6 +963,127 @@ rotor3s!(
     DRotor3x4 => (DMat3x4, DVec3x4, DBivec3x4, f64x4)
 );
 
+rotor3_is_normalized!(scalar: Rotor3 => f32);
+rotor3_is_normalized!(wide: Rotor3x4 => (f32x4, m32x4), Rotor3x8 => (f32x8, m32x8));
+#[cfg(feature = "portable-simd")]
+rotor3_is_normalized!(wide: Rotor3x16 => (f32x16, m32x16));
+
+#[cfg(feature = "f64")]
+rotor3_is_normalized!(scalar: DRotor3 => f64);
+#[cfg(feature = "f64")]
+rotor3_is_normalized!(wide: DRotor3x2 => (f64x2, m64x2), DRotor3x4 => (f64x4, m64x4));
+
+macro_rules! rotor3_wide_conversions {
+    ($($rn:ident => ($nonwidet:ident, $bt:ident, $t:ident, $lanes:literal)),+) => {
+        $(impl $rn {
+            /// A wide rotor with `rotor` in every lane.
+            #[inline]
+            pub fn splat(rotor: $nonwidet) -> Self {
+                Self::new(
+                    $t::splat(rotor.s),
+                    $bt::new($t::splat(rotor.bv.xy), $t::splat(rotor.bv.xz), $t::splat(rotor.bv.yz)),
+                )
+            }
+        }
+
+        impl From<[$nonwidet; $lanes]> for $rn {
+            #[inline]
+            fn from(rotors: [$nonwidet; $lanes]) -> Self {
+                Self::new(
+                    $t::from(rotors.map(|r| r.s)),
+                    $bt::new(
+                        $t::from(rotors.map(|r| r.bv.xy)),
+                        $t::from(rotors.map(|r| r.bv.xz)),
+                        $t::from(rotors.map(|r| r.bv.yz)),
+                    ),
+                )
+            }
+        })+
+    };
+}
+
+rotor3_wide_conversions!(
+    Rotor3x4 => (Rotor3, Bivec3x4, f32x4, 4),
+    Rotor3x8 => (Rotor3, Bivec3x8, f32x8, 8)
+);
+
+#[cfg(feature = "portable-simd")]
+rotor3_wide_conversions!(Rotor3x16 => (Rotor3, Bivec3x16, f32x16, 16));
+
+#[cfg(feature = "f64")]
+rotor3_wide_conversions!(
+    DRotor3x2 => (DRotor3, DBivec3x2, f64x2, 2),
+    DRotor3x4 => (DRotor3, DBivec3x4, f64x4, 4)
+);
+
+crate::util::impl_halves!(Rotor3x8 => Rotor3x4: (s, bv));
+
+#[cfg(feature = "portable-simd")]
+crate::util::impl_halves!(Rotor3x16 => Rotor3x8: (s, bv));
+
+#[cfg(feature = "f64")]
+crate::util::impl_halves!(DRotor3x4 => DRotor3x2: (s, bv));
+
+macro_rules! rotor3_average {
+    ($($rn:ident => ($vt:ident, $mt:ident, $bt:ident, $t:ident)),+) => {
+        $(impl $rn {
+            /// The average of `rotors`, each weighted by the accompanying weight, e.g. for blending
+            /// several animation sources or fusing sensor readings.
+            ///
+            /// This is the rotation which minimizes the weighted sum of squared chordal distances
+            /// to the inputs, found as the dominant eigenvector of the sum of the weighted outer
+            /// products of the rotors (Markley et al., "Averaging Quaternions"). Unlike a
+            /// normalized weighted sum, it doesn't depend on the order or the sign of the inputs.
+            ///
+            /// Weights should not be negative. Returns the identity if `rotors` is empty or all
+            /// weights are zero.
+            pub fn weighted_average(rotors: &[($rn, $t)]) -> Self {
+                Self::average_of(rotors.iter().copied())
+            }
+
+            /// The average of `rotors` with equal weights, see [`Self::weighted_average`].
+            pub fn mean(rotors: &[$rn]) -> Self {
+                Self::average_of(rotors.iter().map(|r| (*r, 1.0)))
+            }
+
+            fn average_of(rotors: impl Iterator<Item = ($rn, $t)> + Clone) -> Self {
+                let as_vec = |r: $rn| $vt::new(r.s, r.bv.xy, r.bv.xz, r.bv.yz);
+
+                // Accumulate the outer products, along with a weighted sum of the rotors flipped
+                // into the same hemisphere as a first guess of the eigenvector.
+                let mut m = $mt::new($vt::zero(), $vt::zero(), $vt::zero(), $vt::zero());
+                let mut guess = $vt::zero();
+                for (r, w) in rotors {
+                    let v = as_vec(r);
+                    m += $mt::new(v * (v.x * w), v * (v.y * w), v * (v.z * w), v * (v.w * w));
+                    guess += if guess.dot(v) < 0.0 { -v * w } else { v * w };
+                }
+                if guess == $vt::zero() {
+                    return Self::identity();
+                }
+
+                // Power iteration, which converges quickly from the first guess when the
+                // rotations are close to each other.
+                let mut v = guess.normalized();
+                for _ in 0..64 {
+                    let next = (m * v).normalized();
+                    let converged = (next - v).mag_sq() < $t::EPSILON * $t::EPSILON;
+                    v = next;
+                    if converged {
+                        break;
+                    }
+                }
+                Self::new(v.x, $bt::new(v.y, v.z, v.w))
+            }
+        })+
+    };
+}
+
+rotor3_average!(Rotor3 => (Vec4, Mat4, Bivec3, f32));
+
+#[cfg(feature = "f64")]
+rotor3_average!(DRotor3 => (DVec4, DMat4, DBivec3, f64));
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -901,7 +1175,7 @@ mod test {
 
     #[test]
     pub fn rotor_scaling() {
-        use std::f32::consts::PI;
+        use core::f32::consts::PI;
 
         let axis = Vec3::new(0.42, 0.123, 0.789).normalized(); //aribitrary rotation axis
         let plane = Bivec3::from_normalized_axis(axis).normalized();
@@ -917,6 +1191,81 @@ mod test {
         assert!(scaled_rotor_1.eq_eps(scaled_rotor_2));
     }
 
+    #[test]
+    pub fn rotor_constraints() {
+        let plane = Bivec3::from_normalized_axis(Vec3::new(1.0, 2.0, -2.0).normalized());
+        let rotor = Rotor3::from_angle_plane(2.0, plane);
+        assert!(rotor
+            .clamped_angle(0.5)
+            .eq_eps(Rotor3::from_angle_plane(0.5, plane)));
+        assert!((rotor * -1.0)
+            .clamped_angle(0.5)
+            .eq_eps(Rotor3::from_angle_plane(0.5, plane)));
+        assert_eq!(rotor.clamped_angle(2.5), rotor);
+        assert_eq!(Rotor3::identity().clamped_angle(0.0), Rotor3::identity());
+
+        let yaw = Rotor3::from_rotation_xz(0.7);
+        let swing = Rotor3::from_rotation_xy(0.4);
+        let twist = (yaw * swing).constrained_to_plane(Bivec3::unit_xz());
+        assert!(twist.mag().eq_eps(1.0));
+        assert!(twist.bv.xy.eq_eps(0.0) && twist.bv.yz.eq_eps(0.0));
+        assert!(yaw.constrained_to_plane(Bivec3::unit_xz()).eq_eps(yaw));
+        let half_turn = Rotor3::from_rotation_yz(core::f32::consts::PI);
+        let twist = half_turn.constrained_to_plane(Bivec3::unit_xy());
+        assert!(twist.dot(Rotor3::identity()).abs().eq_eps(1.0));
+        let exact = Rotor3::new(0.0, Bivec3::unit_yz()).constrained_to_plane(Bivec3::unit_xy());
+        assert_eq!(exact, Rotor3::identity());
+
+        let wide = Rotor3x4::splat(rotor).clamped_angle(f32x4::from([0.5, 1.0, 2.0, 3.0]));
+        let (angles, _) = wide.into_angle_plane();
+        let angles: [f32; 4] = angles.into();
+        for (angle, expected) in angles.iter().zip([0.5, 1.0, 2.0, 2.0]) {
+            assert!(angle.eq_eps(expected));
+        }
+    }
+
+    #[test]
+    pub fn rotor_averaging() {
+        let a = Rotor3::from_rotation_xy(0.2);
+        let b = Rotor3::from_rotation_xy(0.6);
+        // The sign of a rotor doesn't change the rotation it represents, or the average.
+        let mean = Rotor3::mean(&[a, b * -1.0]);
+        assert!(mean.dot(Rotor3::from_rotation_xy(0.4)).abs().eq_eps(1.0));
+
+        let weighted = Rotor3::weighted_average(&[(a, 3.0), (b, 1.0)]);
+        let expected = Rotor3::from_rotation_xy(0.3);
+        assert!(weighted.dot(expected).abs() > 1.0 - 1e-5);
+
+        let spread = [
+            Rotor3::from_euler_angles(0.3, -0.2, 0.1),
+            Rotor3::from_euler_angles(-0.1, 0.4, 0.2),
+            Rotor3::from_euler_angles(0.2, 0.1, -0.5),
+        ];
+        let mean = Rotor3::mean(&spread);
+        assert!(mean.mag().eq_eps(1.0));
+        let reversed = Rotor3::mean(&[spread[2], spread[1], spread[0]]);
+        assert!(mean.dot(reversed).abs().eq_eps(1.0));
+
+        assert_eq!(Rotor3::mean(&[]), Rotor3::identity());
+        assert_eq!(Rotor3::weighted_average(&[(a, 0.0)]), Rotor3::identity());
+    }
+
+    #[test]
+    pub fn billboards_face_camera() {
+        let object = Vec3::new(1.0, 2.0, 3.0);
+        let camera = Vec3::new(-4.0, 5.0, 10.0);
+        let to_camera = (camera - object).normalized();
+
+        let spherical = Rotor3::billboard_spherical(object, camera, Vec3::unit_y());
+        assert!((spherical * Vec3::unit_z()).eq_eps(to_camera));
+        assert!((spherical * Vec3::unit_x()).y.eq_eps(0.0));
+
+        let cylindrical = Rotor3::billboard_cylindrical(object, camera);
+        let flat = Vec3::new(to_camera.x, 0.0, to_camera.z).normalized();
+        assert!((cylindrical * Vec3::unit_z()).eq_eps(flat));
+        assert!((cylindrical * Vec3::unit_y()).eq_eps(Vec3::unit_y()));
+    }
+
     // This test exists because Rotor3 used to implement PartialEq without DRotor3 getting the same
     // impl. Use `cargo test --all-features` to run
     #[cfg(feature = "f64")]
@@ -926,4 +1275,75 @@ mod test {
         let i = DRotor3::identity();
         assert_eq!(i, i);
     }
+
+    #[test]
+    pub fn rotor2_angle_and_batch_rotate() {
+        for &angle in &[0.0f32, 0.5, -1.2, 3.0] {
+            let rotor = Rotor2::from_angle(angle);
+            assert!((rotor.angle() - angle).abs() < 1e-5);
+        }
+
+        // Rotations by more than PI are wrapped to the equivalent one the other way around.
+        use core::f32::consts::PI;
+        for &(angle, wrapped) in &[
+            (1.5 * PI, -0.5 * PI),
+            (-1.5 * PI, 0.5 * PI),
+            (1.9 * PI, -0.1 * PI),
+        ] {
+            let rotor = Rotor2::from_angle(angle);
+            assert!(rotor.s < 0.0);
+            assert!((rotor.angle() - wrapped).abs() < 1e-5);
+            assert!((rotor * Vec2::unit_x()).eq_eps(Rotor2::from_angle(wrapped) * Vec2::unit_x()));
+        }
+
+        let a = Vec2::new(1.0, 2.0).normalized();
+        let b = Vec2::new(-3.0, 1.0).normalized();
+        let rotor = Rotor2::from_rotation_between(a, b);
+        assert!(Rotor2::from_angle(rotor.angle()).eq_eps(rotor));
+
+        let mut vecs = [a, Vec2::unit_x(), Vec2::new(-2.0, 0.5)];
+        rotor.rotate_vecs(&mut vecs);
+        assert!(vecs[0].eq_eps(b));
+        for (rotated, original) in vecs.iter().zip([a, Vec2::unit_x(), Vec2::new(-2.0, 0.5)]) {
+            assert!(rotated.eq_eps(rotor * original));
+        }
+    }
+
+    #[test]
+    pub fn fast_renormalization() {
+        let rotor = Rotor3::from_euler_angles(0.3, -1.2, 2.0);
+        let drifted = rotor * 1.001;
+        assert!(!drifted.is_normalized(1e-4));
+        let fixed = drifted.normalized_fast();
+        assert!(fixed.is_normalized(1e-5));
+        assert!(fixed.eq_eps(rotor));
+
+        let wide = Rotor3x4::splat(drifted).normalized_fast();
+        assert!(wide.is_normalized(f32x4::splat(1e-5)).all());
+    }
+
+    #[cfg(feature = "portable-simd")]
+    #[test]
+    fn rotor3x16_matches_scalar() {
+        let rotors: [Rotor3; 16] = core::array::from_fn(|i| {
+            let t = i as f32;
+            Rotor3::from_euler_angles(0.3 * t, -0.2 * t, 1.0 - 0.1 * t)
+        });
+        let vecs: [Vec3; 16] = core::array::from_fn(|i| Vec3::new(1.0, i as f32, -2.0));
+        let wide = Rotor3x16::from(rotors);
+        assert!(wide.is_normalized(f32x16::splat(1e-5)).all());
+
+        let rotated: [Vec3; 16] = (wide * Vec3x16::from(vecs)).into();
+        let composed: [Vec3; 16] = ((wide * wide.reversed()) * Vec3x16::from(vecs)).into();
+        let matrices = wide.into_matrix();
+        for i in 0..16 {
+            assert!(rotated[i].eq_eps(rotors[i] * vecs[i]));
+            assert!(composed[i].eq_eps(vecs[i]));
+            let col: [Vec3; 16] = matrices.cols[0].into();
+            assert!(col[i].eq_eps(rotors[i].into_matrix().cols[0]));
+        }
+
+        let halves: [Rotor3x8; 2] = wide.into();
+        assert_eq!(Rotor3x16::from(halves), wide);
+    }
 }
@@ -0,0 +1,350 @@
+//! Sampling of random directions, points and rotations.
+//!
+//! Each sampling function comes in two flavors. The `*_from_uniform` functions are pure
+//! mappings which take uniformly distributed random numbers in `[0, 1)` and warp them into the
+//! desired distribution, which lets you bring your own (quasi-)random number source, including
+//! one that produces SIMD random numbers for the wide types. The `sample_*` functions simply
+//! draw those numbers from a [`rand::Rng`].
+//!
+//! In addition, the vector and rotor types implement [`Distribution`] for [`Standard`], so that
+//! e.g. `rng.gen::<Vec3>()` works. For vectors this samples each component uniformly in
+//! `[0, 1)`, while for rotors it samples a uniformly distributed rotation. The [`UnitVector`]
+//! and [`UnitRotor`] distributions can be used to make the intent explicit.
+use crate::*;
+
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+/// A distribution over uniformly distributed unit vectors, i.e. directions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnitVector;
+
+/// A distribution over uniformly distributed normalized rotors, i.e. rotations.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnitRotor;
+
+macro_rules! samples {
+    ($($tt:ident => ($v2t:ident, $vt:ident, $rt:ident, $t:ident)),+) => {
+        $(impl $v2t {
+            /// Map a uniform random point in `[0, 1)^2` to a uniformly distributed point
+            /// inside the unit disk.
+            #[inline]
+            pub fn disk_from_uniform(u: $v2t) -> Self {
+                let r = u.x.sqrt();
+                let (s, c) = Transcendental::sin_cos(u.y * $t::splat(core::$tt::consts::TAU));
+                Self::new(r * c, r * s)
+            }
+
+            /// Sample a uniformly distributed point inside the unit disk.
+            #[inline]
+            pub fn sample_disk<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::disk_from_uniform(rng.gen())
+            }
+        }
+
+        impl $vt {
+            /// Map a uniform random point in `[0, 1)^2` to a uniformly distributed
+            /// direction, i.e. a point on the surface of the unit sphere.
+            #[inline]
+            pub fn unit_sphere_from_uniform(u: $v2t) -> Self {
+                let z = $t::splat(1.0) - $t::splat(2.0) * u.x;
+                let r = ($t::splat(1.0) - z * z).max($t::splat(0.0)).sqrt();
+                let (s, c) = Transcendental::sin_cos(u.y * $t::splat(core::$tt::consts::TAU));
+                Self::new(r * c, r * s, z)
+            }
+
+            /// Sample a uniformly distributed direction, i.e. a point on the surface of
+            /// the unit sphere.
+            #[inline]
+            pub fn sample_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::unit_sphere_from_uniform(rng.gen())
+            }
+
+            /// Map a uniform random point in `[0, 1)^2` to a uniformly distributed direction
+            /// in the hemisphere around `normal`.
+            ///
+            /// `normal` need not be normalized.
+            #[inline]
+            pub fn hemisphere_from_uniform(u: $v2t, normal: Self) -> Self {
+                let v = Self::unit_sphere_from_uniform(u);
+                Self::flip_into_hemisphere(v, normal)
+            }
+
+            /// Sample a uniformly distributed direction in the hemisphere around `normal`.
+            ///
+            /// `normal` need not be normalized.
+            #[inline]
+            pub fn sample_hemisphere<R: Rng + ?Sized>(normal: Self, rng: &mut R) -> Self {
+                Self::flip_into_hemisphere(Self::sample_unit_sphere(rng), normal)
+            }
+
+            /// Map a uniform random point in `[0, 1)^2` to a cosine-weighted direction in the
+            /// hemisphere around `normal`, i.e. one distributed proportionally to the cosine of
+            /// its angle with `normal`.
+            ///
+            /// `normal` *must* be normalized.
+            #[inline]
+            pub fn cosine_hemisphere_from_uniform(u: $v2t, normal: Self) -> Self {
+                // a point on the unit sphere tangent to the surface at the normal,
+                // projected back onto the unit sphere around the origin, is cosine distributed
+                (normal + Self::unit_sphere_from_uniform(u)).normalized()
+            }
+
+            /// Sample a cosine-weighted direction in the hemisphere around `normal`, i.e. one
+            /// distributed proportionally to the cosine of its angle with `normal`.
+            ///
+            /// `normal` *must* be normalized.
+            #[inline]
+            pub fn sample_cosine_hemisphere<R: Rng + ?Sized>(normal: Self, rng: &mut R) -> Self {
+                (normal + Self::sample_unit_sphere(rng)).normalized()
+            }
+        }
+
+        impl $rt {
+            /// Map a uniform random point in `[0, 1)^3` to a uniformly distributed rotation.
+            #[inline]
+            pub fn uniform_from_uniform(u: $vt) -> Self {
+                // see Shoemake, "Uniform random rotations", Graphics Gems III
+                let a = ($t::splat(1.0) - u.x).sqrt();
+                let b = u.x.sqrt();
+                let (s1, c1) = Transcendental::sin_cos(u.y * $t::splat(core::$tt::consts::TAU));
+                let (s2, c2) = Transcendental::sin_cos(u.z * $t::splat(core::$tt::consts::TAU));
+                Self::from_quaternion_array([a * s1, a * c1, b * s2, b * c2])
+            }
+
+            /// Sample a uniformly distributed rotation.
+            #[inline]
+            pub fn sample_uniform<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                Self::uniform_from_uniform(rng.gen())
+            }
+        })+
+    };
+}
+
+/// Drawing of uniform random numbers in `[0, 1)` for every lane of a scalar or wide type.
+trait GenUniform {
+    fn gen_uniform<R: Rng + ?Sized>(rng: &mut R) -> Self;
+}
+
+macro_rules! impl_gen_uniform {
+    ($($t:ident => $tt:ident, $lanes:expr),+) => {
+        $(impl GenUniform for $t {
+            #[inline]
+            fn gen_uniform<R: Rng + ?Sized>(rng: &mut R) -> Self {
+                $t::from(rng.gen::<[$tt; $lanes]>())
+            }
+        })+
+    };
+}
+
+impl GenUniform for f32 {
+    #[inline]
+    fn gen_uniform<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+}
+
+impl GenUniform for f64 {
+    #[inline]
+    fn gen_uniform<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+}
+
+impl_gen_uniform!(f32x4 => f32, 4, f32x8 => f32, 8, f64x2 => f64, 2, f64x4 => f64, 4);
+
+macro_rules! distributions {
+    ($($tt:ident => ($v2t:ident, $vt:ident, $v4t:ident, $r2t:ident, $rt:ident, $t:ident)),+) => {
+        $(impl Distribution<$v2t> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $v2t {
+                $v2t::new($t::gen_uniform(rng), $t::gen_uniform(rng))
+            }
+        }
+
+        impl Distribution<$vt> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vt {
+                $vt::new($t::gen_uniform(rng), $t::gen_uniform(rng), $t::gen_uniform(rng))
+            }
+        }
+
+        impl Distribution<$v4t> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $v4t {
+                $v4t::new(
+                    $t::gen_uniform(rng),
+                    $t::gen_uniform(rng),
+                    $t::gen_uniform(rng),
+                    $t::gen_uniform(rng),
+                )
+            }
+        }
+
+        impl Distribution<$r2t> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $r2t {
+                UnitRotor.sample(rng)
+            }
+        }
+
+        impl Distribution<$rt> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $rt {
+                UnitRotor.sample(rng)
+            }
+        }
+
+        impl Distribution<$v2t> for UnitVector {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $v2t {
+                let (s, c) = Transcendental::sin_cos($t::gen_uniform(rng) * $t::splat(core::$tt::consts::TAU));
+                $v2t::new(c, s)
+            }
+        }
+
+        impl Distribution<$vt> for UnitVector {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vt {
+                $vt::sample_unit_sphere(rng)
+            }
+        }
+
+        impl Distribution<$r2t> for UnitRotor {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $r2t {
+                $r2t::from_angle($t::gen_uniform(rng) * $t::splat(core::$tt::consts::TAU))
+            }
+        }
+
+        impl Distribution<$rt> for UnitRotor {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $rt {
+                $rt::sample_uniform(rng)
+            }
+        })+
+    };
+}
+
+distributions!(
+    f32 => (Vec2, Vec3, Vec4, Rotor2, Rotor3, f32),
+    f32 => (Vec2x4, Vec3x4, Vec4x4, Rotor2x4, Rotor3x4, f32x4),
+    f32 => (Vec2x8, Vec3x8, Vec4x8, Rotor2x8, Rotor3x8, f32x8)
+);
+
+#[cfg(feature = "f64")]
+distributions!(
+    f64 => (DVec2, DVec3, DVec4, DRotor2, DRotor3, f64),
+    f64 => (DVec2x2, DVec3x2, DVec4x2, DRotor2x2, DRotor3x2, f64x2),
+    f64 => (DVec2x4, DVec3x4, DVec4x4, DRotor2x4, DRotor3x4, f64x4)
+);
+
+impl Distribution<Vec3A> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
+        Vec3A::from(<Standard as Distribution<Vec3>>::sample(self, rng))
+    }
+}
+
+impl Distribution<Vec3A> for UnitVector {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
+        Vec3A::from(Vec3::sample_unit_sphere(rng))
+    }
+}
+
+macro_rules! impl_flip_scalar {
+    ($($vt:ident),+) => {
+        $(impl $vt {
+            #[inline]
+            fn flip_into_hemisphere(v: Self, normal: Self) -> Self {
+                if v.dot(normal) < 0.0 {
+                    -v
+                } else {
+                    v
+                }
+            }
+        })+
+    };
+}
+
+macro_rules! impl_flip_wide {
+    ($($vt:ident => $t:ident),+) => {
+        $(impl $vt {
+            #[inline]
+            fn flip_into_hemisphere(v: Self, normal: Self) -> Self {
+                Self::blend(v.dot(normal).cmp_lt($t::splat(0.0)), -v, v)
+            }
+        })+
+    };
+}
+
+samples!(
+    f32 => (Vec2, Vec3, Rotor3, f32),
+    f32 => (Vec2x4, Vec3x4, Rotor3x4, f32x4),
+    f32 => (Vec2x8, Vec3x8, Rotor3x8, f32x8)
+);
+
+impl_flip_scalar!(Vec3);
+impl_flip_wide!(Vec3x4 => f32x4, Vec3x8 => f32x8);
+
+#[cfg(feature = "f64")]
+samples!(
+    f64 => (DVec2, DVec3, DRotor3, f64),
+    f64 => (DVec2x2, DVec3x2, DRotor3x2, f64x2),
+    f64 => (DVec2x4, DVec3x4, DRotor3x4, f64x4)
+);
+
+#[cfg(feature = "f64")]
+impl_flip_scalar!(DVec3);
+#[cfg(feature = "f64")]
+impl_flip_wide!(DVec3x2 => f64x2, DVec3x4 => f64x4);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::mock::StepRng;
+
+    #[test]
+    fn samples_are_well_formed() {
+        let mut rng = StepRng::new(0x1234_5678_9abc_def0, 0x9e37_79b9_7f4a_7c15);
+        let normal = Vec3::new(0.0, 1.0, 0.0);
+        for _ in 0..64 {
+            assert!((Vec3::sample_unit_sphere(&mut rng).mag() - 1.0).abs() < 1e-5);
+            assert!(Vec3::sample_hemisphere(normal, &mut rng).dot(normal) >= 0.0);
+            assert!(Vec3::sample_cosine_hemisphere(normal, &mut rng).dot(normal) >= 0.0);
+            assert!(Vec2::sample_disk(&mut rng).mag() <= 1.0);
+            assert!((Rotor3::sample_uniform(&mut rng).mag() - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn distributions() {
+        let mut rng = StepRng::new(0x1234_5678_9abc_def0, 0x9e37_79b9_7f4a_7c15);
+        for _ in 0..64 {
+            let v: Vec3 = rng.gen();
+            assert!(v.component_min() >= 0.0 && v.component_max() < 1.0);
+            let u: Vec2 = UnitVector.sample(&mut rng);
+            assert!((u.mag() - 1.0).abs() < 1e-5);
+            let r: Rotor2 = rng.gen();
+            assert!((r.mag() - 1.0).abs() < 1e-5);
+            let r: Rotor3 = UnitRotor.sample(&mut rng);
+            assert!((r.mag() - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn wide_matches_scalar() {
+        let u = [
+            Vec2::new(0.1, 0.2),
+            Vec2::new(0.3, 0.9),
+            Vec2::new(0.5, 0.5),
+            Vec2::new(0.99, 0.01),
+        ];
+        let wide: [Vec3; 4] = Vec3x4::unit_sphere_from_uniform(Vec2x4::from(u)).into();
+        for (u, w) in u.iter().zip(wide.iter()) {
+            let s = Vec3::unit_sphere_from_uniform(*u);
+            assert!((s - *w).mag() < 1e-5);
+        }
+    }
+}
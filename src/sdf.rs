@@ -0,0 +1,193 @@
+//! Signed distance functions for ray marching and collision queries.
+//!
+//! The primitives return the signed distance from a point to a shape centered on the origin,
+//! negative inside the shape. The boolean operators combine the distances of two shapes, and
+//! the domain operators transform the point before it is passed to a primitive, so that a scene
+//! is built by nesting calls. The formulas are the well known ones collected by Inigo Quilez.
+//!
+//! The functions in this module take `Vec3`, while the ones with the same names in [`x8`]
+//! evaluate eight points at once with `Vec3x8`.
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! use ultraviolet::sdf;
+//!
+//! let scene = |p: Vec3| {
+//!     let body = sdf::cuboid(p, Vec3::broadcast(1.0));
+//!     let hole = sdf::sphere(p - Vec3::unit_y(), 0.5);
+//!     sdf::smooth_subtraction(body, hole, 0.1)
+//! };
+//! assert_eq!(scene(Vec3::new(2.0, 0.0, 0.0)), 1.0);
+//! assert!(scene(Vec3::new(0.0, 0.75, 0.0)) > 0.0);
+//! ```
+use crate::*;
+
+macro_rules! sdfs {
+    ($vt:ident, $v2t:ident, $it:ident, $t:ident) => {
+        /// A sphere with the given `radius`.
+        #[inline]
+        pub fn sphere(p: $vt, radius: $t) -> $t {
+            p.mag() - radius
+        }
+
+        /// An axis aligned box, extending `half_extents` from the origin along each axis.
+        #[inline]
+        pub fn cuboid(p: $vt, half_extents: $vt) -> $t {
+            let q = p.abs() - half_extents;
+            let outside = q.max_by_component($vt::zero()).mag();
+            let inside = q.component_max().min($t::splat(0.0));
+            outside + inside
+        }
+
+        /// A capsule around the line segment from `a` to `b`.
+        #[inline]
+        pub fn capsule(p: $vt, a: $vt, b: $vt, radius: $t) -> $t {
+            let pa = p - a;
+            let ba = b - a;
+            let h = (pa.dot(ba) / ba.mag_sq())
+                .max($t::splat(0.0))
+                .min($t::splat(1.0));
+            (pa - ba * h).mag() - radius
+        }
+
+        /// A torus in the xz plane, with `major_radius` from the origin to the center of the
+        /// tube and `minor_radius` the radius of the tube.
+        #[inline]
+        pub fn torus(p: $vt, major_radius: $t, minor_radius: $t) -> $t {
+            let q = $v2t::new($v2t::new(p.x, p.z).mag() - major_radius, p.y);
+            q.mag() - minor_radius
+        }
+
+        /// The half space below the plane with the given unit `normal`, at `offset` from the
+        /// origin along it.
+        #[inline]
+        pub fn plane(p: $vt, normal: $vt, offset: $t) -> $t {
+            p.dot(normal) - offset
+        }
+
+        /// The union of two shapes.
+        #[inline]
+        pub fn union(a: $t, b: $t) -> $t {
+            a.min(b)
+        }
+
+        /// The intersection of two shapes.
+        #[inline]
+        pub fn intersection(a: $t, b: $t) -> $t {
+            a.max(b)
+        }
+
+        /// Shape `a` with shape `b` cut out of it.
+        #[inline]
+        pub fn subtraction(a: $t, b: $t) -> $t {
+            a.max(-b)
+        }
+
+        /// The union of two shapes, blended together over a distance of about `k`.
+        #[inline]
+        pub fn smooth_union(a: $t, b: $t, k: $t) -> $t {
+            let half = $t::splat(0.5);
+            let h = (half + half * (b - a) / k)
+                .max($t::splat(0.0))
+                .min($t::splat(1.0));
+            b + (a - b) * h - k * h * ($t::splat(1.0) - h)
+        }
+
+        /// The intersection of two shapes, blended together over a distance of about `k`.
+        #[inline]
+        pub fn smooth_intersection(a: $t, b: $t, k: $t) -> $t {
+            let half = $t::splat(0.5);
+            let h = (half - half * (b - a) / k)
+                .max($t::splat(0.0))
+                .min($t::splat(1.0));
+            b + (a - b) * h + k * h * ($t::splat(1.0) - h)
+        }
+
+        /// Shape `a` with shape `b` cut out of it, with the edges of the cut blended over a
+        /// distance of about `k`.
+        #[inline]
+        pub fn smooth_subtraction(a: $t, b: $t, k: $t) -> $t {
+            smooth_intersection(a, -b, k)
+        }
+
+        /// Repeat space infinitely in a grid of cells of size `period`, centered on the origin.
+        #[inline]
+        pub fn repeat(p: $vt, period: $vt) -> $vt {
+            p - period * (p / period).round()
+        }
+
+        /// Move `p` into the local space of a shape placed in the world with `transform`.
+        #[inline]
+        pub fn to_local(p: $vt, transform: $it) -> $vt {
+            transform.inversed() * p
+        }
+    };
+}
+
+sdfs!(Vec3, Vec2, Isometry3, f32);
+
+/// The same signed distance functions as in the [parent module](super), evaluating eight
+/// points at once.
+pub mod x8 {
+    use crate::*;
+
+    sdfs!(Vec3x8, Vec2x8, Isometry3x8, f32x8);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    #[test]
+    fn primitives_and_ops() {
+        assert!(cuboid(Vec3::new(3.0, 4.0, 0.5), Vec3::new(0.0, 0.0, 1.0)).eq_eps(5.0));
+        assert!(cuboid(Vec3::new(0.0, 0.5, 0.0), Vec3::broadcast(1.0)).eq_eps(-0.5));
+        assert!(
+            capsule(Vec3::new(1.0, 3.0, 0.0), Vec3::zero(), Vec3::unit_y(), 0.5)
+                .eq_eps(5.0f32.sqrt() - 0.5)
+        );
+        assert!(torus(Vec3::new(3.0, 1.0, 0.0), 2.0, 0.5).eq_eps(2.0f32.sqrt() - 0.5));
+        assert!(plane(Vec3::new(5.0, 2.0, 1.0), Vec3::unit_y(), 1.5).eq_eps(0.5));
+
+        // Far apart shapes are unaffected by the blend, and overlapping ones are smoothed.
+        assert!(smooth_union(1.0, 3.0, 0.5).eq_eps(1.0));
+        assert!(smooth_union(1.0, 1.0, 0.5) < 1.0);
+        assert!(smooth_subtraction(-2.0, 3.0, 0.5).eq_eps(-2.0));
+        assert!(smooth_intersection(1.0, 1.0, 0.5) > 1.0);
+
+        assert!(repeat(Vec3::new(4.25, -3.5, 0.5), Vec3::broadcast(2.0))
+            .eq_eps(Vec3::new(0.25, 0.5, 0.5)));
+        let transform = Isometry3::new(Vec3::new(0.0, 5.0, 0.0), Rotor3::from_rotation_xy(1.0));
+        assert!(sphere(to_local(Vec3::new(0.0, 5.0, 0.0), transform), 1.0).eq_eps(-1.0));
+
+        let points = [
+            Vec3::new(3.0, 4.0, 0.5),
+            Vec3::new(0.0, 0.5, 0.0),
+            Vec3::new(1.0, 3.0, 0.0),
+            Vec3::new(-2.0, 1.0, 7.0),
+            Vec3::zero(),
+            Vec3::new(0.5, 0.5, -0.5),
+            Vec3::new(10.0, -3.0, 2.0),
+            Vec3::new(0.1, 0.2, 0.3),
+        ];
+        let scene = |p: Vec3| {
+            let body = cuboid(p, Vec3::new(1.0, 0.5, 2.0));
+            let ring = torus(p - Vec3::unit_y(), 1.0, 0.25);
+            smooth_union(body, ring, 0.2)
+        };
+        let wide: [f32; 8] = {
+            let p = Vec3x8::from(points);
+            let body = x8::cuboid(p, Vec3x8::splat(Vec3::new(1.0, 0.5, 2.0)));
+            let ring = x8::torus(
+                p - Vec3x8::splat(Vec3::unit_y()),
+                f32x8::splat(1.0),
+                f32x8::splat(0.25),
+            );
+            x8::smooth_union(body, ring, f32x8::splat(0.2)).into()
+        };
+        for (p, d) in points.iter().zip(wide) {
+            assert!(d.eq_eps(scene(*p)));
+        }
+    }
+}
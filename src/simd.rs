@@ -0,0 +1,698 @@
+//! The wide float types implemented over `core::simd`, which replace those of `wide` when the
+//! `portable-simd` feature is enabled.
+//!
+//! Each type wraps a [`Simd`] vector and provides the subset of the API of its `wide`
+//! counterpart which this crate and its users rely on: arithmetic and bitwise operators, the
+//! `CmpEq` family of comparisons returning lane masks, `blend`, rounding, `sqrt`, `mul_add`,
+//! reductions and the transcendental functions. Lane masks are represented the same way as in
+//! `wide`, i.e. as float vectors whose lanes have either all or none of their bits set, so code
+//! written against either backend behaves the same.
+//!
+//! Arithmetic, comparisons, `abs`, `min`/`max` and the reductions compile to `core::simd`
+//! operations. With `std`, so do `sqrt`, `floor`, `ceil` and (with the `fma` target feature)
+//! `mul_add`; without it they are computed with `libm` on each lane. The transcendental functions
+//! are always computed lane by lane with the scalar functions of [`Transcendental`].
+use crate::*;
+
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::*;
+use core::simd::cmp::{SimdPartialEq, SimdPartialOrd};
+use core::simd::num::{SimdFloat, SimdInt};
+use core::simd::{Mask, Select, Simd};
+#[cfg(feature = "std")]
+use std::simd::StdFloat;
+use wide::{CmpEq, CmpGe, CmpGt, CmpLe, CmpLt, CmpNe};
+
+macro_rules! simd_floats {
+    ($($t:ident => [$s:ident; $lanes:literal], $u:ident, $m:ident, $pow:ident, $roundeven:ident);+ $(;)?) => {
+        $(
+        #[doc = concat!("A vector of ", stringify!($lanes), " `", stringify!($s), "` lanes, implemented with `core::simd`.")]
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy, Default, PartialEq)]
+        #[repr(transparent)]
+        pub struct $t(Simd<$s, $lanes>);
+
+        impl $t {
+            pub const ZERO: Self = Self::new([0.0; $lanes]);
+            pub const ONE: Self = Self::new([1.0; $lanes]);
+            pub const HALF: Self = Self::new([0.5; $lanes]);
+            pub const E: Self = Self::new([core::$s::consts::E; $lanes]);
+            pub const FRAC_1_PI: Self = Self::new([core::$s::consts::FRAC_1_PI; $lanes]);
+            pub const FRAC_2_PI: Self = Self::new([core::$s::consts::FRAC_2_PI; $lanes]);
+            pub const FRAC_1_SQRT_2: Self = Self::new([core::$s::consts::FRAC_1_SQRT_2; $lanes]);
+            pub const FRAC_PI_2: Self = Self::new([core::$s::consts::FRAC_PI_2; $lanes]);
+            pub const FRAC_PI_3: Self = Self::new([core::$s::consts::FRAC_PI_3; $lanes]);
+            pub const FRAC_PI_4: Self = Self::new([core::$s::consts::FRAC_PI_4; $lanes]);
+            pub const FRAC_PI_6: Self = Self::new([core::$s::consts::FRAC_PI_6; $lanes]);
+            pub const FRAC_PI_8: Self = Self::new([core::$s::consts::FRAC_PI_8; $lanes]);
+            pub const LN_2: Self = Self::new([core::$s::consts::LN_2; $lanes]);
+            pub const LN_10: Self = Self::new([core::$s::consts::LN_10; $lanes]);
+            pub const PI: Self = Self::new([core::$s::consts::PI; $lanes]);
+            pub const SQRT_2: Self = Self::new([core::$s::consts::SQRT_2; $lanes]);
+            pub const TAU: Self = Self::new([core::$s::consts::TAU; $lanes]);
+
+            #[inline]
+            pub const fn new(array: [$s; $lanes]) -> Self {
+                Self(Simd::from_array(array))
+            }
+
+            #[inline]
+            pub fn splat(elem: $s) -> Self {
+                Self(Simd::splat(elem))
+            }
+
+            #[inline]
+            pub fn to_array(self) -> [$s; $lanes] {
+                self.0.to_array()
+            }
+
+            #[inline]
+            pub fn as_array_ref(&self) -> &[$s; $lanes] {
+                self.0.as_array()
+            }
+
+            #[inline]
+            pub fn as_array_mut(&mut self) -> &mut [$s; $lanes] {
+                self.0.as_mut_array()
+            }
+
+            /// Apply `f` to each lane.
+            #[inline]
+            fn map(self, f: impl FnMut($s) -> $s) -> Self {
+                Self::new(self.to_array().map(f))
+            }
+
+            /// Apply `f` to each pair of lanes of `self` and `other`.
+            #[inline]
+            fn zip_map(self, other: Self, mut f: impl FnMut($s, $s) -> $s) -> Self {
+                let (a, b) = (self.to_array(), other.to_array());
+                Self::new(core::array::from_fn(|i| f(a[i], b[i])))
+            }
+
+            /// The lanes of `self` whose sign bit is set, which for the result of a comparison
+            /// are the lanes where it holds.
+            #[inline]
+            fn sign_mask(self) -> Mask<$m, $lanes> {
+                self.0.is_sign_negative()
+            }
+
+            /// A lane mask in the representation of `wide`, i.e. with all bits set where `mask`
+            /// is set.
+            #[inline]
+            fn from_mask(mask: Mask<$m, $lanes>) -> Self {
+                Self(Simd::from_bits(mask.to_simd().cast::<$u>()))
+            }
+
+            /// Take the lanes of `t` where the sign bit of `self` is set and those of `f`
+            /// elsewhere.
+            #[inline]
+            pub fn blend(self, t: Self, f: Self) -> Self {
+                Self(self.sign_mask().select(t.0, f.0))
+            }
+
+            #[inline]
+            pub fn abs(self) -> Self {
+                Self(self.0.abs())
+            }
+
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn floor(self) -> Self {
+                Self(self.0.floor())
+            }
+
+            #[cfg(not(feature = "std"))]
+            #[inline]
+            pub fn floor(self) -> Self {
+                self.map(FloatExt::floor)
+            }
+
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn ceil(self) -> Self {
+                Self(self.0.ceil())
+            }
+
+            #[cfg(not(feature = "std"))]
+            #[inline]
+            pub fn ceil(self) -> Self {
+                self.map(FloatExt::ceil)
+            }
+
+            /// Round each lane to the nearest integer, with ties rounding to even like `wide`.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn round(self) -> Self {
+                self.map($s::round_ties_even)
+            }
+
+            /// Round each lane to the nearest integer, with ties rounding to even like `wide`.
+            #[cfg(not(feature = "std"))]
+            #[inline]
+            pub fn round(self) -> Self {
+                self.map(libm::$roundeven)
+            }
+
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn sqrt(self) -> Self {
+                Self(self.0.sqrt())
+            }
+
+            #[cfg(not(feature = "std"))]
+            #[inline]
+            pub fn sqrt(self) -> Self {
+                self.map(FloatExt::sqrt)
+            }
+
+            #[inline]
+            pub fn recip(self) -> Self {
+                Self(self.0.recip())
+            }
+
+            #[inline]
+            pub fn recip_sqrt(self) -> Self {
+                self.sqrt().recip()
+            }
+
+            /// The lane-wise maximum, returning the other lane where one of them is `NaN`.
+            #[inline]
+            pub fn max(self, rhs: Self) -> Self {
+                Self(self.0.simd_max(rhs.0))
+            }
+
+            /// The lane-wise minimum, returning the other lane where one of them is `NaN`.
+            #[inline]
+            pub fn min(self, rhs: Self) -> Self {
+                Self(self.0.simd_min(rhs.0))
+            }
+
+            #[inline]
+            pub fn fast_max(self, rhs: Self) -> Self {
+                self.max(rhs)
+            }
+
+            #[inline]
+            pub fn fast_min(self, rhs: Self) -> Self {
+                self.min(rhs)
+            }
+
+            #[inline]
+            pub fn is_nan(self) -> Self {
+                Self::from_mask(self.0.is_nan())
+            }
+
+            #[inline]
+            pub fn is_finite(self) -> Self {
+                Self::from_mask(self.0.is_finite())
+            }
+
+            #[inline]
+            pub fn is_inf(self) -> Self {
+                Self::from_mask(self.0.is_infinite())
+            }
+
+            /// `self * m + a`, fused into a single rounding if the `fma` target feature is
+            /// enabled, like `wide`.
+            #[cfg(all(feature = "std", target_feature = "fma"))]
+            #[inline]
+            pub fn mul_add(self, m: Self, a: Self) -> Self {
+                Self(self.0.mul_add(m.0, a.0))
+            }
+
+            /// `self * m + a`, fused into a single rounding if the `fma` target feature is
+            /// enabled, like `wide`.
+            #[cfg(not(all(feature = "std", target_feature = "fma")))]
+            #[inline]
+            pub fn mul_add(self, m: Self, a: Self) -> Self {
+                self * m + a
+            }
+
+            #[inline]
+            pub fn mul_sub(self, m: Self, s: Self) -> Self {
+                self.mul_add(m, -s)
+            }
+
+            #[inline]
+            pub fn mul_neg_add(self, m: Self, a: Self) -> Self {
+                (-self).mul_add(m, a)
+            }
+
+            #[inline]
+            pub fn mul_neg_sub(self, m: Self, s: Self) -> Self {
+                (-self).mul_add(m, -s)
+            }
+
+            /// Flip the sign of the lanes of `self` where the sign bit of `signs` is set.
+            #[inline]
+            pub fn flip_signs(self, signs: Self) -> Self {
+                self ^ (signs & Self::splat(-0.0))
+            }
+
+            #[inline]
+            pub fn copysign(self, sign: Self) -> Self {
+                Self(self.0.copysign(sign.0))
+            }
+
+            #[inline]
+            pub fn sin(self) -> Self {
+                self.map(<$s as Transcendental>::sin)
+            }
+
+            #[inline]
+            pub fn cos(self) -> Self {
+                self.map(<$s as Transcendental>::cos)
+            }
+
+            #[inline]
+            pub fn tan(self) -> Self {
+                self.map(<$s as Transcendental>::tan)
+            }
+
+            #[inline]
+            pub fn sin_cos(self) -> (Self, Self) {
+                (self.sin(), self.cos())
+            }
+
+            #[inline]
+            pub fn asin(self) -> Self {
+                self.map(<$s as Transcendental>::asin)
+            }
+
+            #[inline]
+            pub fn acos(self) -> Self {
+                self.map(<$s as Transcendental>::acos)
+            }
+
+            #[inline]
+            pub fn asin_acos(self) -> (Self, Self) {
+                (self.asin(), self.acos())
+            }
+
+            #[inline]
+            pub fn atan(self) -> Self {
+                self.map(<$s as Transcendental>::atan)
+            }
+
+            #[inline]
+            pub fn atan2(self, x: Self) -> Self {
+                self.zip_map(x, <$s as Transcendental>::atan2)
+            }
+
+            #[inline]
+            pub fn exp(self) -> Self {
+                self.map(<$s as Transcendental>::exp)
+            }
+
+            #[inline]
+            pub fn ln(self) -> Self {
+                self.map(<$s as Transcendental>::ln)
+            }
+
+            #[inline]
+            pub fn log2(self) -> Self {
+                self.ln() * Self::splat(core::$s::consts::LOG2_E)
+            }
+
+            #[inline]
+            pub fn log10(self) -> Self {
+                self.ln() * Self::splat(core::$s::consts::LOG10_E)
+            }
+
+            #[inline]
+            pub fn $pow(self, y: Self) -> Self {
+                self.zip_map(y, <$s as Transcendental>::powf)
+            }
+
+            #[inline]
+            pub fn powf(self, y: $s) -> Self {
+                self.$pow(Self::splat(y))
+            }
+
+            #[inline]
+            pub fn to_degrees(self) -> Self {
+                Self(self.0.to_degrees())
+            }
+
+            #[inline]
+            pub fn to_radians(self) -> Self {
+                Self(self.0.to_radians())
+            }
+
+            /// A bit per lane, set where the sign bit of the lane is set.
+            #[inline]
+            pub fn move_mask(self) -> i32 {
+                self.sign_mask().to_bitmask() as i32
+            }
+
+            /// Whether the sign bit of any lane is set.
+            #[inline]
+            pub fn any(self) -> bool {
+                self.sign_mask().any()
+            }
+
+            /// Whether the sign bits of all lanes are set.
+            #[inline]
+            pub fn all(self) -> bool {
+                self.sign_mask().all()
+            }
+
+            /// Whether the sign bit of no lane is set.
+            #[inline]
+            pub fn none(self) -> bool {
+                !self.any()
+            }
+
+            #[inline]
+            pub fn reduce_add(self) -> $s {
+                self.0.reduce_sum()
+            }
+        }
+
+        impl From<[$s; $lanes]> for $t {
+            #[inline]
+            fn from(array: [$s; $lanes]) -> Self {
+                Self::new(array)
+            }
+        }
+
+        impl From<$t> for [$s; $lanes] {
+            #[inline]
+            fn from(v: $t) -> Self {
+                v.to_array()
+            }
+        }
+
+        impl From<&[$s]> for $t {
+            /// Copies the lanes of `src`, setting the remaining lanes to zero.
+            ///
+            /// Panics if `src` has more elements than there are lanes, like `wide`.
+            #[inline]
+            fn from(src: &[$s]) -> Self {
+                let mut lanes = [0.0; $lanes];
+                lanes[..src.len()].copy_from_slice(src);
+                Self::new(lanes)
+            }
+        }
+
+        impl From<$s> for $t {
+            /// Splats the single value given across all lanes.
+            #[inline]
+            fn from(elem: $s) -> Self {
+                Self::splat(elem)
+            }
+        }
+
+        impl From<Simd<$s, $lanes>> for $t {
+            #[inline]
+            fn from(simd: Simd<$s, $lanes>) -> Self {
+                Self(simd)
+            }
+        }
+
+        impl From<$t> for Simd<$s, $lanes> {
+            #[inline]
+            fn from(v: $t) -> Self {
+                v.0
+            }
+        }
+
+        simd_floats!(@binop $t, $s, Add, add, AddAssign, add_assign);
+        simd_floats!(@binop $t, $s, Sub, sub, SubAssign, sub_assign);
+        simd_floats!(@binop $t, $s, Mul, mul, MulAssign, mul_assign);
+        simd_floats!(@binop $t, $s, Div, div, DivAssign, div_assign);
+        simd_floats!(@bitop $t, BitAnd, bitand, BitAndAssign, bitand_assign);
+        simd_floats!(@bitop $t, BitOr, bitor, BitOrAssign, bitor_assign);
+        simd_floats!(@bitop $t, BitXor, bitxor, BitXorAssign, bitxor_assign);
+        simd_floats!(@cmp $t, $s, CmpEq, cmp_eq, simd_eq);
+        simd_floats!(@cmp $t, $s, CmpNe, cmp_ne, simd_ne);
+        simd_floats!(@cmp $t, $s, CmpLt, cmp_lt, simd_lt);
+        simd_floats!(@cmp $t, $s, CmpLe, cmp_le, simd_le);
+        simd_floats!(@cmp $t, $s, CmpGt, cmp_gt, simd_gt);
+        simd_floats!(@cmp $t, $s, CmpGe, cmp_ge, simd_ge);
+        simd_floats!(@fmt $t, Debug, Display, LowerExp, UpperExp);
+
+        impl Neg for $t {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl Not for $t {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                Self(Simd::from_bits(!self.0.to_bits()))
+            }
+        }
+
+        impl<RHS> Sum<RHS> for $t
+        where
+            $t: AddAssign<RHS>,
+        {
+            #[inline]
+            fn sum<I: Iterator<Item = RHS>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |mut total, val| {
+                    total += val;
+                    total
+                })
+            }
+        }
+
+        impl<RHS> Product<RHS> for $t
+        where
+            $t: MulAssign<RHS>,
+        {
+            #[inline]
+            fn product<I: Iterator<Item = RHS>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |mut total, val| {
+                    total *= val;
+                    total
+                })
+            }
+        }
+
+        // SAFETY: `Simd<$s, $lanes>` is plain old data without padding, and `$t` is
+        // `repr(transparent)` over it.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Zeroable for $t {}
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Pod for $t {}
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $t {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeTuple;
+                let mut seq = serializer.serialize_tuple($lanes)?;
+                for lane in self.as_array_ref() {
+                    seq.serialize_element(lane)?;
+                }
+                seq.end()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $t {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(<[$s; $lanes]>::deserialize(deserializer)?.into())
+            }
+        }
+        )+
+    };
+    (@binop $t:ident, $s:ident, $op:ident, $f:ident, $op_assign:ident, $f_assign:ident) => {
+        impl $op for $t {
+            type Output = Self;
+
+            #[inline]
+            fn $f(self, rhs: Self) -> Self {
+                Self(self.0.$f(rhs.0))
+            }
+        }
+
+        impl $op<$s> for $t {
+            type Output = Self;
+
+            #[inline]
+            fn $f(self, rhs: $s) -> Self {
+                Self(self.0.$f(Simd::splat(rhs)))
+            }
+        }
+
+        impl $op<$t> for $s {
+            type Output = $t;
+
+            #[inline]
+            fn $f(self, rhs: $t) -> $t {
+                $t(Simd::splat(self).$f(rhs.0))
+            }
+        }
+
+        impl $op_assign for $t {
+            #[inline]
+            fn $f_assign(&mut self, rhs: Self) {
+                self.0.$f_assign(rhs.0);
+            }
+        }
+
+        impl $op_assign<&$t> for $t {
+            #[inline]
+            fn $f_assign(&mut self, rhs: &Self) {
+                self.0.$f_assign(rhs.0);
+            }
+        }
+    };
+    (@bitop $t:ident, $op:ident, $f:ident, $op_assign:ident, $f_assign:ident) => {
+        impl $op for $t {
+            type Output = Self;
+
+            #[inline]
+            fn $f(self, rhs: Self) -> Self {
+                Self(Simd::from_bits(self.0.to_bits().$f(rhs.0.to_bits())))
+            }
+        }
+
+        impl $op_assign for $t {
+            #[inline]
+            fn $f_assign(&mut self, rhs: Self) {
+                *self = self.$f(rhs);
+            }
+        }
+    };
+    (@cmp $t:ident, $s:ident, $cmp:ident, $f:ident, $simd_f:ident) => {
+        impl $cmp for $t {
+            type Output = Self;
+
+            #[inline]
+            fn $f(self, rhs: Self) -> Self {
+                Self::from_mask(self.0.$simd_f(rhs.0))
+            }
+        }
+
+        impl $cmp<$s> for $t {
+            type Output = Self;
+
+            #[inline]
+            fn $f(self, rhs: $s) -> Self {
+                self.$f(Self::splat(rhs))
+            }
+        }
+    };
+    (@fmt $t:ident, $($fmt:ident),+) => {
+        $(impl fmt::$fmt for $t {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "(")?;
+                for (i, lane) in self.as_array_ref().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    fmt::$fmt::fmt(lane, f)?;
+                }
+                write!(f, ")")
+            }
+        })+
+    };
+}
+
+simd_floats!(
+    f32x4 => [f32; 4], u32, i32, pow_f32x4, roundevenf;
+    f32x8 => [f32; 8], u32, i32, pow_f32x8, roundevenf;
+    f32x16 => [f32; 16], u32, i32, pow_f32x16, roundevenf;
+    f64x2 => [f64; 2], u64, i64, pow_f64x2, roundeven;
+    f64x4 => [f64; 4], u64, i64, pow_f64x4, roundeven
+);
+
+/// Conversions to the integer wide types, which are still those of `wide`.
+#[cfg(feature = "int")]
+macro_rules! simd_float_ints {
+    ($($t:ident => $it:ident, $from_int:ident),+) => {
+        $(impl $t {
+            #[inline]
+            pub fn $from_int(v: $it) -> Self {
+                Self::new(v.to_array().map(|l| l as f32))
+            }
+
+            /// Truncate each lane towards zero, saturating at the bounds of `i32`.
+            #[inline]
+            pub fn trunc_int(self) -> $it {
+                $it::from(self.to_array().map(|l| l as i32))
+            }
+
+            /// Round each lane to the nearest integer, with ties rounding to even, saturating at
+            /// the bounds of `i32`.
+            #[inline]
+            pub fn round_int(self) -> $it {
+                self.round().trunc_int()
+            }
+        })+
+    };
+}
+
+#[cfg(feature = "int")]
+simd_float_ints!(f32x4 => i32x4, from_i32x4, f32x8 => i32x8, from_i32x8);
+
+#[cfg(feature = "int")]
+impl From<i32x4> for f64x4 {
+    #[inline]
+    fn from(v: i32x4) -> Self {
+        Self::new(v.to_array().map(|l| l as f64))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn size_and_align() {
+        use core::mem::{align_of, size_of};
+        assert_eq!((size_of::<f32x4>(), align_of::<f32x4>()), (16, 16));
+        assert_eq!((size_of::<f32x8>(), align_of::<f32x8>()), (32, 32));
+        assert_eq!((size_of::<f32x16>(), align_of::<f32x16>()), (64, 64));
+        assert_eq!((size_of::<f64x2>(), align_of::<f64x2>()), (16, 16));
+    }
+
+    #[test]
+    fn masks_match_wide() {
+        let a = f32x4::new([1.0, -2.0, 3.0, f32::NAN]);
+        let b = f32x4::splat(2.0);
+        let lt = a.cmp_lt(b);
+        assert_eq!(lt.move_mask(), 0b0011);
+        assert_eq!(lt.to_array()[0].to_bits(), u32::MAX);
+        assert_eq!(lt.to_array()[2].to_bits(), 0);
+        assert_eq!(lt.blend(a, b).to_array()[..3], [1.0, -2.0, 2.0]);
+        assert!(a.is_nan().any() && !a.is_nan().all());
+        assert_eq!((!lt).move_mask(), 0b1100);
+
+        let wide = wide::f32x4::from(a.to_array()).cmp_lt(wide::f32x4::splat(2.0));
+        assert_eq!(wide.move_mask(), lt.move_mask());
+    }
+
+    #[test]
+    fn arithmetic_and_rounding() {
+        let a = f32x16::new(core::array::from_fn(|i| i as f32 - 7.5));
+        assert_eq!(a.reduce_add(), 0.0);
+        assert_eq!((a * 2.0 - a).to_array(), a.to_array());
+        assert_eq!(a.abs().min(f32x16::ONE).to_array()[0], 1.0);
+        assert_eq!(a.round().to_array()[..4], [-8.0, -6.0, -6.0, -4.0]);
+        assert_eq!(f32x4::splat(9.0).sqrt(), f32x4::splat(3.0));
+        assert_eq!(
+            f32x4::splat(1.5).mul_add(f32x4::splat(2.0), f32x4::ONE),
+            f32x4::splat(4.0)
+        );
+        assert_eq!(
+            f64x2::splat(-1.0)
+                .flip_signs(f64x2::new([-1.0, 1.0]))
+                .to_array(),
+            [1.0, -1.0]
+        );
+
+        let (s, c) = f32x8::splat(0.5).sin_cos();
+        assert_eq!(s.to_array()[7], 0.5f32.sin());
+        assert_eq!(c.to_array()[0], 0.5f32.cos());
+    }
+}
@@ -0,0 +1,397 @@
+//! Owning containers which store vectors and rotors eight at a time, in the wide types.
+//!
+//! [`Vec3s`], [`Vec4s`] and [`Rotor3s`] behave like a `Vec` of the scalar type: elements are
+//! pushed, read and written one at a time by index. Internally they are stored as a `Vec` of
+//! `Vec3x8`, `Vec4x8` or `Rotor3x8` (an "array of structures of arrays"), so the bulk operations
+//! such as [`Vec3s::rotate`] run on eight elements at once without the caller having to pad the
+//! data or keep track of lanes.
+//!
+//! The last chunk is padded when the number of elements isn't a multiple of eight. The padding
+//! lanes are transformed along with the rest by bulk operations, so their values are unspecified
+//! when the chunks are accessed directly with [`Vec3s::chunks`].
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! use ultraviolet::soa::Vec3s;
+//!
+//! let mut points: Vec3s = (0..10).map(|i| Vec3::new(i as f32, 0.0, 0.0)).collect();
+//! points.rotate(Rotor3::from_rotation_xy(core::f32::consts::FRAC_PI_2));
+//! points += Vec3::unit_z();
+//! assert_eq!(points.len(), 10);
+//! assert!((points.get(9).unwrap() - Vec3::new(0.0, 9.0, 1.0)).mag() < 1e-5);
+//! ```
+use crate::*;
+
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::*;
+
+/// Crate-private access to single lanes of the wide types stored in the containers.
+trait Lanes {
+    type Elem;
+    fn padding() -> Self;
+    fn lane(&self, i: usize) -> Self::Elem;
+    fn set_lane(&mut self, i: usize, value: Self::Elem);
+}
+
+macro_rules! impl_vec_lanes {
+    ($($wt:ident => $st:ident, [$($field:ident),+]),+) => {
+        $(impl Lanes for $wt {
+            type Elem = $st;
+
+            #[inline]
+            fn padding() -> Self {
+                $wt::zero()
+            }
+
+            #[inline]
+            fn lane(&self, i: usize) -> $st {
+                $st::new($(self.$field.as_array_ref()[i]),+)
+            }
+
+            #[inline]
+            fn set_lane(&mut self, i: usize, value: $st) {
+                $(self.$field.as_array_mut()[i] = value.$field;)+
+            }
+        })+
+    };
+}
+
+impl_vec_lanes!(Vec3x8 => Vec3, [x, y, z], Vec4x8 => Vec4, [x, y, z, w]);
+
+impl Lanes for Rotor3x8 {
+    type Elem = Rotor3;
+
+    #[inline]
+    fn padding() -> Self {
+        Rotor3x8::identity()
+    }
+
+    #[inline]
+    fn lane(&self, i: usize) -> Rotor3 {
+        Rotor3::new(
+            self.s.as_array_ref()[i],
+            Bivec3::new(
+                self.bv.xy.as_array_ref()[i],
+                self.bv.xz.as_array_ref()[i],
+                self.bv.yz.as_array_ref()[i],
+            ),
+        )
+    }
+
+    #[inline]
+    fn set_lane(&mut self, i: usize, value: Rotor3) {
+        self.s.as_array_mut()[i] = value.s;
+        self.bv.xy.as_array_mut()[i] = value.bv.xy;
+        self.bv.xz.as_array_mut()[i] = value.bv.xz;
+        self.bv.yz.as_array_mut()[i] = value.bv.yz;
+    }
+}
+
+macro_rules! soa_containers {
+    ($($n:ident => ($wt:ident, $st:ident)),+) => {
+        $(/// A growable buffer of
+        #[doc = concat!("`", stringify!($st), "`")]
+        /// stored in chunks of
+        #[doc = concat!("`", stringify!($wt), "`.")]
+        ///
+        /// See the [module documentation](self) for how the elements are laid out.
+        #[derive(Clone, Debug, Default)]
+        pub struct $n {
+            chunks: Vec<$wt>,
+            len: usize,
+        }
+
+        impl $n {
+            #[inline]
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// An empty buffer with room for at least `capacity` elements.
+            #[inline]
+            pub fn with_capacity(capacity: usize) -> Self {
+                Self {
+                    chunks: Vec::with_capacity(capacity.div_ceil(8)),
+                    len: 0,
+                }
+            }
+
+            /// The number of elements, not counting padding.
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            #[inline]
+            pub fn clear(&mut self) {
+                self.chunks.clear();
+                self.len = 0;
+            }
+
+            /// Append an element to the end of the buffer.
+            #[inline]
+            pub fn push(&mut self, value: $st) {
+                let lane = self.len % 8;
+                if lane == 0 {
+                    self.chunks.push($wt::padding());
+                }
+                self.chunks.last_mut().unwrap().set_lane(lane, value);
+                self.len += 1;
+            }
+
+            /// Remove the last element and return it, or `None` if the buffer is empty.
+            #[inline]
+            pub fn pop(&mut self) -> Option<$st> {
+                let value = self.get(self.len.checked_sub(1)?)?;
+                self.len -= 1;
+                if self.len % 8 == 0 {
+                    self.chunks.pop();
+                }
+                Some(value)
+            }
+
+            /// The element at `index`, or `None` if it is out of bounds.
+            #[inline]
+            pub fn get(&self, index: usize) -> Option<$st> {
+                if index < self.len {
+                    Some(self.chunks[index / 8].lane(index % 8))
+                } else {
+                    None
+                }
+            }
+
+            /// Overwrite the element at `index`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `index` is out of bounds.
+            #[inline]
+            pub fn set(&mut self, index: usize, value: $st) {
+                assert!(
+                    index < self.len,
+                    "index {} out of bounds for length {}",
+                    index,
+                    self.len
+                );
+                self.chunks[index / 8].set_lane(index % 8, value);
+            }
+
+            /// Iterate over the elements, in order.
+            #[inline]
+            pub fn iter(&self) -> impl Iterator<Item = $st> + '_ {
+                (0..self.len).map(move |i| self.chunks[i / 8].lane(i % 8))
+            }
+
+            /// The underlying chunks, including the padding lanes of the last one.
+            #[inline]
+            pub fn chunks(&self) -> &[$wt] {
+                &self.chunks
+            }
+
+            /// The underlying chunks, including the padding lanes of the last one.
+            #[inline]
+            pub fn chunks_mut(&mut self) -> &mut [$wt] {
+                &mut self.chunks
+            }
+        }
+
+        impl Extend<$st> for $n {
+            fn extend<I: IntoIterator<Item = $st>>(&mut self, iter: I) {
+                for value in iter {
+                    self.push(value);
+                }
+            }
+        }
+
+        impl FromIterator<$st> for $n {
+            fn from_iter<I: IntoIterator<Item = $st>>(iter: I) -> Self {
+                let mut buffer = Self::new();
+                buffer.extend(iter);
+                buffer
+            }
+        }
+        )+
+    };
+}
+
+soa_containers!(
+    Vec3s => (Vec3x8, Vec3),
+    Vec4s => (Vec4x8, Vec4),
+    Rotor3s => (Rotor3x8, Rotor3)
+);
+
+macro_rules! soa_vec_ops {
+    ($($n:ident => ($wt:ident, $st:ident)),+) => {
+        $(impl AddAssign<$st> for $n {
+            #[inline]
+            fn add_assign(&mut self, rhs: $st) {
+                let rhs = $wt::splat(rhs);
+                for chunk in &mut self.chunks {
+                    *chunk += rhs;
+                }
+            }
+        }
+
+        /// Adds the elements pairwise.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the buffers have different lengths.
+        impl AddAssign<&$n> for $n {
+            #[inline]
+            fn add_assign(&mut self, rhs: &$n) {
+                assert_eq!(self.len, rhs.len, "buffer lengths differ");
+                for (chunk, rhs) in self.chunks.iter_mut().zip(&rhs.chunks) {
+                    *chunk += *rhs;
+                }
+            }
+        }
+        )+
+    };
+}
+
+soa_vec_ops!(Vec3s => (Vec3x8, Vec3), Vec4s => (Vec4x8, Vec4));
+
+impl Vec3s {
+    /// Rotate every element by `rotor`.
+    #[inline]
+    pub fn rotate(&mut self, rotor: Rotor3) {
+        let rotor = Rotor3x8::splat(rotor);
+        for chunk in &mut self.chunks {
+            rotor.rotate_vec(chunk);
+        }
+    }
+
+    /// Multiply every element by `mat`.
+    #[inline]
+    pub fn transform(&mut self, mat: Mat3) {
+        let mat = Mat3x8::new(
+            Vec3x8::splat(mat.cols[0]),
+            Vec3x8::splat(mat.cols[1]),
+            Vec3x8::splat(mat.cols[2]),
+        );
+        for chunk in &mut self.chunks {
+            *chunk = mat * *chunk;
+        }
+    }
+
+    /// Transform every element by `mat`, interpreting them as points, see
+    /// [`Mat4::transform_point3`].
+    #[inline]
+    pub fn transform_points(&mut self, mat: Mat4) {
+        let mat = splat_mat4(mat);
+        for chunk in &mut self.chunks {
+            *chunk = mat.transform_point3(*chunk);
+        }
+    }
+
+    /// Transform every element by `mat`, interpreting them as vectors, see
+    /// [`Mat4::transform_vec3`].
+    #[inline]
+    pub fn transform_vecs(&mut self, mat: Mat4) {
+        let mat = splat_mat4(mat);
+        for chunk in &mut self.chunks {
+            *chunk = mat.transform_vec3(*chunk);
+        }
+    }
+}
+
+#[inline]
+pub(crate) fn splat_mat4(mat: Mat4) -> Mat4x8 {
+    Mat4x8::new(
+        Vec4x8::splat(mat.cols[0]),
+        Vec4x8::splat(mat.cols[1]),
+        Vec4x8::splat(mat.cols[2]),
+        Vec4x8::splat(mat.cols[3]),
+    )
+}
+
+impl Vec4s {
+    /// Multiply every element by `mat`.
+    #[inline]
+    pub fn transform(&mut self, mat: Mat4) {
+        let mat = splat_mat4(mat);
+        for chunk in &mut self.chunks {
+            *chunk = mat * *chunk;
+        }
+    }
+}
+
+impl Rotor3s {
+    /// Rotate each element of `vecs` by the rotor at the same index in `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffers have different lengths.
+    #[inline]
+    pub fn rotate(&self, vecs: &mut Vec3s) {
+        assert_eq!(self.len, vecs.len, "buffer lengths differ");
+        for (rotor, chunk) in self.chunks.iter().zip(&mut vecs.chunks) {
+            rotor.rotate_vec(chunk);
+        }
+    }
+
+    /// Normalize every element.
+    #[inline]
+    pub fn normalize(&mut self) {
+        for chunk in &mut self.chunks {
+            chunk.normalize();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    #[test]
+    fn containers() {
+        let mut vecs: Vec3s = (0..11).map(|i| Vec3::new(i as f32, 1.0, 0.0)).collect();
+        assert_eq!(vecs.len(), 11);
+        assert_eq!(vecs.chunks().len(), 2);
+        assert_eq!(vecs.get(11), None);
+        vecs.set(10, Vec3::new(-1.0, 0.0, 0.0));
+        assert_eq!(vecs.pop(), Some(Vec3::new(-1.0, 0.0, 0.0)));
+        assert_eq!(vecs.len(), 10);
+
+        let mut rotors: Rotor3s = (0..10)
+            .map(|i| Rotor3::from_rotation_xz(i as f32 * 0.1))
+            .collect();
+        rotors.normalize();
+        let expected: Vec<Vec3> = vecs
+            .iter()
+            .zip(rotors.iter())
+            .map(|(v, r)| r * v + Vec3::unit_z())
+            .collect();
+        rotors.rotate(&mut vecs);
+        vecs += Vec3::unit_z();
+        assert_eq!(vecs.iter().count(), 10);
+        for (v, e) in vecs.iter().zip(&expected) {
+            assert!(v.eq_eps(*e));
+        }
+
+        let mat = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+        let mut points = vecs.clone();
+        points.transform_points(mat);
+        let mut offsets = vecs.clone();
+        offsets.transform_vecs(mat);
+        assert!(points
+            .get(9)
+            .unwrap()
+            .eq_eps(vecs.get(9).unwrap() + Vec3::new(1.0, 2.0, 3.0)));
+        assert!(offsets.get(9).unwrap().eq_eps(vecs.get(9).unwrap()));
+
+        let mut colors: Vec4s = (0..3).map(|i| Vec4::broadcast(i as f32)).collect();
+        let ones: Vec4s = colors.iter().map(|_| Vec4::one()).collect();
+        colors += &ones;
+        colors.transform(Mat4::from_scale(2.0));
+        assert_eq!(colors.get(2), Some(Vec4::new(6.0, 6.0, 6.0, 3.0)));
+    }
+}
@@ -5,7 +5,8 @@
 //! especially composition and inverse.
 use crate::*;
 
-use std::ops::*;
+use alloc::vec::Vec;
+use core::ops::*;
 
 macro_rules! isometries {
     ($($ison:ident => ($mt:ident, $rt:ident, $vt:ident, $t:ident)),+) => {
@@ -33,7 +34,7 @@ macro_rules! isometries {
             }
 
             #[inline]
-            pub fn identity() -> Self {
+            pub const fn identity() -> Self {
                 Self { rotation: $rt::identity(), translation: $vt::zero() }
             }
 
@@ -43,7 +44,7 @@ macro_rules! isometries {
             /// part of this isometry, not the translational part.
             #[inline]
             pub fn prepend_rotation(&mut self, rotor: $rt) {
-                self.rotation = rotor * self.rotation;
+                self.rotation *= rotor;
             }
 
             /// Add a rotation *after* this isometry.
@@ -51,6 +52,7 @@ macro_rules! isometries {
             /// This means the rotation will affect both the rotational and
             /// translational parts of this isometry, since it is being applied
             /// 'after' this isometry's translational part.
+            #[inline]
             pub fn append_rotation(&mut self, rotor: $rt) {
                 self.rotation = rotor * self.rotation;
                 self.translation = rotor * self.translation;
@@ -81,7 +83,7 @@ macro_rules! isometries {
             /// rotated by this isometry's rotational part.
             #[inline]
             pub fn prepend_isometry(&mut self, other: Self) {
-                *self = *self * other;
+                *self *= other;
             }
 
             /// Append transformation by another isometry.
@@ -156,6 +158,13 @@ macro_rules! isometries {
             }
         }
 
+        impl MulAssign<$ison> for $ison {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $ison) {
+                *self = *self * rhs;
+            }
+        }
+
         impl Mul<$ison> for $ison {
             type Output = Self;
             #[inline]
@@ -189,6 +198,9 @@ isometries!(
     Isometry3x8 => (Mat4x8, Rotor3x8, Vec3x8, f32x8)
 );
 
+#[cfg(feature = "portable-simd")]
+isometries!(Isometry3x16 => (Mat4x16, Rotor3x16, Vec3x16, f32x16));
+
 #[cfg(feature = "f64")]
 isometries!(
     DIsometry2 => (DMat3, DRotor2, DVec2, f64),
@@ -200,8 +212,82 @@ isometries!(
     DIsometry3x4 => (DMat4x4, DRotor3x4, DVec3x4, f64x4)
 );
 
+macro_rules! isometry2s {
+    ($($ison:ident => ($rt:ident, $vt:ident, $t:ident)),+) => {
+        $(impl $ison {
+            /// Construct an isometry from the pose of an object in 2d space, i.e. its
+            /// `position` and the `angle` it is rotated by.
+            ///
+            /// The angle follows the convention of `Rotor2::from_angle`, i.e. positive
+            /// angles rotate the x unit vector towards the y unit vector.
+            #[inline]
+            pub fn from_pose(position: $vt, angle: $t) -> Self {
+                Self::new(position, $rt::from_angle(angle))
+            }
+        })+
+    }
+}
+
+isometry2s!(
+    Isometry2 => (Rotor2, Vec2, f32),
+    Isometry2x4 => (Rotor2x4, Vec2x4, f32x4),
+    Isometry2x8 => (Rotor2x8, Vec2x8, f32x8)
+);
+
+#[cfg(feature = "f64")]
+isometry2s!(
+    DIsometry2 => (DRotor2, DVec2, f64),
+    DIsometry2x2 => (DRotor2x2, DVec2x2, f64x2),
+    DIsometry2x4 => (DRotor2x4, DVec2x4, f64x4)
+);
+
+macro_rules! isometry3s {
+    ($($ison:ident => ($m3t:ident, $vt:ident)),+) => {
+        $(impl $ison {
+            /// Construct a 'look-at' isometry from an eye position, a focus position to look towards,
+            /// and a vector that defines the 'up' direction.
+            ///
+            /// This is the world-to-view transform equivalent to `Mat4::look_at`, and so assumes
+            /// a *right*-handed, y-up coordinate space. Its inverse is the pose of the camera
+            /// in world space.
+            ///
+            /// There is no counterpart to `Mat4::look_at_lh`, since that matrix flips the
+            /// handedness of the basis and so is not an isometry.
+            #[inline]
+            pub fn look_at(eye: $vt, at: $vt, up: $vt) -> Self {
+                let f = (at - eye).normalized();
+                let r = f.cross(up).normalized();
+                let u = r.cross(f);
+                let basis = $m3t::new(
+                    $vt::new(r.x, u.x, -f.x),
+                    $vt::new(r.y, u.y, -f.y),
+                    $vt::new(r.z, u.z, -f.z),
+                );
+                let rotation = basis.into_rotor3();
+                Self::new(-(rotation * eye), rotation)
+            }
+        })+
+    }
+}
+
+isometry3s!(
+    Isometry3 => (Mat3, Vec3),
+    Isometry3x4 => (Mat3x4, Vec3x4),
+    Isometry3x8 => (Mat3x8, Vec3x8)
+);
+
+#[cfg(feature = "portable-simd")]
+isometry3s!(Isometry3x16 => (Mat3x16, Vec3x16));
+
+#[cfg(feature = "f64")]
+isometry3s!(
+    DIsometry3 => (DMat3, DVec3),
+    DIsometry3x2 => (DMat3x2, DVec3x2),
+    DIsometry3x4 => (DMat3x4, DVec3x4)
+);
+
 macro_rules! similarities {
-    ($($sn:ident => ($mt:ident, $rt:ident, $vt:ident, $t:ident)),+) => {
+    ($($sn:ident => ($mt:ident, $rt:ident, $vt:ident, $ison:ident, $t:ident)),+) => {
         $(
         /// A Similarity, i.e. an Isometry but with an added uniform scaling.
         ///
@@ -227,8 +313,8 @@ macro_rules! similarities {
             }
 
             #[inline]
-            pub fn identity() -> Self {
-                Self { rotation: $rt::identity(), translation: $vt::zero(), scale: $t::splat(1.0) }
+            pub const fn identity() -> Self {
+                Self { rotation: $rt::identity(), translation: $vt::zero(), scale: <$t as Consts>::ONE }
             }
 
             /// Add a scaling *before* this similarity.
@@ -257,7 +343,7 @@ macro_rules! similarities {
             /// part of this similarity, not the translational part.
             #[inline]
             pub fn prepend_rotation(&mut self, rotor: $rt) {
-                self.rotation = rotor * self.rotation;
+                self.rotation *= rotor;
             }
 
             /// Add a rotation *after* this similarity.
@@ -265,6 +351,7 @@ macro_rules! similarities {
             /// This means the rotation will affect both the rotational and
             /// translational parts of this similarity, since it is being applied
             /// *after* this similarity's translational part.
+            #[inline]
             pub fn append_rotation(&mut self, rotor: $rt) {
                 self.rotation = rotor * self.rotation;
                 self.translation = rotor * self.translation;
@@ -296,7 +383,7 @@ macro_rules! similarities {
             /// will be scaled by the other similarity's scaling part.
             #[inline]
             pub fn prepend_similarity(&mut self, other: Self) {
-                *self = *self * other;
+                *self *= other;
             }
 
             /// Append transformation by another similarity.
@@ -310,6 +397,24 @@ macro_rules! similarities {
                 *self = other * *self;
             }
 
+            /// Prepend transformation by an isometry.
+            ///
+            /// This is the same as `prepend_similarity` with an unscaled similarity, i.e. the
+            /// isometry takes place *before* this similarity.
+            #[inline]
+            pub fn prepend_isometry(&mut self, other: $ison) {
+                self.prepend_similarity(other.into());
+            }
+
+            /// Append transformation by an isometry.
+            ///
+            /// This is the same as `append_similarity` with an unscaled similarity, i.e. the
+            /// isometry takes place *after* this similarity.
+            #[inline]
+            pub fn append_isometry(&mut self, other: $ison) {
+                self.append_similarity(other.into());
+            }
+
             #[inline]
             pub fn inverse(&mut self) {
                 self.rotation.reverse();
@@ -339,6 +444,13 @@ macro_rules! similarities {
             }
         }
 
+        impl From<$ison> for $sn {
+            #[inline]
+            fn from(iso: $ison) -> Self {
+                Self::new(iso.translation, iso.rotation, $t::splat(1.0))
+            }
+        }
+
         impl Mul<$sn> for $rt {
             type Output = $sn;
             #[inline]
@@ -376,6 +488,13 @@ macro_rules! similarities {
             }
         }
 
+        impl MulAssign<$sn> for $sn {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $sn) {
+                *self = *self * rhs;
+            }
+        }
+
         impl Mul<$sn> for $sn {
             type Output = Self;
             #[inline]
@@ -402,22 +521,770 @@ macro_rules! similarities {
 }
 
 similarities!(
-    Similarity2 => (Mat3, Rotor2, Vec2, f32),
-    Similarity2x4 => (Mat3x4, Rotor2x4, Vec2x4, f32x4),
-    Similarity2x8 => (Mat3x8, Rotor2x8, Vec2x8, f32x8),
+    Similarity2 => (Mat3, Rotor2, Vec2, Isometry2, f32),
+    Similarity2x4 => (Mat3x4, Rotor2x4, Vec2x4, Isometry2x4, f32x4),
+    Similarity2x8 => (Mat3x8, Rotor2x8, Vec2x8, Isometry2x8, f32x8),
 
-    Similarity3 => (Mat4, Rotor3, Vec3, f32),
-    Similarity3x4 => (Mat4x4, Rotor3x4, Vec3x4, f32x4),
-    Similarity3x8 => (Mat4x8, Rotor3x8, Vec3x8, f32x8)
+    Similarity3 => (Mat4, Rotor3, Vec3, Isometry3, f32),
+    Similarity3x4 => (Mat4x4, Rotor3x4, Vec3x4, Isometry3x4, f32x4),
+    Similarity3x8 => (Mat4x8, Rotor3x8, Vec3x8, Isometry3x8, f32x8)
 );
 
 #[cfg(feature = "f64")]
 similarities!(
-    DSimilarity2 => (DMat3, DRotor2, DVec2, f64),
-    DSimilarity2x2 => (DMat3x2, DRotor2x2, DVec2x2, f64x2),
-    DSimilarity2x4 => (DMat3x4, DRotor2x4, DVec2x4, f64x4),
+    DSimilarity2 => (DMat3, DRotor2, DVec2, DIsometry2, f64),
+    DSimilarity2x2 => (DMat3x2, DRotor2x2, DVec2x2, DIsometry2x2, f64x2),
+    DSimilarity2x4 => (DMat3x4, DRotor2x4, DVec2x4, DIsometry2x4, f64x4),
+
+    DSimilarity3 => (DMat4, DRotor3, DVec3, DIsometry3, f64),
+    DSimilarity3x2 => (DMat4x2, DRotor3x2, DVec3x2, DIsometry3x2, f64x2),
+    DSimilarity3x4 => (DMat4x4, DRotor3x4, DVec3x4, DIsometry3x4, f64x4)
+);
+
+macro_rules! transforms {
+    ($($tn:ident => ($mt:ident, $rt:ident, $vt:ident, $ison:ident, $sn:ident, $t:ident)),+) => {
+        $(
+        /// A 3d transformation with a non-uniform scale, i.e. a Similarity whose scale may differ
+        /// along each axis.
+        ///
+        /// Defined as a (non-uniform) scaling followed by a rotation followed by a translation.
+        ///
+        /// Note that, unlike isometries and similarities, such transforms are not closed under
+        /// composition and inversion: the exact result can contain shear, which is not representable
+        /// by this type. Composition is therefore only exact when the scale being carried through
+        /// a rotation is uniform, which is the same approximation most game engines make for their
+        /// scene hierarchies, and only the scalar types can be inverted, when their scale is
+        /// uniform. Use a homogeneous `Mat4` when you need exact results with arbitrary scales.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(C)]
+        pub struct $tn {
+            pub translation: $vt,
+            pub rotation: $rt,
+            pub scale: $vt,
+        }
+
+        derive_default_identity!($tn);
+
+        impl $tn {
+            #[inline]
+            pub const fn new(translation: $vt, rotation: $rt, scale: $vt) -> Self {
+                Self { translation, rotation, scale }
+            }
+
+            #[inline]
+            pub const fn identity() -> Self {
+                Self { rotation: $rt::identity(), translation: $vt::zero(), scale: $vt::one() }
+            }
+
+            /// Transform a vector by self, interpreting it as a point, i.e. it will be
+            /// scaled, rotated and translated.
+            #[inline]
+            pub fn transform_vec(&self, mut vec: $vt) -> $vt {
+                vec *= self.scale;
+                vec = self.rotation * vec;
+                vec += self.translation;
+                vec
+            }
+
+            /// Transform a vector by self, interpreting it as a direction or displacement,
+            /// i.e. it will only be scaled and rotated.
+            ///
+            /// Note that this does not preserve the length of the vector, nor is it
+            /// the right way to transform surface normals when the scale is non-uniform.
+            #[inline]
+            pub fn transform_direction(&self, vec: $vt) -> $vt {
+                self.rotation * (vec * self.scale)
+            }
+
+            #[inline]
+            pub fn into_homogeneous_matrix(self) -> $mt {
+                $mt::from_translation_rotation_scale(self.translation, self.rotation, self.scale)
+            }
+        }
+
+        impl From<$ison> for $tn {
+            #[inline]
+            fn from(iso: $ison) -> Self {
+                Self::new(iso.translation, iso.rotation, $vt::one())
+            }
+        }
+
+        impl From<$sn> for $tn {
+            #[inline]
+            fn from(sim: $sn) -> Self {
+                Self::new(sim.translation, sim.rotation, $vt::new(sim.scale, sim.scale, sim.scale))
+            }
+        }
+
+        impl Mul<$vt> for $tn {
+            type Output = $vt;
+            #[inline]
+            fn mul(self, vec: $vt) -> $vt {
+                self.transform_vec(vec)
+            }
+        }
+
+        /// Compose two transforms, such that `base` is applied first.
+        ///
+        /// This is only exact if `self.scale` is uniform, see the type level documentation.
+        impl Mul<$tn> for $tn {
+            type Output = Self;
+            #[inline]
+            fn mul(self, base: $tn) -> $tn {
+                let trans = self.transform_vec(base.translation);
+                let rot = self.rotation * base.rotation;
+                let scale = self.scale * base.scale;
+                $tn::new(trans, rot, scale)
+            }
+        }
+
+        impl MulAssign<$tn> for $tn {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $tn) {
+                *self = *self * rhs;
+            }
+        }
+        )+
+    }
+}
+
+transforms!(
+    Transform3 => (Mat4, Rotor3, Vec3, Isometry3, Similarity3, f32),
+    Transform3x4 => (Mat4x4, Rotor3x4, Vec3x4, Isometry3x4, Similarity3x4, f32x4),
+    Transform3x8 => (Mat4x8, Rotor3x8, Vec3x8, Isometry3x8, Similarity3x8, f32x8)
+);
+
+#[cfg(feature = "f64")]
+transforms!(
+    DTransform3 => (DMat4, DRotor3, DVec3, DIsometry3, DSimilarity3, f64),
+    DTransform3x2 => (DMat4x2, DRotor3x2, DVec3x2, DIsometry3x2, DSimilarity3x2, f64x2),
+    DTransform3x4 => (DMat4x4, DRotor3x4, DVec3x4, DIsometry3x4, DSimilarity3x4, f64x4)
+);
+
+macro_rules! transform3_inverse {
+    ($($tn:ident => ($t:ident, $tolerance:expr)),+) => {
+        $(impl $tn {
+            /// The inverse of this transform, or `None` if its scale is not uniform.
+            ///
+            /// The inverse of a non-uniform scale followed by a rotation is a rotation followed
+            /// by a scale, which is in general a shear and so not representable by this type.
+            #[doc = concat!("The scale is uniform if its components agree within a relative tolerance of `", stringify!($tolerance), "`.")]
+            #[inline]
+            pub fn inversed(self) -> Option<Self> {
+                let (sx, sy, sz) = (self.scale.x.abs(), self.scale.y.abs(), self.scale.z.abs());
+                let max = sx.max(sy).max(sz);
+                if (sx - sy).abs() > $tolerance * max || (sx - sz).abs() > $tolerance * max {
+                    return None;
+                }
+
+                let rotation = self.rotation.reversed();
+                let scale = self.scale.recip();
+                let translation = rotation * (-self.translation) * scale;
+                Some(Self::new(translation, rotation, scale))
+            }
+        })+
+    }
+}
+
+transform3_inverse!(Transform3 => (f32, 1e-5));
+
+#[cfg(feature = "f64")]
+transform3_inverse!(DTransform3 => (f64, 1e-10));
+
+macro_rules! impl_interpolate_with {
+    ($($n:ident => $t:ident [$($scale:ident)?]),+) => {
+        $(impl $n {
+            /// Interpolate between `self` and `other` by `t` from 0.0 to 1.0, by linearly
+            /// interpolating the translation and scale and spherically interpolating the rotation.
+            ///
+            /// The rotation takes the shortest path and is normalized, so the result can be used
+            /// directly, e.g. to interpolate between two network updates or to blend the poses
+            /// of two animations. For the wide types, each lane is interpolated by its own `t`.
+            #[inline]
+            pub fn interpolate_with(&self, other: Self, t: $t) -> Self {
+                let mut result = *self;
+                result.translation = self.translation.lerp(other.translation, t);
+                result.rotation = self.rotation.slerp(other.rotation, t).normalized();
+                $(result.$scale = self.$scale.lerp(other.$scale, t);)?
+                result
+            }
+        })+
+    };
+}
+
+impl_interpolate_with!(
+    Isometry3 => f32 [], Isometry3x4 => f32x4 [], Isometry3x8 => f32x8 [],
+    Similarity3 => f32 [scale], Similarity3x4 => f32x4 [scale], Similarity3x8 => f32x8 [scale]
+);
+
+#[cfg(feature = "f64")]
+impl_interpolate_with!(
+    DIsometry3 => f64 [], DIsometry3x2 => f64x2 [], DIsometry3x4 => f64x4 [],
+    DSimilarity3 => f64 [scale], DSimilarity3x2 => f64x2 [scale], DSimilarity3x4 => f64x4 [scale]
+);
+
+macro_rules! impl_isometry3_log {
+    ($($n:ident => ($vt:ident, $rt:ident, $bt:ident, $t:ident)),+) => {
+        $(impl $n {
+            /// The logarithm of `self`, i.e. its twist coordinates `(rotation, velocity)` such
+            /// that `Self::exp(rotation, velocity) == self`.
+            ///
+            /// `rotation` is the plane of the rotation scaled by its angle, with the angle between
+            /// 0 and PI, so that `Rotor3::from_angle_plane(rotation.mag(), rotation.normalized())`
+            /// is the rotation of `self`. Following this screw motion for unit time, rotating by
+            /// `rotation` and translating by `velocity` at the same time, ends up at `self`.
+            /// Twists can be added and scaled, e.g. for interpolating along a screw motion or
+            /// measuring the error between two poses as `(a.inversed() * b).ln()`.
+            ///
+            /// `self.rotation` must be normalized.
+            pub fn ln(&self) -> ($bt, $vt) {
+                // -r represents the same rotation as r, with an angle between 0 and PI.
+                let r = if self.rotation.s < 0.0 { self.rotation * -1.0 } else { self.rotation };
+                let sin_half = r.bv.mag();
+                let half = Transcendental::atan2(sin_half, r.s);
+                let scale = if sin_half > 0.0 { half / sin_half } else { 1.0 };
+                let rotation = r.bv * (-2.0 * scale);
+
+                let angle = 2.0 * half;
+                let axis = Self::twist_axis(rotation);
+                // The inverse of the matrix which integrates the velocity along the screw.
+                let c = if angle < 0.1 {
+                    1.0 / 12.0 + angle * angle / 720.0
+                } else {
+                    let (sin, cos) = Transcendental::sin_cos(angle);
+                    (1.0 - angle * sin / (2.0 * (1.0 - cos))) / (angle * angle)
+                };
+                let t = self.translation;
+                let at = axis.cross(t);
+                (rotation, t - at * 0.5 + axis.cross(at) * c)
+            }
+
+            /// The isometry reached by following the screw motion with twist coordinates
+            /// `(rotation, velocity)` for unit time. The inverse of [`Self::ln`].
+            pub fn exp(rotation: $bt, velocity: $vt) -> Self {
+                let angle = rotation.mag();
+                let axis = Self::twist_axis(rotation);
+                let (sin, cos) = Transcendental::sin_cos(angle);
+                let (b, c) = if angle < 0.1 {
+                    let a2 = angle * angle;
+                    (
+                        0.5 - a2 / 24.0 + a2 * a2 / 720.0,
+                        1.0 / 6.0 - a2 / 120.0 + a2 * a2 / 5040.0,
+                    )
+                } else {
+                    ((1.0 - cos) / (angle * angle), (angle - sin) / (angle * angle * angle))
+                };
+                let av = axis.cross(velocity);
+                let translation = velocity + av * b + axis.cross(av) * c;
+
+                let (sin_half, cos_half) = Transcendental::sin_cos(angle * 0.5);
+                let scale = if angle > 0.0 { sin_half / angle } else { 0.5 };
+                Self::new(translation, $rt::new(cos_half, rotation * -scale))
+            }
+
+            /// The axis of the rotation `rotation`, scaled by its angle.
+            #[inline]
+            fn twist_axis(rotation: $bt) -> $vt {
+                $vt::new(rotation.yz, -rotation.xz, rotation.xy)
+            }
+        })+
+    };
+}
+
+impl_isometry3_log!(Isometry3 => (Vec3, Rotor3, Bivec3, f32));
+
+#[cfg(feature = "f64")]
+impl_isometry3_log!(DIsometry3 => (DVec3, DRotor3, DBivec3, f64));
+
+macro_rules! impl_invert_all {
+    ($($n:ident => ($wn:ident, $wvt:ident, $wrt:ident, $wbt:ident, $wt:ident, $vt:ident, $rt:ident, $bt:ident, $t:ident, $lanes:expr) [$($scale:ident)?]),+) => {
+        $(impl $n {
+            /// Invert every transform in `transforms` in place.
+            ///
+            /// This is equivalent to calling `inverse` on each element, but does the work
+            /// in blocks using the corresponding wide type, which is considerably faster for
+            /// large batches such as inverse bind poses.
+            pub fn invert_all(transforms: &mut [$n]) {
+                let mut chunks = transforms.chunks_exact_mut($lanes);
+                for chunk in &mut chunks {
+                    let lanes = |f: fn(&$n) -> $t| {
+                        let mut a = [0.0; $lanes];
+                        for (a, tf) in a.iter_mut().zip(chunk.iter()) {
+                            *a = f(tf);
+                        }
+                        $wt::from(a)
+                    };
+
+                    let mut wide = $wn {
+                        translation: $wvt::new(
+                            lanes(|tf| tf.translation.x),
+                            lanes(|tf| tf.translation.y),
+                            lanes(|tf| tf.translation.z),
+                        ),
+                        rotation: $wrt::new(
+                            lanes(|tf| tf.rotation.s),
+                            $wbt::new(
+                                lanes(|tf| tf.rotation.bv.xy),
+                                lanes(|tf| tf.rotation.bv.xz),
+                                lanes(|tf| tf.rotation.bv.yz),
+                            ),
+                        ),
+                        $($scale: lanes(|tf| tf.$scale),)?
+                    };
+
+                    wide.inverse();
+
+                    let tx: [$t; $lanes] = wide.translation.x.into();
+                    let ty: [$t; $lanes] = wide.translation.y.into();
+                    let tz: [$t; $lanes] = wide.translation.z.into();
+                    let s: [$t; $lanes] = wide.rotation.s.into();
+                    let xy: [$t; $lanes] = wide.rotation.bv.xy.into();
+                    let xz: [$t; $lanes] = wide.rotation.bv.xz.into();
+                    let yz: [$t; $lanes] = wide.rotation.bv.yz.into();
+                    $(let $scale: [$t; $lanes] = wide.$scale.into();)?
+
+                    for (i, tf) in chunk.iter_mut().enumerate() {
+                        tf.translation = $vt::new(tx[i], ty[i], tz[i]);
+                        tf.rotation = $rt::new(s[i], $bt::new(xy[i], xz[i], yz[i]));
+                        $(tf.$scale = $scale[i];)?
+                    }
+                }
+
+                for tf in chunks.into_remainder() {
+                    tf.inverse();
+                }
+            }
+        }
+
+        impl InvertAll for $n {
+            #[inline]
+            fn invert_all(transforms: &mut [Self]) {
+                $n::invert_all(transforms);
+            }
+        })+
+    };
+}
+
+/// Transforms which can be inverted in batches with [`invert_all`].
+pub trait InvertAll: Sized {
+    /// Invert every transform in `transforms` in place.
+    fn invert_all(transforms: &mut [Self]);
+}
 
-    DSimilarity3 => (DMat4, DRotor3, DVec3, f64),
-    DSimilarity3x2 => (DMat4x2, DRotor3x2, DVec3x2, f64x2),
-    DSimilarity3x4 => (DMat4x4, DRotor3x4, DVec3x4, f64x4)
+/// Invert every transform in `transforms` in place, in blocks of the corresponding wide type.
+///
+/// This is the same as calling e.g. [`Isometry3::invert_all`], for code which is generic over
+/// the transform type or prefers a free function.
+///
+/// ```rust
+/// # use ultraviolet::*;
+/// let mut bind_poses = vec![Isometry3::new(Vec3::unit_x(), Rotor3::identity()); 10];
+/// transform::invert_all(&mut bind_poses);
+/// assert_eq!(bind_poses[9].translation, -Vec3::unit_x());
+/// ```
+#[inline]
+pub fn invert_all<T: InvertAll>(transforms: &mut [T]) {
+    T::invert_all(transforms);
+}
+
+impl_invert_all!(
+    Isometry3 => (Isometry3x8, Vec3x8, Rotor3x8, Bivec3x8, f32x8, Vec3, Rotor3, Bivec3, f32, 8) [],
+    Similarity3 => (Similarity3x8, Vec3x8, Rotor3x8, Bivec3x8, f32x8, Vec3, Rotor3, Bivec3, f32, 8) [scale]
+);
+
+#[cfg(feature = "f64")]
+impl_invert_all!(
+    DIsometry3 => (DIsometry3x4, DVec3x4, DRotor3x4, DBivec3x4, f64x4, DVec3, DRotor3, DBivec3, f64, 4) [],
+    DSimilarity3 => (DSimilarity3x4, DVec3x4, DRotor3x4, DBivec3x4, f64x4, DVec3, DRotor3, DBivec3, f64, 4) [scale]
 );
+
+macro_rules! transform_stacks {
+    ($($n:ident => ($mt:ident, $ison:ident, $sn:ident, $vt:ident)),+) => {
+        $(
+        /// A stack of nested transformations, such as the nodes of a scene graph, which keeps
+        /// the composed world matrix of each level so that the current one is always at hand.
+        ///
+        /// This suits immediate-mode rendering and debug drawing, where the transformation of
+        /// a parent is pushed before drawing its children and popped afterwards.
+        ///
+        /// ```rust
+        /// # use ultraviolet::*;
+        /// let mut stack = TransformStack::new();
+        /// stack.push_isometry(Isometry3::new(Vec3::unit_x(), Rotor3::identity()));
+        /// stack.push_similarity(Similarity3::new(Vec3::zero(), Rotor3::identity(), 2.0));
+        /// assert_eq!(stack.transform_point3(Vec3::unit_y()), Vec3::new(1.0, 2.0, 0.0));
+        ///
+        /// stack.pop();
+        /// assert_eq!(stack.transform_point3(Vec3::unit_y()), Vec3::new(1.0, 1.0, 0.0));
+        /// ```
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $n {
+            worlds: Vec<$mt>,
+        }
+
+        impl Default for $n {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl $n {
+            /// A stack containing only the identity at its root.
+            #[inline]
+            pub fn new() -> Self {
+                Self::with_root($mt::identity())
+            }
+
+            /// A stack with the given root transformation, which is never popped.
+            #[inline]
+            pub fn with_root(root: $mt) -> Self {
+                Self { worlds: alloc::vec![root] }
+            }
+
+            /// Push a transformation relative to the current one, which it is applied *before*.
+            #[inline]
+            pub fn push_matrix(&mut self, local: $mt) {
+                let world = self.world() * local;
+                self.worlds.push(world);
+            }
+
+            /// Push an isometry relative to the current transformation, see [`Self::push_matrix`].
+            #[inline]
+            pub fn push_isometry(&mut self, local: $ison) {
+                self.push_matrix(local.into_homogeneous_matrix());
+            }
+
+            /// Push a similarity relative to the current transformation, see [`Self::push_matrix`].
+            #[inline]
+            pub fn push_similarity(&mut self, local: $sn) {
+                self.push_matrix(local.into_homogeneous_matrix());
+            }
+
+            /// Pop the most recently pushed transformation, returning the world matrix it had.
+            ///
+            /// Returns `None` and leaves the stack unchanged if only the root is left.
+            #[inline]
+            pub fn pop(&mut self) -> Option<$mt> {
+                if self.worlds.len() > 1 {
+                    self.worlds.pop()
+                } else {
+                    None
+                }
+            }
+
+            /// Pop everything but the root.
+            #[inline]
+            pub fn clear(&mut self) {
+                self.worlds.truncate(1);
+            }
+
+            /// The number of transformations pushed on top of the root.
+            #[inline]
+            pub fn depth(&self) -> usize {
+                self.worlds.len() - 1
+            }
+
+            /// The current world matrix, i.e. the composition of the root and every
+            /// transformation pushed since.
+            #[inline]
+            pub fn world(&self) -> $mt {
+                // The root can't be popped, so there is always a last element.
+                self.worlds[self.worlds.len() - 1]
+            }
+
+            /// Push `local`, call `f` with the stack, and pop `local` again.
+            #[inline]
+            pub fn scoped<R>(&mut self, local: $mt, f: impl FnOnce(&mut Self) -> R) -> R {
+                let depth = self.depth();
+                self.push_matrix(local);
+                let result = f(self);
+                self.worlds.truncate(depth + 1);
+                result
+            }
+
+            /// Transform a point from the current space to world space.
+            #[inline]
+            pub fn transform_point3(&self, point: $vt) -> $vt {
+                self.world().transform_point3(point)
+            }
+
+            /// Transform a direction from the current space to world space, ignoring translation.
+            #[inline]
+            pub fn transform_vec3(&self, vec: $vt) -> $vt {
+                self.world().transform_vec3(vec)
+            }
+        }
+        )+
+    };
+}
+
+transform_stacks!(TransformStack => (Mat4, Isometry3, Similarity3, Vec3));
+
+#[cfg(feature = "f64")]
+transform_stacks!(DTransformStack => (DMat4, DIsometry3, DSimilarity3, DVec3));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    pub fn invert_all_matches_inverse() {
+        let transforms: Vec<Similarity3> = (0..11)
+            .map(|i| {
+                let i = i as f32;
+                Similarity3::new(
+                    Vec3::new(i, -2.0 * i, 0.5),
+                    Rotor3::from_euler_angles(0.1 * i, 0.3, -0.2 * i),
+                    1.0 + 0.1 * i,
+                )
+            })
+            .collect();
+
+        let mut inverted = transforms.clone();
+        Similarity3::invert_all(&mut inverted);
+        for (tf, inv) in transforms.iter().zip(inverted.iter()) {
+            let expected = tf.inversed();
+            assert!(inv.translation.eq_eps(expected.translation));
+            assert!(inv.rotation.eq_eps(expected.rotation));
+            assert!(inv.scale.eq_eps(expected.scale));
+        }
+
+        let mut isos: Vec<Isometry3> = transforms
+            .iter()
+            .map(|tf| Isometry3::new(tf.translation, tf.rotation))
+            .collect();
+        invert_all(&mut isos);
+        for (tf, inv) in transforms.iter().zip(isos.iter()) {
+            let expected = Isometry3::new(tf.translation, tf.rotation).inversed();
+            assert!(inv.translation.eq_eps(expected.translation));
+            assert!(inv.rotation.eq_eps(expected.rotation));
+        }
+    }
+
+    #[test]
+    pub fn isometry_look_at_and_pose() {
+        let eye = Vec3::new(1.0, 2.0, 3.0);
+        let at = Vec3::new(-2.0, 0.5, 1.0);
+        let up = Vec3::unit_y();
+        let view = Isometry3::look_at(eye, at, up);
+        let mat = Mat4::look_at(eye, at, up);
+        let close = |a: Mat4, b: Mat4| {
+            a.as_array()
+                .iter()
+                .zip(b.as_array())
+                .all(|(x, y)| (x - y).abs() < 1e-5)
+        };
+        assert!(close(view.into_homogeneous_matrix(), mat));
+        for p in [Vec3::zero(), at, Vec3::new(4.0, -1.0, 2.0)] {
+            assert!(view.transform_vec(p).eq_eps(mat.transform_point3(p)));
+        }
+        assert!(view.transform_vec(eye).mag() < 1e-6);
+
+        let pose = Isometry2::from_pose(Vec2::new(3.0, -1.0), core::f32::consts::FRAC_PI_2);
+        assert!(pose
+            .transform_vec(Vec2::unit_x())
+            .eq_eps(Vec2::new(3.0, 0.0)));
+    }
+
+    #[test]
+    pub fn prepend_append_match_composition() {
+        let base = Similarity3::new(
+            Vec3::new(1.0, -2.0, 0.5),
+            Rotor3::from_euler_angles(0.3, -0.7, 0.2),
+            2.0,
+        );
+        let rotor = Rotor3::from_euler_angles(-0.4, 0.1, 0.9);
+        let iso = Isometry3::new(Vec3::new(-3.0, 0.25, 4.0), rotor);
+        let v = Vec3::new(0.5, 1.5, -2.0);
+
+        let mut prepended = base;
+        prepended.prepend_rotation(rotor);
+        assert!(prepended
+            .transform_vec(v)
+            .eq_eps(base.transform_vec(rotor * v)));
+
+        let mut appended = base;
+        appended.append_rotation(rotor);
+        assert!(appended
+            .transform_vec(v)
+            .eq_eps(rotor * base.transform_vec(v)));
+
+        let mut prepended = base;
+        prepended.prepend_isometry(iso);
+        assert!(prepended
+            .transform_vec(v)
+            .eq_eps(base.transform_vec(iso.transform_vec(v))));
+
+        let mut appended = base;
+        appended.append_isometry(iso);
+        assert!(appended
+            .transform_vec(v)
+            .eq_eps(iso.transform_vec(base.transform_vec(v))));
+
+        let mut prepended = iso;
+        prepended.prepend_rotation(base.rotation);
+        assert!(prepended
+            .transform_vec(v)
+            .eq_eps(iso.transform_vec(base.rotation * v)));
+    }
+
+    #[test]
+    pub fn transform3_matches_matrix() {
+        let tf = Transform3::new(
+            Vec3::new(1.0, -2.0, 0.5),
+            Rotor3::from_euler_angles(0.3, -0.7, 0.2),
+            Vec3::new(2.0, 0.5, 3.0),
+        );
+        let mat = tf.into_homogeneous_matrix();
+        let v = Vec3::new(0.5, 1.5, -2.0);
+        assert!((tf * v).eq_eps(mat.transform_point3(v)));
+        assert!(tf.transform_direction(v).eq_eps(mat.transform_vec3(v)));
+
+        let uniform = Transform3::from(Similarity3::new(
+            Vec3::new(-3.0, 0.25, 4.0),
+            Rotor3::from_euler_angles(-0.4, 0.1, 0.9),
+            1.5,
+        ));
+        assert!((uniform * tf).transform_vec(v).eq_eps(uniform * (tf * v)));
+        let inverse = uniform.inversed().unwrap();
+        assert!(inverse.transform_vec(uniform * v).eq_eps(v));
+        assert!(inverse.transform_vec(Vec3::zero()).eq_eps(
+            uniform
+                .into_homogeneous_matrix()
+                .inversed()
+                .transform_point3(Vec3::zero())
+        ));
+    }
+
+    #[test]
+    pub fn transform3_inverse_non_uniform_scale() {
+        let rotation = Rotor3::from_euler_angles(0.3, -0.7, 0.2);
+        let tf = Transform3::new(
+            Vec3::new(1.0, -2.0, 0.5),
+            rotation,
+            Vec3::new(2.0, 0.5, 3.0),
+        );
+        assert_eq!(tf.inversed(), None);
+
+        let scaled = Transform3::new(tf.translation, rotation, Vec3::new(2.0, 2.0, 2.0 + 1e-7));
+        let inverse = scaled.inversed().unwrap();
+        let v = Vec3::new(0.5, 1.5, -2.0);
+        assert!(inverse.transform_vec(scaled * v).eq_eps(v));
+    }
+
+    #[test]
+    pub fn const_identities() {
+        const AXES: [Vec3; 3] = [Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z()];
+        const IDENTITY: Similarity3 = Similarity3::identity();
+        const BASIS: Mat3 = Mat3::identity();
+        const WIDE: Isometry3x4 = Isometry3x4::identity();
+
+        for (i, axis) in AXES.iter().enumerate() {
+            assert_eq!(IDENTITY.transform_vec(*axis), *axis);
+            assert_eq!(BASIS.cols[i], *axis);
+        }
+        assert_eq!(WIDE, Isometry3x4::new(Vec3x4::zero(), Rotor3x4::identity()));
+    }
+
+    #[test]
+    pub fn transform_stack() {
+        let iso = Isometry3::new(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(0.7));
+        let sim = Similarity3::new(
+            Vec3::new(-1.0, 0.0, 0.5),
+            Rotor3::from_rotation_yz(-0.3),
+            2.0,
+        );
+        let local = Mat4::from_nonuniform_scale(Vec3::new(1.0, 3.0, 0.5));
+        let v = Vec3::new(0.5, -1.0, 2.0);
+
+        let mut stack = TransformStack::new();
+        stack.push_isometry(iso);
+        stack.push_similarity(sim);
+        assert_eq!(stack.depth(), 2);
+        assert!(stack
+            .transform_point3(v)
+            .eq_eps(iso.transform_vec(sim.transform_vec(v))));
+
+        let nested = stack.scoped(local, |stack| {
+            assert_eq!(stack.depth(), 3);
+            stack.transform_point3(v)
+        });
+        assert_eq!(stack.depth(), 2);
+        assert!(nested.eq_eps(stack.transform_point3(local.transform_point3(v))));
+
+        assert!(stack.pop().is_some());
+        assert!(stack.transform_vec3(v).eq_eps(iso.rotation * v));
+        stack.clear();
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.world(), Mat4::identity());
+    }
+
+    #[test]
+    fn interpolate_with() {
+        let a = Similarity3::new(Vec3::new(1.0, 0.0, 0.0), Rotor3::identity(), 1.0);
+        let b = Similarity3::new(Vec3::new(3.0, 2.0, 0.0), Rotor3::from_rotation_xz(1.0), 2.0);
+        let mid = a.interpolate_with(b, 0.5);
+        assert!(mid.translation.eq_eps(Vec3::new(2.0, 1.0, 0.0)));
+        assert!(mid.rotation.eq_eps(Rotor3::from_rotation_xz(0.5)));
+        assert!(mid.scale.eq_eps(1.5));
+
+        // Rotors on opposite hemispheres still take the shortest path.
+        let c = Isometry3::new(Vec3::zero(), Rotor3::from_rotation_xz(1.0) * -1.0);
+        let iso = Isometry3::new(Vec3::zero(), Rotor3::identity()).interpolate_with(c, 0.5);
+        assert!(iso.rotation.eq_eps(Rotor3::from_rotation_xz(0.5)));
+
+        let splat = |sim: Similarity3| {
+            Similarity3x4::new(
+                Vec3x4::splat(sim.translation),
+                Rotor3x4::splat(sim.rotation),
+                f32x4::splat(sim.scale),
+            )
+        };
+        let wide = splat(a).interpolate_with(splat(b), f32x4::from([0.0, 0.5, 1.0, 0.25]));
+        let lanes: [Vec3; 4] = wide.translation.into();
+        assert!(lanes[1].eq_eps(mid.translation));
+        assert!(lanes[2].eq_eps(b.translation));
+        let scales: [f32; 4] = wide.scale.into();
+        assert!(scales[3].eq_eps(1.25));
+        let xz: [f32; 4] = wide.rotation.bv.xz.into();
+        assert!(xz[1].eq_eps(mid.rotation.bv.xz));
+    }
+
+    #[test]
+    fn isometry3_ln_exp() {
+        let iso = Isometry3::new(
+            Vec3::new(1.0, -2.0, 0.5),
+            Rotor3::from_euler_angles(0.3, -1.2, 2.0),
+        );
+        let (rotation, velocity) = iso.ln();
+        let back = Isometry3::exp(rotation, velocity);
+        assert!(back.translation.eq_eps(iso.translation));
+        assert!(back.rotation.eq_eps(iso.rotation));
+
+        let (angle, plane) = iso.rotation.into_angle_plane();
+        assert!(
+            Rotor3::from_angle_plane(rotation.mag(), rotation.normalized()).eq_eps(iso.rotation)
+        );
+        assert!(rotation.mag().eq_eps(angle) && rotation.normalized().eq_eps(plane));
+
+        // Following a tenth of the screw motion ten times ends up at the same place.
+        let step = Isometry3::exp(rotation * 0.1, velocity * 0.1);
+        let mut stepped = Isometry3::identity();
+        for _ in 0..10 {
+            stepped = step * stepped;
+        }
+        assert!(stepped.translation.eq_eps(iso.translation));
+        assert!(stepped.rotation.eq_eps(iso.rotation));
+
+        let small = Isometry3::new(Vec3::unit_z(), Rotor3::from_rotation_xy(1e-4));
+        let (rotation, velocity) = small.ln();
+        assert!(Isometry3::exp(rotation, velocity)
+            .translation
+            .eq_eps(small.translation));
+        let (rotation, velocity) = Isometry3::identity().ln();
+        assert_eq!(rotation, Bivec3::zero());
+        assert_eq!(velocity, Vec3::zero());
+    }
+}
@@ -0,0 +1,380 @@
+//! Thin newtypes that tag a value with its physical unit.
+//!
+//! These are entirely optional, but can help to avoid mixing up e.g. degrees and radians
+//! at API boundaries. The angle-taking constructors of the scalar rotors and matrices, and the
+//! perspective projections, have `_radians` variants which take anything that converts into
+//! [`Radians`], including [`Degrees`]:
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! let rotor = Rotor3::from_angle_plane_radians(Degrees(90.0), Bivec3::unit_xy());
+//! let proj = projection::perspective_vk_radians(Degrees(60.0), 16.0 / 9.0, 0.1, 100.0);
+//! ```
+//!
+//! The plain constructors take a bare value in radians, which [`Radians`] converts into with
+//! `.into()`.
+use crate::*;
+
+use core::ops::*;
+
+macro_rules! scalar_units {
+    ($($n:ident => $t:ident),+) => {
+        $(impl Add for $n {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                $n(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $n {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $n {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                $n(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign for $n {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Neg for $n {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                $n(-self.0)
+            }
+        }
+
+        impl Mul<$t> for $n {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: $t) -> Self {
+                $n(self.0 * rhs)
+            }
+        }
+
+        impl Mul<$n> for $t {
+            type Output = $n;
+            #[inline]
+            fn mul(self, rhs: $n) -> $n {
+                $n(self * rhs.0)
+            }
+        }
+
+        impl Div<$t> for $n {
+            type Output = Self;
+            #[inline]
+            fn div(self, rhs: $t) -> Self {
+                $n(self.0 / rhs)
+            }
+        }
+
+        /// The ratio between two quantities of the same unit.
+        impl Div for $n {
+            type Output = $t;
+            #[inline]
+            fn div(self, rhs: Self) -> $t {
+                self.0 / rhs.0
+            }
+        }
+        )+
+    };
+}
+
+macro_rules! units {
+    ($($rad:ident, $deg:ident, $sec:ident, $met:ident => ($vt:ident, $t:ident)),+) => {
+        $(/// An angle in radians.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        #[repr(transparent)]
+        pub struct $rad(pub $t);
+
+        /// An angle in degrees.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        #[repr(transparent)]
+        pub struct $deg(pub $t);
+
+        /// A duration in seconds.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        #[repr(transparent)]
+        pub struct $sec(pub $t);
+
+        /// A position or displacement in meters.
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[repr(transparent)]
+        pub struct $met(pub $vt);
+
+        impl $rad {
+            #[inline]
+            pub fn to_degrees(self) -> $deg {
+                $deg(self.0.to_degrees())
+            }
+
+            #[inline]
+            pub fn sin_cos(self) -> ($t, $t) {
+                Transcendental::sin_cos(self.0)
+            }
+        }
+
+        impl $deg {
+            #[inline]
+            pub fn to_radians(self) -> $rad {
+                $rad(self.0.to_radians())
+            }
+        }
+
+        impl From<$deg> for $rad {
+            #[inline]
+            fn from(deg: $deg) -> $rad {
+                deg.to_radians()
+            }
+        }
+
+        impl From<$rad> for $deg {
+            #[inline]
+            fn from(rad: $rad) -> $deg {
+                rad.to_degrees()
+            }
+        }
+
+        /// Unwrap the angle as a plain value in radians.
+        impl From<$rad> for $t {
+            #[inline]
+            fn from(rad: $rad) -> $t {
+                rad.0
+            }
+        }
+
+        impl From<$sec> for $t {
+            #[inline]
+            fn from(sec: $sec) -> $t {
+                sec.0
+            }
+        }
+
+        impl From<core::time::Duration> for $sec {
+            #[inline]
+            fn from(duration: core::time::Duration) -> $sec {
+                $sec(duration.as_secs_f64() as $t)
+            }
+        }
+
+        impl From<$met> for $vt {
+            #[inline]
+            fn from(met: $met) -> $vt {
+                met.0
+            }
+        }
+
+        impl Add for $met {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                $met(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $met {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $met {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                $met(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign for $met {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Neg for $met {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                $met(-self.0)
+            }
+        }
+
+        impl Mul<$t> for $met {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: $t) -> Self {
+                $met(self.0 * rhs)
+            }
+        }
+
+        impl Mul<$met> for $t {
+            type Output = $met;
+            #[inline]
+            fn mul(self, rhs: $met) -> $met {
+                $met(self * rhs.0)
+            }
+        }
+
+        impl Div<$t> for $met {
+            type Output = Self;
+            #[inline]
+            fn div(self, rhs: $t) -> Self {
+                $met(self.0 / rhs)
+            }
+        }
+
+        scalar_units!($rad => $t, $deg => $t, $sec => $t);
+        )+
+    };
+}
+
+units!(Radians, Degrees, Seconds, Meters => (Vec3, f32));
+
+#[cfg(feature = "f64")]
+units!(DRadians, DDegrees, DSeconds, DMeters => (DVec3, f64));
+
+macro_rules! angle_constructors {
+    ($($rad:ident => ($r2t:ident, $r3t:ident, $m3t:ident, $m4t:ident, $v3t:ident, $v4t:ident, $bt:ident)),+) => {
+        $(impl $r2t {
+            /// [`Self::from_angle`] with the angle in any unit which converts into radians.
+            #[inline]
+            pub fn from_angle_radians(angle: impl Into<$rad>) -> Self {
+                Self::from_angle(angle.into().0)
+            }
+        }
+
+        impl $r3t {
+            /// [`Self::from_angle_plane`] with the angle in any unit which converts into radians.
+            #[inline]
+            pub fn from_angle_plane_radians(angle: impl Into<$rad>, plane: $bt) -> Self {
+                Self::from_angle_plane(angle.into().0, plane)
+            }
+
+            /// [`Self::from_rotation_xy`] with the angle in any unit which converts into radians.
+            #[inline]
+            pub fn from_rotation_xy_radians(angle: impl Into<$rad>) -> Self {
+                Self::from_rotation_xy(angle.into().0)
+            }
+
+            /// [`Self::from_rotation_xz`] with the angle in any unit which converts into radians.
+            #[inline]
+            pub fn from_rotation_xz_radians(angle: impl Into<$rad>) -> Self {
+                Self::from_rotation_xz(angle.into().0)
+            }
+
+            /// [`Self::from_rotation_yz`] with the angle in any unit which converts into radians.
+            #[inline]
+            pub fn from_rotation_yz_radians(angle: impl Into<$rad>) -> Self {
+                Self::from_rotation_yz(angle.into().0)
+            }
+        }
+
+        angle_constructors!(@mat $rad => $m3t, $v3t, $bt);
+        angle_constructors!(@mat $rad => $m4t, $v4t, $bt);)+
+    };
+    (@mat $rad:ident => $mt:ident, $vt:ident, $bt:ident) => {
+        impl $mt {
+            /// [`Self::from_rotation_x`] with the angle in any unit which converts into radians.
+            #[inline]
+            pub fn from_rotation_x_radians(angle: impl Into<$rad>) -> Self {
+                Self::from_rotation_x(angle.into().0)
+            }
+
+            /// [`Self::from_rotation_y`] with the angle in any unit which converts into radians.
+            #[inline]
+            pub fn from_rotation_y_radians(angle: impl Into<$rad>) -> Self {
+                Self::from_rotation_y(angle.into().0)
+            }
+
+            /// [`Self::from_rotation_z`] with the angle in any unit which converts into radians.
+            #[inline]
+            pub fn from_rotation_z_radians(angle: impl Into<$rad>) -> Self {
+                Self::from_rotation_z(angle.into().0)
+            }
+
+            /// [`Self::from_rotation_around`] with the angle in any unit which converts into
+            /// radians.
+            #[inline]
+            pub fn from_rotation_around_radians(axis: $vt, angle: impl Into<$rad>) -> Self {
+                Self::from_rotation_around(axis, angle.into().0)
+            }
+
+            /// [`Self::from_angle_plane`] with the angle in any unit which converts into radians.
+            #[inline]
+            pub fn from_angle_plane_radians(angle: impl Into<$rad>, plane: $bt) -> Self {
+                Self::from_angle_plane(angle.into().0, plane)
+            }
+        }
+    };
+}
+
+angle_constructors!(Radians => (Rotor2, Rotor3, Mat3, Mat4, Vec3, Vec4, Bivec3));
+
+#[cfg(feature = "f64")]
+angle_constructors!(DRadians => (DRotor2, DRotor3, DMat3, DMat4, DVec3, DVec4, DBivec3));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn degrees_into_radians() {
+        let rad: f32 = Degrees(180.0).to_radians().into();
+        assert!((rad - core::f32::consts::PI).abs() < 1e-6);
+        assert_eq!(
+            Rotor3::from_angle_plane(Degrees(90.0).to_radians().into(), Bivec3::unit_xy()),
+            Rotor3::from_angle_plane(core::f32::consts::FRAC_PI_2, Bivec3::unit_xy())
+        );
+        assert!((Radians::from(Degrees(45.0)).to_degrees().0 - 45.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn typed_angle_constructors() {
+        use core::f32::consts::FRAC_PI_2;
+
+        let eq = |a: Mat4, b: Mat4| {
+            let (a, b) = (a.as_array(), b.as_array());
+            a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-6)
+        };
+        assert_eq!(
+            Rotor2::from_angle_radians(Radians(1.0)),
+            Rotor2::from_angle(1.0)
+        );
+        assert_eq!(
+            Rotor3::from_rotation_yz_radians(Degrees(90.0)),
+            Rotor3::from_rotation_yz(FRAC_PI_2)
+        );
+        assert_eq!(
+            Mat3::from_angle_plane_radians(Degrees(90.0), Bivec3::unit_xz()),
+            Mat3::from_angle_plane(FRAC_PI_2, Bivec3::unit_xz())
+        );
+        assert!(eq(
+            Mat4::from_rotation_around_radians(Vec4::unit_y(), Degrees(90.0)),
+            Mat4::from_rotation_around(Vec4::unit_y(), FRAC_PI_2)
+        ));
+        assert!(eq(
+            projection::lh_yup::perspective_infinite_z_gl_radians(Degrees(90.0), 1.5, 0.1),
+            projection::lh_yup::perspective_infinite_z_gl(FRAC_PI_2, 1.5, 0.1)
+        ));
+        assert!(eq(
+            projection::perspective_wgpu_dx_radians(Radians(0.8), 1.5, 0.1, 10.0),
+            projection::perspective_wgpu_dx(0.8, 1.5, 0.1, 10.0)
+        ));
+    }
+}
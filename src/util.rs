@@ -18,6 +18,508 @@ impl Splat<f64> for f64 {
     }
 }
 
+/// Zero and one as associated constants, so that constructors shared between scalar and
+/// wide types can be `const fn`.
+///
+/// Always refer to these with a qualified path, i.e. `<T as Consts>::ZERO`, as the wide
+/// types and `num_traits` both provide constants of the same name.
+pub(crate) trait Consts {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_consts {
+    ($($t:ident => $zero:expr, $one:expr),+) => {
+        $(impl Consts for $t {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+        })+
+    };
+}
+
+impl_consts!(
+    f32 => 0.0, 1.0,
+    f64 => 0.0, 1.0,
+    f32x4 => f32x4::ZERO, f32x4::ONE,
+    f32x8 => f32x8::ZERO, f32x8::ONE,
+    f64x2 => f64x2::ZERO, f64x2::ONE,
+    f64x4 => f64x4::ZERO, f64x4::ONE
+);
+
+#[cfg(feature = "portable-simd")]
+impl_consts!(f32x16 => f32x16::ZERO, f32x16::ONE);
+
+/// Float methods which are only inherent to `f32` and `f64` when `std` is available, and
+/// are otherwise provided through `libm`.
+#[cfg(not(feature = "std"))]
+#[allow(dead_code)]
+pub(crate) trait FloatExt: Sized {
+    fn sqrt(self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn fract(self) -> Self;
+}
+
+#[cfg(not(feature = "std"))]
+macro_rules! impl_float_ext {
+    ($t:ident => $sqrt:ident, $fma:ident, $floor:ident, $ceil:ident, $round:ident, $trunc:ident) => {
+        impl FloatExt for $t {
+            #[inline]
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                libm::$fma(self, a, b)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                libm::$floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                libm::$ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                libm::$round(self)
+            }
+
+            #[inline]
+            fn trunc(self) -> Self {
+                libm::$trunc(self)
+            }
+
+            #[inline]
+            fn fract(self) -> Self {
+                self - libm::$trunc(self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "std"))]
+impl_float_ext!(f32 => sqrtf, fmaf, floorf, ceilf, roundf, truncf);
+
+#[cfg(not(feature = "std"))]
+impl_float_ext!(f64 => sqrt, fma, floor, ceil, round, trunc);
+
+/// Fused multiply-add of the scalar and wide float types, i.e. `self * mul + add` with a single
+/// rounding.
+///
+/// The scalar types always compute this correctly rounded. `wide` only fuses the operation when
+/// the `fma` target feature is enabled, so with the `deterministic` feature the wide types
+/// compute it with `libm` on each lane instead.
+pub(crate) trait FusedMulAdd: Sized {
+    fn fused_mul_add(self, mul: Self, add: Self) -> Self;
+}
+
+macro_rules! impl_fused_mul_add {
+    ($($t:ident),+) => {
+        $(impl FusedMulAdd for $t {
+            #[inline]
+            fn fused_mul_add(self, mul: Self, add: Self) -> Self {
+                self.mul_add(mul, add)
+            }
+        })+
+    };
+}
+
+impl_fused_mul_add!(f32, f64);
+
+macro_rules! impl_fused_mul_add_wide {
+    ($($t:ident => $tt:ident),+) => {
+        $(impl FusedMulAdd for $t {
+            #[cfg(not(feature = "deterministic"))]
+            #[inline]
+            fn fused_mul_add(self, mul: Self, add: Self) -> Self {
+                self.mul_add(mul, add)
+            }
+
+            #[cfg(feature = "deterministic")]
+            #[inline]
+            fn fused_mul_add(self, mul: Self, add: Self) -> Self {
+                let (s, m, a) = (self.to_array(), mul.to_array(), add.to_array());
+                $t::from(core::array::from_fn(|i| <$tt as FusedMulAdd>::fused_mul_add(s[i], m[i], a[i])))
+            }
+        })+
+    };
+}
+
+impl_fused_mul_add_wide!(f32x4 => f32, f32x8 => f32, f64x2 => f64, f64x4 => f64);
+
+#[cfg(feature = "portable-simd")]
+impl_fused_mul_add_wide!(f32x16 => f32);
+
+/// Transcendental functions of the scalar and wide float types.
+///
+/// Always call these with a qualified path, i.e. `Transcendental::sin_cos(x)`, since the
+/// inherent methods of `f32` and `f64` would otherwise take precedence whenever `std` is linked.
+///
+/// The scalar types use `libm` when `std` is disabled or the `deterministic` feature is
+/// enabled, and the inherent methods otherwise. The wide types use the approximations provided
+/// by `wide`, whose results depend on the target features (e.g. whether FMA is available), so
+/// with the `deterministic` feature they use `libm` on each lane instead.
+#[allow(dead_code)]
+pub(crate) trait Transcendental: Sized {
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+}
+
+macro_rules! impl_transcendental {
+    ($t:ident => $sin:ident, $cos:ident, $tan:ident, $asin:ident, $acos:ident, $atan:ident,
+        $atan2:ident, $exp:ident, $ln:ident, $pow:ident) => {
+        #[cfg(any(not(feature = "std"), feature = "deterministic"))]
+        impl Transcendental for $t {
+            #[inline]
+            fn sin(self) -> Self {
+                libm::$sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                libm::$cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                libm::$tan(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                (libm::$sin(self), libm::$cos(self))
+            }
+
+            #[inline]
+            fn asin(self) -> Self {
+                libm::$asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                libm::$acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> Self {
+                libm::$atan(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                libm::$exp(self)
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                libm::$ln(self)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                libm::$pow(self, n)
+            }
+        }
+
+        #[cfg(all(feature = "std", not(feature = "deterministic")))]
+        impl Transcendental for $t {
+            #[inline]
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                $t::tan(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                $t::sin_cos(self)
+            }
+
+            #[inline]
+            fn asin(self) -> Self {
+                $t::asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> Self {
+                $t::atan(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                $t::powf(self, n)
+            }
+        }
+    };
+}
+
+impl_transcendental!(f32 => sinf, cosf, tanf, asinf, acosf, atanf, atan2f, expf, logf, powf);
+
+impl_transcendental!(f64 => sin, cos, tan, asin, acos, atan, atan2, exp, log, pow);
+
+macro_rules! impl_transcendental_wide {
+    ($($t:ident => $tt:ident, $pow:ident),+) => {
+        $(#[cfg(not(feature = "deterministic"))]
+        impl Transcendental for $t {
+            #[inline]
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                $t::tan(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                $t::sin_cos(self)
+            }
+
+            #[inline]
+            fn asin(self) -> Self {
+                $t::asin(self)
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+
+            #[inline]
+            fn atan(self) -> Self {
+                $t::atan(self)
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                $t::$pow(self, n)
+            }
+        }
+
+        #[cfg(feature = "deterministic")]
+        impl Transcendental for $t {
+            #[inline]
+            fn sin(self) -> Self {
+                $t::from(self.to_array().map(<$tt as Transcendental>::sin))
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                $t::from(self.to_array().map(<$tt as Transcendental>::cos))
+            }
+
+            #[inline]
+            fn tan(self) -> Self {
+                $t::from(self.to_array().map(<$tt as Transcendental>::tan))
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                (Transcendental::sin(self), Transcendental::cos(self))
+            }
+
+            #[inline]
+            fn asin(self) -> Self {
+                $t::from(self.to_array().map(<$tt as Transcendental>::asin))
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                $t::from(self.to_array().map(<$tt as Transcendental>::acos))
+            }
+
+            #[inline]
+            fn atan(self) -> Self {
+                $t::from(self.to_array().map(<$tt as Transcendental>::atan))
+            }
+
+            #[inline]
+            fn atan2(self, other: Self) -> Self {
+                let (a, b) = (self.to_array(), other.to_array());
+                $t::from(core::array::from_fn(|i| Transcendental::atan2(a[i], b[i])))
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                $t::from(self.to_array().map(<$tt as Transcendental>::exp))
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                $t::from(self.to_array().map(<$tt as Transcendental>::ln))
+            }
+
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                let (a, b) = (self.to_array(), n.to_array());
+                $t::from(core::array::from_fn(|i| Transcendental::powf(a[i], b[i])))
+            }
+        })+
+    };
+}
+
+impl_transcendental_wide!(
+    f32x4 => f32, pow_f32x4,
+    f32x8 => f32, pow_f32x8,
+    f64x2 => f64, pow_f64x2,
+    f64x4 => f64, pow_f64x4
+);
+
+#[cfg(feature = "portable-simd")]
+impl_transcendental_wide!(f32x16 => f32, pow_f32x16);
+
+/// Wide types which can be split into two values with half as many lanes each, and joined back
+/// together, for the conversions between the x4 and x8 (or x2 and x4) types.
+pub(crate) trait Halves: Sized {
+    type Half;
+
+    /// Join the lanes of `halves[0]` followed by those of `halves[1]`.
+    fn join(halves: [Self::Half; 2]) -> Self;
+
+    /// The first and the second half of the lanes of `self`.
+    fn split(self) -> [Self::Half; 2];
+}
+
+macro_rules! impl_halves_float {
+    ($($t:ident => ($ht:ident, $st:ident, $n:literal)),+) => {
+        $(impl Halves for $t {
+            type Half = $ht;
+
+            #[inline]
+            fn join([a, b]: [$ht; 2]) -> Self {
+                let (a, b): ([$st; $n], [$st; $n]) = (a.into(), b.into());
+                let mut lanes = [0.0; 2 * $n];
+                lanes[..$n].copy_from_slice(&a);
+                lanes[$n..].copy_from_slice(&b);
+                $t::from(lanes)
+            }
+
+            #[inline]
+            fn split(self) -> [$ht; 2] {
+                let lanes: [$st; 2 * $n] = self.into();
+                let (mut a, mut b) = ([0.0; $n], [0.0; $n]);
+                a.copy_from_slice(&lanes[..$n]);
+                b.copy_from_slice(&lanes[$n..]);
+                [$ht::from(a), $ht::from(b)]
+            }
+        })+
+    };
+}
+
+impl_halves_float!(f32x8 => (f32x4, f32, 4), f64x4 => (f64x2, f64, 2));
+
+#[cfg(feature = "portable-simd")]
+impl_halves_float!(f32x16 => (f32x8, f32, 8));
+
+/// Implement [`Halves`] and the `From` conversions to and from pairs of halves for wide structs,
+/// field by field.
+macro_rules! impl_halves {
+    ($($n:ident => $hn:ident: ($($f:ident),+)),+) => {
+        $(impl $crate::util::Halves for $n {
+            type Half = $hn;
+
+            #[inline]
+            fn join([a, b]: [$hn; 2]) -> Self {
+                Self { $($f: $crate::util::Halves::join([a.$f, b.$f])),+ }
+            }
+
+            #[inline]
+            fn split(self) -> [$hn; 2] {
+                $(let $f = $crate::util::Halves::split(self.$f);)+
+                [$hn { $($f: $f[0]),+ }, $hn { $($f: $f[1]),+ }]
+            }
+        }
+
+        impl From<[$hn; 2]> for $n {
+            #[inline]
+            fn from(halves: [$hn; 2]) -> Self {
+                $crate::util::Halves::join(halves)
+            }
+        }
+
+        impl From<$n> for [$hn; 2] {
+            #[inline]
+            fn from(wide: $n) -> Self {
+                $crate::util::Halves::split(wide)
+            }
+        })+
+    };
+}
+
+pub(crate) use impl_halves;
+
+#[allow(dead_code)]
 pub trait EqualsEps {
     fn eq_eps(self, other: Self) -> bool;
 }
@@ -37,33 +539,36 @@ macro_rules! impl_eq_eps_wide {
 
 impl_eq_eps_wide!(f32x4, f32x8, f64x2, f64x4);
 
+#[cfg(feature = "portable-simd")]
+impl_eq_eps_wide!(f32x16);
+
 impl EqualsEps for f32 {
     fn eq_eps(self, other: Self) -> bool {
         let diff = (self - other).abs();
-        if diff <= 0.01 {
-            true
-        } else {
+        let eq = diff <= 0.01;
+        #[cfg(any(feature = "std", test))]
+        if !eq {
             println!(
                 "{} should equal {} with epsilon 0.01 but doesn't.",
                 self, other
             );
-            false
         }
+        eq
     }
 }
 
 impl EqualsEps for f64 {
     fn eq_eps(self, other: Self) -> bool {
         let diff = (self - other).abs();
-        if diff <= 0.01 {
-            true
-        } else {
+        let eq = diff <= 0.01;
+        #[cfg(any(feature = "std", test))]
+        if !eq {
             println!(
                 "{} should equal {} with epsilon 0.01 but doesn't.",
                 self, other
             );
-            false
         }
+        eq
     }
 }
 
@@ -80,6 +585,7 @@ macro_rules! derive_default_identity {
 }
 
 /// A simple trait extension to simulate `TryFrom` for types that are not from this crate.
+#[cfg(feature = "int")]
 pub trait TryFromExt<Source>: Sized {
     type Error;
 
@@ -87,12 +593,14 @@ pub trait TryFromExt<Source>: Sized {
 }
 
 /// A simple trait extension to simulate `TryInto` for types that are not from this crate.
+#[cfg(feature = "int")]
 pub trait TryIntoExt<Target> {
     type Error;
 
     fn try_into(self) -> Result<Target, Self::Error>;
 }
 
+#[cfg(feature = "int")]
 impl<Source, Target, E> TryIntoExt<Target> for Source
 where
     Target: TryFromExt<Source, Error = E>,
@@ -103,3 +611,29 @@ where
         Target::try_from(self)
     }
 }
+
+#[cfg(all(test, feature = "deterministic"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deterministic_lanes_match_scalar() {
+        let angles = [-2.5f32, 0.1, 1.0, 3.0, 7.5, -0.7, 0.0, 100.0];
+        let (sin, cos) = Transcendental::sin_cos(f32x8::from(angles));
+        for (i, angle) in angles.iter().enumerate() {
+            assert_eq!(sin.as_array_ref()[i], libm::sinf(*angle));
+            assert_eq!(cos.as_array_ref()[i], libm::cosf(*angle));
+        }
+
+        let rotor = Rotor3::from_euler_angles(0.3, -1.2, 2.0);
+        let wide =
+            Rotor3x4::from_euler_angles(f32x4::splat(0.3), f32x4::splat(-1.2), f32x4::splat(2.0));
+        assert_eq!(wide.s.as_array_ref()[3], rotor.s);
+        assert_eq!(wide.bv.xz.as_array_ref()[3], rotor.bv.xz);
+        let a = f32x4::from([0.1, 1.0 / 3.0, 1e8, -2.5]);
+        let fused = FusedMulAdd::fused_mul_add(a, a, -a);
+        for (i, x) in a.to_array().iter().enumerate() {
+            assert_eq!(fused.as_array_ref()[i], libm::fmaf(*x, *x, -*x));
+        }
+    }
+}
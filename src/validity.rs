@@ -0,0 +1,225 @@
+//! Validity checks for catching degenerate math, such as `is_finite` and `has_nan`.
+use crate::*;
+
+use core::ops::{BitAnd, BitOr};
+
+/// Crate-private per-component checks, which are implemented for the scalar types and then
+/// reduced field by field for everything else. For scalar types the mask is a `bool`, while for
+/// wide types it is a lane mask.
+trait Validity {
+    type Mask: BitAnd<Output = Self::Mask> + BitOr<Output = Self::Mask> + Copy;
+
+    fn finite_mask(&self) -> Self::Mask;
+    fn nan_mask(&self) -> Self::Mask;
+}
+
+macro_rules! impl_validity_scalar {
+    ($($t:ident => $mask:ty),+) => {
+        $(impl Validity for $t {
+            type Mask = $mask;
+
+            #[inline]
+            fn finite_mask(&self) -> $mask {
+                self.is_finite()
+            }
+
+            #[inline]
+            fn nan_mask(&self) -> $mask {
+                self.is_nan()
+            }
+        })+
+    };
+}
+
+impl_validity_scalar!(f32 => bool, f64 => bool, f32x4 => f32x4, f32x8 => f32x8, f64x2 => f64x2, f64x4 => f64x4);
+
+impl<T: Validity, const N: usize> Validity for [T; N] {
+    type Mask = T::Mask;
+
+    #[inline]
+    fn finite_mask(&self) -> T::Mask {
+        self[1..]
+            .iter()
+            .fold(self[0].finite_mask(), |m, c| m & c.finite_mask())
+    }
+
+    #[inline]
+    fn nan_mask(&self) -> T::Mask {
+        self[1..]
+            .iter()
+            .fold(self[0].nan_mask(), |m, c| m | c.nan_mask())
+    }
+}
+
+macro_rules! validity {
+    ($($mask:ident => ($($n:ident { $f0:ident $(, $f:ident)* }),+)),+) => {
+        $($(impl Validity for $n {
+            type Mask = $mask;
+
+            #[inline]
+            fn finite_mask(&self) -> $mask {
+                self.$f0.finite_mask() $(& self.$f.finite_mask())*
+            }
+
+            #[inline]
+            fn nan_mask(&self) -> $mask {
+                self.$f0.nan_mask() $(| self.$f.nan_mask())*
+            }
+        }
+
+        impl $n {
+            /// Whether every component is finite, i.e. neither infinite nor NaN.
+            ///
+            /// For wide types, this returns a mask which is set in each lane for which this holds.
+            #[inline]
+            pub fn is_finite(&self) -> $mask {
+                self.finite_mask()
+            }
+
+            /// Whether any component is NaN.
+            ///
+            /// For wide types, this returns a mask which is set in each lane for which this holds.
+            #[inline]
+            pub fn has_nan(&self) -> $mask {
+                self.nan_mask()
+            }
+        })+)+
+    };
+}
+
+validity!(
+    bool => (
+        Vec2 { x, y },
+        Vec3 { x, y, z },
+        Vec4 { x, y, z, w },
+        Bivec2 { xy },
+        Bivec3 { xy, xz, yz },
+        Rotor2 { s, bv },
+        Rotor3 { s, bv },
+        Mat2 { cols },
+        Mat3 { cols },
+        Mat4 { cols },
+        Isometry2 { translation, rotation },
+        Isometry3 { translation, rotation },
+        Similarity2 { translation, rotation, scale },
+        Similarity3 { translation, rotation, scale },
+        Transform3 { translation, rotation, scale }
+    ),
+    m32x4 => (
+        Vec2x4 { x, y },
+        Vec3x4 { x, y, z },
+        Vec4x4 { x, y, z, w },
+        Bivec2x4 { xy },
+        Bivec3x4 { xy, xz, yz },
+        Rotor2x4 { s, bv },
+        Rotor3x4 { s, bv },
+        Mat2x4 { cols },
+        Mat3x4 { cols },
+        Mat4x4 { cols },
+        Isometry2x4 { translation, rotation },
+        Isometry3x4 { translation, rotation },
+        Similarity2x4 { translation, rotation, scale },
+        Similarity3x4 { translation, rotation, scale },
+        Transform3x4 { translation, rotation, scale }
+    ),
+    m32x8 => (
+        Vec2x8 { x, y },
+        Vec3x8 { x, y, z },
+        Vec4x8 { x, y, z, w },
+        Bivec2x8 { xy },
+        Bivec3x8 { xy, xz, yz },
+        Rotor2x8 { s, bv },
+        Rotor3x8 { s, bv },
+        Mat2x8 { cols },
+        Mat3x8 { cols },
+        Mat4x8 { cols },
+        Isometry2x8 { translation, rotation },
+        Isometry3x8 { translation, rotation },
+        Similarity2x8 { translation, rotation, scale },
+        Similarity3x8 { translation, rotation, scale },
+        Transform3x8 { translation, rotation, scale }
+    )
+);
+
+#[cfg(feature = "f64")]
+validity!(
+    bool => (
+        DVec2 { x, y },
+        DVec3 { x, y, z },
+        DVec4 { x, y, z, w },
+        DBivec2 { xy },
+        DBivec3 { xy, xz, yz },
+        DRotor2 { s, bv },
+        DRotor3 { s, bv },
+        DMat2 { cols },
+        DMat3 { cols },
+        DMat4 { cols },
+        DIsometry2 { translation, rotation },
+        DIsometry3 { translation, rotation },
+        DSimilarity2 { translation, rotation, scale },
+        DSimilarity3 { translation, rotation, scale },
+        DTransform3 { translation, rotation, scale }
+    ),
+    m64x2 => (
+        DVec2x2 { x, y },
+        DVec3x2 { x, y, z },
+        DVec4x2 { x, y, z, w },
+        DBivec2x2 { xy },
+        DBivec3x2 { xy, xz, yz },
+        DRotor2x2 { s, bv },
+        DRotor3x2 { s, bv },
+        DMat2x2 { cols },
+        DMat3x2 { cols },
+        DMat4x2 { cols },
+        DIsometry2x2 { translation, rotation },
+        DIsometry3x2 { translation, rotation },
+        DSimilarity2x2 { translation, rotation, scale },
+        DSimilarity3x2 { translation, rotation, scale },
+        DTransform3x2 { translation, rotation, scale }
+    ),
+    m64x4 => (
+        DVec2x4 { x, y },
+        DVec3x4 { x, y, z },
+        DVec4x4 { x, y, z, w },
+        DBivec2x4 { xy },
+        DBivec3x4 { xy, xz, yz },
+        DRotor2x4 { s, bv },
+        DRotor3x4 { s, bv },
+        DMat2x4 { cols },
+        DMat3x4 { cols },
+        DMat4x4 { cols },
+        DIsometry2x4 { translation, rotation },
+        DIsometry3x4 { translation, rotation },
+        DSimilarity2x4 { translation, rotation, scale },
+        DSimilarity3x4 { translation, rotation, scale },
+        DTransform3x4 { translation, rotation, scale }
+    )
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn finite_and_nan() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert!(v.is_finite() && !v.has_nan());
+        assert!(!Vec3::new(1.0, f32::INFINITY, 3.0).is_finite());
+        assert!(Vec3::new(1.0, 2.0, f32::NAN).has_nan());
+
+        let mut m = Mat4::identity();
+        assert!(m.is_finite());
+        m.cols[3].w = f32::NAN;
+        assert!(!m.is_finite() && m.has_nan());
+        assert!(Isometry3::new(v, Rotor3::new(f32::NAN, Bivec3::zero())).has_nan());
+
+        let w = Vec3x4::from([
+            v,
+            Vec3::broadcast(f32::NAN),
+            v,
+            Vec3::broadcast(f32::INFINITY),
+        ]);
+        assert_eq!(w.is_finite().move_mask(), 0b0101);
+        assert_eq!(w.has_nan().move_mask(), 0b0010);
+    }
+}
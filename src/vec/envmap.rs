@@ -0,0 +1,185 @@
+//! Mappings between directions and environment map texture coordinates, for skyboxes and
+//! image based lighting.
+//!
+//! Both mappings use the y-up convention of the rest of the crate, and texture coordinates
+//! have `(0, 0)` in the top left corner of the image, as in Vulkan, D3D and most image formats.
+use crate::*;
+
+macro_rules! equirects {
+    ($($tt:ident => ($v2t:ident, $vt:ident, $t:ident)),+) => {
+        $(impl $vt {
+            /// Map a direction to texture coordinates in an equirectangular (latitude-longitude)
+            /// environment map.
+            ///
+            /// `u` wraps around the vertical axis, starting and ending at `+z` with `-z` in the
+            /// middle of the image, and `v` goes from `+y` at the top to `-y` at the bottom.
+            /// The `u = 0` and `u = 1` columns are the same seam, so sample it with a repeating
+            /// address mode.
+            ///
+            /// `self` must be normalized.
+            #[inline]
+            pub fn to_equirect_uv(&self) -> $v2t {
+                let one = $t::splat(1.0);
+                let phi = Transcendental::atan2(self.x, -self.z);
+                let theta = Transcendental::acos(self.y.max(-one).min(one));
+                $v2t::new(
+                    $t::splat(0.5) + phi * $t::splat(0.5 * core::$tt::consts::FRAC_1_PI),
+                    theta * $t::splat(core::$tt::consts::FRAC_1_PI),
+                )
+            }
+
+            /// Map texture coordinates in an equirectangular environment map to the
+            /// normalized direction they represent, see [`Self::to_equirect_uv`].
+            #[inline]
+            pub fn from_equirect_uv(uv: $v2t) -> Self {
+                let phi = (uv.x - $t::splat(0.5)) * $t::splat(core::$tt::consts::TAU);
+                let theta = uv.y * $t::splat(core::$tt::consts::PI);
+                let (sin_phi, cos_phi) = Transcendental::sin_cos(phi);
+                let (sin_theta, cos_theta) = Transcendental::sin_cos(theta);
+                Self::new(sin_theta * sin_phi, cos_theta, -sin_theta * cos_phi)
+            }
+        })+
+    };
+}
+
+/// A face of a cube map, in the order in which they are stored as array layers in Vulkan, D3D
+/// and OpenGL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum CubeFace {
+    PositiveX = 0,
+    NegativeX = 1,
+    PositiveY = 2,
+    NegativeY = 3,
+    PositiveZ = 4,
+    NegativeZ = 5,
+}
+
+impl CubeFace {
+    /// All the faces, in layer order.
+    pub const ALL: [CubeFace; 6] = [
+        CubeFace::PositiveX,
+        CubeFace::NegativeX,
+        CubeFace::PositiveY,
+        CubeFace::NegativeY,
+        CubeFace::PositiveZ,
+        CubeFace::NegativeZ,
+    ];
+
+    /// The array layer of this face.
+    #[inline]
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+macro_rules! cubemaps {
+    ($($v2t:ident, $vt:ident => $t:ident),+) => {
+        $(impl $vt {
+            /// Find the cube map face a direction points at, and the texture coordinates on that
+            /// face, following the face orientations of the Vulkan, D3D and OpenGL specs.
+            ///
+            /// Directions exactly between two faces go to the face of the earlier axis, in the
+            /// order `x`, `y`, `z`. `self` need not be normalized, but must not be zero.
+            #[inline]
+            pub fn to_cube_face_uv(&self) -> (CubeFace, $v2t) {
+                let abs = self.abs();
+                let (face, ma, sc, tc) = if abs.x >= abs.y && abs.x >= abs.z {
+                    if self.x >= 0.0 {
+                        (CubeFace::PositiveX, abs.x, -self.z, -self.y)
+                    } else {
+                        (CubeFace::NegativeX, abs.x, self.z, -self.y)
+                    }
+                } else if abs.y >= abs.z {
+                    if self.y >= 0.0 {
+                        (CubeFace::PositiveY, abs.y, self.x, self.z)
+                    } else {
+                        (CubeFace::NegativeY, abs.y, self.x, -self.z)
+                    }
+                } else if self.z >= 0.0 {
+                    (CubeFace::PositiveZ, abs.z, self.x, -self.y)
+                } else {
+                    (CubeFace::NegativeZ, abs.z, -self.x, -self.y)
+                };
+                let uv = ($v2t::new(sc, tc) / ma + $v2t::one()) * 0.5;
+                (face, uv.clamped($v2t::zero(), $v2t::one()))
+            }
+
+            /// Map a cube map face and texture coordinates on it to the normalized direction
+            /// they represent, see [`Self::to_cube_face_uv`].
+            #[inline]
+            pub fn from_cube_face_uv(face: CubeFace, uv: $v2t) -> Self {
+                let sc = uv.x * 2.0 - 1.0;
+                let tc = uv.y * 2.0 - 1.0;
+                let dir = match face {
+                    CubeFace::PositiveX => $vt::new(1.0, -tc, -sc),
+                    CubeFace::NegativeX => $vt::new(-1.0, -tc, sc),
+                    CubeFace::PositiveY => $vt::new(sc, 1.0, tc),
+                    CubeFace::NegativeY => $vt::new(sc, -1.0, -tc),
+                    CubeFace::PositiveZ => $vt::new(sc, -tc, 1.0),
+                    CubeFace::NegativeZ => $vt::new(-sc, -tc, -1.0),
+                };
+                dir.normalized()
+            }
+        })+
+    };
+}
+
+equirects!(
+    f32 => (Vec2, Vec3, f32),
+    f32 => (Vec2x4, Vec3x4, f32x4),
+    f32 => (Vec2x8, Vec3x8, f32x8)
+);
+
+#[cfg(feature = "f64")]
+equirects!(
+    f64 => (DVec2, DVec3, f64),
+    f64 => (DVec2x2, DVec3x2, f64x2),
+    f64 => (DVec2x4, DVec3x4, f64x4)
+);
+
+cubemaps!(Vec2, Vec3 => f32);
+
+#[cfg(feature = "f64")]
+cubemaps!(DVec2, DVec3 => f64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    #[test]
+    fn equirect_roundtrip() {
+        assert!(Vec3::new(0.0, 0.0, -1.0)
+            .to_equirect_uv()
+            .eq_eps(Vec2::new(0.5, 0.5)));
+        assert!(Vec3::unit_x().to_equirect_uv().eq_eps(Vec2::new(0.75, 0.5)));
+        assert!(Vec3::unit_y().to_equirect_uv().y.eq_eps(0.0));
+
+        let dir = Vec3::new(0.3, -0.4, 0.5).normalized();
+        assert!(Vec3::from_equirect_uv(dir.to_equirect_uv()).eq_eps(dir));
+        let wide = Vec3x4::splat(dir).to_equirect_uv();
+        assert!(
+            Vec2::new(wide.x.as_array_ref()[0], wide.y.as_array_ref()[0])
+                .eq_eps(dir.to_equirect_uv())
+        );
+    }
+
+    #[test]
+    fn cube_face_roundtrip() {
+        // The top left texel of +X is towards +y and +z, see the tables in the Vulkan spec.
+        let (face, uv) = Vec3::new(1.0, 1.0, 1.0).to_cube_face_uv();
+        assert_eq!(face, CubeFace::PositiveX);
+        assert!(uv.eq_eps(Vec2::zero()));
+
+        for face in CubeFace::ALL {
+            let center = Vec3::from_cube_face_uv(face, Vec2::broadcast(0.5));
+            assert_eq!(center.to_cube_face_uv().0, face);
+
+            let uv = Vec2::new(0.2, 0.9);
+            let (back_face, back_uv) = Vec3::from_cube_face_uv(face, uv).to_cube_face_uv();
+            assert_eq!(back_face, face);
+            assert!(back_uv.eq_eps(uv));
+        }
+    }
+}
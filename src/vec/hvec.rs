@@ -0,0 +1,123 @@
+//! Half precision vectors, for storage and interchange with the GPU.
+//!
+//! These types only store their components as [`half::f16`] and have no arithmetic of their own.
+//! Convert them to and from the corresponding `f32` vector types to do math with them.
+use crate::*;
+
+use half::{f16, slice::HalfFloatSliceExt};
+
+macro_rules! hvecs {
+    ($($n:ident => ($vt:ident, $len:expr) { $($field:ident),+ }),+) => {
+        $(/// A vector of `f16` components, meant for storage and interchange.
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[repr(C)]
+        pub struct $n {
+            $(pub $field: f16),+
+        }
+
+        impl $n {
+            #[inline]
+            pub const fn new($($field: f16),+) -> Self {
+                $n { $($field),+ }
+            }
+
+            /// Convert the vectors in `src` to half precision, writing them into `dst`.
+            ///
+            /// This is vectorized over the whole slice, and so may be considerably faster
+            /// than converting each vector in turn.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the two slices have different lengths.
+            #[inline]
+            pub fn convert_from_f32_slice(src: &[$vt], dst: &mut [$n]) {
+                assert_eq!(src.len(), dst.len(), "slices must have the same length");
+                // SAFETY: both vector types are `repr(C)` structs of `$len` components without
+                // any padding, so the component slices cover exactly the same memory.
+                let (src, dst) = unsafe {
+                    (
+                        core::slice::from_raw_parts(src.as_ptr() as *const f32, src.len() * $len),
+                        core::slice::from_raw_parts_mut(dst.as_mut_ptr() as *mut f16, dst.len() * $len),
+                    )
+                };
+                dst.convert_from_f32_slice(src);
+            }
+
+            /// Convert the half precision vectors in `src` to `f32`, writing them into `dst`.
+            ///
+            /// This is vectorized over the whole slice, and so may be considerably faster
+            /// than converting each vector in turn.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the two slices have different lengths.
+            #[inline]
+            pub fn convert_to_f32_slice(src: &[$n], dst: &mut [$vt]) {
+                assert_eq!(src.len(), dst.len(), "slices must have the same length");
+                // SAFETY: both vector types are `repr(C)` structs of `$len` components without
+                // any padding, so the component slices cover exactly the same memory.
+                let (src, dst) = unsafe {
+                    (
+                        core::slice::from_raw_parts(src.as_ptr() as *const f16, src.len() * $len),
+                        core::slice::from_raw_parts_mut(dst.as_mut_ptr() as *mut f32, dst.len() * $len),
+                    )
+                };
+                src.convert_to_f32_slice(dst);
+            }
+        }
+
+        /// Round each component to the nearest representable `f16`.
+        impl From<$vt> for $n {
+            #[inline]
+            fn from(v: $vt) -> Self {
+                $n { $($field: f16::from_f32(v.$field)),+ }
+            }
+        }
+
+        impl From<$n> for $vt {
+            #[inline]
+            fn from(v: $n) -> Self {
+                $vt { $($field: v.$field.to_f32()),+ }
+            }
+        }
+
+        impl From<[f16; $len]> for $n {
+            #[inline]
+            fn from(comps: [f16; $len]) -> Self {
+                let [$($field),+] = comps;
+                $n { $($field),+ }
+            }
+        }
+
+        impl From<$n> for [f16; $len] {
+            #[inline]
+            fn from(v: $n) -> Self {
+                [$(v.$field),+]
+            }
+        })+
+    };
+}
+
+hvecs!(
+    HVec2 => (Vec2, 2) { x, y },
+    HVec3 => (Vec3, 3) { x, y, z },
+    HVec4 => (Vec4, 4) { x, y, z, w }
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn slice_conversion_roundtrip() {
+        let vecs = [Vec3::new(1.0, -2.5, 0.125), Vec3::new(65504.0, 0.0, -1.0)];
+        let mut halves = [HVec3::default(); 2];
+        HVec3::convert_from_f32_slice(&vecs, &mut halves);
+        assert_eq!(halves[0], HVec3::from(vecs[0]));
+        assert_eq!(halves[1].x, f16::MAX);
+
+        let mut back = [Vec3::zero(); 2];
+        HVec3::convert_to_f32_slice(&halves, &mut back);
+        assert_eq!(back, vecs);
+    }
+}
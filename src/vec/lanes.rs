@@ -0,0 +1,228 @@
+//! Iteration over and reduction across the lanes of the wide vector types.
+//!
+//! Each wide vector converts into an iterator over its lanes as scalar vectors, and a `Vec` of
+//! wide vectors can be collected from an iterator of scalar vectors. Together these convert
+//! between the two layouts with standard iterator chains:
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! let points = (0..10).map(|i| Vec3::new(i as f32, 0.0, 0.0));
+//! let wide: Vec<Vec3x8> = points.collect();
+//! assert_eq!(wide.len(), 2);
+//!
+//! let scaled: Vec<Vec3> = wide.iter().flat_map(|v| *v * f32x8::splat(2.0)).take(10).collect();
+//! assert_eq!(scaled[9], Vec3::new(18.0, 0.0, 0.0));
+//! ```
+use crate::*;
+
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+/// Reductions across the lanes of a wide scalar, for the horizontal reductions of the vectors.
+trait ReduceLanes {
+    type Scalar;
+
+    fn lanes_min(self) -> Self::Scalar;
+    fn lanes_max(self) -> Self::Scalar;
+    fn lanes_add(self) -> Self::Scalar;
+}
+
+macro_rules! impl_reduce_lanes {
+    ($($wt:ident => $t:ident),+) => {
+        $(impl ReduceLanes for $wt {
+            type Scalar = $t;
+
+            #[inline]
+            fn lanes_min(self) -> $t {
+                let lanes = self.to_array();
+                lanes[1..].iter().fold(lanes[0], |a, &b| a.min(b))
+            }
+
+            #[inline]
+            fn lanes_max(self) -> $t {
+                let lanes = self.to_array();
+                lanes[1..].iter().fold(lanes[0], |a, &b| a.max(b))
+            }
+
+            #[inline]
+            fn lanes_add(self) -> $t {
+                self.reduce_add()
+            }
+        })+
+    };
+}
+
+#[cfg(feature = "int")]
+macro_rules! impl_reduce_lanes_int {
+    ($($wt:ident => $t:ident),+) => {
+        $(impl ReduceLanes for $wt {
+            type Scalar = $t;
+
+            #[inline]
+            fn lanes_min(self) -> $t {
+                self.to_array().iter().fold($t::MAX, |a, &b| a.min(b))
+            }
+
+            #[inline]
+            fn lanes_max(self) -> $t {
+                self.to_array().iter().fold($t::MIN, |a, &b| a.max(b))
+            }
+
+            #[inline]
+            fn lanes_add(self) -> $t {
+                self.to_array().iter().fold(0, |a, &b| a.wrapping_add(b))
+            }
+        })+
+    };
+}
+
+impl_reduce_lanes!(f32x4 => f32, f32x8 => f32);
+
+#[cfg(feature = "f64")]
+impl_reduce_lanes!(f64x2 => f64, f64x4 => f64);
+
+#[cfg(feature = "int")]
+impl_reduce_lanes_int!(i32x4 => i32, i32x8 => i32, u32x4 => u32, u32x8 => u32);
+
+macro_rules! lane_iters {
+    ($($wt:ident => ($st:ident, $lanes:literal) { $($field:ident),+ }),+) => {
+        $(impl $wt {
+            /// Iterate over the lanes of `self` as scalar vectors.
+            #[inline]
+            pub fn iter_lanes(&self) -> core::array::IntoIter<$st, $lanes> {
+                IntoIterator::into_iter(<[$st; $lanes]>::from(*self))
+            }
+
+            /// The componentwise minimum across all lanes, e.g. the minimum corner of the
+            /// bounding box of the points in `self`.
+            #[inline]
+            pub fn horizontal_min(&self) -> $st {
+                $st { $($field: self.$field.lanes_min()),+ }
+            }
+
+            /// The componentwise maximum across all lanes.
+            #[inline]
+            pub fn horizontal_max(&self) -> $st {
+                $st { $($field: self.$field.lanes_max()),+ }
+            }
+
+            /// The sum of all lanes.
+            #[inline]
+            pub fn horizontal_add(&self) -> $st {
+                $st { $($field: self.$field.lanes_add()),+ }
+            }
+        }
+
+        impl IntoIterator for $wt {
+            type Item = $st;
+            type IntoIter = core::array::IntoIter<$st, $lanes>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_lanes()
+            }
+        }
+
+        /// Packs the vectors into wide vectors in order.
+        ///
+        /// If the number of vectors isn't a multiple of the lane count, the remaining lanes of
+        /// the last wide vector are filled with zero vectors. Keep track of the number of
+        /// vectors to skip them, e.g. with `take` when converting back.
+        impl FromIterator<$st> for Vec<$wt> {
+            fn from_iter<I: IntoIterator<Item = $st>>(iter: I) -> Self {
+                let mut iter = iter.into_iter();
+                let mut wide = Vec::with_capacity(iter.size_hint().0.div_ceil($lanes));
+                while let Some(first) = iter.next() {
+                    let mut lanes = [$st::zero(); $lanes];
+                    lanes[0] = first;
+                    for (lane, value) in lanes[1..].iter_mut().zip(&mut iter) {
+                        *lane = value;
+                    }
+                    wide.push($wt::from(lanes));
+                }
+                wide
+            }
+        })+
+    };
+}
+
+lane_iters!(
+    Vec2x4 => (Vec2, 4) { x, y },
+    Vec3x4 => (Vec3, 4) { x, y, z },
+    Vec4x4 => (Vec4, 4) { x, y, z, w },
+    Vec2x8 => (Vec2, 8) { x, y },
+    Vec3x8 => (Vec3, 8) { x, y, z },
+    Vec4x8 => (Vec4, 8) { x, y, z, w }
+);
+
+#[cfg(feature = "f64")]
+lane_iters!(
+    DVec2x2 => (DVec2, 2) { x, y },
+    DVec3x2 => (DVec3, 2) { x, y, z },
+    DVec4x2 => (DVec4, 2) { x, y, z, w },
+    DVec2x4 => (DVec2, 4) { x, y },
+    DVec3x4 => (DVec3, 4) { x, y, z },
+    DVec4x4 => (DVec4, 4) { x, y, z, w }
+);
+
+#[cfg(feature = "int")]
+lane_iters!(
+    IVec2x4 => (IVec2, 4) { x, y },
+    IVec3x4 => (IVec3, 4) { x, y, z },
+    IVec4x4 => (IVec4, 4) { x, y, z, w },
+    IVec2x8 => (IVec2, 8) { x, y },
+    IVec3x8 => (IVec3, 8) { x, y, z },
+    IVec4x8 => (IVec4, 8) { x, y, z, w },
+    UVec2x4 => (UVec2, 4) { x, y },
+    UVec3x4 => (UVec3, 4) { x, y, z },
+    UVec4x4 => (UVec4, 4) { x, y, z, w },
+    UVec2x8 => (UVec2, 8) { x, y },
+    UVec3x8 => (UVec3, 8) { x, y, z },
+    UVec4x8 => (UVec4, 8) { x, y, z, w }
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lanes_roundtrip() {
+        let vecs: Vec<Vec2> = (0..5).map(|i| Vec2::new(i as f32, -(i as f32))).collect();
+        let wide: Vec<Vec2x4> = vecs.iter().copied().collect();
+        assert_eq!(wide.len(), 2);
+        assert_eq!(wide[1].iter_lanes().nth(1), Some(Vec2::zero()));
+
+        let back: Vec<Vec2> = wide.into_iter().flatten().take(vecs.len()).collect();
+        assert_eq!(back, vecs);
+
+        let empty: Vec<Vec3x8> = core::iter::empty::<Vec3>().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn lane_reductions() {
+        let points = Vec3x4::from([
+            Vec3::new(1.0, -2.0, 3.0),
+            Vec3::new(-4.0, 5.0, 0.5),
+            Vec3::new(2.0, 2.0, -6.0),
+            Vec3::new(0.0, 1.0, 1.0),
+        ]);
+        assert_eq!(points.horizontal_min(), Vec3::new(-4.0, -2.0, -6.0));
+        assert_eq!(points.horizontal_max(), Vec3::new(2.0, 5.0, 3.0));
+        assert_eq!(points.horizontal_add(), Vec3::new(-1.0, 6.0, -1.5));
+        #[cfg(feature = "int")]
+        {
+            let ints = UVec2x4::from([
+                UVec2::new(3, 1),
+                UVec2::new(u32::MAX, 7),
+                UVec2::new(2, 0),
+                UVec2::new(1, 4),
+            ]);
+            assert_eq!(ints.horizontal_min(), UVec2::new(1, 0));
+            assert_eq!(ints.horizontal_max(), UVec2::new(u32::MAX, 7));
+            assert_eq!(ints.horizontal_add(), UVec2::new(5, 12));
+        }
+        let maxes: [f32; 4] = points.component_max().into();
+        assert_eq!(maxes, [3.0, 5.0, 2.0, 1.0]);
+    }
+}
@@ -1,14 +1,32 @@
 //! Vectors and points, i.e. directed line segments and locations.
 mod vec2;
 mod vec3;
+mod vec3a;
 mod vec4;
 
+mod envmap;
+mod lanes;
+mod packed;
+mod robust;
+mod slices;
+mod swizzle;
+
+#[cfg(feature = "half")]
+mod hvec;
+
 #[cfg(feature = "num-traits")]
 mod num_traits;
 
 pub use vec2::*;
 pub use vec3::*;
+pub use vec3a::*;
 pub use vec4::*;
 
+pub use envmap::CubeFace;
+pub use slices::*;
+
+#[cfg(feature = "half")]
+pub use hvec::*;
+
 #[cfg(feature = "num-traits")]
 pub use ::num_traits::*;
@@ -0,0 +1,218 @@
+//! Conversions between float vectors and the packed normalized integer formats commonly used
+//! for vertex attributes and textures on the GPU.
+//!
+//! Encoding follows the rules used by Vulkan, D3D and OpenGL: `unorm` values are clamped to
+//! `[0, 1]` and `snorm` values to `[-1, 1]` before being scaled and rounded to the nearest
+//! integer, and `NaN` encodes as zero.
+use crate::*;
+
+#[inline]
+fn to_unorm(v: f32, max: f32) -> f32 {
+    (v.clamp(0.0, 1.0) * max).round()
+}
+
+#[inline]
+fn to_snorm(v: f32, max: f32) -> f32 {
+    (v.clamp(-1.0, 1.0) * max).round()
+}
+
+#[inline]
+fn from_snorm(v: f32, max: f32) -> f32 {
+    // Both the most negative integer and the one after it decode to -1.0.
+    (v / max).max(-1.0)
+}
+
+impl Vec2 {
+    /// Encode each component as a 16-bit unsigned normalized integer.
+    #[inline]
+    pub fn to_unorm16x2(&self) -> [u16; 2] {
+        [
+            to_unorm(self.x, 65535.0) as u16,
+            to_unorm(self.y, 65535.0) as u16,
+        ]
+    }
+
+    /// Decode a vector from two 16-bit unsigned normalized integers.
+    #[inline]
+    pub fn from_unorm16x2(packed: [u16; 2]) -> Self {
+        Self::new(packed[0] as f32 / 65535.0, packed[1] as f32 / 65535.0)
+    }
+
+    /// Encode each component as a 16-bit signed normalized integer.
+    #[inline]
+    pub fn to_snorm16x2(&self) -> [i16; 2] {
+        [
+            to_snorm(self.x, 32767.0) as i16,
+            to_snorm(self.y, 32767.0) as i16,
+        ]
+    }
+
+    /// Decode a vector from two 16-bit signed normalized integers.
+    #[inline]
+    pub fn from_snorm16x2(packed: [i16; 2]) -> Self {
+        Self::new(
+            from_snorm(packed[0] as f32, 32767.0),
+            from_snorm(packed[1] as f32, 32767.0),
+        )
+    }
+}
+
+impl Vec3 {
+    /// Map a unit vector onto the `[-1, 1]` square using the octahedral encoding described in
+    /// "A Survey of Efficient Representations for Independent Unit Vectors" by Cigolle et al.
+    ///
+    /// `self` must be normalized.
+    #[inline]
+    pub fn to_oct(&self) -> Vec2 {
+        let l1 = self.x.abs() + self.y.abs() + self.z.abs();
+        let x = self.x / l1;
+        let y = self.y / l1;
+        if self.z < 0.0 {
+            Vec2::new((1.0 - y.abs()).copysign(x), (1.0 - x.abs()).copysign(y))
+        } else {
+            Vec2::new(x, y)
+        }
+    }
+
+    /// Decode a unit vector from its octahedral encoding, see [`Vec3::to_oct`].
+    #[inline]
+    pub fn from_oct(oct: Vec2) -> Self {
+        let z = 1.0 - oct.x.abs() - oct.y.abs();
+        let (x, y) = if z < 0.0 {
+            (
+                (1.0 - oct.y.abs()).copysign(oct.x),
+                (1.0 - oct.x.abs()).copysign(oct.y),
+            )
+        } else {
+            (oct.x, oct.y)
+        };
+        Self::new(x, y, z).normalized()
+    }
+
+    /// Encode a unit vector into two 16-bit unsigned normalized integers using the
+    /// octahedral encoding, see [`Vec3::to_oct`].
+    ///
+    /// `self` must be normalized.
+    #[inline]
+    pub fn to_oct_u16x2(&self) -> [u16; 2] {
+        (self.to_oct() * 0.5 + Vec2::broadcast(0.5)).to_unorm16x2()
+    }
+
+    /// Decode a unit vector from its octahedral encoding in two 16-bit unsigned normalized
+    /// integers, see [`Vec3::to_oct_u16x2`].
+    #[inline]
+    pub fn from_oct_u16x2(packed: [u16; 2]) -> Self {
+        Self::from_oct(Vec2::from_unorm16x2(packed) * 2.0 - Vec2::one())
+    }
+}
+
+impl Vec4 {
+    /// Encode each component as an 8-bit unsigned normalized integer, e.g. for an `RGBA8` color.
+    #[inline]
+    pub fn to_unorm8x4(&self) -> [u8; 4] {
+        [
+            to_unorm(self.x, 255.0) as u8,
+            to_unorm(self.y, 255.0) as u8,
+            to_unorm(self.z, 255.0) as u8,
+            to_unorm(self.w, 255.0) as u8,
+        ]
+    }
+
+    /// Decode a vector from four 8-bit unsigned normalized integers.
+    #[inline]
+    pub fn from_unorm8x4(packed: [u8; 4]) -> Self {
+        Self::new(
+            packed[0] as f32 / 255.0,
+            packed[1] as f32 / 255.0,
+            packed[2] as f32 / 255.0,
+            packed[3] as f32 / 255.0,
+        )
+    }
+
+    /// Encode each component as an 8-bit signed normalized integer.
+    #[inline]
+    pub fn to_snorm8x4(&self) -> [i8; 4] {
+        [
+            to_snorm(self.x, 127.0) as i8,
+            to_snorm(self.y, 127.0) as i8,
+            to_snorm(self.z, 127.0) as i8,
+            to_snorm(self.w, 127.0) as i8,
+        ]
+    }
+
+    /// Decode a vector from four 8-bit signed normalized integers.
+    #[inline]
+    pub fn from_snorm8x4(packed: [i8; 4]) -> Self {
+        Self::new(
+            from_snorm(packed[0] as f32, 127.0),
+            from_snorm(packed[1] as f32, 127.0),
+            from_snorm(packed[2] as f32, 127.0),
+            from_snorm(packed[3] as f32, 127.0),
+        )
+    }
+
+    /// Encode `x`, `y` and `z` as 10-bit and `w` as 2-bit unsigned normalized integers packed
+    /// into a `u32`, with `x` in the least significant bits.
+    ///
+    /// This is the layout of `A2B10G10R10_UNORM_PACK32` in Vulkan, `R10G10B10A2_UNORM` in D3D
+    /// and `GL_UNSIGNED_INT_2_10_10_10_REV` in OpenGL.
+    #[inline]
+    pub fn to_rgb10a2(&self) -> u32 {
+        let r = to_unorm(self.x, 1023.0) as u32;
+        let g = to_unorm(self.y, 1023.0) as u32;
+        let b = to_unorm(self.z, 1023.0) as u32;
+        let a = to_unorm(self.w, 3.0) as u32;
+        r | (g << 10) | (b << 20) | (a << 30)
+    }
+
+    /// Decode a vector from the packed format described in [`Vec4::to_rgb10a2`].
+    #[inline]
+    pub fn from_rgb10a2(packed: u32) -> Self {
+        Self::new(
+            (packed & 0x3ff) as f32 / 1023.0,
+            ((packed >> 10) & 0x3ff) as f32 / 1023.0,
+            ((packed >> 20) & 0x3ff) as f32 / 1023.0,
+            (packed >> 30) as f32 / 3.0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    #[test]
+    fn normalized_integers() {
+        let color = Vec4::new(1.0, 0.5, -0.25, f32::NAN);
+        assert_eq!(color.to_unorm8x4(), [255, 128, 0, 0]);
+        assert_eq!(color.to_snorm8x4(), [127, 64, -32, 0]);
+        assert_eq!(
+            Vec4::from_unorm8x4([255, 0, 51, 255]),
+            Vec4::new(1.0, 0.0, 0.2, 1.0)
+        );
+        assert_eq!(
+            Vec2::from_snorm16x2([i16::MIN, 32767]),
+            Vec2::new(-1.0, 1.0)
+        );
+        assert_eq!(Vec2::new(-2.0, 0.0).to_snorm16x2(), [-32767, 0]);
+
+        let packed = Vec4::new(1.0, 0.0, 1.0, 1.0 / 3.0).to_rgb10a2();
+        assert_eq!(packed, 0x3ff | (0x3ff << 20) | (1 << 30));
+        assert!(Vec4::from_rgb10a2(packed).eq_eps(Vec4::new(1.0, 0.0, 1.0, 1.0 / 3.0)));
+    }
+
+    #[test]
+    fn octahedral_roundtrip() {
+        for n in [
+            Vec3::unit_z(),
+            -Vec3::unit_z(),
+            Vec3::new(1.0, -2.0, 3.0).normalized(),
+            Vec3::new(-0.3, 0.2, -0.9).normalized(),
+        ] {
+            assert!(Vec3::from_oct(n.to_oct()).eq_eps(n));
+            let decoded = Vec3::from_oct_u16x2(n.to_oct_u16x2());
+            assert!((decoded - n).mag() < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Magnitude and normalization which stay accurate for vectors with tiny or huge components.
+//!
+//! The plain [`Vec3::mag`] and [`Vec3::normalized`] square the components, which underflows to
+//! zero for subnormal vectors and overflows to infinity for huge ones, and normalizing a zero
+//! vector silently produces `NaN`. The methods here first scale the vector by its largest
+//! component, like `hypot`, at the cost of a division, and handle zero and non-finite vectors
+//! explicitly.
+use crate::*;
+
+use wide::{CmpEq, CmpGt};
+
+macro_rules! robust_scalar {
+    ($($vt:ident => $t:ident),+) => {
+        $(impl $vt {
+            /// The magnitude of `self`, computed without intermediate overflow or underflow.
+            ///
+            /// Returns infinity if any component is infinite, and `NaN` if any is `NaN`.
+            #[inline]
+            pub fn mag_robust(&self) -> $t {
+                let max = self.abs().component_max();
+                if self.has_nan() {
+                    $t::NAN
+                } else if max == 0.0 || max.is_infinite() {
+                    max
+                } else {
+                    max * (*self / max).mag()
+                }
+            }
+
+            /// `self` normalized, or `None` if it cannot be, i.e. if it is zero or has a
+            /// component which is infinite or `NaN`.
+            ///
+            /// Unlike [`Self::normalized`], this gives a unit vector for every other input, even
+            /// when its squared magnitude would underflow or overflow.
+            #[inline]
+            pub fn try_normalized(&self) -> Option<Self> {
+                let max = self.abs().component_max();
+                if max == 0.0 || !self.is_finite() {
+                    None
+                } else {
+                    Some((*self / max).normalized())
+                }
+            }
+
+            /// `self` normalized, or `default` if it cannot be, see [`Self::try_normalized`].
+            #[inline]
+            pub fn normalized_or(&self, default: Self) -> Self {
+                self.try_normalized().unwrap_or(default)
+            }
+        })+
+    };
+}
+
+macro_rules! robust_wide {
+    ($($vt:ident => ($t:ident, $st:ident)),+) => {
+        $(impl $vt {
+            /// The magnitude of `self`, computed without intermediate overflow or underflow.
+            ///
+            /// Lanes with an infinite component give infinity, and lanes with a `NaN` component
+            /// give `NaN`.
+            #[inline]
+            pub fn mag_robust(&self) -> $t {
+                let max = self.abs().component_max();
+                let scaled = max * (*self / max).mag();
+                let special = max.cmp_eq($t::splat(0.0)) | max.cmp_eq($t::splat($st::INFINITY));
+                let mag = special.blend(max, scaled);
+                self.has_nan().blend($t::splat($st::NAN), mag)
+            }
+
+            /// `self` normalized, or `default` in the lanes where it cannot be, i.e. where it is
+            /// zero or has a component which is infinite or `NaN`.
+            #[inline]
+            pub fn normalized_or(&self, default: Self) -> Self {
+                let max = self.abs().component_max();
+                let valid = max.cmp_gt($t::splat(0.0)) & self.is_finite();
+                Self::blend(valid, (*self / max).normalized(), default)
+            }
+        })+
+    };
+}
+
+robust_scalar!(Vec2 => f32, Vec3 => f32, Vec4 => f32);
+robust_wide!(
+    Vec2x4 => (f32x4, f32), Vec3x4 => (f32x4, f32), Vec4x4 => (f32x4, f32),
+    Vec2x8 => (f32x8, f32), Vec3x8 => (f32x8, f32), Vec4x8 => (f32x8, f32)
+);
+
+#[cfg(feature = "f64")]
+robust_scalar!(DVec2 => f64, DVec3 => f64, DVec4 => f64);
+#[cfg(feature = "f64")]
+robust_wide!(
+    DVec2x2 => (f64x2, f64), DVec3x2 => (f64x2, f64), DVec4x2 => (f64x2, f64),
+    DVec2x4 => (f64x4, f64), DVec3x4 => (f64x4, f64), DVec4x4 => (f64x4, f64)
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::EqualsEps;
+
+    #[test]
+    fn tiny_and_huge_vectors() {
+        let tiny = Vec3::new(3e-39, 4e-39, 0.0);
+        assert_eq!(tiny.mag(), 0.0);
+        assert!((tiny.mag_robust() / 5e-39).eq_eps(1.0));
+        assert!(tiny
+            .try_normalized()
+            .unwrap()
+            .eq_eps(Vec3::new(0.6, 0.8, 0.0)));
+
+        let huge = Vec3::new(1.5e38, 0.0, -2e38);
+        assert_eq!(huge.mag(), f32::INFINITY);
+        assert!((huge.mag_robust() / 2.5e38).eq_eps(1.0));
+        assert!(huge
+            .try_normalized()
+            .unwrap()
+            .eq_eps(Vec3::new(0.6, 0.0, -0.8)));
+
+        assert_eq!(Vec3::zero().try_normalized(), None);
+        assert_eq!(Vec3::zero().mag_robust(), 0.0);
+        assert_eq!(Vec3::new(1.0, f32::NAN, 0.0).try_normalized(), None);
+        assert_eq!(
+            Vec2::new(f32::INFINITY, 1.0).normalized_or(Vec2::unit_y()),
+            Vec2::unit_y()
+        );
+
+        let wide = Vec3x4::from([tiny, huge, Vec3::zero(), Vec3::new(0.0, 2.0, 0.0)]);
+        let normalized: [Vec3; 4] = wide.normalized_or(Vec3x4::splat(Vec3::unit_z())).into();
+        assert!(normalized[0].eq_eps(tiny.try_normalized().unwrap()));
+        assert!(normalized[1].eq_eps(huge.try_normalized().unwrap()));
+        assert_eq!(normalized[2], Vec3::unit_z());
+        assert_eq!(normalized[3], Vec3::unit_y());
+        let mags: [f32; 4] = wide.mag_robust().into();
+        assert_eq!(mags[2..], [0.0, 2.0]);
+    }
+}
@@ -0,0 +1,215 @@
+//! Bulk operations on slices of scalar vectors, which run on the wide types internally.
+//!
+//! With the `rayon` feature, each operation has a `par_` version which splits the slices into
+//! chunks of a fixed number of elements and processes them in parallel.
+use crate::soa::splat_mat4;
+use crate::*;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+/// The number of elements handled by each task in the parallel versions, a multiple of the
+/// number of lanes.
+#[cfg(feature = "rayon")]
+const PAR_CHUNK: usize = 1024;
+
+/// Replace each element of `vecs` by the result of `wide` on eight elements at a time, and of
+/// `scalar` on the remaining ones.
+#[inline]
+fn map_in_place(vecs: &mut [Vec3], wide: impl Fn(Vec3x8) -> Vec3x8, scalar: impl Fn(Vec3) -> Vec3) {
+    let mut vecs8 = vecs.chunks_exact_mut(8);
+    for chunk in &mut vecs8 {
+        let v = Vec3x8::from(<[Vec3; 8]>::try_from(&*chunk).unwrap());
+        chunk.copy_from_slice(&<[Vec3; 8]>::from(wide(v)));
+    }
+    for v in vecs8.into_remainder() {
+        *v = scalar(*v);
+    }
+}
+
+fn dot_into(a: &[Vec3], b: &[Vec3], out: &mut [f32]) {
+    let mut a8 = a.chunks_exact(8);
+    let mut b8 = b.chunks_exact(8);
+    let mut out8 = out.chunks_exact_mut(8);
+    for ((a, b), out) in (&mut a8).zip(&mut b8).zip(&mut out8) {
+        let a = Vec3x8::from(<[Vec3; 8]>::try_from(a).unwrap());
+        let b = Vec3x8::from(<[Vec3; 8]>::try_from(b).unwrap());
+        out.copy_from_slice(a.dot(b).as_array_ref());
+    }
+    for ((a, b), out) in a8
+        .remainder()
+        .iter()
+        .zip(b8.remainder())
+        .zip(out8.into_remainder())
+    {
+        *out = a.dot(*b);
+    }
+}
+
+fn cross_into(a: &[Vec3], b: &[Vec3], out: &mut [Vec3]) {
+    let mut a8 = a.chunks_exact(8);
+    let mut b8 = b.chunks_exact(8);
+    let mut out8 = out.chunks_exact_mut(8);
+    for ((a, b), out) in (&mut a8).zip(&mut b8).zip(&mut out8) {
+        let a = Vec3x8::from(<[Vec3; 8]>::try_from(a).unwrap());
+        let b = Vec3x8::from(<[Vec3; 8]>::try_from(b).unwrap());
+        out.copy_from_slice(&<[Vec3; 8]>::from(a.cross(b)));
+    }
+    for ((a, b), out) in a8
+        .remainder()
+        .iter()
+        .zip(b8.remainder())
+        .zip(out8.into_remainder())
+    {
+        *out = a.cross(*b);
+    }
+}
+
+/// The dot products of the pairs of vectors at the same index in `a` and `b`, computed eight
+/// at a time with [`Vec3x8`].
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+pub fn dot_slices(a: &[Vec3], b: &[Vec3]) -> Vec<f32> {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let mut out = vec![0.0; a.len()];
+    dot_into(a, b, &mut out);
+    out
+}
+
+/// The cross products of the pairs of vectors at the same index in `a` and `b`, computed eight
+/// at a time with [`Vec3x8`].
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+pub fn cross_slices(a: &[Vec3], b: &[Vec3]) -> Vec<Vec3> {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let mut out = vec![Vec3::zero(); a.len()];
+    cross_into(a, b, &mut out);
+    out
+}
+
+/// Transform each of `points` by `mat` in place, eight at a time with [`Mat4x8`], see
+/// [`Mat4::transform_point3`].
+pub fn transform_points(points: &mut [Vec3], mat: Mat4) {
+    let wide = splat_mat4(mat);
+    map_in_place(
+        points,
+        |p| wide.transform_point3(p),
+        |p| mat.transform_point3(p),
+    );
+}
+
+/// Rotate each of `vecs` by `rotor` in place, eight at a time with [`Rotor3x8`].
+pub fn rotate_vecs(vecs: &mut [Vec3], rotor: Rotor3) {
+    let wide = Rotor3x8::splat(rotor);
+    map_in_place(vecs, |v| v.rotated_by(wide), |v| v.rotated_by(rotor));
+}
+
+/// Like [`dot_slices`], but split into tasks run in parallel on the `rayon` thread pool.
+#[cfg(feature = "rayon")]
+pub fn par_dot_slices(a: &[Vec3], b: &[Vec3]) -> Vec<f32> {
+    use rayon::prelude::*;
+
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let mut out = vec![0.0; a.len()];
+    out.par_chunks_mut(PAR_CHUNK)
+        .zip(a.par_chunks(PAR_CHUNK).zip(b.par_chunks(PAR_CHUNK)))
+        .for_each(|(out, (a, b))| dot_into(a, b, out));
+    out
+}
+
+/// Like [`cross_slices`], but split into tasks run in parallel on the `rayon` thread pool.
+#[cfg(feature = "rayon")]
+pub fn par_cross_slices(a: &[Vec3], b: &[Vec3]) -> Vec<Vec3> {
+    use rayon::prelude::*;
+
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let mut out = vec![Vec3::zero(); a.len()];
+    out.par_chunks_mut(PAR_CHUNK)
+        .zip(a.par_chunks(PAR_CHUNK).zip(b.par_chunks(PAR_CHUNK)))
+        .for_each(|(out, (a, b))| cross_into(a, b, out));
+    out
+}
+
+/// Like [`transform_points`], but split into tasks run in parallel on the `rayon` thread pool.
+#[cfg(feature = "rayon")]
+pub fn par_transform_points(points: &mut [Vec3], mat: Mat4) {
+    use rayon::prelude::*;
+
+    points
+        .par_chunks_mut(PAR_CHUNK)
+        .for_each(|points| transform_points(points, mat));
+}
+
+/// Like [`rotate_vecs`], but split into tasks run in parallel on the `rayon` thread pool.
+#[cfg(feature = "rayon")]
+pub fn par_rotate_vecs(vecs: &mut [Vec3], rotor: Rotor3) {
+    use rayon::prelude::*;
+
+    vecs.par_chunks_mut(PAR_CHUNK)
+        .for_each(|vecs| rotate_vecs(vecs, rotor));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::*;
+
+    #[test]
+    fn slice_products() {
+        let a: Vec<Vec3> = (0..21)
+            .map(|i| Vec3::new(i as f32, 1.0 - i as f32, 0.5 * i as f32))
+            .collect();
+        let b: Vec<Vec3> = (0..21)
+            .map(|i| Vec3::new(2.0, i as f32 * 0.25, -1.0))
+            .collect();
+        let dots = dot_slices(&a, &b);
+        let crosses = cross_slices(&a, &b);
+        assert_eq!(dots.len(), 21);
+        assert_eq!(crosses.len(), 21);
+        for i in 0..21 {
+            assert!(dots[i].eq_eps(a[i].dot(b[i])));
+            assert!(crosses[i].eq_eps(a[i].cross(b[i])));
+        }
+        assert!(dot_slices(&[], &[]).is_empty());
+
+        #[cfg(feature = "rayon")]
+        {
+            assert_eq!(par_dot_slices(&a, &b), dots);
+            assert_eq!(par_cross_slices(&a, &b), crosses);
+        }
+    }
+
+    #[test]
+    fn slice_transforms() {
+        let points: Vec<Vec3> = (0..2051)
+            .map(|i| Vec3::new(i as f32, 1.0, -0.5 * i as f32))
+            .collect();
+        let mat = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0))
+            * Mat4::from_nonuniform_scale(Vec3::new(2.0, -1.0, 0.5));
+        let rotor = Rotor3::from_euler_angles(0.3, -1.2, 2.0);
+
+        let mut transformed = points.clone();
+        transform_points(&mut transformed, mat);
+        let mut rotated = points.clone();
+        rotate_vecs(&mut rotated, rotor);
+        for ((p, t), r) in points.iter().zip(&transformed).zip(&rotated) {
+            assert!(t.eq_eps(mat.transform_point3(*p)));
+            assert!(r.eq_eps(p.rotated_by(rotor)));
+        }
+
+        #[cfg(feature = "rayon")]
+        {
+            let mut par_transformed = points.clone();
+            par_transform_points(&mut par_transformed, mat);
+            assert_eq!(par_transformed, transformed);
+            let mut par_rotated = points.clone();
+            par_rotate_vecs(&mut par_rotated, rotor);
+            assert_eq!(par_rotated, rotated);
+        }
+    }
+}
@@ -0,0 +1,528 @@
+//! Swizzles, i.e. new vectors made from any combination of the components of a vector, as in
+//! shading languages.
+//!
+//! Every combination of two, three or four components is available as a method named after
+//! them, such as `v.zxy()` or `v.xxyy()`, for both the scalar and wide vectors. Additionally,
+//! `xy0`/`xy1` and `xyz0`/`xyz1` extend a vector by a component of zero or one.
+//!
+//! In the other direction, `with_x` and friends return a copy of a vector with one component
+//! replaced, and `set_xy` and friends overwrite several components at once. These are available
+//! on the integer vectors as well.
+//!
+//! Finally, every vector can change dimension with `extend`, which appends a given component,
+//! and `truncate`, which drops the last one. Unlike `xyz()` and friends, which always pad with
+//! zero, or `into_homogeneous_point`, which always pads with one, `extend` makes the new
+//! component explicit.
+//!
+//! ```rust
+//! # use ultraviolet::*;
+//! let v = Vec3::new(1.0, 2.0, 3.0);
+//! assert_eq!(v.zyx(), Vec3::new(3.0, 2.0, 1.0));
+//! assert_eq!(v.xz(), Vec2::new(1.0, 3.0));
+//! assert_eq!(v.xyz1(), Vec4::new(1.0, 2.0, 3.0, 1.0));
+//! assert_eq!(v.with_y(0.0), Vec3::new(1.0, 0.0, 3.0));
+//!
+//! let mut u = Vec4::zero();
+//! u.set_yw(Vec2::new(5.0, 6.0));
+//! assert_eq!(u, Vec4::new(0.0, 5.0, 0.0, 6.0));
+//!
+//! assert_eq!(v.truncate().extend(8.0), Vec3::new(1.0, 2.0, 8.0));
+//! ```
+use crate::util::Consts;
+use crate::*;
+
+/// Emit a `pub` function with the qualifiers in the leading brackets, e.g. `[const]`, so that
+/// the qualifiers can be passed through the repetitions of another macro as a single token.
+macro_rules! qualified_fn {
+    ([$($qual:tt)*] $(#[$attr:meta])* fn $($rest:tt)*) => {
+        $(#[$attr])* pub $($qual)* fn $($rest)*
+    };
+}
+
+/// Generate the swizzle methods in the given tables, each entry of which is the name of the
+/// method and the components it is made of. The methods have the qualifiers in `$qual`, which
+/// is `[const]` unless the components can't be read in a `const fn`.
+macro_rules! swizzle_fns {
+    ($qual:tt $v2t:ident, $v3t:ident, $v4t:ident;
+        [$($n2:ident: $a2:ident $b2:ident),+]
+        [$($n3:ident: $a3:ident $b3:ident $c3:ident),+]
+        [$($n4:ident: $a4:ident $b4:ident $c4:ident $d4:ident),+]) => {
+        $(qualified_fn!($qual #[inline] fn $n2(&self) -> $v2t {
+            $v2t::new(self.$a2, self.$b2)
+        });)+
+
+        $(qualified_fn!($qual #[inline] fn $n3(&self) -> $v3t {
+            $v3t::new(self.$a3, self.$b3, self.$c3)
+        });)+
+
+        $(qualified_fn!($qual #[inline] fn $n4(&self) -> $v4t {
+            $v4t::new(self.$a4, self.$b4, self.$c4, self.$d4)
+        });)+
+    };
+}
+
+macro_rules! vec2_swizzles {
+    ($($n:ident => ($t:ident, $v2t:ident, $v3t:ident, $v4t:ident)),+) => {
+        $(impl $n {
+            swizzle_fns!([const] $v2t, $v3t, $v4t;
+            [
+                xx: x x, yx: y x, yy: y y
+            ]
+            [
+                xxx: x x x, xxy: x x y, xyx: x y x, xyy: x y y, yxx: y x x,
+                yxy: y x y, yyx: y y x, yyy: y y y
+            ]
+            [
+                xxxx: x x x x, xxxy: x x x y, xxyx: x x y x, xxyy: x x y y,
+                xyxx: x y x x, xyxy: x y x y, xyyx: x y y x, xyyy: x y y y,
+                yxxx: y x x x, yxxy: y x x y, yxyx: y x y x, yxyy: y x y y,
+                yyxx: y y x x, yyxy: y y x y, yyyx: y y y x, yyyy: y y y y
+            ]);
+
+            #[inline]
+            pub const fn xy0(&self) -> $v3t {
+                $v3t::new(self.x, self.y, <$t as Consts>::ZERO)
+            }
+
+            #[inline]
+            pub const fn xy1(&self) -> $v3t {
+                $v3t::new(self.x, self.y, <$t as Consts>::ONE)
+            }
+        })+
+    };
+}
+
+macro_rules! vec3_swizzles {
+    ($($n:ident => ($t:ident, $v2t:ident, $v3t:ident, $v4t:ident)),+) => {
+        $(vec3_swizzles!(@impl [const] $n => ($t, $v2t, $v3t, $v4t));)+
+    };
+    (@impl $qual:tt $n:ident => ($t:ident, $v2t:ident, $v3t:ident, $v4t:ident)) => {
+        impl $n {
+            swizzle_fns!($qual $v2t, $v3t, $v4t;
+            [
+                xx: x x, xz: x z, yx: y x, yy: y y, yz: y z, zx: z x,
+                zy: z y, zz: z z
+            ]
+            [
+                xxx: x x x, xxy: x x y, xxz: x x z, xyx: x y x, xyy: x y y,
+                xzx: x z x, xzy: x z y, xzz: x z z, yxx: y x x, yxy: y x y,
+                yxz: y x z, yyx: y y x, yyy: y y y, yyz: y y z, yzx: y z x,
+                yzy: y z y, yzz: y z z, zxx: z x x, zxy: z x y, zxz: z x z,
+                zyx: z y x, zyy: z y y, zyz: z y z, zzx: z z x, zzy: z z y,
+                zzz: z z z
+            ]
+            [
+                xxxx: x x x x, xxxy: x x x y, xxxz: x x x z, xxyx: x x y x,
+                xxyy: x x y y, xxyz: x x y z, xxzx: x x z x, xxzy: x x z y,
+                xxzz: x x z z, xyxx: x y x x, xyxy: x y x y, xyxz: x y x z,
+                xyyx: x y y x, xyyy: x y y y, xyyz: x y y z, xyzx: x y z x,
+                xyzy: x y z y, xyzz: x y z z, xzxx: x z x x, xzxy: x z x y,
+                xzxz: x z x z, xzyx: x z y x, xzyy: x z y y, xzyz: x z y z,
+                xzzx: x z z x, xzzy: x z z y, xzzz: x z z z, yxxx: y x x x,
+                yxxy: y x x y, yxxz: y x x z, yxyx: y x y x, yxyy: y x y y,
+                yxyz: y x y z, yxzx: y x z x, yxzy: y x z y, yxzz: y x z z,
+                yyxx: y y x x, yyxy: y y x y, yyxz: y y x z, yyyx: y y y x,
+                yyyy: y y y y, yyyz: y y y z, yyzx: y y z x, yyzy: y y z y,
+                yyzz: y y z z, yzxx: y z x x, yzxy: y z x y, yzxz: y z x z,
+                yzyx: y z y x, yzyy: y z y y, yzyz: y z y z, yzzx: y z z x,
+                yzzy: y z z y, yzzz: y z z z, zxxx: z x x x, zxxy: z x x y,
+                zxxz: z x x z, zxyx: z x y x, zxyy: z x y y, zxyz: z x y z,
+                zxzx: z x z x, zxzy: z x z y, zxzz: z x z z, zyxx: z y x x,
+                zyxy: z y x y, zyxz: z y x z, zyyx: z y y x, zyyy: z y y y,
+                zyyz: z y y z, zyzx: z y z x, zyzy: z y z y, zyzz: z y z z,
+                zzxx: z z x x, zzxy: z z x y, zzxz: z z x z, zzyx: z z y x,
+                zzyy: z z y y, zzyz: z z y z, zzzx: z z z x, zzzy: z z z y,
+                zzzz: z z z z
+            ]);
+
+            qualified_fn!($qual #[inline] fn xyz0(&self) -> $v4t {
+                $v4t::new(self.x, self.y, self.z, <$t as Consts>::ZERO)
+            });
+
+            qualified_fn!($qual #[inline] fn xyz1(&self) -> $v4t {
+                $v4t::new(self.x, self.y, self.z, <$t as Consts>::ONE)
+            });
+        }
+    };
+}
+
+macro_rules! vec4_swizzles {
+    ($($n:ident => ($v2t:ident, $v3t:ident, $v4t:ident)),+) => {
+        $(impl $n {
+            swizzle_fns!([const] $v2t, $v3t, $v4t;
+            [
+                xx: x x, xz: x z, xw: x w, yx: y x, yy: y y, yz: y z,
+                yw: y w, zx: z x, zy: z y, zz: z z, zw: z w, wx: w x,
+                wy: w y, wz: w z, ww: w w
+            ]
+            [
+                xxx: x x x, xxy: x x y, xxz: x x z, xxw: x x w, xyx: x y x,
+                xyy: x y y, xyw: x y w, xzx: x z x, xzy: x z y, xzz: x z z,
+                xzw: x z w, xwx: x w x, xwy: x w y, xwz: x w z, xww: x w w,
+                yxx: y x x, yxy: y x y, yxz: y x z, yxw: y x w, yyx: y y x,
+                yyy: y y y, yyz: y y z, yyw: y y w, yzx: y z x, yzy: y z y,
+                yzz: y z z, yzw: y z w, ywx: y w x, ywy: y w y, ywz: y w z,
+                yww: y w w, zxx: z x x, zxy: z x y, zxz: z x z, zxw: z x w,
+                zyx: z y x, zyy: z y y, zyz: z y z, zyw: z y w, zzx: z z x,
+                zzy: z z y, zzz: z z z, zzw: z z w, zwx: z w x, zwy: z w y,
+                zwz: z w z, zww: z w w, wxx: w x x, wxy: w x y, wxz: w x z,
+                wxw: w x w, wyx: w y x, wyy: w y y, wyz: w y z, wyw: w y w,
+                wzx: w z x, wzy: w z y, wzz: w z z, wzw: w z w, wwx: w w x,
+                wwy: w w y, wwz: w w z, www: w w w
+            ]
+            [
+                xxxx: x x x x, xxxy: x x x y, xxxz: x x x z, xxxw: x x x w,
+                xxyx: x x y x, xxyy: x x y y, xxyz: x x y z, xxyw: x x y w,
+                xxzx: x x z x, xxzy: x x z y, xxzz: x x z z, xxzw: x x z w,
+                xxwx: x x w x, xxwy: x x w y, xxwz: x x w z, xxww: x x w w,
+                xyxx: x y x x, xyxy: x y x y, xyxz: x y x z, xyxw: x y x w,
+                xyyx: x y y x, xyyy: x y y y, xyyz: x y y z, xyyw: x y y w,
+                xyzx: x y z x, xyzy: x y z y, xyzz: x y z z, xywx: x y w x,
+                xywy: x y w y, xywz: x y w z, xyww: x y w w, xzxx: x z x x,
+                xzxy: x z x y, xzxz: x z x z, xzxw: x z x w, xzyx: x z y x,
+                xzyy: x z y y, xzyz: x z y z, xzyw: x z y w, xzzx: x z z x,
+                xzzy: x z z y, xzzz: x z z z, xzzw: x z z w, xzwx: x z w x,
+                xzwy: x z w y, xzwz: x z w z, xzww: x z w w, xwxx: x w x x,
+                xwxy: x w x y, xwxz: x w x z, xwxw: x w x w, xwyx: x w y x,
+                xwyy: x w y y, xwyz: x w y z, xwyw: x w y w, xwzx: x w z x,
+                xwzy: x w z y, xwzz: x w z z, xwzw: x w z w, xwwx: x w w x,
+                xwwy: x w w y, xwwz: x w w z, xwww: x w w w, yxxx: y x x x,
+                yxxy: y x x y, yxxz: y x x z, yxxw: y x x w, yxyx: y x y x,
+                yxyy: y x y y, yxyz: y x y z, yxyw: y x y w, yxzx: y x z x,
+                yxzy: y x z y, yxzz: y x z z, yxzw: y x z w, yxwx: y x w x,
+                yxwy: y x w y, yxwz: y x w z, yxww: y x w w, yyxx: y y x x,
+                yyxy: y y x y, yyxz: y y x z, yyxw: y y x w, yyyx: y y y x,
+                yyyy: y y y y, yyyz: y y y z, yyyw: y y y w, yyzx: y y z x,
+                yyzy: y y z y, yyzz: y y z z, yyzw: y y z w, yywx: y y w x,
+                yywy: y y w y, yywz: y y w z, yyww: y y w w, yzxx: y z x x,
+                yzxy: y z x y, yzxz: y z x z, yzxw: y z x w, yzyx: y z y x,
+                yzyy: y z y y, yzyz: y z y z, yzyw: y z y w, yzzx: y z z x,
+                yzzy: y z z y, yzzz: y z z z, yzzw: y z z w, yzwx: y z w x,
+                yzwy: y z w y, yzwz: y z w z, yzww: y z w w, ywxx: y w x x,
+                ywxy: y w x y, ywxz: y w x z, ywxw: y w x w, ywyx: y w y x,
+                ywyy: y w y y, ywyz: y w y z, ywyw: y w y w, ywzx: y w z x,
+                ywzy: y w z y, ywzz: y w z z, ywzw: y w z w, ywwx: y w w x,
+                ywwy: y w w y, ywwz: y w w z, ywww: y w w w, zxxx: z x x x,
+                zxxy: z x x y, zxxz: z x x z, zxxw: z x x w, zxyx: z x y x,
+                zxyy: z x y y, zxyz: z x y z, zxyw: z x y w, zxzx: z x z x,
+                zxzy: z x z y, zxzz: z x z z, zxzw: z x z w, zxwx: z x w x,
+                zxwy: z x w y, zxwz: z x w z, zxww: z x w w, zyxx: z y x x,
+                zyxy: z y x y, zyxz: z y x z, zyxw: z y x w, zyyx: z y y x,
+                zyyy: z y y y, zyyz: z y y z, zyyw: z y y w, zyzx: z y z x,
+                zyzy: z y z y, zyzz: z y z z, zyzw: z y z w, zywx: z y w x,
+                zywy: z y w y, zywz: z y w z, zyww: z y w w, zzxx: z z x x,
+                zzxy: z z x y, zzxz: z z x z, zzxw: z z x w, zzyx: z z y x,
+                zzyy: z z y y, zzyz: z z y z, zzyw: z z y w, zzzx: z z z x,
+                zzzy: z z z y, zzzz: z z z z, zzzw: z z z w, zzwx: z z w x,
+                zzwy: z z w y, zzwz: z z w z, zzww: z z w w, zwxx: z w x x,
+                zwxy: z w x y, zwxz: z w x z, zwxw: z w x w, zwyx: z w y x,
+                zwyy: z w y y, zwyz: z w y z, zwyw: z w y w, zwzx: z w z x,
+                zwzy: z w z y, zwzz: z w z z, zwzw: z w z w, zwwx: z w w x,
+                zwwy: z w w y, zwwz: z w w z, zwww: z w w w, wxxx: w x x x,
+                wxxy: w x x y, wxxz: w x x z, wxxw: w x x w, wxyx: w x y x,
+                wxyy: w x y y, wxyz: w x y z, wxyw: w x y w, wxzx: w x z x,
+                wxzy: w x z y, wxzz: w x z z, wxzw: w x z w, wxwx: w x w x,
+                wxwy: w x w y, wxwz: w x w z, wxww: w x w w, wyxx: w y x x,
+                wyxy: w y x y, wyxz: w y x z, wyxw: w y x w, wyyx: w y y x,
+                wyyy: w y y y, wyyz: w y y z, wyyw: w y y w, wyzx: w y z x,
+                wyzy: w y z y, wyzz: w y z z, wyzw: w y z w, wywx: w y w x,
+                wywy: w y w y, wywz: w y w z, wyww: w y w w, wzxx: w z x x,
+                wzxy: w z x y, wzxz: w z x z, wzxw: w z x w, wzyx: w z y x,
+                wzyy: w z y y, wzyz: w z y z, wzyw: w z y w, wzzx: w z z x,
+                wzzy: w z z y, wzzz: w z z z, wzzw: w z z w, wzwx: w z w x,
+                wzwy: w z w y, wzwz: w z w z, wzww: w z w w, wwxx: w w x x,
+                wwxy: w w x y, wwxz: w w x z, wwxw: w w x w, wwyx: w w y x,
+                wwyy: w w y y, wwyz: w w y z, wwyw: w w y w, wwzx: w w z x,
+                wwzy: w w z y, wwzz: w w z z, wwzw: w w z w, wwwx: w w w x,
+                wwwy: w w w y, wwwz: w w w z, wwww: w w w w
+            ]);
+
+        })+
+    };
+}
+
+vec2_swizzles!(
+    Vec2 => (f32, Vec2, Vec3, Vec4),
+    Vec2x4 => (f32x4, Vec2x4, Vec3x4, Vec4x4),
+    Vec2x8 => (f32x8, Vec2x8, Vec3x8, Vec4x8)
+);
+vec3_swizzles!(
+    Vec3 => (f32, Vec2, Vec3, Vec4),
+    Vec3x4 => (f32x4, Vec2x4, Vec3x4, Vec4x4),
+    Vec3x8 => (f32x8, Vec2x8, Vec3x8, Vec4x8)
+);
+// Not `const`, since the components of a `Vec3A` are read through `Deref`.
+vec3_swizzles!(@impl [] Vec3A => (f32, Vec2, Vec3A, Vec4));
+vec4_swizzles!(
+    Vec4 => (Vec2, Vec3, Vec4),
+    Vec4x4 => (Vec2x4, Vec3x4, Vec4x4),
+    Vec4x8 => (Vec2x8, Vec3x8, Vec4x8)
+);
+
+#[cfg(feature = "portable-simd")]
+vec2_swizzles!(Vec2x16 => (f32x16, Vec2x16, Vec3x16, Vec4x16));
+#[cfg(feature = "portable-simd")]
+vec3_swizzles!(Vec3x16 => (f32x16, Vec2x16, Vec3x16, Vec4x16));
+#[cfg(feature = "portable-simd")]
+vec4_swizzles!(Vec4x16 => (Vec2x16, Vec3x16, Vec4x16));
+
+#[cfg(feature = "f64")]
+vec2_swizzles!(
+    DVec2 => (f64, DVec2, DVec3, DVec4),
+    DVec2x2 => (f64x2, DVec2x2, DVec3x2, DVec4x2),
+    DVec2x4 => (f64x4, DVec2x4, DVec3x4, DVec4x4)
+);
+#[cfg(feature = "f64")]
+vec3_swizzles!(
+    DVec3 => (f64, DVec2, DVec3, DVec4),
+    DVec3x2 => (f64x2, DVec2x2, DVec3x2, DVec4x2),
+    DVec3x4 => (f64x4, DVec2x4, DVec3x4, DVec4x4)
+);
+#[cfg(feature = "f64")]
+vec4_swizzles!(
+    DVec4 => (DVec2, DVec3, DVec4),
+    DVec4x2 => (DVec2x2, DVec3x2, DVec4x2),
+    DVec4x4 => (DVec2x4, DVec3x4, DVec4x4)
+);
+
+/// Generate the `with_*` methods for the given components, and the `set_*` methods in the given
+/// tables, each entry of which is the name of the method and the components it writes to.
+macro_rules! setter_fns {
+    ($t:ident, $v2t:ident, $v3t:ident;
+        [$($with:ident: $c:ident),+]
+        [$($n2:ident: $a2:ident $b2:ident),*]
+        [$($n3:ident: $a3:ident $b3:ident $c3:ident),*]) => {
+        $(#[doc = concat!("A copy of `self` with its `", stringify!($c), "` component replaced by `", stringify!($c), "`.")]
+        #[inline]
+        pub const fn $with(self, $c: $t) -> Self {
+            Self { $c, ..self }
+        })+
+
+        $(#[doc = concat!("Overwrite the `", stringify!($a2), "` and `", stringify!($b2), "` components of `self` with those of `v`.")]
+        #[inline]
+        pub fn $n2(&mut self, v: $v2t) {
+            self.$a2 = v.x;
+            self.$b2 = v.y;
+        })*
+
+        $(#[doc = concat!("Overwrite the `", stringify!($a3), "`, `", stringify!($b3), "` and `", stringify!($c3), "` components of `self` with those of `v`.")]
+        #[inline]
+        pub fn $n3(&mut self, v: $v3t) {
+            self.$a3 = v.x;
+            self.$b3 = v.y;
+            self.$c3 = v.z;
+        })*
+    };
+}
+
+macro_rules! vec2_setters {
+    ($($n:ident => $t:ident),+) => {
+        $(impl $n {
+            setter_fns!($t, $n, $n; [with_x: x, with_y: y] [] []);
+        })+
+    };
+}
+
+macro_rules! vec3_setters {
+    ($($n:ident => ($t:ident, $v2t:ident)),+) => {
+        $(impl $n {
+            setter_fns!($t, $v2t, $n;
+            [with_x: x, with_y: y, with_z: z]
+            [set_xy: x y, set_xz: x z, set_yz: y z]
+            []);
+        })+
+    };
+}
+
+macro_rules! vec4_setters {
+    ($($n:ident => ($t:ident, $v2t:ident, $v3t:ident)),+) => {
+        $(impl $n {
+            setter_fns!($t, $v2t, $v3t;
+            [with_x: x, with_y: y, with_z: z, with_w: w]
+            [
+                set_xy: x y, set_xz: x z, set_xw: x w, set_yz: y z, set_yw: y w,
+                set_zw: z w
+            ]
+            [set_xyz: x y z, set_xyw: x y w, set_xzw: x z w, set_yzw: y z w]);
+        })+
+    };
+}
+
+vec2_setters!(Vec2 => f32, Vec2x4 => f32x4, Vec2x8 => f32x8);
+vec3_setters!(
+    Vec3 => (f32, Vec2),
+    Vec3x4 => (f32x4, Vec2x4),
+    Vec3x8 => (f32x8, Vec2x8)
+);
+vec4_setters!(
+    Vec4 => (f32, Vec2, Vec3),
+    Vec4x4 => (f32x4, Vec2x4, Vec3x4),
+    Vec4x8 => (f32x8, Vec2x8, Vec3x8)
+);
+
+#[cfg(feature = "portable-simd")]
+vec2_setters!(Vec2x16 => f32x16);
+#[cfg(feature = "portable-simd")]
+vec3_setters!(Vec3x16 => (f32x16, Vec2x16));
+#[cfg(feature = "portable-simd")]
+vec4_setters!(Vec4x16 => (f32x16, Vec2x16, Vec3x16));
+
+#[cfg(feature = "f64")]
+vec2_setters!(DVec2 => f64, DVec2x2 => f64x2, DVec2x4 => f64x4);
+#[cfg(feature = "f64")]
+vec3_setters!(
+    DVec3 => (f64, DVec2),
+    DVec3x2 => (f64x2, DVec2x2),
+    DVec3x4 => (f64x4, DVec2x4)
+);
+#[cfg(feature = "f64")]
+vec4_setters!(
+    DVec4 => (f64, DVec2, DVec3),
+    DVec4x2 => (f64x2, DVec2x2, DVec3x2),
+    DVec4x4 => (f64x4, DVec2x4, DVec3x4)
+);
+
+#[cfg(feature = "int")]
+vec2_setters!(
+    IVec2 => i32, IVec2x4 => i32x4, IVec2x8 => i32x8,
+    UVec2 => u32, UVec2x4 => u32x4, UVec2x8 => u32x8,
+    U8Vec2 => u8, U16Vec2 => u16, I64Vec2 => i64
+);
+#[cfg(feature = "int")]
+vec3_setters!(
+    IVec3 => (i32, IVec2), IVec3x4 => (i32x4, IVec2x4), IVec3x8 => (i32x8, IVec2x8),
+    UVec3 => (u32, UVec2), UVec3x4 => (u32x4, UVec2x4), UVec3x8 => (u32x8, UVec2x8),
+    U8Vec3 => (u8, U8Vec2), U16Vec3 => (u16, U16Vec2), I64Vec3 => (i64, I64Vec2)
+);
+#[cfg(feature = "int")]
+vec4_setters!(
+    IVec4 => (i32, IVec2, IVec3),
+    IVec4x4 => (i32x4, IVec2x4, IVec3x4),
+    IVec4x8 => (i32x8, IVec2x8, IVec3x8),
+    UVec4 => (u32, UVec2, UVec3),
+    UVec4x4 => (u32x4, UVec2x4, UVec3x4),
+    UVec4x8 => (u32x8, UVec2x8, UVec3x8),
+    U8Vec4 => (u8, U8Vec2, U8Vec3),
+    U16Vec4 => (u16, U16Vec2, U16Vec3),
+    I64Vec4 => (i64, I64Vec2, I64Vec3)
+);
+
+macro_rules! resize_fns {
+    ($(($v2t:ident, $v3t:ident, $v4t:ident) => $t:ident),+) => {
+        $(impl $v2t {
+            /// Extend `self` to 3d with the given `z` component.
+            #[inline]
+            pub const fn extend(self, z: $t) -> $v3t {
+                $v3t::new(self.x, self.y, z)
+            }
+        }
+
+        impl $v3t {
+            /// Extend `self` to 4d with the given `w` component.
+            #[inline]
+            pub const fn extend(self, w: $t) -> $v4t {
+                $v4t::new(self.x, self.y, self.z, w)
+            }
+
+            /// Convert `self` to 2d by removing its `z` component.
+            ///
+            /// On the float vectors this is the same as `truncated`, but `const`.
+            #[inline]
+            pub const fn truncate(self) -> $v2t {
+                $v2t::new(self.x, self.y)
+            }
+        }
+
+        impl $v4t {
+            /// Convert `self` to 3d by removing its `w` component.
+            ///
+            /// On the float vectors this is the same as `truncated`, but `const`.
+            #[inline]
+            pub const fn truncate(self) -> $v3t {
+                $v3t::new(self.x, self.y, self.z)
+            }
+        })+
+    };
+}
+
+resize_fns!(
+    (Vec2, Vec3, Vec4) => f32,
+    (Vec2x4, Vec3x4, Vec4x4) => f32x4,
+    (Vec2x8, Vec3x8, Vec4x8) => f32x8
+);
+
+#[cfg(feature = "portable-simd")]
+resize_fns!((Vec2x16, Vec3x16, Vec4x16) => f32x16);
+
+#[cfg(feature = "f64")]
+resize_fns!(
+    (DVec2, DVec3, DVec4) => f64,
+    (DVec2x2, DVec3x2, DVec4x2) => f64x2,
+    (DVec2x4, DVec3x4, DVec4x4) => f64x4
+);
+
+#[cfg(feature = "int")]
+resize_fns!(
+    (IVec2, IVec3, IVec4) => i32,
+    (IVec2x4, IVec3x4, IVec4x4) => i32x4,
+    (IVec2x8, IVec3x8, IVec4x8) => i32x8,
+    (UVec2, UVec3, UVec4) => u32,
+    (UVec2x4, UVec3x4, UVec4x4) => u32x4,
+    (UVec2x8, UVec3x8, UVec4x8) => u32x8,
+    (U8Vec2, U8Vec3, U8Vec4) => u8,
+    (U16Vec2, U16Vec3, U16Vec4) => u16,
+    (I64Vec2, I64Vec3, I64Vec4) => i64
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn swizzles() {
+        let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.wzyx(), Vec4::new(4.0, 3.0, 2.0, 1.0));
+        assert_eq!(v.yyw(), Vec3::new(2.0, 2.0, 4.0));
+        assert_eq!(v.zx(), Vec2::new(3.0, 1.0));
+        assert_eq!(v.xyz().xyz0(), Vec4::new(1.0, 2.0, 3.0, 0.0));
+        assert_eq!(v.xy().yx().xy1(), Vec3::new(2.0, 1.0, 1.0));
+
+        let wide = Vec3x4::from([Vec3::new(1.0, 2.0, 3.0); 4]);
+        let lanes: [Vec2; 4] = wide.zy().into();
+        assert_eq!(lanes[3], Vec2::new(3.0, 2.0));
+    }
+
+    #[test]
+    fn setters() {
+        let v = Vec3::new(1.0, 2.0, 3.0).with_x(7.0).with_z(9.0);
+        assert_eq!(v, Vec3::new(7.0, 2.0, 9.0));
+
+        let mut u = Vec4::one();
+        u.set_xzw(Vec3::new(2.0, 3.0, 4.0));
+        u.set_xy(u.wz());
+        assert_eq!(u, Vec4::new(4.0, 3.0, 3.0, 4.0));
+
+        #[cfg(feature = "int")]
+        {
+            let mut i = IVec4::zero().with_w(1);
+            i.set_xy(IVec2::new(2, 3));
+            assert_eq!(i, IVec4::new(2, 3, 0, 1));
+        }
+    }
+
+    #[test]
+    fn resize() {
+        let v = Vec2x4::new(f32x4::splat(1.0), f32x4::splat(2.0));
+        assert_eq!(
+            v.extend(f32x4::ONE)
+                .extend(f32x4::ZERO)
+                .truncate()
+                .truncate(),
+            v
+        );
+        assert_eq!(
+            Vec3::new(1.0, 2.0, 3.0).extend(1.0),
+            Vec3::new(1.0, 2.0, 3.0).into_homogeneous_point()
+        );
+    }
+}
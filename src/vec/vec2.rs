@@ -1,4 +1,4 @@
-use std::ops::*;
+use core::ops::*;
 
 use crate::util::EqualsEps;
 use crate::*;
@@ -30,13 +30,13 @@ macro_rules! vec2s {
             }
 
             #[inline]
-            pub fn unit_x() -> Self {
-                $n{ x: $t::splat(1.0), y: $t::splat(0.0) }
+            pub const fn unit_x() -> Self {
+                $n{ x: <$t as Consts>::ONE, y: <$t as Consts>::ZERO }
             }
 
             #[inline]
-            pub fn unit_y() -> Self {
-                $n{ x: $t::splat(0.0), y: $t::splat(1.0) }
+            pub const fn unit_y() -> Self {
+                $n{ x: <$t as Consts>::ZERO, y: <$t as Consts>::ONE }
             }
 
             /// Create a homogeneous 2d *point* from this vector interpreted as a point,
@@ -69,6 +69,21 @@ macro_rules! vec2s {
                 v.into()
             }
 
+            /// Create a vector from polar coordinates, where `r` is the distance from the origin
+            /// and `theta` is the angle in radians measured from the +X axis towards the +Y axis.
+            #[inline]
+            pub fn from_polar(r: $t, theta: $t) -> Self {
+                let (s, c) = Transcendental::sin_cos(theta);
+                Self::new(r * c, r * s)
+            }
+
+            /// Convert this vector into polar coordinates `(r, theta)`, using the same convention
+            /// as [`Self::from_polar`]. `theta` is in the range `[-PI, PI]`.
+            #[inline]
+            pub fn into_polar(self) -> ($t, $t) {
+                (self.mag(), Transcendental::atan2(self.y, self.x))
+            }
+
             #[inline]
             pub fn dot(&self, other: $n) -> $t {
                 (self.x * other.x) + (self.y * other.y)
@@ -125,6 +140,42 @@ macro_rules! vec2s {
                 *self - ($t::splat(2.0) * self.dot(normal) * normal)
             }
 
+            /// The unsigned angle between `self` and `other` in radians, in the range `[0, PI]`.
+            ///
+            /// Neither vector needs to be normalized.
+            #[inline]
+            pub fn angle_between(&self, other: $n) -> $t {
+                Transcendental::atan2(self.wedge(other).xy.abs(), self.dot(other))
+            }
+
+            /// The signed angle in radians which rotates `self` onto `other`, in the range
+            /// `[-PI, PI]`. The angle is positive if the rotation is counterclockwise, i.e. from
+            /// +X towards +Y.
+            ///
+            /// Neither vector needs to be normalized.
+            #[inline]
+            pub fn signed_angle(&self, other: $n) -> $t {
+                Transcendental::atan2(self.wedge(other).xy, self.dot(other))
+            }
+
+            /// The vector projection of `self` onto `other`, i.e. the component of `self`
+            /// which is parallel to `other`.
+            ///
+            /// `other` does not need to be normalized, but must not be zero.
+            #[inline]
+            pub fn project_onto(&self, other: $n) -> Self {
+                other * (self.dot(other) / other.mag_sq())
+            }
+
+            /// The vector rejection of `self` from `other`, i.e. the component of `self`
+            /// which is perpendicular to `other`.
+            ///
+            /// `other` does not need to be normalized, but must not be zero.
+            #[inline]
+            pub fn reject_from(&self, other: $n) -> Self {
+                *self - self.project_onto(other)
+            }
+
             #[inline]
             pub fn mag_sq(&self) -> $t {
                 (self.x * self.x) + (self.y * self.y)
@@ -153,8 +204,8 @@ macro_rules! vec2s {
             #[inline]
             pub fn mul_add(&self, mul: $n, add: $n) -> Self {
                 $n::new(
-                    self.x.mul_add(mul.x, add.x),
-                    self.y.mul_add(mul.y, add.y),
+                    self.x.fused_mul_add(mul.x, add.x),
+                    self.y.fused_mul_add(mul.y, add.y),
                 )
             }
 
@@ -163,6 +214,50 @@ macro_rules! vec2s {
                 Self::new(self.x.abs(), self.y.abs())
             }
 
+            /// The largest integer less than or equal to each component.
+            #[inline]
+            pub fn floor(&self) -> Self {
+                Self::new(self.x.floor(), self.y.floor())
+            }
+
+            /// The smallest integer greater than or equal to each component.
+            #[inline]
+            pub fn ceil(&self) -> Self {
+                Self::new(self.x.ceil(), self.y.ceil())
+            }
+
+            /// Each component with the magnitude of `self` and the sign of the corresponding
+            /// component of `sign`.
+            #[inline]
+            pub fn copysign(&self, sign: Self) -> Self {
+                Self::new(self.x.copysign(sign.x), self.y.copysign(sign.y))
+            }
+
+            /// The square root of each component.
+            #[inline]
+            pub fn sqrt(&self) -> Self {
+                Self::new(self.x.sqrt(), self.y.sqrt())
+            }
+
+            /// `e` raised to the power of each component.
+            #[inline]
+            pub fn exp(&self) -> Self {
+                Self::new(Transcendental::exp(self.x), Transcendental::exp(self.y))
+            }
+
+            /// The natural logarithm of each component.
+            #[inline]
+            pub fn ln(&self) -> Self {
+                Self::new(Transcendental::ln(self.x), Transcendental::ln(self.y))
+            }
+
+            /// The reciprocal `1.0 / x` of each component.
+            #[inline]
+            pub fn recip(&self) -> Self {
+                let one = $t::splat(1.0);
+                Self::new(one / self.x, one / self.y)
+            }
+
             #[inline]
             pub fn clamp(&mut self, min: Self, max: Self) {
                 self.x = self.x.max(min.x).min(max.x);
@@ -218,20 +313,22 @@ macro_rules! vec2s {
             }
 
             #[inline]
-            pub fn zero() -> Self {
-                Self::broadcast($t::splat(0.0))
+            pub const fn zero() -> Self {
+                Self::broadcast(<$t as Consts>::ZERO)
             }
 
             #[inline]
-            pub fn one() -> Self {
-                Self::broadcast($t::splat(1.0))
+            pub const fn one() -> Self {
+                Self::broadcast(<$t as Consts>::ONE)
             }
 
+            /// Extend `self` to 3d with a `z` component of zero, see also [`Self::extend`].
             #[inline]
             pub fn xyz(&self) -> $v3t {
                 $v3t::new(self.x, self.y, $t::splat(0.0))
             }
 
+            /// Extend `self` to 4d with `z` and `w` components of zero.
             #[inline]
             pub fn xyzw(&self) -> $v4t {
                 $v4t::new(self.x, self.y, $t::splat(0.0), $t::splat(0.0))
@@ -240,7 +337,7 @@ macro_rules! vec2s {
             /// Get the [`core::alloc::Layout`] of `Self`
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$t>()).unwrap()
             }
 
             /// Interpret `self` as a statically-sized array of its base numeric type
@@ -263,7 +360,7 @@ macro_rules! vec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $t, 2)
+                    core::slice::from_raw_parts(self as *const $n as *const $t, 2)
                 }
             }
 
@@ -273,7 +370,7 @@ macro_rules! vec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 2)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 2)
                 }
             }
 
@@ -282,7 +379,7 @@ macro_rules! vec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const u8, 2 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts(self as *const $n as *const u8, 2 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -291,7 +388,7 @@ macro_rules! vec2s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 2 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 2 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -494,7 +591,7 @@ macro_rules! vec2s {
                 match index {
                     0 => &self.x,
                     1 => &self.y,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
@@ -504,12 +601,12 @@ macro_rules! vec2s {
                 match index {
                     0 => &mut self.x,
                     1 => &mut self.y,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
 
-        impl std::iter::Sum<$n> for $n {
+        impl core::iter::Sum<$n> for $n {
             fn sum<I>(iter: I) -> Self where I: Iterator<Item = Self> {
                 // Kahan summation algorithm
                 // https://en.wikipedia.org/wiki/Kahan_summation_algorithm
@@ -533,6 +630,41 @@ macro_rules! vec2s {
 macro_rules! impl_scalar_vec2s {
     ($(($vt:ident, $v3t:ident) => $t:ident),+) => {
         $(impl $vt {
+            /// Each component rounded to the nearest integer, with half-way cases rounded away
+            /// from zero, like `f32::round`.
+            #[inline]
+            pub fn round(&self) -> Self {
+                Self::new(self.x.round(), self.y.round())
+            }
+
+            /// The integer part of each component, i.e. each component rounded towards zero.
+            #[inline]
+            pub fn trunc(&self) -> Self {
+                Self::new(self.x.trunc(), self.y.trunc())
+            }
+
+            /// The fractional part of each component, i.e. `self - self.trunc()`.
+            ///
+            /// Note that this differs from `fract` in GLSL for negative values, which is
+            /// `self - self.floor()` instead.
+            #[inline]
+            pub fn fract(&self) -> Self {
+                Self::new(self.x.fract(), self.y.fract())
+            }
+
+            /// The sign of each component: 1.0 if it is positive or +0.0, -1.0 if it is
+            /// negative or -0.0, and NaN if it is NaN.
+            #[inline]
+            pub fn signum(&self) -> Self {
+                Self::new(self.x.signum(), self.y.signum())
+            }
+
+            /// Each component raised to the power `n`.
+            #[inline]
+            pub fn powf(&self, n: $t) -> Self {
+                Self::new(Transcendental::powf(self.x, n), Transcendental::powf(self.y, n))
+            }
+
             #[inline]
             pub fn refract(&mut self, normal: Self, eta: $t) {
                 *self = self.refracted(normal, eta);
@@ -595,6 +727,56 @@ macro_rules! impl_wide_vec2s {
                 }
             }
 
+            /// Each component rounded to the nearest integer, with half-way cases rounded away
+            /// from zero, like `f32::round`.
+            #[inline]
+            pub fn round(&self) -> Self {
+                let round = |c: $t| {
+                    // `abs - trunc` is exact, so half-way cases are found exactly.
+                    let abs = c.abs();
+                    let trunc = abs.floor();
+                    let up = (abs - trunc).cmp_ge($t::splat(0.5));
+                    up.blend(trunc + $t::splat(1.0), trunc).copysign(c)
+                };
+                Self::new(round(self.x), round(self.y))
+            }
+
+            /// The integer part of each component, i.e. each component rounded towards zero.
+            #[inline]
+            pub fn trunc(&self) -> Self {
+                Self::new(
+                    self.x.abs().floor().copysign(self.x),
+                    self.y.abs().floor().copysign(self.y),
+                )
+            }
+
+            /// The fractional part of each component, i.e. `self - self.trunc()`.
+            ///
+            /// Note that this differs from `fract` in GLSL for negative values, which is
+            /// `self - self.floor()` instead.
+            #[inline]
+            pub fn fract(&self) -> Self {
+                *self - self.trunc()
+            }
+
+            /// The sign of each component: 1.0 if it is positive or +0.0, -1.0 if it is
+            /// negative or -0.0, and NaN if it is NaN.
+            #[inline]
+            pub fn signum(&self) -> Self {
+                let one = $t::splat(1.0);
+                Self::new(
+                    self.x.is_nan().blend(self.x, one.copysign(self.x)),
+                    self.y.is_nan().blend(self.y, one.copysign(self.y)),
+                )
+            }
+
+            /// Each component raised to the power `n`.
+            #[inline]
+            pub fn powf(&self, n: $tt) -> Self {
+                let n = $t::splat(n);
+                Self::new(Transcendental::powf(self.x, n), Transcendental::powf(self.y, n))
+            }
+
             #[inline]
             pub fn refract(&mut self, normal: Self, eta: $t) {
                 *self = self.refracted(normal, eta);
@@ -737,12 +919,43 @@ impl From<[DVec2; 4]> for DVec2x4 {
     }
 }
 
+#[cfg(feature = "portable-simd")]
+impl From<Vec2x16> for [Vec2; 16] {
+    #[inline]
+    fn from(v: Vec2x16) -> [Vec2; 16] {
+        let (xs, ys) = (v.x.to_array(), v.y.to_array());
+        core::array::from_fn(|i| Vec2::new(xs[i], ys[i]))
+    }
+}
+
+#[cfg(feature = "portable-simd")]
+impl From<[Vec2; 16]> for Vec2x16 {
+    #[inline]
+    fn from(vecs: [Vec2; 16]) -> Self {
+        Self {
+            x: f32x16::from(vecs.map(|v| v.x)),
+            y: f32x16::from(vecs.map(|v| v.y)),
+        }
+    }
+}
+
+crate::util::impl_halves!(Vec2x8 => Vec2x4: (x, y));
+
+#[cfg(feature = "portable-simd")]
+crate::util::impl_halves!(Vec2x16 => Vec2x8: (x, y));
+
+#[cfg(feature = "f64")]
+crate::util::impl_halves!(DVec2x4 => DVec2x2: (x, y));
+
 vec2s!(
     (Vec2, Bivec2, Rotor2, Vec3, Vec4) => f32,
     (Vec2x4, Bivec2x4, Rotor2x4, Vec3x4, Vec4x4) => f32x4,
     (Vec2x8, Bivec2x8, Rotor2x8, Vec3x8, Vec4x8) => f32x8
 );
 
+#[cfg(feature = "portable-simd")]
+vec2s!((Vec2x16, Bivec2x16, Rotor2x16, Vec3x16, Vec4x16) => f32x16);
+
 #[cfg(feature = "f64")]
 vec2s!(
     (DVec2, DBivec2, DRotor2, DVec3, DVec4) => f64,
@@ -764,6 +977,9 @@ impl_wide_vec2s!(
     Vec2x8 => f32, f32x8, m32x8, Vec2, Vec3x8
 );
 
+#[cfg(feature = "portable-simd")]
+impl_wide_vec2s!(Vec2x16 => f32, f32x16, m32x16, Vec2, Vec3x16);
+
 #[cfg(feature = "f64")]
 impl_wide_vec2s!(
     DVec2x2 => f64, f64x2, m64x2, DVec2, DVec3x2,
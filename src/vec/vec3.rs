@@ -1,10 +1,10 @@
-use std::ops::*;
+use core::ops::*;
 
 use crate::util::EqualsEps;
 use crate::*;
 
 macro_rules! vec3s {
-    ($(($v2t:ident, $n:ident, $bn:ident, $rn:ident, $v4t:ident) => $t:ident),+) => {
+    ($($(#[$attr:meta])* ($v2t:ident, $n:ident, $bn:ident, $rn:ident, $v4t:ident) => $t:ident),+) => {
         $(/// A set of three coordinates which may be interpreted as a point or vector in 3d space,
         /// or as a homogeneous 2d vector or point.
         ///
@@ -13,6 +13,7 @@ macro_rules! vec3s {
         /// coordinates it is quite important.
         #[derive(Clone, Copy, Debug, Default, PartialEq)]
         #[repr(C)]
+        $(#[$attr])*
         pub struct $n {
             pub x: $t,
             pub y: $t,
@@ -31,18 +32,18 @@ macro_rules! vec3s {
             }
 
             #[inline]
-            pub fn unit_x() -> Self {
-                $n{ x: $t::splat(1.0), y: $t::splat(0.0), z: $t::splat(0.0) }
+            pub const fn unit_x() -> Self {
+                $n{ x: <$t as Consts>::ONE, y: <$t as Consts>::ZERO, z: <$t as Consts>::ZERO }
             }
 
             #[inline]
-            pub fn unit_y() -> Self {
-                $n{ x: $t::splat(0.0), y: $t::splat(1.0), z: $t::splat(0.0) }
+            pub const fn unit_y() -> Self {
+                $n{ x: <$t as Consts>::ZERO, y: <$t as Consts>::ONE, z: <$t as Consts>::ZERO }
             }
 
             #[inline]
-            pub fn unit_z() -> Self {
-                $n{ x: $t::splat(0.0), y: $t::splat(0.0), z: $t::splat(1.0) }
+            pub const fn unit_z() -> Self {
+                $n{ x: <$t as Consts>::ZERO, y: <$t as Consts>::ZERO, z: <$t as Consts>::ONE }
             }
 
             /// Create a homogeneous 3d *point* from this vector interpreted as a point,
@@ -75,6 +76,57 @@ macro_rules! vec3s {
                 v.into()
             }
 
+            /// Create a vector from spherical coordinates, with +Y as the polar axis.
+            ///
+            /// * `r` is the distance from the origin.
+            /// * `theta` is the polar angle (inclination) in radians, measured from the +Y axis.
+            /// * `phi` is the azimuthal angle in radians, measured in the XZ plane from the +X
+            ///   axis towards the +Z axis.
+            ///
+            /// That is, `x = r sin(theta) cos(phi)`, `y = r cos(theta)` and `z = r sin(theta) sin(phi)`.
+            #[inline]
+            pub fn from_spherical(r: $t, theta: $t, phi: $t) -> Self {
+                let (st, ct) = Transcendental::sin_cos(theta);
+                let (sp, cp) = Transcendental::sin_cos(phi);
+                Self::new(r * st * cp, r * ct, r * st * sp)
+            }
+
+            /// Convert this vector into spherical coordinates `(r, theta, phi)`, using the same
+            /// convention as [`Self::from_spherical`]. `theta` is in the range `[0, PI]` and `phi`
+            /// is in the range `[-PI, PI]`.
+            #[inline]
+            pub fn into_spherical(self) -> ($t, $t, $t) {
+                let rho = (self.x * self.x + self.z * self.z).sqrt();
+                (
+                    self.mag(),
+                    Transcendental::atan2(rho, self.y),
+                    Transcendental::atan2(self.z, self.x),
+                )
+            }
+
+            /// Create a vector from cylindrical coordinates, with +Y as the cylinder's axis.
+            ///
+            /// * `rho` is the distance from the Y axis.
+            /// * `phi` is the azimuthal angle in radians, measured in the XZ plane from the +X
+            ///   axis towards the +Z axis, as in [`Self::from_spherical`].
+            /// * `y` is the height along the Y axis.
+            #[inline]
+            pub fn from_cylindrical(rho: $t, phi: $t, y: $t) -> Self {
+                let (s, c) = Transcendental::sin_cos(phi);
+                Self::new(rho * c, y, rho * s)
+            }
+
+            /// Convert this vector into cylindrical coordinates `(rho, phi, y)`, using the same
+            /// convention as [`Self::from_cylindrical`]. `phi` is in the range `[-PI, PI]`.
+            #[inline]
+            pub fn into_cylindrical(self) -> ($t, $t, $t) {
+                (
+                    (self.x * self.x + self.z * self.z).sqrt(),
+                    Transcendental::atan2(self.z, self.x),
+                    self.y,
+                )
+            }
+
             #[inline]
             pub fn dot(&self, other: $n) -> $t {
                 (self.x * other.x) + (self.y * other.y) + (self.z * other.z)
@@ -111,13 +163,12 @@ macro_rules! vec3s {
 
             #[inline]
             pub fn rotate_by(&mut self, rotor: $rn) {
-                rotor.rotate_vec(self);
+                *self = rotor * *self;
             }
 
             #[inline]
-            pub fn rotated_by(mut self, rotor: $rn) -> Self {
-                rotor.rotate_vec(&mut self);
-                self
+            pub fn rotated_by(self, rotor: $rn) -> Self {
+                rotor * self
             }
 
             #[inline]
@@ -141,6 +192,55 @@ macro_rules! vec3s {
                 a
             }
 
+            /// The unsigned angle between `self` and `other` in radians, in the range `[0, PI]`.
+            ///
+            /// Neither vector needs to be normalized.
+            #[inline]
+            pub fn angle_between(&self, other: $n) -> $t {
+                Transcendental::atan2(self.cross(other).mag(), self.dot(other))
+            }
+
+            /// The signed angle in radians which rotates `self` onto `other` around `axis`, in
+            /// the range `[-PI, PI]`. The angle is positive if the rotation is counterclockwise
+            /// when looking down `axis` towards the origin, i.e. if `self.cross(other)` points
+            /// along `axis`.
+            ///
+            /// The magnitude is [`Self::angle_between`], so `self` and `other` should be
+            /// perpendicular to `axis` for the result to be the angle of a rotation around it.
+            /// None of the vectors need to be normalized.
+            #[inline]
+            pub fn signed_angle(&self, other: $n, axis: $n) -> $t {
+                let cross = self.cross(other);
+                Transcendental::atan2(cross.mag(), self.dot(other)).copysign(cross.dot(axis))
+            }
+
+            /// The vector projection of `self` onto `other`, i.e. the component of `self`
+            /// which is parallel to `other`.
+            ///
+            /// `other` does not need to be normalized, but must not be zero.
+            #[inline]
+            pub fn project_onto(&self, other: $n) -> Self {
+                other * (self.dot(other) / other.mag_sq())
+            }
+
+            /// The vector rejection of `self` from `other`, i.e. the component of `self`
+            /// which is perpendicular to `other`.
+            ///
+            /// `other` does not need to be normalized, but must not be zero.
+            #[inline]
+            pub fn reject_from(&self, other: $n) -> Self {
+                *self - self.project_onto(other)
+            }
+
+            /// Project `self` onto the plane through the origin with the given `normal`.
+            ///
+            /// This is the same as [`Self::reject_from`] the normal. `normal` does not need to be
+            /// normalized, but must not be zero.
+            #[inline]
+            pub fn project_onto_plane(&self, normal: $n) -> Self {
+                self.reject_from(normal)
+            }
+
             #[inline]
             pub fn mag_sq(&self) -> $t {
                 (self.x * self.x) + (self.y * self.y) + (self.z * self.z)
@@ -188,6 +288,8 @@ macro_rules! vec3s {
             }
 
             /// Convert `self` into a Vec2 by simply removing its `z` component.
+            ///
+            /// The same as [`Self::truncate`], which takes `self` by value and is `const`.
             #[inline]
             pub fn truncated(&self) -> $v2t {
                 $v2t::new(
@@ -199,9 +301,9 @@ macro_rules! vec3s {
             #[inline]
             pub fn mul_add(&self, mul: $n, add: $n) -> Self {
                 $n::new(
-                    self.x.mul_add(mul.x, add.x),
-                    self.y.mul_add(mul.y, add.y),
-                    self.z.mul_add(mul.z, add.z),
+                    self.x.fused_mul_add(mul.x, add.x),
+                    self.y.fused_mul_add(mul.y, add.y),
+                    self.z.fused_mul_add(mul.z, add.z),
                 )
             }
 
@@ -210,6 +312,58 @@ macro_rules! vec3s {
                 Self::new(self.x.abs(), self.y.abs(), self.z.abs())
             }
 
+            /// The largest integer less than or equal to each component.
+            #[inline]
+            pub fn floor(&self) -> Self {
+                Self::new(self.x.floor(), self.y.floor(), self.z.floor())
+            }
+
+            /// The smallest integer greater than or equal to each component.
+            #[inline]
+            pub fn ceil(&self) -> Self {
+                Self::new(self.x.ceil(), self.y.ceil(), self.z.ceil())
+            }
+
+            /// Each component with the magnitude of `self` and the sign of the corresponding
+            /// component of `sign`.
+            #[inline]
+            pub fn copysign(&self, sign: Self) -> Self {
+                Self::new(self.x.copysign(sign.x), self.y.copysign(sign.y), self.z.copysign(sign.z))
+            }
+
+            /// The square root of each component.
+            #[inline]
+            pub fn sqrt(&self) -> Self {
+                Self::new(self.x.sqrt(), self.y.sqrt(), self.z.sqrt())
+            }
+
+            /// `e` raised to the power of each component.
+            #[inline]
+            pub fn exp(&self) -> Self {
+                Self::new(
+                    Transcendental::exp(self.x),
+                    Transcendental::exp(self.y),
+                    Transcendental::exp(self.z),
+                )
+            }
+
+            /// The natural logarithm of each component.
+            #[inline]
+            pub fn ln(&self) -> Self {
+                Self::new(
+                    Transcendental::ln(self.x),
+                    Transcendental::ln(self.y),
+                    Transcendental::ln(self.z),
+                )
+            }
+
+            /// The reciprocal `1.0 / x` of each component.
+            #[inline]
+            pub fn recip(&self) -> Self {
+                let one = $t::splat(1.0);
+                Self::new(one / self.x, one / self.y, one / self.z)
+            }
+
             #[inline]
             pub fn clamp(&mut self, min: Self, max: Self) {
                 self.x = self.x.max(min.x).min(max.x);
@@ -270,13 +424,13 @@ macro_rules! vec3s {
             }
 
             #[inline]
-            pub fn zero() -> Self {
-                Self::broadcast($t::splat(0.0))
+            pub const fn zero() -> Self {
+                Self::broadcast(<$t as Consts>::ZERO)
             }
 
             #[inline]
-            pub fn one() -> Self {
-                Self::broadcast($t::splat(1.0))
+            pub const fn one() -> Self {
+                Self::broadcast(<$t as Consts>::ONE)
             }
 
             #[inline]
@@ -284,6 +438,7 @@ macro_rules! vec3s {
                 $v2t::new(self.x, self.y)
             }
 
+            /// Extend `self` to 4d with a `w` component of zero, see also [`Self::extend`].
             #[inline]
             pub fn xyzw(&self) -> $v4t {
                 $v4t::new(self.x, self.y, self.z, $t::splat(0.0))
@@ -292,7 +447,7 @@ macro_rules! vec3s {
             /// Get the [`core::alloc::Layout`] of `Self`
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<Self>()).unwrap()
             }
 
             /// Interpret `self` as a statically-sized array of its base numeric type
@@ -315,7 +470,7 @@ macro_rules! vec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $t, 3)
+                    core::slice::from_raw_parts(self as *const $n as *const $t, 3)
                 }
             }
 
@@ -325,7 +480,7 @@ macro_rules! vec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 3)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 3)
                 }
             }
 
@@ -334,7 +489,7 @@ macro_rules! vec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const u8, 3 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts(self as *const $n as *const u8, 3 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -343,7 +498,7 @@ macro_rules! vec3s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 3 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 3 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -553,7 +708,7 @@ macro_rules! vec3s {
                     0 => &self.x,
                     1 => &self.y,
                     2 => &self.z,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
@@ -564,12 +719,12 @@ macro_rules! vec3s {
                     0 => &mut self.x,
                     1 => &mut self.y,
                     2 => &mut self.z,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
 
-        impl std::iter::Sum<$n> for $n {
+        impl core::iter::Sum<$n> for $n {
             fn sum<I>(iter: I) -> Self where I: Iterator<Item = Self> {
                 // Kahan summation algorithm
                 // https://en.wikipedia.org/wiki/Kahan_summation_algorithm
@@ -593,6 +748,45 @@ macro_rules! vec3s {
 macro_rules! impl_scalar_vec3s {
     ($(($vt:ident, $v2t:ident, $v4t:ident) => $t:ident),+) => {
         $(impl $vt {
+            /// Each component rounded to the nearest integer, with half-way cases rounded away
+            /// from zero, like `f32::round`.
+            #[inline]
+            pub fn round(&self) -> Self {
+                Self::new(self.x.round(), self.y.round(), self.z.round())
+            }
+
+            /// The integer part of each component, i.e. each component rounded towards zero.
+            #[inline]
+            pub fn trunc(&self) -> Self {
+                Self::new(self.x.trunc(), self.y.trunc(), self.z.trunc())
+            }
+
+            /// The fractional part of each component, i.e. `self - self.trunc()`.
+            ///
+            /// Note that this differs from `fract` in GLSL for negative values, which is
+            /// `self - self.floor()` instead.
+            #[inline]
+            pub fn fract(&self) -> Self {
+                Self::new(self.x.fract(), self.y.fract(), self.z.fract())
+            }
+
+            /// The sign of each component: 1.0 if it is positive or +0.0, -1.0 if it is
+            /// negative or -0.0, and NaN if it is NaN.
+            #[inline]
+            pub fn signum(&self) -> Self {
+                Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+            }
+
+            /// Each component raised to the power `n`.
+            #[inline]
+            pub fn powf(&self, n: $t) -> Self {
+                Self::new(
+                    Transcendental::powf(self.x, n),
+                    Transcendental::powf(self.y, n),
+                    Transcendental::powf(self.z, n),
+                )
+            }
+
             #[inline]
             pub fn refract(&mut self, normal: Self, eta: $t) {
                 *self = self.refracted(normal, eta);
@@ -610,6 +804,22 @@ macro_rules! impl_scalar_vec3s {
                     i * eta - (eta * ndi + k.sqrt()) * n
                 }
             }
+
+            /// Construct two vectors which together with `self` form a right-handed
+            /// orthonormal basis, such that `t.cross(b) == self` for the returned `(t, b)`.
+            ///
+            /// `self` *must* be normalized. This uses the branchless method described in
+            /// "Building an Orthonormal Basis, Revisited" by Duff et al.
+            #[inline]
+            pub fn orthonormal_basis(&self) -> (Self, Self) {
+                let sign = (1.0 as $t).copysign(self.z);
+                let a = -1.0 / (sign + self.z);
+                let b = self.x * self.y * a;
+                (
+                    Self::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+                    Self::new(b, sign + self.y * self.y * a, -self.y),
+                )
+            }
         }
 
         impl From<$v2t> for $vt {
@@ -673,6 +883,62 @@ macro_rules! impl_wide_vec3s {
                 }
             }
 
+            /// Each component rounded to the nearest integer, with half-way cases rounded away
+            /// from zero, like `f32::round`.
+            #[inline]
+            pub fn round(&self) -> Self {
+                let round = |c: $t| {
+                    // `abs - trunc` is exact, so half-way cases are found exactly.
+                    let abs = c.abs();
+                    let trunc = abs.floor();
+                    let up = (abs - trunc).cmp_ge($t::splat(0.5));
+                    up.blend(trunc + $t::splat(1.0), trunc).copysign(c)
+                };
+                Self::new(round(self.x), round(self.y), round(self.z))
+            }
+
+            /// The integer part of each component, i.e. each component rounded towards zero.
+            #[inline]
+            pub fn trunc(&self) -> Self {
+                Self::new(
+                    self.x.abs().floor().copysign(self.x),
+                    self.y.abs().floor().copysign(self.y),
+                    self.z.abs().floor().copysign(self.z),
+                )
+            }
+
+            /// The fractional part of each component, i.e. `self - self.trunc()`.
+            ///
+            /// Note that this differs from `fract` in GLSL for negative values, which is
+            /// `self - self.floor()` instead.
+            #[inline]
+            pub fn fract(&self) -> Self {
+                *self - self.trunc()
+            }
+
+            /// The sign of each component: 1.0 if it is positive or +0.0, -1.0 if it is
+            /// negative or -0.0, and NaN if it is NaN.
+            #[inline]
+            pub fn signum(&self) -> Self {
+                let one = $t::splat(1.0);
+                Self::new(
+                    self.x.is_nan().blend(self.x, one.copysign(self.x)),
+                    self.y.is_nan().blend(self.y, one.copysign(self.y)),
+                    self.z.is_nan().blend(self.z, one.copysign(self.z)),
+                )
+            }
+
+            /// Each component raised to the power `n`.
+            #[inline]
+            pub fn powf(&self, n: $tt) -> Self {
+                let n = $t::splat(n);
+                Self::new(
+                    Transcendental::powf(self.x, n),
+                    Transcendental::powf(self.y, n),
+                    Transcendental::powf(self.z, n),
+                )
+            }
+
             #[inline]
             pub fn refract(&mut self, normal: Self, eta: $t) {
                 *self = self.refracted(normal, eta);
@@ -692,6 +958,23 @@ macro_rules! impl_wide_vec3s {
 
                 Self::blend(mask, Self::zero(), out)
             }
+
+            /// Construct two vectors which together with `self` form a right-handed
+            /// orthonormal basis, such that `t.cross(b) == self` for the returned `(t, b)`.
+            ///
+            /// `self` *must* be normalized. This uses the branchless method described in
+            /// "Building an Orthonormal Basis, Revisited" by Duff et al.
+            #[inline]
+            pub fn orthonormal_basis(&self) -> (Self, Self) {
+                let one = $t::splat(1.0);
+                let sign = one.flip_signs(self.z);
+                let a = -one / (sign + self.z);
+                let b = self.x * self.y * a;
+                (
+                    Self::new(one + sign * self.x * self.x * a, sign * b, -sign * self.x),
+                    Self::new(b, sign + self.y * self.y * a, -self.y),
+                )
+            }
         }
 
         impl From<$v2t> for $vt {
@@ -836,12 +1119,44 @@ impl From<[DVec3; 4]> for DVec3x4 {
     }
 }
 
+#[cfg(feature = "portable-simd")]
+impl From<Vec3x16> for [Vec3; 16] {
+    #[inline]
+    fn from(v: Vec3x16) -> [Vec3; 16] {
+        let (xs, ys, zs) = (v.x.to_array(), v.y.to_array(), v.z.to_array());
+        core::array::from_fn(|i| Vec3::new(xs[i], ys[i], zs[i]))
+    }
+}
+
+#[cfg(feature = "portable-simd")]
+impl From<[Vec3; 16]> for Vec3x16 {
+    #[inline]
+    fn from(vecs: [Vec3; 16]) -> Self {
+        Self {
+            x: f32x16::from(vecs.map(|v| v.x)),
+            y: f32x16::from(vecs.map(|v| v.y)),
+            z: f32x16::from(vecs.map(|v| v.z)),
+        }
+    }
+}
+
+crate::util::impl_halves!(Vec3x8 => Vec3x4: (x, y, z));
+
+#[cfg(feature = "portable-simd")]
+crate::util::impl_halves!(Vec3x16 => Vec3x8: (x, y, z));
+
+#[cfg(feature = "f64")]
+crate::util::impl_halves!(DVec3x4 => DVec3x2: (x, y, z));
+
 vec3s!(
     (Vec2, Vec3, Bivec3, Rotor3, Vec4) => f32,
     (Vec2x4, Vec3x4, Bivec3x4, Rotor3x4, Vec4x4) => f32x4,
     (Vec2x8, Vec3x8, Bivec3x8, Rotor3x8, Vec4x8) => f32x8
 );
 
+#[cfg(feature = "portable-simd")]
+vec3s!((Vec2x16, Vec3x16, Bivec3x16, Rotor3x16, Vec4x16) => f32x16);
+
 #[cfg(feature = "f64")]
 vec3s!(
     (DVec2, DVec3, DBivec3, DRotor3, DVec4) => f64,
@@ -863,8 +1178,179 @@ impl_wide_vec3s!(
     Vec3x8 => f32, f32x8, m32x8, Vec3, Vec2x8, Vec4x8
 );
 
+#[cfg(feature = "portable-simd")]
+impl_wide_vec3s!(Vec3x16 => f32, f32x16, m32x16, Vec3, Vec2x16, Vec4x16);
+
 #[cfg(feature = "f64")]
 impl_wide_vec3s!(
     DVec3x2 => f64, f64x2, m64x2, DVec3, DVec2x2, DVec4x2,
     DVec3x4 => f64, f64x4, m64x4, DVec3, DVec2x4, DVec4x4
 );
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn coordinate_conversions() {
+        let v = Vec3::new(1.0, -2.0, 3.0);
+        let (r, theta, phi) = v.into_spherical();
+        assert!(Vec3::from_spherical(r, theta, phi).eq_eps(v));
+        let (rho, phi, y) = v.into_cylindrical();
+        assert!(Vec3::from_cylindrical(rho, phi, y).eq_eps(v));
+        assert!(Vec3::from_spherical(2.0, 0.0, 1.0).eq_eps(Vec3::new(0.0, 2.0, 0.0)));
+        assert_eq!(Vec3::zero().into_spherical(), (0.0, 0.0, 0.0));
+
+        let (r, theta) = Vec2::new(-1.0, 1.0).into_polar();
+        assert!(r.eq_eps(2.0f32.sqrt()));
+        assert!(theta.eq_eps(core::f32::consts::FRAC_PI_4 * 3.0));
+        assert!(Vec2::from_polar(r, theta).eq_eps(Vec2::new(-1.0, 1.0)));
+
+        let vs = Vec3x4::from([
+            v,
+            Vec3::unit_x(),
+            -Vec3::unit_y(),
+            Vec3::new(0.5, 0.5, -4.0),
+        ]);
+        let (r, theta, phi) = vs.into_spherical();
+        assert!(Vec3x4::from_spherical(r, theta, phi).eq_eps(vs));
+    }
+
+    #[test]
+    fn angles_and_projections() {
+        let a = Vec3::new(2.0, 0.0, 0.0);
+        let b = Vec3::new(1.0, 1.0, 0.0);
+        assert!(a.angle_between(b).eq_eps(core::f32::consts::FRAC_PI_4));
+        assert!(a.angle_between(-a).eq_eps(core::f32::consts::PI));
+        assert!(b.project_onto(a).eq_eps(Vec3::new(1.0, 0.0, 0.0)));
+        assert!(b.reject_from(a).eq_eps(Vec3::new(0.0, 1.0, 0.0)));
+        assert!(b.project_onto_plane(Vec3::unit_y()).eq_eps(Vec3::unit_x()));
+
+        let (a, b) = (Vec2::new(1.0, 0.0), Vec2::new(0.0, -3.0));
+        assert!(a.angle_between(b).eq_eps(core::f32::consts::FRAC_PI_2));
+        assert!(a.signed_angle(b).eq_eps(-core::f32::consts::FRAC_PI_2));
+        assert!(b.signed_angle(a).eq_eps(core::f32::consts::FRAC_PI_2));
+        assert!(b.project_onto(a * 2.0).eq_eps(Vec2::zero()));
+        assert!(Vec2::new(3.0, 4.0)
+            .project_onto(b)
+            .eq_eps(Vec2::new(0.0, 4.0)));
+        assert!(Vec2::new(3.0, 4.0)
+            .reject_from(b)
+            .eq_eps(Vec2::new(3.0, 0.0)));
+
+        let (a, b) = (Vec3::unit_x(), Vec3::new(0.0, 0.0, -2.0));
+        let angle = core::f32::consts::FRAC_PI_2;
+        assert!(a.signed_angle(b, Vec3::unit_y()).eq_eps(angle));
+        assert!(a.signed_angle(b, -Vec3::unit_y()).eq_eps(-angle));
+        assert!(b.signed_angle(a, Vec3::unit_y()).eq_eps(-angle));
+        let rotated = Rotor3::from_rotation_xz(-0.7) * a;
+        assert!(a.signed_angle(rotated, Vec3::unit_y()).eq_eps(0.7));
+        let wide = Vec3x4::splat(a).signed_angle(Vec3x4::splat(b), Vec3x4::splat(-Vec3::unit_y()));
+        assert!(wide.eq_eps(f32x4::splat(-angle)));
+    }
+
+    #[test]
+    fn rounding() {
+        let v = Vec3::new(-1.5, 0.25, 2.75);
+        assert_eq!(v.floor(), Vec3::new(-2.0, 0.0, 2.0));
+        assert_eq!(v.ceil(), Vec3::new(-1.0, 1.0, 3.0));
+        assert_eq!(v.trunc(), Vec3::new(-1.0, 0.0, 2.0));
+        assert_eq!(v.fract(), Vec3::new(-0.5, 0.25, 0.75));
+        assert_eq!(v.signum(), Vec3::new(-1.0, 1.0, 1.0));
+        assert_eq!(v.copysign(-Vec3::one()), -v.abs());
+
+        let w = Vec3x4::from([v; 4]);
+        assert_eq!(w.floor(), Vec3x4::from([v.floor(); 4]));
+        assert_eq!(w.ceil(), Vec3x4::from([v.ceil(); 4]));
+        assert_eq!(w.trunc(), Vec3x4::from([v.trunc(); 4]));
+        assert_eq!(w.fract(), Vec3x4::from([v.fract(); 4]));
+        assert_eq!(w.signum(), Vec3x4::from([v.signum(); 4]));
+        let ties = Vec3::new(0.5, -1.5, 2.25);
+        assert_eq!(ties.round(), Vec3::new(1.0, -2.0, 2.0));
+        assert_eq!(
+            Vec3x4::from([ties; 4]).round(),
+            Vec3x4::from([ties.round(); 4])
+        );
+        let edges = Vec3::new(0.49999997, -0.0, 8388609.0);
+        assert_eq!(
+            Vec3x4::from([edges; 4]).round(),
+            Vec3x4::from([edges.round(); 4])
+        );
+        assert!(Vec3x4::splat(Vec3::broadcast(f32::NAN))
+            .round()
+            .x
+            .is_nan()
+            .all());
+    }
+
+    #[test]
+    fn componentwise_math() {
+        let v = Vec3::new(0.5, 1.0, 4.0);
+        assert!(v.sqrt().eq_eps(Vec3::new(0.5f32.sqrt(), 1.0, 2.0)));
+        assert!(v.powf(2.0).eq_eps(v * v));
+        assert!(v.ln().exp().eq_eps(v));
+        assert!(v.recip().eq_eps(Vec3::new(2.0, 1.0, 0.25)));
+
+        let w = Vec3x4::from([v; 4]);
+        assert!(w.sqrt().eq_eps(Vec3x4::from([v.sqrt(); 4])));
+        assert!(w.powf(2.0).eq_eps(Vec3x4::from([v.powf(2.0); 4])));
+        assert!(w.exp().eq_eps(Vec3x4::from([v.exp(); 4])));
+        assert!(w.ln().eq_eps(Vec3x4::from([v.ln(); 4])));
+        assert!(w.recip().eq_eps(Vec3x4::from([v.recip(); 4])));
+    }
+
+    #[test]
+    fn width_halves_roundtrip() {
+        let vecs: [Vec3; 8] =
+            core::array::from_fn(|i| Vec3::new(i as f32, -(i as f32), 0.5 * i as f32));
+        let wide = Vec3x8::from(vecs);
+        let halves: [Vec3x4; 2] = wide.into();
+        assert_eq!(
+            halves[0],
+            Vec3x4::from([vecs[0], vecs[1], vecs[2], vecs[3]])
+        );
+        assert_eq!(
+            halves[1],
+            Vec3x4::from([vecs[4], vecs[5], vecs[6], vecs[7]])
+        );
+        assert_eq!(Vec3x8::from(halves), wide);
+
+        let v2 = Vec2x8::from(vecs.map(|v| v.xy()));
+        assert_eq!(Vec2x8::from(<[Vec2x4; 2]>::from(v2)), v2);
+        let v4 = Vec4x8::from(vecs.map(|v| v.into_homogeneous_point()));
+        assert_eq!(Vec4x8::from(<[Vec4x4; 2]>::from(v4)), v4);
+
+        let rotor = Rotor3x8::from(vecs.map(|v| Rotor3::from_euler_angles(v.x, v.y, v.z)));
+        let [lo, hi]: [Rotor3x4; 2] = rotor.into();
+        assert_eq!(lo.s, f32x4::from(&<[f32; 8]>::from(rotor.s)[..4]));
+        assert_eq!(hi.bv.yz, f32x4::from(&<[f32; 8]>::from(rotor.bv.yz)[4..]));
+        assert_eq!(Rotor3x8::from([lo, hi]), rotor);
+        assert_eq!(Bivec3x8::from(<[Bivec3x4; 2]>::from(rotor.bv)), rotor.bv);
+    }
+
+    #[cfg(feature = "portable-simd")]
+    #[test]
+    fn vec3x16_matches_scalar() {
+        let vecs: [Vec3; 16] =
+            core::array::from_fn(|i| Vec3::new(i as f32 - 4.0, 1.0 - 0.5 * i as f32, 2.0));
+        let other = Vec3::new(0.5, -2.0, 3.0);
+        let wide = Vec3x16::from(vecs);
+        assert_eq!(<[Vec3; 16]>::from(wide), vecs);
+
+        let cross: [Vec3; 16] = wide.cross(Vec3x16::splat(other)).into();
+        let dot = wide.dot(Vec3x16::splat(other)).to_array();
+        let normalized: [Vec3; 16] = wide.normalized().into();
+        for i in 0..16 {
+            assert_eq!(cross[i], vecs[i].cross(other));
+            assert_eq!(dot[i], vecs[i].dot(other));
+            assert!(normalized[i].eq_eps(vecs[i].normalized()));
+        }
+
+        let halves: [Vec3x8; 2] = wide.into();
+        assert_eq!(
+            halves[1],
+            Vec3x8::from(core::array::from_fn(|i| vecs[8 + i]))
+        );
+        assert_eq!(Vec3x16::from(halves), wide);
+    }
+}
@@ -0,0 +1,673 @@
+//! A 3d vector stored in a single 16 byte SIMD register.
+use core::ops::*;
+
+use crate::util::EqualsEps;
+use crate::*;
+
+/// A set of three coordinates stored in the first three lanes of an [`f32x4`], whose fourth lane
+/// is always zero.
+///
+/// `Vec3A` is 16 bytes in size and aligned to 16 bytes, e.g. to match the layout of a `vec3` in
+/// GPU buffers or of other libraries' aligned vectors. Its arithmetic, dot and cross products,
+/// magnitude and normalization operate on the whole register at once.
+///
+/// It dereferences to [`Vec3`], which gives access to its `x`, `y` and `z` components as well as
+/// the rest of the `Vec3` API, such as [`Vec3::angle_between`], which takes and returns `Vec3`s.
+/// Since the fourth lane must stay zero, `Vec3A` is not `bytemuck::Pod`; use
+/// [`Vec3::as_byte_slice`] to get at its components.
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct Vec3A(f32x4);
+
+/// Zero the fourth lane of `v`, which e.g. a division by zero or a multiplication by infinity
+/// turns into `NaN`.
+#[inline]
+fn zero_w(v: f32x4) -> f32x4 {
+    f32x4::new([1.0, 1.0, 1.0, 0.0])
+        .cmp_ge(f32x4::ONE)
+        .blend(v, f32x4::ZERO)
+}
+
+/// The lanes of `v` rotated from `(x, y, z, w)` to `(y, z, x, w)`.
+#[inline]
+fn yzx(v: f32x4) -> f32x4 {
+    let [x, y, z, w] = v.to_array();
+    f32x4::new([y, z, x, w])
+}
+
+impl Vec3A {
+    #[inline]
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self(f32x4::new([x, y, z, 0.0]))
+    }
+
+    #[inline]
+    pub const fn broadcast(val: f32) -> Self {
+        Self::new(val, val, val)
+    }
+
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::broadcast(0.0)
+    }
+
+    #[inline]
+    pub const fn one() -> Self {
+        Self::broadcast(1.0)
+    }
+
+    #[inline]
+    pub const fn unit_x() -> Self {
+        Self::new(1.0, 0.0, 0.0)
+    }
+
+    #[inline]
+    pub const fn unit_y() -> Self {
+        Self::new(0.0, 1.0, 0.0)
+    }
+
+    #[inline]
+    pub const fn unit_z() -> Self {
+        Self::new(0.0, 0.0, 1.0)
+    }
+
+    /// The register holding `self`, whose fourth lane is zero.
+    #[inline]
+    pub fn to_f32x4(self) -> f32x4 {
+        self.0
+    }
+
+    #[inline]
+    pub fn dot(&self, other: Self) -> f32 {
+        (self.0 * other.0).reduce_add()
+    }
+
+    #[inline]
+    pub fn cross(&self, other: Self) -> Self {
+        // With `c = self * other.yzx - self.yzx * other`, the cross product is `c.yzx`.
+        let c = self.0 * yzx(other.0) - yzx(self.0) * other.0;
+        Self(yzx(c))
+    }
+
+    #[inline]
+    pub fn mag_sq(&self) -> f32 {
+        self.dot(*self)
+    }
+
+    #[inline]
+    pub fn mag(&self) -> f32 {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    #[must_use = "Did you mean to use `.normalize()` to normalize `self` in place?"]
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        *self / self.mag()
+    }
+
+    #[inline]
+    pub fn reflect(&mut self, normal: Self) {
+        *self -= 2.0 * self.dot(normal) * normal;
+    }
+
+    #[inline]
+    pub fn reflected(&self, normal: Self) -> Self {
+        let mut a = *self;
+        a.reflect(normal);
+        a
+    }
+
+    /// The vector projection of `self` onto `other`.
+    ///
+    /// `other` does not need to be normalized, but must not be zero.
+    #[inline]
+    pub fn project_onto(&self, other: Self) -> Self {
+        other * (self.dot(other) / other.mag_sq())
+    }
+
+    /// The vector rejection of `self` from `other`, i.e. the component of `self`
+    /// which is perpendicular to `other`.
+    ///
+    /// `other` does not need to be normalized, but must not be zero.
+    #[inline]
+    pub fn reject_from(&self, other: Self) -> Self {
+        *self - self.project_onto(other)
+    }
+
+    #[inline]
+    pub fn rotate_by(&mut self, rotor: Rotor3) {
+        *self = rotor * *self;
+    }
+
+    #[inline]
+    pub fn rotated_by(self, rotor: Rotor3) -> Self {
+        rotor * self
+    }
+
+    #[inline]
+    pub fn mul_add(&self, mul: Self, add: Self) -> Self {
+        Self(self.0.mul_add(mul.0, add.0))
+    }
+
+    #[inline]
+    pub fn abs(&self) -> Self {
+        Self(self.0.abs())
+    }
+
+    #[inline]
+    pub fn floor(&self) -> Self {
+        Self(self.0.floor())
+    }
+
+    #[inline]
+    pub fn ceil(&self) -> Self {
+        Self(self.0.ceil())
+    }
+
+    #[inline]
+    pub fn round(&self) -> Self {
+        Self(self.0.round())
+    }
+
+    #[inline]
+    pub fn sqrt(&self) -> Self {
+        Self(self.0.sqrt())
+    }
+
+    #[inline]
+    pub fn recip(&self) -> Self {
+        Self(zero_w(f32x4::ONE / self.0))
+    }
+
+    #[inline]
+    pub fn clamp(&mut self, min: Self, max: Self) {
+        *self = self.clamped(min, max);
+    }
+
+    #[inline]
+    pub fn clamped(self, min: Self, max: Self) -> Self {
+        self.max_by_component(min).min_by_component(max)
+    }
+
+    #[inline]
+    pub fn max_by_component(&self, other: Self) -> Self {
+        Self(self.0.max(other.0))
+    }
+
+    #[inline]
+    pub fn min_by_component(&self, other: Self) -> Self {
+        Self(self.0.min(other.0))
+    }
+
+    #[inline]
+    pub fn component_max(&self) -> f32 {
+        self.x.max(self.y).max(self.z)
+    }
+
+    #[inline]
+    pub fn component_min(&self) -> f32 {
+        self.x.min(self.y).min(self.z)
+    }
+
+    /// Extend `self` to 4d with the given `w` component.
+    #[inline]
+    pub fn extend(self, w: f32) -> Vec4 {
+        Vec4::new(self.x, self.y, self.z, w)
+    }
+
+    /// Convert `self` to 2d by removing its `z` component.
+    #[inline]
+    pub fn truncate(self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+
+    /// A copy of `self` with its `x` component replaced by `x`.
+    #[inline]
+    pub fn with_x(mut self, x: f32) -> Self {
+        self.x = x;
+        self
+    }
+
+    /// A copy of `self` with its `y` component replaced by `y`.
+    #[inline]
+    pub fn with_y(mut self, y: f32) -> Self {
+        self.y = y;
+        self
+    }
+
+    /// A copy of `self` with its `z` component replaced by `z`.
+    #[inline]
+    pub fn with_z(mut self, z: f32) -> Self {
+        self.z = z;
+        self
+    }
+
+    /// Overwrite the `x` and `y` components of `self` with those of `v`.
+    #[inline]
+    pub fn set_xy(&mut self, v: Vec2) {
+        self.x = v.x;
+        self.y = v.y;
+    }
+
+    /// Overwrite the `x` and `z` components of `self` with those of `v`.
+    #[inline]
+    pub fn set_xz(&mut self, v: Vec2) {
+        self.x = v.x;
+        self.z = v.y;
+    }
+
+    /// Overwrite the `y` and `z` components of `self` with those of `v`.
+    #[inline]
+    pub fn set_yz(&mut self, v: Vec2) {
+        self.y = v.x;
+        self.z = v.y;
+    }
+
+    /// Get the [`core::alloc::Layout`] of `Self`
+    #[inline]
+    pub fn layout() -> alloc::alloc::Layout {
+        alloc::alloc::Layout::from_size_align(
+            core::mem::size_of::<Self>(),
+            core::mem::align_of::<Self>(),
+        )
+        .unwrap()
+    }
+}
+
+impl Deref for Vec3A {
+    type Target = Vec3;
+
+    #[inline]
+    fn deref(&self) -> &Vec3 {
+        // SAFETY: an `f32x4` is four `f32`s in lane order, the first three of which have the
+        // layout of a `Vec3`, and its alignment is larger than that of `Vec3`.
+        unsafe { &*(self as *const Self as *const Vec3) }
+    }
+}
+
+impl DerefMut for Vec3A {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Vec3 {
+        // SAFETY: as for `deref`. Writing through the `Vec3` leaves the fourth lane untouched.
+        unsafe { &mut *(self as *mut Self as *mut Vec3) }
+    }
+}
+
+impl core::fmt::Debug for Vec3A {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Vec3A")
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .finish()
+    }
+}
+
+impl PartialEq for Vec3A {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl EqualsEps for Vec3A {
+    fn eq_eps(self, other: Self) -> bool {
+        (*self).eq_eps(*other)
+    }
+}
+
+impl From<Vec3> for Vec3A {
+    #[inline]
+    fn from(vec: Vec3) -> Self {
+        Self::new(vec.x, vec.y, vec.z)
+    }
+}
+
+impl From<Vec3A> for Vec3 {
+    #[inline]
+    fn from(vec: Vec3A) -> Self {
+        *vec
+    }
+}
+
+impl From<Vec2> for Vec3A {
+    #[inline]
+    fn from(vec: Vec2) -> Self {
+        Self::new(vec.x, vec.y, 0.0)
+    }
+}
+
+impl From<Vec4> for Vec3A {
+    #[inline]
+    fn from(vec: Vec4) -> Self {
+        Self::new(vec.x, vec.y, vec.z)
+    }
+}
+
+impl From<Vec3A> for Vec4 {
+    #[inline]
+    fn from(vec: Vec3A) -> Self {
+        Self::new(vec.x, vec.y, vec.z, 0.0)
+    }
+}
+
+impl From<Vec3A> for [f32; 3] {
+    #[inline]
+    fn from(v: Vec3A) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl From<[f32; 3]> for Vec3A {
+    #[inline]
+    fn from(comps: [f32; 3]) -> Self {
+        Self::new(comps[0], comps[1], comps[2])
+    }
+}
+
+impl From<&[f32; 3]> for Vec3A {
+    #[inline]
+    fn from(comps: &[f32; 3]) -> Self {
+        Self::from(*comps)
+    }
+}
+
+impl From<&mut [f32; 3]> for Vec3A {
+    #[inline]
+    fn from(comps: &mut [f32; 3]) -> Self {
+        Self::from(*comps)
+    }
+}
+
+impl From<(f32, f32, f32)> for Vec3A {
+    #[inline]
+    fn from(comps: (f32, f32, f32)) -> Self {
+        Self::new(comps.0, comps.1, comps.2)
+    }
+}
+
+impl From<&(f32, f32, f32)> for Vec3A {
+    #[inline]
+    fn from(comps: &(f32, f32, f32)) -> Self {
+        Self::from(*comps)
+    }
+}
+
+impl From<Vec3A> for (f32, f32, f32) {
+    #[inline]
+    fn from(v: Vec3A) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl Add for Vec3A {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Vec3A {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vec3A {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Vec3A {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Vec3A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Mul<Vec3A> for f32 {
+    type Output = Vec3A;
+    #[inline]
+    fn mul(self, rhs: Vec3A) -> Vec3A {
+        rhs * self
+    }
+}
+
+impl Mul<f32> for Vec3A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self(zero_w(self.0 * rhs))
+    }
+}
+
+impl MulAssign for Vec3A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<f32> for Vec3A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Vec3A {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self(zero_w(self.0 / rhs.0))
+    }
+}
+
+impl Div<f32> for Vec3A {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f32) -> Self {
+        Self(zero_w(self.0 / rhs))
+    }
+}
+
+impl DivAssign for Vec3A {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl DivAssign<f32> for Vec3A {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+impl Neg for Vec3A {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::zero() - self
+    }
+}
+
+impl Index<usize> for Vec3A {
+    type Output = f32;
+
+    fn index(&self, index: usize) -> &f32 {
+        &(**self)[index]
+    }
+}
+
+impl IndexMut<usize> for Vec3A {
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        &mut (**self)[index]
+    }
+}
+
+impl core::iter::Sum<Vec3A> for Vec3A {
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        // Kahan summation algorithm
+        // https://en.wikipedia.org/wiki/Kahan_summation_algorithm
+        let mut sum = Vec3A::zero();
+        let mut c = Vec3A::zero();
+        for v in iter {
+            let y = v - c;
+            let t = sum + y;
+            c = (t - sum) - y;
+            sum = t;
+        }
+        sum
+    }
+}
+
+impl Mul<Vec3A> for Rotor3 {
+    type Output = Vec3A;
+    #[inline]
+    fn mul(self, rhs: Vec3A) -> Vec3A {
+        Vec3A::from(self * *rhs)
+    }
+}
+
+impl Mul<Vec3A> for Mat3 {
+    type Output = Vec3A;
+    #[inline]
+    fn mul(self, rhs: Vec3A) -> Vec3A {
+        let [x, y, z] = self.cols.map(Vec3A::from);
+        x * rhs.x + y * rhs.y + z * rhs.z
+    }
+}
+
+/// Transforms `rhs` as a point, like [`Mat4::transform_point3`].
+impl Mul<Vec3A> for Mat4 {
+    type Output = Vec3A;
+    #[inline]
+    fn mul(self, rhs: Vec3A) -> Vec3A {
+        Vec3A::from(self.transform_point3(*rhs))
+    }
+}
+
+impl Mul<Vec3A> for Isometry3 {
+    type Output = Vec3A;
+    #[inline]
+    fn mul(self, rhs: Vec3A) -> Vec3A {
+        Vec3A::from(self * *rhs)
+    }
+}
+
+impl Mul<Vec3A> for Similarity3 {
+    type Output = Vec3A;
+    #[inline]
+    fn mul(self, rhs: Vec3A) -> Vec3A {
+        Vec3A::from(self * *rhs)
+    }
+}
+
+impl From<[Vec3A; 4]> for Vec3x4 {
+    #[inline]
+    fn from(vecs: [Vec3A; 4]) -> Self {
+        Self::from(vecs.map(Vec3::from))
+    }
+}
+
+impl From<[Vec3A; 8]> for Vec3x8 {
+    #[inline]
+    fn from(vecs: [Vec3A; 8]) -> Self {
+        Self::from(vecs.map(Vec3::from))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        assert_eq!(core::mem::size_of::<Vec3A>(), 16);
+        assert_eq!(core::mem::align_of::<Vec3A>(), 16);
+        assert_eq!(Vec3A::layout().align(), 16);
+        assert_eq!(
+            Vec3A::new(1.0, 2.0, 3.0).to_f32x4().to_array(),
+            [1.0, 2.0, 3.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec3A::new(1.0, 2.0, 3.0);
+        let b = Vec3A::from(Vec3::new(-2.0, 0.5, 1.0));
+        let (va, vb) = (Vec3::from(a), Vec3::from(b));
+        assert_eq!(Vec3::from(a + b), va + vb);
+        assert_eq!(Vec3::from(a - b), va - vb);
+        assert_eq!(Vec3::from(a * b), va * vb);
+        assert_eq!(Vec3::from(a / b), va / vb);
+        assert_eq!(Vec3::from(-a), -va);
+        assert_eq!(Vec3::from(2.0 * a), 2.0 * va);
+        assert_eq!(a.dot(b), va.dot(vb));
+        assert_eq!(Vec3::from(a.cross(b)), va.cross(vb));
+        assert!(Vec3::from(a.normalized()).eq_eps(va.normalized()));
+
+        // Operations which would turn a zero fourth lane into NaN leave it zero.
+        for v in [
+            a / 0.0,
+            a * f32::INFINITY,
+            a.recip(),
+            Vec3A::zero().normalized(),
+        ] {
+            assert_eq!(v.to_f32x4().to_array()[3], 0.0);
+        }
+    }
+
+    #[test]
+    fn vec3_api() {
+        let mut a = Vec3A::new(1.0, 2.0, 3.0);
+        let b = Vec3A::from(Vec3::new(-2.0, 0.5, 1.0));
+        assert_eq!(a.zyx(), Vec3A::new(3.0, 2.0, 1.0));
+        assert_eq!(a.extend(4.0).truncate(), Vec3::from(a));
+        assert_eq!(a.as_byte_slice().len(), 12);
+        assert_eq!(
+            a.angle_between(*b),
+            Vec3::from(a).angle_between(Vec3::from(b))
+        );
+        a.z = 5.0;
+        a[0] = -1.0;
+        assert_eq!(a, Vec3A::new(-1.0, 2.0, 5.0));
+        assert_eq!(a.to_f32x4().to_array()[3], 0.0);
+
+        let rotor = Rotor3::from_rotation_xy(1.0);
+        assert!(Vec3::from(a.rotated_by(rotor)).eq_eps(Vec3::from(a).rotated_by(rotor)));
+        assert_eq!(Vec3x4::from([a; 4]), Vec3x4::splat(Vec3::from(a)));
+
+        let mat = Mat4::from_translation(Vec3::unit_x()) * Mat4::from_scale(2.0);
+        assert_eq!(Vec3::from(mat * a), mat.transform_point3(Vec3::from(a)));
+        let mat3 = Mat3::from_rotation_z(1.0);
+        assert!(Vec3::from(mat3 * a).eq_eps(mat3 * Vec3::from(a)));
+        let iso = Isometry3::new(Vec3::unit_y(), rotor);
+        assert_eq!(Vec3::from(iso * a), iso * Vec3::from(a));
+        let sim = Similarity3::new(Vec3::unit_z(), rotor, 0.5);
+        assert_eq!(Vec3::from(sim * a), sim * Vec3::from(a));
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::*;
+use core::ops::*;
 
 use crate::util::EqualsEps;
 use crate::*;
@@ -32,23 +32,43 @@ macro_rules! vec4s {
             }
 
             #[inline]
-            pub fn unit_x() -> Self {
-                $n{ x: $t::splat(1.0), y: $t::splat(0.0), z: $t::splat(0.0), w: $t::splat(0.0) }
+            pub const fn unit_x() -> Self {
+                $n {
+                    x: <$t as Consts>::ONE,
+                    y: <$t as Consts>::ZERO,
+                    z: <$t as Consts>::ZERO,
+                    w: <$t as Consts>::ZERO,
+                }
             }
 
             #[inline]
-            pub fn unit_y() -> Self {
-                $n{ x: $t::splat(0.0), y: $t::splat(1.0), z: $t::splat(0.0), w: $t::splat(0.0) }
+            pub const fn unit_y() -> Self {
+                $n {
+                    x: <$t as Consts>::ZERO,
+                    y: <$t as Consts>::ONE,
+                    z: <$t as Consts>::ZERO,
+                    w: <$t as Consts>::ZERO,
+                }
             }
 
             #[inline]
-            pub fn unit_z() -> Self {
-                $n{ x: $t::splat(0.0), y: $t::splat(0.0), z: $t::splat(1.0), w: $t::splat(0.0) }
+            pub const fn unit_z() -> Self {
+                $n {
+                    x: <$t as Consts>::ZERO,
+                    y: <$t as Consts>::ZERO,
+                    z: <$t as Consts>::ONE,
+                    w: <$t as Consts>::ZERO,
+                }
             }
 
             #[inline]
-            pub fn unit_w() -> Self {
-                $n{ x: $t::splat(0.0), y: $t::splat(0.0), z: $t::splat(0.0), w: $t::splat(1.0) }
+            pub const fn unit_w() -> Self {
+                $n {
+                    x: <$t as Consts>::ZERO,
+                    y: <$t as Consts>::ZERO,
+                    z: <$t as Consts>::ZERO,
+                    w: <$t as Consts>::ONE,
+                }
             }
 
             #[inline]
@@ -117,6 +137,8 @@ macro_rules! vec4s {
             }
 
             /// Convert `self` into a Vec3 by simply removing its `w` component.
+            ///
+            /// The same as [`Self::truncate`], which takes `self` by value and is `const`.
             #[inline]
             pub fn truncated(&self) -> $v3t {
                 $v3t::new(
@@ -129,10 +151,10 @@ macro_rules! vec4s {
             #[inline]
             pub fn mul_add(&self, mul: $n, add: $n) -> Self {
                 $n::new(
-                    self.x.mul_add(mul.x, add.x),
-                    self.y.mul_add(mul.y, add.y),
-                    self.z.mul_add(mul.z, add.z),
-                    self.w.mul_add(mul.w, add.w),
+                    self.x.fused_mul_add(mul.x, add.x),
+                    self.y.fused_mul_add(mul.y, add.y),
+                    self.z.fused_mul_add(mul.z, add.z),
+                    self.w.fused_mul_add(mul.w, add.w),
                 )
             }
 
@@ -141,6 +163,65 @@ macro_rules! vec4s {
                 Self::new(self.x.abs(), self.y.abs(), self.z.abs(), self.w.abs())
             }
 
+            /// The largest integer less than or equal to each component.
+            #[inline]
+            pub fn floor(&self) -> Self {
+                Self::new(self.x.floor(), self.y.floor(), self.z.floor(), self.w.floor())
+            }
+
+            /// The smallest integer greater than or equal to each component.
+            #[inline]
+            pub fn ceil(&self) -> Self {
+                Self::new(self.x.ceil(), self.y.ceil(), self.z.ceil(), self.w.ceil())
+            }
+
+            /// Each component with the magnitude of `self` and the sign of the corresponding
+            /// component of `sign`.
+            #[inline]
+            pub fn copysign(&self, sign: Self) -> Self {
+                Self::new(
+                    self.x.copysign(sign.x),
+                    self.y.copysign(sign.y),
+                    self.z.copysign(sign.z),
+                    self.w.copysign(sign.w),
+                )
+            }
+
+            /// The square root of each component.
+            #[inline]
+            pub fn sqrt(&self) -> Self {
+                Self::new(self.x.sqrt(), self.y.sqrt(), self.z.sqrt(), self.w.sqrt())
+            }
+
+            /// `e` raised to the power of each component.
+            #[inline]
+            pub fn exp(&self) -> Self {
+                Self::new(
+                    Transcendental::exp(self.x),
+                    Transcendental::exp(self.y),
+                    Transcendental::exp(self.z),
+                    Transcendental::exp(self.w),
+                )
+            }
+
+            /// The natural logarithm of each component.
+            #[inline]
+            pub fn ln(&self) -> Self {
+                Self::new(
+                    Transcendental::ln(self.x),
+                    Transcendental::ln(self.y),
+                    Transcendental::ln(self.z),
+                    Transcendental::ln(self.w),
+                )
+            }
+
+            /// The reciprocal `1.0 / x` of each component.
+            #[inline]
+            pub fn recip(&self) -> Self {
+                let one = $t::splat(1.0);
+                Self::new(one / self.x, one / self.y, one / self.z, one / self.w)
+            }
+
             #[inline]
             pub fn clamp(&mut self, min: Self, max: Self) {
                 self.x = self.x.max(min.x).min(max.x);
@@ -206,13 +287,13 @@ macro_rules! vec4s {
             }
 
             #[inline]
-            pub fn zero() -> Self {
-                Self::broadcast($t::splat(0.0))
+            pub const fn zero() -> Self {
+                Self::broadcast(<$t as Consts>::ZERO)
             }
 
             #[inline]
-            pub fn one() -> Self {
-                Self::broadcast($t::splat(1.0))
+            pub const fn one() -> Self {
+                Self::broadcast(<$t as Consts>::ONE)
             }
 
             #[inline]
@@ -228,7 +309,7 @@ macro_rules! vec4s {
             /// Get the [`core::alloc::Layout`] of `Self`
             #[inline]
             pub fn layout() -> alloc::alloc::Layout {
-                alloc::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<$t>()).unwrap()
+                alloc::alloc::Layout::from_size_align(core::mem::size_of::<Self>(), core::mem::align_of::<$t>()).unwrap()
             }
 
             /// Interpret `self` as a statically-sized array of its base numeric type
@@ -251,7 +332,7 @@ macro_rules! vec4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const $t, 4)
+                    core::slice::from_raw_parts(self as *const $n as *const $t, 4)
                 }
             }
 
@@ -261,7 +342,7 @@ macro_rules! vec4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 4)
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut $t, 4)
                 }
             }
 
@@ -270,7 +351,7 @@ macro_rules! vec4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts(self as *const $n as *const u8, 4 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts(self as *const $n as *const u8, 4 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -279,7 +360,7 @@ macro_rules! vec4s {
                 // This is safe because we are statically bounding our slices to the size of these
                 // vectors
                 unsafe {
-                    std::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 4 * std::mem::size_of::<$t>())
+                    core::slice::from_raw_parts_mut(self as *mut $n as *mut u8, 4 * core::mem::size_of::<$t>())
                 }
             }
 
@@ -496,7 +577,7 @@ macro_rules! vec4s {
                     1 => &self.y,
                     2 => &self.z,
                     3 => &self.w,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
@@ -508,12 +589,12 @@ macro_rules! vec4s {
                     1 => &mut self.y,
                     2 => &mut self.z,
                     3 => &mut self.w,
-                    _ => panic!("Invalid for vector of type: {}", std::any::type_name::<$n>()),
+                    _ => panic!("Invalid for vector of type: {}", core::any::type_name::<$n>()),
                 }
             }
         }
 
-        impl std::iter::Sum<$n> for $n {
+        impl core::iter::Sum<$n> for $n {
             fn sum<I>(iter: I) -> Self where I: Iterator<Item = Self> {
                 // Kahan summation algorithm
                 // https://en.wikipedia.org/wiki/Kahan_summation_algorithm
@@ -537,6 +618,46 @@ macro_rules! vec4s {
 macro_rules! impl_scalar_vec4s {
     ($(($vt:ident, $v3t:ident) => $t:ident),+) => {
         $(impl $vt {
+            /// Each component rounded to the nearest integer, with half-way cases rounded away
+            /// from zero, like `f32::round`.
+            #[inline]
+            pub fn round(&self) -> Self {
+                Self::new(self.x.round(), self.y.round(), self.z.round(), self.w.round())
+            }
+
+            /// The integer part of each component, i.e. each component rounded towards zero.
+            #[inline]
+            pub fn trunc(&self) -> Self {
+                Self::new(self.x.trunc(), self.y.trunc(), self.z.trunc(), self.w.trunc())
+            }
+
+            /// The fractional part of each component, i.e. `self - self.trunc()`.
+            ///
+            /// Note that this differs from `fract` in GLSL for negative values, which is
+            /// `self - self.floor()` instead.
+            #[inline]
+            pub fn fract(&self) -> Self {
+                Self::new(self.x.fract(), self.y.fract(), self.z.fract(), self.w.fract())
+            }
+
+            /// The sign of each component: 1.0 if it is positive or +0.0, -1.0 if it is
+            /// negative or -0.0, and NaN if it is NaN.
+            #[inline]
+            pub fn signum(&self) -> Self {
+                Self::new(self.x.signum(), self.y.signum(), self.z.signum(), self.w.signum())
+            }
+
+            /// Each component raised to the power `n`.
+            #[inline]
+            pub fn powf(&self, n: $t) -> Self {
+                Self::new(
+                    Transcendental::powf(self.x, n),
+                    Transcendental::powf(self.y, n),
+                    Transcendental::powf(self.z, n),
+                    Transcendental::powf(self.w, n),
+                )
+            }
+
             #[inline]
             pub fn refract(&mut self, normal: Self, eta: $t) {
                 *self = self.refracted(normal, eta);
@@ -609,6 +730,65 @@ macro_rules! impl_wide_vec4s {
                     w: mask.blend(tru.w, fals.w),
                 }
             }
+
+            /// Each component rounded to the nearest integer, with half-way cases rounded away
+            /// from zero, like `f32::round`.
+            #[inline]
+            pub fn round(&self) -> Self {
+                let round = |c: $t| {
+                    // `abs - trunc` is exact, so half-way cases are found exactly.
+                    let abs = c.abs();
+                    let trunc = abs.floor();
+                    let up = (abs - trunc).cmp_ge($t::splat(0.5));
+                    up.blend(trunc + $t::splat(1.0), trunc).copysign(c)
+                };
+                Self::new(round(self.x), round(self.y), round(self.z), round(self.w))
+            }
+
+            /// The integer part of each component, i.e. each component rounded towards zero.
+            #[inline]
+            pub fn trunc(&self) -> Self {
+                Self::new(
+                    self.x.abs().floor().copysign(self.x),
+                    self.y.abs().floor().copysign(self.y),
+                    self.z.abs().floor().copysign(self.z),
+                    self.w.abs().floor().copysign(self.w),
+                )
+            }
+
+            /// The fractional part of each component, i.e. `self - self.trunc()`.
+            ///
+            /// Note that this differs from `fract` in GLSL for negative values, which is
+            /// `self - self.floor()` instead.
+            #[inline]
+            pub fn fract(&self) -> Self {
+                *self - self.trunc()
+            }
+
+            /// The sign of each component: 1.0 if it is positive or +0.0, -1.0 if it is
+            /// negative or -0.0, and NaN if it is NaN.
+            #[inline]
+            pub fn signum(&self) -> Self {
+                let one = $t::splat(1.0);
+                Self::new(
+                    self.x.is_nan().blend(self.x, one.copysign(self.x)),
+                    self.y.is_nan().blend(self.y, one.copysign(self.y)),
+                    self.z.is_nan().blend(self.z, one.copysign(self.z)),
+                    self.w.is_nan().blend(self.w, one.copysign(self.w)),
+                )
+            }
+
+            /// Each component raised to the power `n`.
+            #[inline]
+            pub fn powf(&self, n: $tt) -> Self {
+                let n = $t::splat(n);
+                Self::new(
+                    Transcendental::powf(self.x, n),
+                    Transcendental::powf(self.y, n),
+                    Transcendental::powf(self.z, n),
+                    Transcendental::powf(self.w, n),
+                )
+            }
         }
 
         impl From<$nonwidet> for $vt {
@@ -762,12 +942,50 @@ impl From<[DVec4; 4]> for DVec4x4 {
     }
 }
 
+#[cfg(feature = "portable-simd")]
+impl From<Vec4x16> for [Vec4; 16] {
+    #[inline]
+    fn from(v: Vec4x16) -> [Vec4; 16] {
+        let (xs, ys, zs, ws) = (
+            v.x.to_array(),
+            v.y.to_array(),
+            v.z.to_array(),
+            v.w.to_array(),
+        );
+        core::array::from_fn(|i| Vec4::new(xs[i], ys[i], zs[i], ws[i]))
+    }
+}
+
+#[cfg(feature = "portable-simd")]
+impl From<[Vec4; 16]> for Vec4x16 {
+    #[inline]
+    fn from(vecs: [Vec4; 16]) -> Self {
+        Self {
+            x: f32x16::from(vecs.map(|v| v.x)),
+            y: f32x16::from(vecs.map(|v| v.y)),
+            z: f32x16::from(vecs.map(|v| v.z)),
+            w: f32x16::from(vecs.map(|v| v.w)),
+        }
+    }
+}
+
+crate::util::impl_halves!(Vec4x8 => Vec4x4: (x, y, z, w));
+
+#[cfg(feature = "portable-simd")]
+crate::util::impl_halves!(Vec4x16 => Vec4x8: (x, y, z, w));
+
+#[cfg(feature = "f64")]
+crate::util::impl_halves!(DVec4x4 => DVec4x2: (x, y, z, w));
+
 vec4s!(
     Vec4, Vec2, Vec3 => f32,
     Vec4x4, Vec2x4, Vec3x4 => f32x4,
     Vec4x8, Vec2x8, Vec3x8 => f32x8
 );
 
+#[cfg(feature = "portable-simd")]
+vec4s!(Vec4x16, Vec2x16, Vec3x16 => f32x16);
+
 #[cfg(feature = "f64")]
 vec4s!(
     DVec4, DVec2, DVec3 => f64,
@@ -789,6 +1007,9 @@ impl_wide_vec4s!(
     Vec4x8 => f32, f32x8, m32x8, Vec4, Vec3x8
 );
 
+#[cfg(feature = "portable-simd")]
+impl_wide_vec4s!(Vec4x16 => f32, f32x16, m32x16, Vec4, Vec3x16);
+
 #[cfg(feature = "f64")]
 impl_wide_vec4s!(
     DVec4x2 => f64, f64x2, m64x2, DVec4, DVec3x2,